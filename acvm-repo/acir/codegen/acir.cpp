@@ -818,13 +818,52 @@ namespace Circuit {
             static MemoryInit bincodeDeserialize(std::vector<uint8_t>);
         };
 
-        std::variant<Arithmetic, BlackBoxFuncCall, Directive, Brillig, MemoryOp, MemoryInit> value;
+        struct BrilligCall {
+            uint32_t id;
+            std::vector<Circuit::BrilligInputs> inputs;
+            std::vector<Circuit::BrilligOutputs> outputs;
+            std::optional<Circuit::Expression> predicate;
+
+            friend bool operator==(const BrilligCall&, const BrilligCall&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static BrilligCall bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Lookup {
+            uint32_t table_id;
+            Circuit::Expression key;
+            std::vector<Circuit::Witness> values;
+            std::optional<Circuit::Expression> predicate;
+
+            friend bool operator==(const Lookup&, const Lookup&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Lookup bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Arithmetic, BlackBoxFuncCall, Directive, Brillig, MemoryOp, MemoryInit, BrilligCall, Lookup> value;
 
         friend bool operator==(const Opcode&, const Opcode&);
         std::vector<uint8_t> bincodeSerialize() const;
         static Opcode bincodeDeserialize(std::vector<uint8_t>);
     };
 
+    struct BrilligBytecode {
+        std::vector<Circuit::BrilligOpcode> bytecode;
+
+        friend bool operator==(const BrilligBytecode&, const BrilligBytecode&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static BrilligBytecode bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct LookupTable {
+        std::vector<std::string> keys;
+        std::vector<std::vector<std::string>> value_columns;
+
+        friend bool operator==(const LookupTable&, const LookupTable&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static LookupTable bincodeDeserialize(std::vector<uint8_t>);
+    };
+
     struct OpcodeLocation {
 
         struct Acir {
@@ -866,6 +905,8 @@ namespace Circuit {
         Circuit::PublicInputs public_parameters;
         Circuit::PublicInputs return_values;
         std::vector<std::tuple<Circuit::OpcodeLocation, std::string>> assert_messages;
+        std::vector<Circuit::BrilligBytecode> unconstrained_functions;
+        std::vector<Circuit::LookupTable> lookup_tables;
 
         friend bool operator==(const Circuit&, const Circuit&);
         std::vector<uint8_t> bincodeSerialize() const;
@@ -2881,6 +2922,48 @@ Circuit::Brillig serde::Deserializable<Circuit::Brillig>::deserialize(Deserializ
     return obj;
 }
 
+namespace Circuit {
+
+    inline bool operator==(const BrilligBytecode &lhs, const BrilligBytecode &rhs) {
+        if (!(lhs.bytecode == rhs.bytecode)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> BrilligBytecode::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<BrilligBytecode>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline BrilligBytecode BrilligBytecode::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<BrilligBytecode>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace Circuit
+
+template <>
+template <typename Serializer>
+void serde::Serializable<Circuit::BrilligBytecode>::serialize(const Circuit::BrilligBytecode &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.bytecode)>::serialize(obj.bytecode, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+Circuit::BrilligBytecode serde::Deserializable<Circuit::BrilligBytecode>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    Circuit::BrilligBytecode obj;
+    obj.bytecode = serde::Deserializable<decltype(obj.bytecode)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
 namespace Circuit {
 
     inline bool operator==(const BrilligInputs &lhs, const BrilligInputs &rhs) {
@@ -3774,6 +3857,8 @@ namespace Circuit {
         if (!(lhs.public_parameters == rhs.public_parameters)) { return false; }
         if (!(lhs.return_values == rhs.return_values)) { return false; }
         if (!(lhs.assert_messages == rhs.assert_messages)) { return false; }
+        if (!(lhs.unconstrained_functions == rhs.unconstrained_functions)) { return false; }
+        if (!(lhs.lookup_tables == rhs.lookup_tables)) { return false; }
         return true;
     }
 
@@ -3804,6 +3889,8 @@ void serde::Serializable<Circuit::Circuit>::serialize(const Circuit::Circuit &ob
     serde::Serializable<decltype(obj.public_parameters)>::serialize(obj.public_parameters, serializer);
     serde::Serializable<decltype(obj.return_values)>::serialize(obj.return_values, serializer);
     serde::Serializable<decltype(obj.assert_messages)>::serialize(obj.assert_messages, serializer);
+    serde::Serializable<decltype(obj.unconstrained_functions)>::serialize(obj.unconstrained_functions, serializer);
+    serde::Serializable<decltype(obj.lookup_tables)>::serialize(obj.lookup_tables, serializer);
     serializer.decrease_container_depth();
 }
 
@@ -3818,6 +3905,8 @@ Circuit::Circuit serde::Deserializable<Circuit::Circuit>::deserialize(Deserializ
     obj.public_parameters = serde::Deserializable<decltype(obj.public_parameters)>::deserialize(deserializer);
     obj.return_values = serde::Deserializable<decltype(obj.return_values)>::deserialize(deserializer);
     obj.assert_messages = serde::Deserializable<decltype(obj.assert_messages)>::deserialize(deserializer);
+    obj.unconstrained_functions = serde::Deserializable<decltype(obj.unconstrained_functions)>::deserialize(deserializer);
+    obj.lookup_tables = serde::Deserializable<decltype(obj.lookup_tables)>::deserialize(deserializer);
     deserializer.decrease_container_depth();
     return obj;
 }
@@ -4176,6 +4265,51 @@ Circuit::HeapVector serde::Deserializable<Circuit::HeapVector>::deserialize(Dese
     return obj;
 }
 
+namespace Circuit {
+
+    inline bool operator==(const LookupTable &lhs, const LookupTable &rhs) {
+        if (!(lhs.keys == rhs.keys)) { return false; }
+        if (!(lhs.value_columns == rhs.value_columns)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> LookupTable::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<LookupTable>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline LookupTable LookupTable::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<LookupTable>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace Circuit
+
+template <>
+template <typename Serializer>
+void serde::Serializable<Circuit::LookupTable>::serialize(const Circuit::LookupTable &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.keys)>::serialize(obj.keys, serializer);
+    serde::Serializable<decltype(obj.value_columns)>::serialize(obj.value_columns, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+Circuit::LookupTable serde::Deserializable<Circuit::LookupTable>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    Circuit::LookupTable obj;
+    obj.keys = serde::Deserializable<decltype(obj.keys)>::deserialize(deserializer);
+    obj.value_columns = serde::Deserializable<decltype(obj.value_columns)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
 namespace Circuit {
 
     inline bool operator==(const MemOp &lhs, const MemOp &rhs) {
@@ -4503,6 +4637,100 @@ Circuit::Opcode::MemoryInit serde::Deserializable<Circuit::Opcode::MemoryInit>::
     return obj;
 }
 
+namespace Circuit {
+
+    inline bool operator==(const Opcode::BrilligCall &lhs, const Opcode::BrilligCall &rhs) {
+        if (!(lhs.id == rhs.id)) { return false; }
+        if (!(lhs.inputs == rhs.inputs)) { return false; }
+        if (!(lhs.outputs == rhs.outputs)) { return false; }
+        if (!(lhs.predicate == rhs.predicate)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Opcode::BrilligCall::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Opcode::BrilligCall>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Opcode::BrilligCall Opcode::BrilligCall::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Opcode::BrilligCall>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace Circuit
+
+template <>
+template <typename Serializer>
+void serde::Serializable<Circuit::Opcode::BrilligCall>::serialize(const Circuit::Opcode::BrilligCall &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.id)>::serialize(obj.id, serializer);
+    serde::Serializable<decltype(obj.inputs)>::serialize(obj.inputs, serializer);
+    serde::Serializable<decltype(obj.outputs)>::serialize(obj.outputs, serializer);
+    serde::Serializable<decltype(obj.predicate)>::serialize(obj.predicate, serializer);
+}
+
+template <>
+template <typename Deserializer>
+Circuit::Opcode::BrilligCall serde::Deserializable<Circuit::Opcode::BrilligCall>::deserialize(Deserializer &deserializer) {
+    Circuit::Opcode::BrilligCall obj;
+    obj.id = serde::Deserializable<decltype(obj.id)>::deserialize(deserializer);
+    obj.inputs = serde::Deserializable<decltype(obj.inputs)>::deserialize(deserializer);
+    obj.outputs = serde::Deserializable<decltype(obj.outputs)>::deserialize(deserializer);
+    obj.predicate = serde::Deserializable<decltype(obj.predicate)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace Circuit {
+
+    inline bool operator==(const Opcode::Lookup &lhs, const Opcode::Lookup &rhs) {
+        if (!(lhs.table_id == rhs.table_id)) { return false; }
+        if (!(lhs.key == rhs.key)) { return false; }
+        if (!(lhs.values == rhs.values)) { return false; }
+        if (!(lhs.predicate == rhs.predicate)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Opcode::Lookup::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Opcode::Lookup>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Opcode::Lookup Opcode::Lookup::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Opcode::Lookup>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace Circuit
+
+template <>
+template <typename Serializer>
+void serde::Serializable<Circuit::Opcode::Lookup>::serialize(const Circuit::Opcode::Lookup &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.table_id)>::serialize(obj.table_id, serializer);
+    serde::Serializable<decltype(obj.key)>::serialize(obj.key, serializer);
+    serde::Serializable<decltype(obj.values)>::serialize(obj.values, serializer);
+    serde::Serializable<decltype(obj.predicate)>::serialize(obj.predicate, serializer);
+}
+
+template <>
+template <typename Deserializer>
+Circuit::Opcode::Lookup serde::Deserializable<Circuit::Opcode::Lookup>::deserialize(Deserializer &deserializer) {
+    Circuit::Opcode::Lookup obj;
+    obj.table_id = serde::Deserializable<decltype(obj.table_id)>::deserialize(deserializer);
+    obj.key = serde::Deserializable<decltype(obj.key)>::deserialize(deserializer);
+    obj.values = serde::Deserializable<decltype(obj.values)>::deserialize(deserializer);
+    obj.predicate = serde::Deserializable<decltype(obj.predicate)>::deserialize(deserializer);
+    return obj;
+}
+
 namespace Circuit {
 
     inline bool operator==(const OpcodeLocation &lhs, const OpcodeLocation &rhs) {
@@ -27,3 +27,13 @@ pub struct Brillig {
     /// Predicate of the Brillig execution - indicates if it should be skipped
     pub predicate: Option<Expression>,
 }
+
+/// A Brillig function which is shared between every [`Opcode::BrilligCall`][crate::circuit::Opcode::BrilligCall]
+/// which references it.
+///
+/// Storing the bytecode once in the [`Circuit`][crate::circuit::Circuit] rather than inside each call-site
+/// avoids embedding the same unconstrained function many times in the serialized artifact.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct BrilligBytecode {
+    pub bytecode: Vec<BrilligOpcode>,
+}
@@ -0,0 +1,43 @@
+use acir_field::FieldElement;
+use serde::{Deserialize, Serialize};
+
+/// A table which [`Opcode::Lookup`][crate::circuit::Opcode::Lookup]s constrain their inputs against.
+///
+/// Each row of the table is made of a key and the values associated with it.
+/// Tables are stored once in the [`Circuit`][crate::circuit::Circuit] and referenced by their index,
+/// so that many lookups may share the same table.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Default)]
+pub struct LookupTable {
+    /// The key of each row of the table. Keys must be distinct.
+    pub keys: Vec<FieldElement>,
+    /// The columns of values associated with the keys. Each column holds one value per key.
+    pub value_columns: Vec<Vec<FieldElement>>,
+}
+
+impl LookupTable {
+    /// Returns the index of the row with the given `key`, if there is one.
+    pub fn find_row(&self, key: FieldElement) -> Option<usize> {
+        self.keys.iter().position(|row_key| *row_key == key)
+    }
+
+    /// Returns the values of the row at index `row`.
+    pub fn row_values(&self, row: usize) -> impl Iterator<Item = FieldElement> + '_ {
+        self.value_columns.iter().map(move |column| column[row])
+    }
+
+    /// Returns true if every column holds one value per key and no key is repeated.
+    pub fn is_well_formed(&self) -> bool {
+        let columns_match_keys =
+            self.value_columns.iter().all(|column| column.len() == self.keys.len());
+        let keys_are_distinct =
+            self.keys.iter().enumerate().all(|(row, key)| self.find_row(*key) == Some(row));
+        columns_match_keys && keys_are_distinct
+    }
+
+    /// Returns true if a lookup assigning `num_values` witnesses assigns one per value column.
+    ///
+    /// A lookup with more witnesses than columns would leave the extra witnesses unconstrained.
+    pub fn accepts_values(&self, num_values: usize) -> bool {
+        self.value_columns.len() == num_values
+    }
+}
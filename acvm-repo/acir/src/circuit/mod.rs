@@ -1,9 +1,12 @@
 pub mod black_box_functions;
 pub mod brillig;
 pub mod directives;
+pub mod lookup;
 pub mod opcodes;
 
 use crate::native_types::Witness;
+use brillig::BrilligBytecode;
+use lookup::LookupTable;
 pub use opcodes::Opcode;
 use thiserror::Error;
 
@@ -39,6 +42,16 @@ pub struct Circuit {
     // c++ code at the moment when it is, due to OpcodeLocation needing a comparison
     // implementation which is never generated.
     pub assert_messages: Vec<(OpcodeLocation, String)>,
+    /// The unconstrained functions which may be called from this circuit through [`Opcode::BrilligCall`].
+    ///
+    /// Each function is referenced by its index in this list.
+    #[serde(default)]
+    pub unconstrained_functions: Vec<BrilligBytecode>,
+    /// The tables which may be used by [`Opcode::Lookup`]s in this circuit.
+    ///
+    /// Each table is referenced by its index in this list.
+    #[serde(default)]
+    pub lookup_tables: Vec<LookupTable>,
 }
 
 impl Circuit {
@@ -50,6 +63,16 @@ impl Circuit {
             .find(|(loc, _)| *loc == opcode_location)
             .map(|(_, message)| message.as_str())
     }
+
+    /// Returns the bytecode of the unconstrained function referenced by an [`Opcode::BrilligCall`] with the given `id`.
+    pub fn get_unconstrained_function(&self, id: u32) -> Option<&BrilligBytecode> {
+        self.unconstrained_functions.get(id as usize)
+    }
+
+    /// Returns the table referenced by an [`Opcode::Lookup`] with the given `table_id`.
+    pub fn get_lookup_table(&self, table_id: u32) -> Option<&LookupTable> {
+        self.lookup_tables.get(table_id as usize)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
@@ -203,6 +226,19 @@ impl std::fmt::Display for Circuit {
         for opcode in &self.opcodes {
             writeln!(f, "{opcode}")?;
         }
+
+        for (id, function) in self.unconstrained_functions.iter().enumerate() {
+            writeln!(f, "unconstrained func {id}: {:?}", function.bytecode)?;
+        }
+
+        for (id, table) in self.lookup_tables.iter().enumerate() {
+            writeln!(
+                f,
+                "lookup table {id}: {} rows, {} value columns",
+                table.keys.len(),
+                table.value_columns.len()
+            )?;
+        }
         Ok(())
     }
 }
@@ -232,6 +268,7 @@ mod tests {
     use std::collections::BTreeSet;
 
     use super::{
+        lookup::LookupTable,
         opcodes::{BlackBoxFuncCall, FunctionInput},
         Circuit, Compression, Opcode, PublicInputs,
     };
@@ -251,15 +288,29 @@ mod tests {
         })
     }
 
+    fn lookup_opcode() -> Opcode {
+        Opcode::Lookup {
+            table_id: 0,
+            key: Witness(1).into(),
+            values: vec![Witness(5)],
+            predicate: None,
+        }
+    }
+
     #[test]
     fn serialization_roundtrip() {
         let circuit = Circuit {
             current_witness_index: 5,
-            opcodes: vec![and_opcode(), range_opcode()],
+            opcodes: vec![and_opcode(), range_opcode(), lookup_opcode()],
             private_parameters: BTreeSet::new(),
             public_parameters: PublicInputs(BTreeSet::from_iter(vec![Witness(2), Witness(12)])),
             return_values: PublicInputs(BTreeSet::from_iter(vec![Witness(4), Witness(12)])),
             assert_messages: Default::default(),
+            unconstrained_functions: Vec::new(),
+            lookup_tables: vec![LookupTable {
+                keys: vec![FieldElement::from(1u128), FieldElement::from(2u128)],
+                value_columns: vec![vec![FieldElement::from(3u128), FieldElement::from(4u128)]],
+            }],
         };
 
         fn read_write(circuit: Circuit) -> (Circuit, Circuit) {
@@ -289,6 +340,8 @@ mod tests {
             public_parameters: PublicInputs(BTreeSet::from_iter(vec![Witness(2)])),
             return_values: PublicInputs(BTreeSet::from_iter(vec![Witness(2)])),
             assert_messages: Default::default(),
+            unconstrained_functions: Vec::new(),
+            lookup_tables: Vec::new(),
         };
 
         let json = serde_json::to_string_pretty(&circuit).unwrap();
@@ -297,6 +350,24 @@ mod tests {
         assert_eq!(circuit, deserialized);
     }
 
+    #[test]
+    fn deserializes_circuits_without_unconstrained_functions_or_lookup_tables() {
+        let circuit = Circuit {
+            current_witness_index: 5,
+            opcodes: vec![and_opcode(), range_opcode()],
+            private_parameters: BTreeSet::from_iter(vec![Witness(1), Witness(2)]),
+            ..Circuit::default()
+        };
+
+        let mut json = serde_json::to_value(&circuit).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("unconstrained_functions");
+        fields.remove("lookup_tables");
+
+        let deserialized: Circuit = serde_json::from_value(json).unwrap();
+        assert_eq!(circuit, deserialized);
+    }
+
     #[test]
     fn does_not_panic_on_invalid_circuit() {
         use std::io::Write;
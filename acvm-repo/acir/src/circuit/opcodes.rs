@@ -1,5 +1,5 @@
 use super::{
-    brillig::Brillig,
+    brillig::{Brillig, BrilligInputs, BrilligOutputs},
     directives::{Directive, QuotientDirective},
 };
 use crate::native_types::{Expression, Witness};
@@ -31,6 +31,31 @@ pub enum Opcode {
         block_id: BlockId,
         init: Vec<Witness>,
     },
+    /// Calls the unconstrained function stored at index `id` of the circuit's
+    /// [`unconstrained_functions`][crate::circuit::Circuit::unconstrained_functions].
+    ///
+    /// This behaves identically to [`Opcode::Brillig`] but allows the bytecode to be shared between call-sites.
+    BrilligCall {
+        /// Index of the [`BrilligBytecode`][super::brillig::BrilligBytecode] being called.
+        id: u32,
+        inputs: Vec<BrilligInputs>,
+        outputs: Vec<BrilligOutputs>,
+        /// Predicate of the Brillig execution - indicates if it should be skipped
+        predicate: Option<Expression>,
+    },
+    /// Constrains `key` to be one of the keys of the table at index `table_id` of the circuit's
+    /// [`lookup_tables`][crate::circuit::Circuit::lookup_tables], and `values` to be the values associated with it.
+    ///
+    /// Backends which do not support lookups have this replaced with arithmetic opcodes by the ACVM compiler.
+    Lookup {
+        /// Index of the [`LookupTable`][super::lookup::LookupTable] being looked up.
+        table_id: u32,
+        key: Expression,
+        /// One witness per value column of the table, holding the values of the row with the given `key`.
+        values: Vec<Witness>,
+        /// Predicate of the lookup - indicates if it should be skipped
+        predicate: Option<Expression>,
+    },
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -57,8 +82,10 @@ impl Opcode {
             Opcode::Directive(directive) => directive.name(),
             Opcode::BlackBoxFuncCall(g) => g.name(),
             Opcode::Brillig(_) => "brillig",
+            Opcode::BrilligCall { .. } => "brillig call",
             Opcode::MemoryOp { .. } => "mem",
             Opcode::MemoryInit { .. } => "init memory block",
+            Opcode::Lookup { .. } => "lookup",
         }
     }
 
@@ -148,6 +175,14 @@ impl std::fmt::Display for Opcode {
                 writeln!(f, "outputs: {:?}", brillig.outputs)?;
                 writeln!(f, "{:?}", brillig.bytecode)
             }
+            Opcode::BrilligCall { id, inputs, outputs, predicate } => {
+                write!(f, "BRILLIG CALL func {id}: ")?;
+                if let Some(pred) = predicate {
+                    writeln!(f, "PREDICATE = {pred}")?;
+                }
+                writeln!(f, "inputs: {inputs:?}")?;
+                write!(f, "outputs: {outputs:?}")
+            }
             Opcode::MemoryOp { block_id, op, predicate } => {
                 write!(f, "MEM ")?;
                 if let Some(pred) = predicate {
@@ -168,6 +203,15 @@ impl std::fmt::Display for Opcode {
                 write!(f, "INIT ")?;
                 write!(f, "(id: {}, len: {}) ", block_id.0, init.len())
             }
+            Opcode::Lookup { table_id, key, values, predicate } => {
+                write!(f, "LOOKUP ")?;
+                if let Some(pred) = predicate {
+                    writeln!(f, "PREDICATE = {pred}")?;
+                }
+                let values: Vec<_> =
+                    values.iter().map(|value| format!("_{}", value.witness_index())).collect();
+                write!(f, "(table: {table_id}, key: {key}, values: [{}])", values.join(", "))
+            }
         }
     }
 }
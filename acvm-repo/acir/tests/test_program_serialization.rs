@@ -13,7 +13,7 @@ use std::collections::BTreeSet;
 
 use acir::{
     circuit::{
-        brillig::{Brillig, BrilligInputs, BrilligOutputs},
+        brillig::{Brillig, BrilligBytecode, BrilligInputs, BrilligOutputs},
         opcodes::{BlackBoxFuncCall, BlockId, FunctionInput, MemOp},
         Circuit, Opcode, PublicInputs,
     },
@@ -45,12 +45,12 @@ fn addition_circuit() {
     let bytes = Circuit::serialize_circuit(&circuit);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 173, 144, 187, 13, 192, 32, 12, 68, 249, 100, 32, 27,
-        219, 96, 119, 89, 37, 40, 176, 255, 8, 17, 18, 5, 74, 202, 240, 154, 235, 158, 238, 238,
-        112, 206, 121, 247, 37, 206, 60, 103, 194, 63, 208, 111, 116, 133, 197, 69, 144, 153, 91,
-        73, 13, 9, 47, 72, 86, 85, 128, 165, 102, 69, 69, 81, 185, 147, 18, 53, 101, 45, 86, 173,
-        128, 33, 83, 195, 46, 70, 125, 202, 226, 190, 94, 16, 166, 103, 108, 13, 203, 151, 254,
-        245, 233, 224, 1, 1, 52, 166, 127, 120, 1, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 173, 144, 75, 14, 128, 32, 12, 68, 249, 120, 160, 150,
+        182, 208, 238, 188, 138, 68, 184, 255, 17, 140, 9, 11, 162, 238, 228, 109, 102, 247, 50,
+        51, 155, 115, 206, 187, 55, 113, 228, 62, 18, 254, 129, 126, 161, 43, 76, 46, 130, 204,
+        220, 74, 106, 72, 120, 64, 178, 170, 2, 44, 53, 43, 42, 138, 202, 153, 148, 168, 41, 107,
+        177, 106, 5, 12, 153, 26, 118, 49, 234, 67, 22, 215, 245, 130, 48, 60, 247, 214, 48, 125,
+        233, 31, 159, 126, 113, 1, 75, 107, 89, 47, 136, 1, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -75,9 +75,9 @@ fn fixed_base_scalar_mul_circuit() {
     let bytes = Circuit::serialize_circuit(&circuit);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 77, 138, 91, 10, 0, 48, 12, 194, 178, 215, 215, 46, 189,
-        163, 175, 165, 10, 21, 36, 10, 57, 192, 160, 146, 188, 226, 139, 78, 113, 69, 183, 190, 61,
-        111, 218, 182, 231, 124, 122, 8, 177, 65, 92, 0, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 117, 138, 73, 10, 0, 32, 12, 3, 199, 237, 228, 167, 125,
+        186, 21, 19, 232, 165, 129, 48, 9, 204, 2, 26, 63, 143, 91, 60, 209, 46, 142, 232, 212,
+        183, 231, 77, 218, 246, 170, 92, 233, 140, 86, 221, 108, 0, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -102,9 +102,9 @@ fn pedersen_circuit() {
     let bytes = Circuit::serialize_circuit(&circuit);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 93, 138, 9, 10, 0, 64, 8, 2, 103, 15, 250, 255, 139,
-        163, 162, 130, 72, 16, 149, 241, 3, 135, 84, 164, 172, 173, 213, 175, 251, 45, 198, 96,
-        243, 211, 50, 152, 67, 220, 211, 92, 0, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 117, 138, 75, 10, 0, 80, 8, 2, 231, 125, 232, 254, 39,
+        142, 138, 90, 68, 144, 32, 42, 227, 7, 14, 169, 72, 25, 91, 171, 95, 247, 27, 140, 198,
+        250, 103, 147, 1, 142, 221, 83, 100, 108, 0, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -143,22 +143,22 @@ fn schnorr_verify_circuit() {
     let bytes = Circuit::serialize_circuit(&circuit);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 77, 210, 87, 78, 2, 1, 20, 134, 209, 177, 247, 222, 123,
-        71, 68, 68, 68, 68, 68, 68, 68, 68, 68, 221, 133, 251, 95, 130, 145, 27, 206, 36, 78, 50,
-        57, 16, 94, 200, 253, 191, 159, 36, 73, 134, 146, 193, 19, 142, 241, 183, 255, 14, 179,
-        233, 247, 145, 254, 59, 217, 127, 71, 57, 198, 113, 78, 48, 125, 167, 56, 205, 25, 206,
-        114, 142, 243, 92, 224, 34, 151, 184, 204, 21, 174, 114, 141, 235, 220, 224, 38, 183, 184,
-        205, 29, 238, 114, 143, 251, 60, 224, 33, 143, 120, 204, 19, 158, 242, 140, 25, 158, 51,
-        203, 11, 230, 120, 201, 60, 175, 88, 224, 53, 139, 188, 97, 137, 183, 44, 243, 142, 21,
-        222, 179, 202, 7, 214, 248, 200, 58, 159, 216, 224, 51, 155, 124, 97, 235, 223, 142, 241,
-        188, 250, 222, 230, 27, 59, 124, 103, 151, 31, 236, 241, 147, 95, 252, 246, 57, 158, 104,
-        47, 186, 139, 214, 162, 179, 104, 44, 250, 74, 219, 154, 242, 63, 162, 165, 232, 40, 26,
-        138, 126, 162, 157, 232, 38, 154, 137, 94, 162, 149, 232, 36, 26, 137, 62, 162, 141, 232,
-        34, 154, 136, 30, 162, 133, 232, 32, 26, 136, 253, 99, 251, 195, 100, 176, 121, 236, 29,
-        91, 159, 218, 56, 99, 219, 172, 77, 115, 182, 204, 219, 176, 96, 187, 162, 205, 74, 182,
-        42, 219, 168, 98, 155, 170, 77, 106, 182, 168, 219, 160, 225, 246, 77, 55, 111, 185, 113,
-        219, 109, 59, 110, 218, 117, 203, 158, 27, 166, 55, 75, 239, 150, 184, 101, 250, 252, 1,
-        19, 89, 159, 101, 220, 3, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 117, 210, 87, 78, 2, 1, 20, 134, 209, 177, 247, 222,
+        123, 67, 68, 68, 68, 68, 68, 68, 68, 68, 68, 212, 93, 184, 255, 37, 24, 185, 225, 144, 248,
+        226, 36, 147, 3, 225, 133, 220, 255, 251, 78, 146, 100, 40, 233, 63, 225, 24, 127, 122,
+        239, 48, 27, 126, 31, 233, 189, 147, 189, 119, 148, 99, 28, 231, 4, 7, 239, 20, 167, 57,
+        195, 89, 206, 113, 158, 11, 92, 228, 18, 151, 185, 194, 85, 174, 113, 157, 27, 220, 228,
+        22, 183, 185, 195, 93, 238, 113, 159, 7, 60, 228, 17, 143, 121, 194, 20, 79, 153, 230, 25,
+        51, 60, 103, 150, 23, 204, 241, 146, 121, 94, 177, 192, 107, 22, 121, 195, 18, 111, 89,
+        230, 29, 43, 188, 103, 149, 15, 172, 241, 145, 117, 62, 177, 193, 103, 54, 255, 236, 24,
+        207, 139, 239, 45, 190, 178, 205, 55, 118, 248, 206, 46, 63, 248, 201, 47, 159, 227, 137,
+        246, 162, 187, 104, 45, 58, 139, 198, 162, 175, 65, 91, 83, 254, 71, 180, 20, 29, 69, 67,
+        209, 79, 180, 19, 221, 68, 51, 209, 75, 180, 18, 157, 68, 35, 209, 71, 180, 17, 93, 68, 19,
+        209, 67, 180, 16, 29, 68, 3, 177, 127, 108, 127, 152, 244, 55, 143, 189, 99, 235, 148, 141,
+        211, 182, 205, 216, 52, 107, 203, 156, 13, 243, 182, 43, 216, 172, 104, 171, 146, 141, 202,
+        182, 169, 216, 164, 106, 139, 154, 13, 234, 110, 223, 112, 243, 166, 27, 183, 220, 182,
+        237, 166, 29, 183, 236, 186, 225, 224, 102, 131, 187, 37, 110, 249, 223, 243, 11, 81, 60,
+        54, 123, 236, 3, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -196,10 +196,11 @@ fn simple_brillig_foreign_call() {
     let bytes = Circuit::serialize_circuit(&circuit);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 173, 143, 49, 10, 64, 33, 12, 67, 99, 63, 124, 60, 142,
-        222, 192, 203, 56, 184, 56, 136, 120, 126, 5, 21, 226, 160, 139, 62, 40, 13, 45, 132, 68,
-        3, 80, 232, 124, 164, 153, 121, 115, 99, 155, 59, 172, 122, 231, 101, 56, 175, 80, 86, 221,
-        230, 31, 58, 196, 226, 83, 62, 53, 91, 16, 122, 10, 246, 84, 99, 243, 0, 30, 59, 1, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 173, 143, 193, 9, 64, 33, 12, 67, 99, 63, 124, 28, 71,
+        55, 112, 25, 15, 94, 60, 136, 56, 191, 130, 10, 241, 34, 130, 62, 40, 13, 45, 132, 68, 3,
+        80, 232, 124, 164, 153, 121, 115, 99, 155, 59, 172, 122, 231, 101, 56, 175, 80, 86, 221,
+        230, 31, 58, 196, 226, 83, 222, 53, 91, 16, 122, 10, 206, 169, 36, 152, 117, 235, 75, 1, 0,
+        0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -270,12 +271,12 @@ fn complex_brillig_foreign_call() {
 
     let expected_serialization: Vec<u8> = vec![
         31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 213, 83, 219, 10, 128, 48, 8, 117, 174, 139, 159, 179,
-        254, 160, 127, 137, 222, 138, 122, 236, 243, 19, 114, 32, 22, 244, 144, 131, 118, 64, 156,
-        178, 29, 14, 59, 74, 0, 16, 224, 66, 228, 64, 57, 7, 169, 53, 242, 189, 81, 114, 250, 134,
-        33, 248, 113, 165, 82, 26, 177, 2, 141, 177, 128, 198, 60, 15, 63, 245, 219, 211, 23, 215,
-        255, 139, 15, 251, 211, 112, 180, 28, 157, 212, 189, 100, 82, 179, 64, 170, 63, 109, 235,
-        190, 204, 135, 166, 178, 150, 216, 62, 154, 252, 250, 70, 147, 35, 220, 119, 93, 227, 4,
-        182, 131, 81, 25, 36, 4, 0, 0,
+        254, 160, 127, 137, 222, 138, 122, 236, 243, 19, 114, 32, 22, 245, 144, 131, 58, 32, 78,
+        217, 14, 135, 29, 37, 0, 8, 112, 32, 114, 160, 156, 131, 212, 26, 249, 94, 47, 57, 189, 67,
+        23, 252, 184, 82, 41, 141, 248, 3, 141, 177, 128, 198, 60, 15, 31, 245, 219, 211, 23, 215,
+        255, 139, 23, 251, 83, 113, 212, 28, 141, 212, 173, 100, 82, 179, 64, 170, 63, 44, 243, 58,
+        141, 155, 166, 178, 150, 216, 62, 154, 252, 248, 70, 147, 35, 156, 119, 253, 14, 59, 189,
+        150, 163, 196, 52, 4, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
@@ -307,11 +308,61 @@ fn memory_op_circuit() {
     let bytes = Circuit::serialize_circuit(&circuit);
 
     let expected_serialization: Vec<u8> = vec![
-        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 213, 146, 49, 14, 0, 32, 8, 3, 139, 192, 127, 240, 7,
-        254, 255, 85, 198, 136, 9, 131, 155, 48, 216, 165, 76, 77, 57, 80, 0, 140, 45, 117, 111,
-        238, 228, 179, 224, 174, 225, 110, 111, 234, 213, 185, 148, 156, 203, 121, 89, 86, 13, 215,
-        126, 131, 43, 153, 187, 115, 40, 185, 62, 153, 3, 136, 83, 60, 30, 96, 2, 12, 235, 225,
-        124, 14, 3, 0, 0,
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 213, 146, 49, 14, 0, 32, 8, 3, 171, 200, 127, 240, 7,
+        254, 255, 85, 134, 136, 9, 131, 78, 194, 224, 45, 101, 106, 74, 129, 1, 16, 22, 108, 90,
+        77, 139, 205, 13, 103, 134, 169, 188, 209, 179, 125, 75, 176, 47, 197, 121, 73, 118, 185,
+        242, 91, 185, 45, 114, 119, 114, 33, 245, 147, 201, 21, 177, 131, 223, 14, 160, 76, 249,
+        207, 59, 43, 30, 3, 0, 0,
+    ];
+
+    assert_eq!(bytes, expected_serialization)
+}
+
+#[test]
+fn brillig_call_circuit() {
+    let w_input = Witness(1);
+    let w_inverted = Witness(2);
+    let w_double_inverted = Witness(3);
+
+    // Both calls share a single unconstrained function which inverts its input.
+    let invert = BrilligBytecode {
+        bytecode: vec![brillig::Opcode::ForeignCall {
+            function: "invert".into(),
+            destinations: vec![RegisterOrMemory::RegisterIndex(RegisterIndex::from(0))],
+            inputs: vec![RegisterOrMemory::RegisterIndex(RegisterIndex::from(0))],
+        }],
+    };
+
+    let first_call = Opcode::BrilligCall {
+        id: 0,
+        inputs: vec![BrilligInputs::Single(w_input.into())],
+        outputs: vec![BrilligOutputs::Simple(w_inverted)],
+        predicate: None,
+    };
+    let second_call = Opcode::BrilligCall {
+        id: 0,
+        inputs: vec![BrilligInputs::Single(w_inverted.into())],
+        outputs: vec![BrilligOutputs::Simple(w_double_inverted)],
+        predicate: None,
+    };
+
+    let circuit = Circuit {
+        current_witness_index: 3,
+        opcodes: vec![first_call, second_call],
+        private_parameters: BTreeSet::from([Witness(1)]),
+        return_values: PublicInputs([Witness(3)].into()),
+        unconstrained_functions: vec![invert],
+        ..Circuit::default()
+    };
+
+    let bytes = Circuit::serialize_circuit(&circuit);
+
+    let expected_serialization: Vec<u8> = vec![
+        31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 189, 144, 59, 10, 0, 33, 16, 67, 51, 51, 176, 236, 113,
+        220, 27, 236, 101, 182, 216, 198, 66, 196, 243, 91, 168, 48, 224, 96, 227, 231, 53, 129,
+        20, 33, 137, 0, 96, 20, 174, 170, 132, 158, 230, 189, 85, 221, 28, 15, 173, 203, 114, 186,
+        47, 159, 30, 194, 155, 134, 136, 46, 75, 198, 0, 49, 188, 166, 183, 58, 225, 247, 233, 11,
+        113, 116, 133, 69, 6, 123, 51, 128, 69, 24, 2, 0, 0,
     ];
 
     assert_eq!(bytes, expected_serialization)
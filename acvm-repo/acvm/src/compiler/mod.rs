@@ -21,6 +21,12 @@ pub enum CompileError {
     UnsupportedBlackBox(BlackBoxFunc),
     #[error("The opcode {0} is not supported by the backend and acvm does not have a fallback implementation")]
     UnsupportedMemoryOpcode(UnsupportedMemoryOpcode),
+    #[error("Lookup references table {0} which does not exist in the circuit")]
+    UnknownLookupTable(u32),
+    #[error("Lookup table {0} must have one value per key in each column and distinct keys")]
+    InvalidLookupTable(u32),
+    #[error("Lookup in table {table_id} assigns {values} values but the table has {columns} value columns")]
+    LookupValuesMismatch { table_id: u32, columns: usize, values: usize },
 }
 
 /// This module moves and decomposes acir opcodes. The transformation map allows consumers of this module to map
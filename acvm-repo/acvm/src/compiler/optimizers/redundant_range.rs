@@ -176,6 +176,8 @@ mod tests {
             public_parameters: PublicInputs::default(),
             return_values: PublicInputs::default(),
             assert_messages: Default::default(),
+            unconstrained_functions: Vec::new(),
+            lookup_tables: Vec::new(),
         }
     }
 
@@ -11,17 +11,21 @@ pub(crate) struct FallbackTransformer;
 impl FallbackTransformer {
     //ACIR pass which replace unsupported opcodes using arithmetic fallback
     pub(crate) fn transform(
-        acir: Circuit,
+        mut acir: Circuit,
         is_supported: impl Fn(&Opcode) -> bool,
         opcode_positions: Vec<usize>,
     ) -> Result<(Circuit, Vec<usize>), CompileError> {
-        let mut acir_supported_opcodes = Vec::with_capacity(acir.opcodes.len());
+        let opcodes = std::mem::take(&mut acir.opcodes);
+        let mut acir_supported_opcodes = Vec::with_capacity(opcodes.len());
         let mut new_opcode_positions = Vec::with_capacity(opcode_positions.len());
         let mut witness_idx = acir.current_witness_index + 1;
 
-        for (idx, opcode) in acir.opcodes.into_iter().enumerate() {
+        for (idx, opcode) in opcodes.into_iter().enumerate() {
             match &opcode {
-                Opcode::Arithmetic(_) | Opcode::Directive(_) | Opcode::Brillig(_) => {
+                Opcode::Arithmetic(_)
+                | Opcode::Directive(_)
+                | Opcode::Brillig(_)
+                | Opcode::BrilligCall { .. } => {
                     // directive, arithmetic expression or blocks are handled by acvm
                     new_opcode_positions.push(opcode_positions[idx]);
                     acir_supported_opcodes.push(opcode);
@@ -56,6 +60,34 @@ impl FallbackTransformer {
                         acir_supported_opcodes.extend(opcodes_fallback);
                     }
                 }
+                Opcode::Lookup { table_id, key, values, predicate } => {
+                    let table = acir
+                        .get_lookup_table(*table_id)
+                        .ok_or(CompileError::UnknownLookupTable(*table_id))?;
+                    if !table.is_well_formed() {
+                        return Err(CompileError::InvalidLookupTable(*table_id));
+                    }
+                    if !table.accepts_values(values.len()) {
+                        return Err(CompileError::LookupValuesMismatch {
+                            table_id: *table_id,
+                            columns: table.value_columns.len(),
+                            values: values.len(),
+                        });
+                    }
+                    if is_supported(&opcode) {
+                        new_opcode_positions.push(opcode_positions[idx]);
+                        acir_supported_opcodes.push(opcode);
+                        continue;
+                    }
+                    // The backend has no lookup argument so we constrain the lookup
+                    // with arithmetic expressions over the contents of the table instead.
+                    let (updated_witness_index, opcodes_fallback) =
+                        stdlib::lookup_fallback::lookup(table, key, values, predicate, witness_idx);
+                    witness_idx = updated_witness_index;
+                    new_opcode_positions
+                        .extend(vec![opcode_positions[idx]; opcodes_fallback.len()]);
+                    acir_supported_opcodes.extend(opcodes_fallback);
+                }
             }
         }
 
@@ -1,5 +1,9 @@
 use acir::{
-    circuit::{brillig::BrilligOutputs, directives::Directive, Circuit, Opcode},
+    circuit::{
+        brillig::{Brillig, BrilligOutputs},
+        directives::Directive,
+        Circuit, Opcode,
+    },
     native_types::{Expression, Witness},
     FieldElement,
 };
@@ -188,8 +192,9 @@ pub(super) fn transform_internal(
                 new_acir_opcode_positions.push(acir_opcode_positions[index]);
                 transformed_opcodes.push(opcode);
             }
-            Opcode::Brillig(ref brillig) => {
-                for output in &brillig.outputs {
+            Opcode::Brillig(Brillig { ref outputs, .. })
+            | Opcode::BrilligCall { ref outputs, .. } => {
+                for output in outputs {
                     match output {
                         BrilligOutputs::Simple(w) => transformer.mark_solvable(*w),
                         BrilligOutputs::Array(v) => {
@@ -202,6 +207,13 @@ pub(super) fn transform_internal(
                 new_acir_opcode_positions.push(acir_opcode_positions[index]);
                 transformed_opcodes.push(opcode);
             }
+            Opcode::Lookup { ref values, .. } => {
+                for witness in values {
+                    transformer.mark_solvable(*witness);
+                }
+                new_acir_opcode_positions.push(acir_opcode_positions[index]);
+                transformed_opcodes.push(opcode);
+            }
         }
     }
 
@@ -1,10 +1,10 @@
 use acir::{
-    brillig::{ForeignCallParam, ForeignCallResult, RegisterIndex, Value},
+    brillig::{ForeignCallParam, ForeignCallResult, Opcode as BrilligOpcode, RegisterIndex, Value},
     circuit::{
-        brillig::{Brillig, BrilligInputs, BrilligOutputs},
+        brillig::{BrilligInputs, BrilligOutputs},
         OpcodeLocation,
     },
-    native_types::WitnessMap,
+    native_types::{Expression, WitnessMap},
     FieldElement,
 };
 use acvm_blackbox_solver::BlackBoxFunctionSolver;
@@ -30,22 +30,22 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     /// Evaluates if the Brillig block should be skipped entirely
     pub(super) fn should_skip(
         witness: &WitnessMap,
-        brillig: &Brillig,
+        predicate: &Option<Expression>,
     ) -> Result<bool, OpcodeResolutionError> {
         // If the predicate is `None`, the block should never be skipped
         // If the predicate is `Some` but we cannot find a value, then we return stalled
-        match &brillig.predicate {
+        match predicate {
             Some(pred) => Ok(get_value(pred, witness)?.is_zero()),
             None => Ok(false),
         }
     }
 
-    /// Assigns the zero value to all outputs of the given [`Brillig`] bytecode.
+    /// Assigns the zero value to all outputs of the given Brillig call.
     pub(super) fn zero_out_brillig_outputs(
         initial_witness: &mut WitnessMap,
-        brillig: &Brillig,
+        outputs: &[BrilligOutputs],
     ) -> Result<(), OpcodeResolutionError> {
-        for output in &brillig.outputs {
+        for output in outputs {
             match output {
                 BrilligOutputs::Simple(witness) => {
                     insert_value(witness, FieldElement::zero(), initial_witness)?;
@@ -64,7 +64,8 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     /// witness.
     pub(super) fn new(
         initial_witness: &WitnessMap,
-        brillig: &'b Brillig,
+        inputs: &[BrilligInputs],
+        bytecode: &'b [BrilligOpcode],
         bb_solver: &'b B,
         acir_index: usize,
     ) -> Result<Self, OpcodeResolutionError> {
@@ -75,7 +76,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
         // Iterate over each input and evaluate the expression(s) associated with it.
         // Push the results into registers and/or memory.
         // If a certain expression is not solvable, we stall the ACVM and do not proceed with Brillig VM execution.
-        for input in &brillig.inputs {
+        for input in inputs {
             match input {
                 BrilligInputs::Single(expr) => match get_value(expr, initial_witness) {
                     Ok(value) => input_register_values.push(value.into()),
@@ -108,7 +109,7 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
         // Instantiate a Brillig VM given the solved input registers and memory
         // along with the Brillig bytecode.
         let input_registers = Registers::load(input_register_values);
-        let vm = VM::new(input_registers, input_memory, &brillig.bytecode, vec![], bb_solver);
+        let vm = VM::new(input_registers, input_memory, bytecode, vec![], bb_solver);
         Ok(Self { vm, acir_index })
     }
 
@@ -174,13 +175,13 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     pub(super) fn finalize(
         self,
         witness: &mut WitnessMap,
-        brillig: &Brillig,
+        outputs: &[BrilligOutputs],
     ) -> Result<(), OpcodeResolutionError> {
         // Finish the Brillig execution by writing the outputs to the witness map
         let vm_status = self.vm.get_status();
         match vm_status {
             VMStatus::Finished => {
-                self.write_brillig_outputs(witness, brillig)?;
+                self.write_brillig_outputs(witness, outputs)?;
                 Ok(())
             }
             _ => panic!("Brillig VM has not completed execution"),
@@ -190,10 +191,10 @@ impl<'b, B: BlackBoxFunctionSolver> BrilligSolver<'b, B> {
     fn write_brillig_outputs(
         &self,
         witness_map: &mut WitnessMap,
-        brillig: &Brillig,
+        outputs: &[BrilligOutputs],
     ) -> Result<(), OpcodeResolutionError> {
         // Write VM execution results into the witness map
-        for (i, output) in brillig.outputs.iter().enumerate() {
+        for (i, output) in outputs.iter().enumerate() {
             let register_value = self.vm.get_registers().get(RegisterIndex::from(i));
             match output {
                 BrilligOutputs::Simple(witness) => {
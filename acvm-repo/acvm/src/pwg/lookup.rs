@@ -0,0 +1,129 @@
+use acir::{
+    circuit::lookup::LookupTable,
+    native_types::{Expression, Witness, WitnessMap},
+    FieldElement,
+};
+
+use super::{get_value, insert_value, ErrorLocation, OpcodeResolutionError};
+
+/// Solves a [`Lookup`][acir::circuit::Opcode::Lookup] opcode by finding the row of `table` whose key
+/// matches the value of `key` and assigning the values of that row to the `values` witnesses.
+///
+/// If the predicate is false, the lookup is skipped and the `values` witnesses are set to zero.
+/// The lookup fails if there isn't exactly one `values` witness per value column of `table`.
+pub(super) fn solve_lookup(
+    initial_witness: &mut WitnessMap,
+    table: &LookupTable,
+    key: &Expression,
+    values: &[Witness],
+    predicate: &Option<Expression>,
+) -> Result<(), OpcodeResolutionError> {
+    if !table.accepts_values(values.len()) {
+        return Err(OpcodeResolutionError::LookupValuesMismatch {
+            columns: table.value_columns.len(),
+            values: values.len(),
+        });
+    }
+
+    let skip = match predicate {
+        Some(pred) => get_value(pred, initial_witness)?.is_zero(),
+        None => false,
+    };
+    if skip {
+        for witness in values {
+            insert_value(witness, FieldElement::zero(), initial_witness)?;
+        }
+        return Ok(());
+    }
+
+    let key = get_value(key, initial_witness)?;
+    let row = table.find_row(key).ok_or(OpcodeResolutionError::UnsatisfiedConstrain {
+        opcode_location: ErrorLocation::Unresolved,
+    })?;
+
+    for (witness, value) in values.iter().zip(table.row_values(row)) {
+        insert_value(witness, value, initial_witness)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use acir::{
+        circuit::lookup::LookupTable,
+        native_types::{Expression, Witness, WitnessMap},
+        FieldElement,
+    };
+
+    use super::solve_lookup;
+    use crate::pwg::OpcodeResolutionError;
+
+    fn squares_table() -> LookupTable {
+        LookupTable {
+            keys: (0..4u128).map(FieldElement::from).collect(),
+            value_columns: vec![(0..4u128).map(|key| FieldElement::from(key * key)).collect()],
+        }
+    }
+
+    #[test]
+    fn assigns_values_of_matching_row() {
+        let mut witness_map = WitnessMap::from(BTreeMap::from([(Witness(1), 3u128.into())]));
+
+        solve_lookup(&mut witness_map, &squares_table(), &Witness(1).into(), &[Witness(2)], &None)
+            .unwrap();
+
+        assert_eq!(witness_map[&Witness(2)], FieldElement::from(9u128));
+    }
+
+    #[test]
+    fn fails_on_missing_key() {
+        let mut witness_map = WitnessMap::from(BTreeMap::from([(Witness(1), 4u128.into())]));
+
+        let result = solve_lookup(
+            &mut witness_map,
+            &squares_table(),
+            &Witness(1).into(),
+            &[Witness(2)],
+            &None,
+        );
+
+        assert!(matches!(result, Err(OpcodeResolutionError::UnsatisfiedConstrain { .. })));
+    }
+
+    #[test]
+    fn skips_lookup_with_false_predicate() {
+        let mut witness_map = WitnessMap::from(BTreeMap::from([(Witness(1), 4u128.into())]));
+
+        solve_lookup(
+            &mut witness_map,
+            &squares_table(),
+            &Witness(1).into(),
+            &[Witness(2)],
+            &Some(Expression::zero()),
+        )
+        .unwrap();
+
+        assert_eq!(witness_map[&Witness(2)], FieldElement::zero());
+    }
+
+    #[test]
+    fn fails_when_values_do_not_match_columns() {
+        let mut witness_map = WitnessMap::from(BTreeMap::from([(Witness(1), 3u128.into())]));
+
+        let result = solve_lookup(
+            &mut witness_map,
+            &squares_table(),
+            &Witness(1).into(),
+            &[Witness(2), Witness(3)],
+            &None,
+        );
+
+        assert_eq!(
+            result,
+            Err(OpcodeResolutionError::LookupValuesMismatch { columns: 1, values: 2 })
+        );
+        assert!(!witness_map.contains_key(&Witness(3)));
+    }
+}
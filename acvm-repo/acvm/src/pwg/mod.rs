@@ -3,14 +3,22 @@
 use std::collections::HashMap;
 
 use acir::{
-    brillig::ForeignCallResult,
-    circuit::{opcodes::BlockId, Opcode, OpcodeLocation},
+    brillig::{ForeignCallResult, Opcode as BrilligOpcode},
+    circuit::{
+        brillig::{BrilligBytecode, BrilligInputs, BrilligOutputs},
+        lookup::LookupTable,
+        opcodes::BlockId,
+        Opcode, OpcodeLocation,
+    },
     native_types::{Expression, Witness, WitnessMap},
     BlackBoxFunc, FieldElement,
 };
 use acvm_blackbox_solver::BlackBoxResolutionError;
 
-use self::{arithmetic::ArithmeticSolver, directives::solve_directives, memory_op::MemoryOpSolver};
+use self::{
+    arithmetic::ArithmeticSolver, directives::solve_directives, lookup::solve_lookup,
+    memory_op::MemoryOpSolver,
+};
 use crate::{BlackBoxFunctionSolver, Language};
 
 use thiserror::Error;
@@ -23,6 +31,7 @@ mod brillig;
 mod directives;
 // black box functions
 mod blackbox;
+mod lookup;
 mod memory_op;
 
 pub use self::brillig::{BrilligSolver, BrilligSolverStatus};
@@ -114,6 +123,14 @@ pub enum OpcodeResolutionError {
     BlackBoxFunctionFailed(BlackBoxFunc, String),
     #[error("Failed to solve brillig function, reason: {message}")]
     BrilligFunctionFailed { message: String, call_stack: Vec<OpcodeLocation> },
+    #[error(
+        "Brillig call references unconstrained function {0} which does not exist in the circuit"
+    )]
+    UnknownUnconstrainedFunction(u32),
+    #[error("Lookup references table {0} which does not exist in the circuit")]
+    UnknownLookupTable(u32),
+    #[error("Lookup assigns {values} values but its table has {columns} value columns")]
+    LookupValuesMismatch { columns: usize, values: usize },
 }
 
 impl From<BlackBoxResolutionError> for OpcodeResolutionError {
@@ -139,6 +156,10 @@ pub struct ACVM<'a, B: BlackBoxFunctionSolver> {
 
     /// A list of opcodes which are to be executed by the ACVM.
     opcodes: &'a [Opcode],
+    /// The unconstrained functions referenced by [`Opcode::BrilligCall`]s within `opcodes`.
+    unconstrained_functions: &'a [BrilligBytecode],
+    /// The tables referenced by [`Opcode::Lookup`]s within `opcodes`.
+    lookup_tables: &'a [LookupTable],
     /// Index of the next opcode to be executed.
     instruction_pointer: usize,
 
@@ -148,13 +169,21 @@ pub struct ACVM<'a, B: BlackBoxFunctionSolver> {
 }
 
 impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
-    pub fn new(backend: &'a B, opcodes: &'a [Opcode], initial_witness: WitnessMap) -> Self {
+    pub fn new(
+        backend: &'a B,
+        opcodes: &'a [Opcode],
+        initial_witness: WitnessMap,
+        unconstrained_functions: &'a [BrilligBytecode],
+        lookup_tables: &'a [LookupTable],
+    ) -> Self {
         let status = if opcodes.is_empty() { ACVMStatus::Solved } else { ACVMStatus::InProgress };
         ACVM {
             status,
             backend,
             block_solvers: HashMap::default(),
             opcodes,
+            unconstrained_functions,
+            lookup_tables,
             instruction_pointer: 0,
             witness_map: initial_witness,
             brillig_solver: None,
@@ -181,6 +210,16 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
         self.opcodes
     }
 
+    /// Returns a slice containing the unconstrained functions which may be called by the circuit being executed.
+    pub fn unconstrained_functions(&self) -> &[BrilligBytecode] {
+        self.unconstrained_functions
+    }
+
+    /// Returns a slice containing the lookup tables which may be used by the circuit being executed.
+    pub fn lookup_tables(&self) -> &[LookupTable] {
+        self.lookup_tables
+    }
+
     /// Returns the index of the current opcode to be executed.
     pub fn instruction_pointer(&self) -> usize {
         self.instruction_pointer
@@ -271,7 +310,15 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
                 let solver = self.block_solvers.entry(*block_id).or_default();
                 solver.solve_memory_op(op, &mut self.witness_map, predicate)
             }
-            Opcode::Brillig(_) => match self.solve_brillig_opcode() {
+            Opcode::Lookup { table_id, key, values, predicate } => {
+                match self.lookup_tables.get(*table_id as usize) {
+                    Some(table) => {
+                        solve_lookup(&mut self.witness_map, table, key, values, predicate)
+                    }
+                    None => Err(OpcodeResolutionError::UnknownLookupTable(*table_id)),
+                }
+            }
+            Opcode::Brillig(_) | Opcode::BrilligCall { .. } => match self.solve_brillig_opcode() {
                 Ok(Some(foreign_call)) => return self.wait_for_foreign_call(foreign_call),
                 res => res.map(|_| ()),
             },
@@ -316,23 +363,58 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
         }
     }
 
+    /// Returns the components of the Brillig opcode at the current instruction pointer,
+    /// or `None` if the current opcode is not executed by the Brillig VM.
+    ///
+    /// For an [`Opcode::BrilligCall`] the bytecode is looked up in the circuit's unconstrained functions.
+    fn current_brillig_call(&self) -> Option<Result<BrilligCall<'a>, OpcodeResolutionError>> {
+        let opcodes: &'a [Opcode] = self.opcodes;
+        match &opcodes[self.instruction_pointer] {
+            Opcode::Brillig(brillig) => Some(Ok(BrilligCall {
+                inputs: &brillig.inputs,
+                outputs: &brillig.outputs,
+                predicate: &brillig.predicate,
+                bytecode: &brillig.bytecode,
+            })),
+            Opcode::BrilligCall { id, inputs, outputs, predicate } => {
+                let unconstrained_functions: &'a [BrilligBytecode] = self.unconstrained_functions;
+                let call = match unconstrained_functions.get(*id as usize) {
+                    Some(function) => {
+                        Ok(BrilligCall { inputs, outputs, predicate, bytecode: &function.bytecode })
+                    }
+                    None => Err(OpcodeResolutionError::UnknownUnconstrainedFunction(*id)),
+                };
+                Some(call)
+            }
+            _ => None,
+        }
+    }
+
     fn solve_brillig_opcode(
         &mut self,
     ) -> Result<Option<ForeignCallWaitInfo>, OpcodeResolutionError> {
-        let Opcode::Brillig(brillig) = &self.opcodes[self.instruction_pointer] else {
+        let Some(brillig) = self.current_brillig_call() else {
             unreachable!("Not executing a Brillig opcode");
         };
+        let brillig = brillig?;
 
         let witness = &mut self.witness_map;
-        if BrilligSolver::<B>::should_skip(witness, brillig)? {
-            return BrilligSolver::<B>::zero_out_brillig_outputs(witness, brillig).map(|_| None);
+        if BrilligSolver::<B>::should_skip(witness, brillig.predicate)? {
+            return BrilligSolver::<B>::zero_out_brillig_outputs(witness, brillig.outputs)
+                .map(|_| None);
         }
 
         // If we're resuming execution after resolving a foreign call then
         // there will be a cached `BrilligSolver` to avoid recomputation.
         let mut solver: BrilligSolver<'_, B> = match self.brillig_solver.take() {
             Some(solver) => solver,
-            None => BrilligSolver::new(witness, brillig, self.backend, self.instruction_pointer)?,
+            None => BrilligSolver::new(
+                witness,
+                brillig.inputs,
+                brillig.bytecode,
+                self.backend,
+                self.instruction_pointer,
+            )?,
         };
         match solver.solve()? {
             BrilligSolverStatus::ForeignCallWait(foreign_call) => {
@@ -345,29 +427,39 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
             }
             BrilligSolverStatus::Finished => {
                 // Write execution outputs
-                solver.finalize(witness, brillig)?;
+                solver.finalize(witness, brillig.outputs)?;
                 Ok(None)
             }
         }
     }
 
     pub fn step_into_brillig_opcode(&mut self) -> StepResult<'a, B> {
-        let Opcode::Brillig(brillig) = &self.opcodes[self.instruction_pointer] else {
+        let Some(brillig) = self.current_brillig_call() else {
             return StepResult::Status(self.solve_opcode());
         };
+        let brillig = match brillig {
+            Ok(brillig) => brillig,
+            Err(err) => return StepResult::Status(self.handle_opcode_resolution(Err(err))),
+        };
 
         let witness = &mut self.witness_map;
-        let should_skip = match BrilligSolver::<B>::should_skip(witness, brillig) {
+        let should_skip = match BrilligSolver::<B>::should_skip(witness, brillig.predicate) {
             Ok(result) => result,
             Err(err) => return StepResult::Status(self.handle_opcode_resolution(Err(err))),
         };
 
         if should_skip {
-            let resolution = BrilligSolver::<B>::zero_out_brillig_outputs(witness, brillig);
+            let resolution = BrilligSolver::<B>::zero_out_brillig_outputs(witness, brillig.outputs);
             return StepResult::Status(self.handle_opcode_resolution(resolution));
         }
 
-        let solver = BrilligSolver::new(witness, brillig, self.backend, self.instruction_pointer);
+        let solver = BrilligSolver::new(
+            witness,
+            brillig.inputs,
+            brillig.bytecode,
+            self.backend,
+            self.instruction_pointer,
+        );
         match solver {
             Ok(solver) => StepResult::IntoBrillig(solver),
             Err(..) => StepResult::Status(self.handle_opcode_resolution(solver.map(|_| ()))),
@@ -375,7 +467,10 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
     }
 
     pub fn finish_brillig_with_solver(&mut self, solver: BrilligSolver<'a, B>) -> ACVMStatus {
-        if !matches!(&self.opcodes[self.instruction_pointer], Opcode::Brillig(..)) {
+        if !matches!(
+            &self.opcodes[self.instruction_pointer],
+            Opcode::Brillig(..) | Opcode::BrilligCall { .. }
+        ) {
             unreachable!("Not executing a Brillig opcode");
         }
         self.brillig_solver = Some(solver);
@@ -383,6 +478,15 @@ impl<'a, B: BlackBoxFunctionSolver> ACVM<'a, B> {
     }
 }
 
+/// The components of an opcode which is executed by the Brillig VM, borrowed from either
+/// an [`Opcode::Brillig`] or an [`Opcode::BrilligCall`] and the unconstrained function it references.
+struct BrilligCall<'a> {
+    inputs: &'a [BrilligInputs],
+    outputs: &'a [BrilligOutputs],
+    predicate: &'a Option<Expression>,
+    bytecode: &'a [BrilligOpcode],
+}
+
 // Returns the concrete value for a particular witness
 // If the witness has no assignment, then
 // an error is returned
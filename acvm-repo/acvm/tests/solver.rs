@@ -3,7 +3,8 @@ use std::collections::BTreeMap;
 use acir::{
     brillig::{BinaryFieldOp, Opcode as BrilligOpcode, RegisterIndex, RegisterOrMemory, Value},
     circuit::{
-        brillig::{Brillig, BrilligInputs, BrilligOutputs},
+        brillig::{Brillig, BrilligBytecode, BrilligInputs, BrilligOutputs},
+        lookup::LookupTable,
         opcodes::{BlockId, MemOp},
         Opcode, OpcodeLocation,
     },
@@ -135,7 +136,7 @@ fn inversion_brillig_oracle_equivalence() {
     ])
     .into();
 
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &[]);
     // use the partial witness generation solver with our acir program
     let solver_status = acvm.solve();
 
@@ -264,7 +265,7 @@ fn double_inversion_brillig_oracle() {
     ])
     .into();
 
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &[]);
 
     // use the partial witness generation solver with our acir program
     let solver_status = acvm.solve();
@@ -385,7 +386,7 @@ fn oracle_dependent_execution() {
     let witness_assignments =
         BTreeMap::from([(w_x, FieldElement::from(2u128)), (w_y, FieldElement::from(2u128))]).into();
 
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &[]);
 
     // use the partial witness generation solver with our acir program
     let solver_status = acvm.solve();
@@ -484,13 +485,119 @@ fn brillig_oracle_predicate() {
     ])
     .into();
 
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &[]);
     let solver_status = acvm.solve();
     assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
 
     // ACVM should be able to be finalized in `Solved` state.
     acvm.finalize();
 }
+
+#[test]
+fn brillig_call_shares_unconstrained_function() {
+    let w_x = Witness(1);
+    let w_y = Witness(2);
+    let w_x_plus_y = Witness(3);
+    let w_x_plus_y_plus_y = Witness(4);
+
+    // Both calls execute the same unconstrained function which adds its two inputs together.
+    let add_function = BrilligBytecode {
+        bytecode: vec![BrilligOpcode::BinaryFieldOp {
+            op: BinaryFieldOp::Add,
+            lhs: RegisterIndex::from(0),
+            rhs: RegisterIndex::from(1),
+            destination: RegisterIndex::from(0),
+        }],
+    };
+
+    let brillig_call = |lhs: Witness, rhs: Witness, output: Witness| Opcode::BrilligCall {
+        id: 0,
+        inputs: vec![
+            BrilligInputs::Single(Expression::from(lhs)),
+            BrilligInputs::Single(Expression::from(rhs)),
+        ],
+        outputs: vec![BrilligOutputs::Simple(output)],
+        predicate: None,
+    };
+
+    let opcodes =
+        vec![brillig_call(w_x, w_y, w_x_plus_y), brillig_call(w_x_plus_y, w_y, w_x_plus_y_plus_y)];
+    let unconstrained_functions = vec![add_function];
+
+    let witness_assignments =
+        BTreeMap::from([(w_x, FieldElement::from(2u128)), (w_y, FieldElement::from(3u128))]).into();
+
+    let mut acvm =
+        ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &unconstrained_functions, &[]);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
+
+    let witness_map = acvm.finalize();
+    assert_eq!(witness_map[&w_x_plus_y], FieldElement::from(5u128));
+    assert_eq!(witness_map[&w_x_plus_y_plus_y], FieldElement::from(8u128));
+}
+
+#[test]
+fn brillig_call_to_unknown_function_fails() {
+    let opcodes =
+        vec![Opcode::BrilligCall { id: 1, inputs: vec![], outputs: vec![], predicate: None }];
+    let unconstrained_functions = vec![BrilligBytecode::default()];
+
+    let mut acvm =
+        ACVM::new(&StubbedBackend, &opcodes, WitnessMap::new(), &unconstrained_functions, &[]);
+    let solver_status = acvm.solve();
+    assert_eq!(
+        solver_status,
+        ACVMStatus::Failure(OpcodeResolutionError::UnknownUnconstrainedFunction(1)),
+    );
+}
+
+#[test]
+fn lookup_assigns_values_from_table() {
+    let w_key = Witness(1);
+    let w_square = Witness(2);
+    let w_cube = Witness(3);
+
+    let lookup_tables = vec![LookupTable {
+        keys: (1..5u128).map(FieldElement::from).collect(),
+        value_columns: vec![
+            (1..5u128).map(|key| FieldElement::from(key * key)).collect(),
+            (1..5u128).map(|key| FieldElement::from(key * key * key)).collect(),
+        ],
+    }];
+    let opcodes = vec![Opcode::Lookup {
+        table_id: 0,
+        key: Expression::from(w_key),
+        values: vec![w_square, w_cube],
+        predicate: None,
+    }];
+
+    let witness_assignments = BTreeMap::from([(w_key, FieldElement::from(3u128))]).into();
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &lookup_tables);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
+
+    let witness_map = acvm.finalize();
+    assert_eq!(witness_map[&w_square], FieldElement::from(9u128));
+    assert_eq!(witness_map[&w_cube], FieldElement::from(27u128));
+}
+
+#[test]
+fn lookup_in_unknown_table_fails() {
+    let opcodes = vec![Opcode::Lookup {
+        table_id: 1,
+        key: Expression::one(),
+        values: vec![Witness(1)],
+        predicate: None,
+    }];
+    let lookup_tables = vec![LookupTable::default()];
+
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, WitnessMap::new(), &[], &lookup_tables);
+    let solver_status = acvm.solve();
+    assert_eq!(solver_status, ACVMStatus::Failure(OpcodeResolutionError::UnknownLookupTable(1)));
+}
+
 #[test]
 fn unsatisfied_opcode_resolved() {
     let a = Witness(0);
@@ -517,7 +624,7 @@ fn unsatisfied_opcode_resolved() {
     values.insert(d, FieldElement::from(2_i128));
 
     let opcodes = vec![Opcode::Arithmetic(opcode_a)];
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, values);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, values, &[], &[]);
     let solver_status = acvm.solve();
     assert_eq!(
         solver_status,
@@ -597,7 +704,7 @@ fn unsatisfied_opcode_resolved_brillig() {
 
     let opcodes = vec![brillig_opcode, Opcode::Arithmetic(opcode_a)];
 
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, values);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, values, &[], &[]);
     let solver_status = acvm.solve();
     assert_eq!(
         solver_status,
@@ -641,7 +748,7 @@ fn memory_operations() {
 
     let opcodes = vec![init, read_op, expression];
 
-    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness);
+    let mut acvm = ACVM::new(&StubbedBackend, &opcodes, initial_witness, &[], &[]);
     let solver_status = acvm.solve();
     assert_eq!(solver_status, ACVMStatus::Solved);
     let witness_map = acvm.finalize();
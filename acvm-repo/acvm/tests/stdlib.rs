@@ -3,6 +3,7 @@ mod solver;
 use crate::solver::StubbedBackend;
 use acir::{
     circuit::{
+        lookup::LookupTable,
         opcodes::{BlackBoxFuncCall, FunctionInput},
         Circuit, Opcode,
     },
@@ -10,7 +11,7 @@ use acir::{
     FieldElement,
 };
 use acvm::{
-    compiler::compile,
+    compiler::{compile, CompileError},
     pwg::{ACVMStatus, ACVM},
     Language,
 };
@@ -74,7 +75,7 @@ macro_rules! test_uint_inner {
                 let uint = $uint::new(w);
                 let (w, extra_opcodes, _) = uint.rol(y, 2);
                 let witness_assignments = BTreeMap::from([(Witness(1), fe)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w.get_inner()).unwrap(), &FieldElement::from(result as u128));
@@ -89,7 +90,7 @@ macro_rules! test_uint_inner {
                 let uint = $uint::new(w);
                 let (w, extra_opcodes, _) = uint.ror(y, 2);
                 let witness_assignments = BTreeMap::from([(Witness(1), fe)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w.get_inner()).unwrap(), &FieldElement::from(result as u128));
@@ -109,7 +110,7 @@ macro_rules! test_uint_inner {
                 let u32_2 = $uint::new(w2);
                 let (q_w, r_w, extra_opcodes, _) = $uint::euclidean_division(&u32_1, &u32_2, 3);
                 let witness_assignments = BTreeMap::from([(Witness(1), lhs),(Witness(2), rhs)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&q_w.get_inner()).unwrap(), &FieldElement::from(q as u128));
@@ -135,7 +136,7 @@ macro_rules! test_uint_inner {
                 let (w2, extra_opcodes, _) = w.add(&u32_3, num_witness);
                 opcodes.extend(extra_opcodes);
                 let witness_assignments = BTreeMap::from([(Witness(1), lhs), (Witness(2), rhs), (Witness(3), rhs_z)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w2.get_inner()).unwrap(), &result);
@@ -160,7 +161,7 @@ macro_rules! test_uint_inner {
                 let (w2, extra_opcodes, _) = w.sub(&u32_3, num_witness);
                 opcodes.extend(extra_opcodes);
                 let witness_assignments = BTreeMap::from([(Witness(1), lhs), (Witness(2), rhs), (Witness(3), rhs_z)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w2.get_inner()).unwrap(), &result);
@@ -175,7 +176,7 @@ macro_rules! test_uint_inner {
                 let u32_1 = $uint::new(w1);
                 let (w, extra_opcodes, _) = u32_1.leftshift(y, 2);
                 let witness_assignments = BTreeMap::from([(Witness(1), lhs)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w.get_inner()).unwrap(), &FieldElement::from(result as u128));
@@ -190,7 +191,7 @@ macro_rules! test_uint_inner {
                 let u32_1 = $uint::new(w1);
                 let (w, extra_opcodes, _) = u32_1.rightshift(y, 2);
                 let witness_assignments = BTreeMap::from([(Witness(1), lhs)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w.get_inner()).unwrap(), &FieldElement::from(result as u128));
@@ -208,7 +209,7 @@ macro_rules! test_uint_inner {
                 let u32_2 = $uint::new(w2);
                 let (w, extra_opcodes, _) = u32_1.less_than_comparison(&u32_2, 3);
                 let witness_assignments = BTreeMap::from([(Witness(1), lhs), (Witness(2), rhs)]).into();
-                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments);
+                let mut acvm = ACVM::new(&StubbedBackend, &extra_opcodes, witness_assignments, &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(acvm.witness_map().get(&w.get_inner()).unwrap(), &FieldElement::from(result as u128));
@@ -290,7 +291,7 @@ macro_rules! test_hashes {
                 let circuit = compile(circuit, Language::PLONKCSat{ width: 3 }, $opcode_support).unwrap().0;
 
                 // solve witnesses
-                let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into());
+                let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into(), &[], &[]);
                 let solver_status = acvm.solve();
 
                 prop_assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
@@ -346,9 +347,90 @@ proptest! {
         let circuit = compile(circuit, Language::PLONKCSat{ width: 3 }, does_not_support_hash_to_field).unwrap().0;
 
         // solve witnesses
-        let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into());
+        let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into(), &[], &[]);
         let solver_status = acvm.solve();
 
         prop_assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
     }
 }
+
+fn does_not_support_lookup(opcode: &Opcode) -> bool {
+    !matches!(opcode, Opcode::Lookup { .. })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+    #[test]
+    fn test_lookup_fallback(
+        rows in proptest::collection::btree_map(any::<u64>(), (any::<u64>(), any::<u64>()), 1..10),
+        row_index in any::<prop::sample::Index>(),
+        predicate in any::<bool>(),
+    ) {
+        let (key, (value_a, value_b)) = rows.iter().nth(row_index.index(rows.len())).map(|(k, v)| (*k, *v)).unwrap();
+        let lookup_table = LookupTable {
+            keys: rows.keys().map(|key| FieldElement::from(*key as u128)).collect(),
+            value_columns: vec![
+                rows.values().map(|(value, _)| FieldElement::from(*value as u128)).collect(),
+                rows.values().map(|(_, value)| FieldElement::from(*value as u128)).collect(),
+            ],
+        };
+
+        let lookup = Opcode::Lookup {
+            table_id: 0,
+            key: Expression::from(Witness(1)),
+            values: vec![Witness(3), Witness(4)],
+            predicate: Some(Expression::from(Witness(2))),
+        };
+        let circuit = Circuit {
+            current_witness_index: 4,
+            opcodes: vec![lookup],
+            lookup_tables: vec![lookup_table],
+            ..Circuit::default()
+        };
+        let circuit = compile(circuit, Language::PLONKCSat{ width: 3 }, does_not_support_lookup).unwrap().0;
+        prop_assert!(circuit.opcodes.iter().all(|opcode| matches!(opcode, Opcode::Arithmetic(_))));
+
+        let witness_assignments = BTreeMap::from([
+            (Witness(1), FieldElement::from(key as u128)),
+            (Witness(2), FieldElement::from(predicate)),
+        ]);
+        let mut acvm = ACVM::new(&StubbedBackend, &circuit.opcodes, witness_assignments.into(), &[], &circuit.lookup_tables);
+        let solver_status = acvm.solve();
+        prop_assert_eq!(solver_status, ACVMStatus::Solved, "should be fully solved");
+
+        let (expected_a, expected_b) = if predicate { (value_a, value_b) } else { (0, 0) };
+        let witness_map = acvm.finalize();
+        prop_assert_eq!(witness_map[&Witness(3)], FieldElement::from(expected_a as u128));
+        prop_assert_eq!(witness_map[&Witness(4)], FieldElement::from(expected_b as u128));
+    }
+}
+
+#[test]
+fn lookup_with_more_values_than_columns_is_rejected() {
+    let lookup_table = LookupTable {
+        keys: (0..4u128).map(FieldElement::from).collect(),
+        value_columns: vec![(0..4u128).map(|key| FieldElement::from(key * key)).collect()],
+    };
+    let lookup = Opcode::Lookup {
+        table_id: 0,
+        key: Expression::from(Witness(1)),
+        values: vec![Witness(2), Witness(3)],
+        predicate: None,
+    };
+    let circuit = Circuit {
+        current_witness_index: 3,
+        opcodes: vec![lookup],
+        lookup_tables: vec![lookup_table],
+        ..Circuit::default()
+    };
+
+    // The extra witness would be left unconstrained, whether or not the backend supports lookups.
+    let backends: [fn(&Opcode) -> bool; 2] = [does_not_support_lookup, |_| true];
+    for is_supported in backends {
+        let result = compile(circuit.clone(), Language::PLONKCSat { width: 3 }, is_supported);
+        assert_eq!(
+            result.err(),
+            Some(CompileError::LookupValuesMismatch { table_id: 0, columns: 1, values: 2 })
+        );
+    }
+}
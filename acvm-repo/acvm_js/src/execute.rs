@@ -67,7 +67,13 @@ pub async fn execute_circuit_with_black_box_solver(
     let circuit: Circuit =
         Circuit::deserialize_circuit(&circuit).expect("Failed to deserialize circuit");
 
-    let mut acvm = ACVM::new(&solver.0, &circuit.opcodes, initial_witness.into());
+    let mut acvm = ACVM::new(
+        &solver.0,
+        &circuit.opcodes,
+        initial_witness.into(),
+        &circuit.unconstrained_functions,
+        &circuit.lookup_tables,
+    );
 
     loop {
         let solver_status = acvm.solve();
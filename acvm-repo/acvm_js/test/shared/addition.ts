@@ -2,11 +2,11 @@ import { WitnessMap } from '@noir-lang/acvm_js';
 
 // See `addition_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 173, 144, 187, 13, 192, 32, 12, 68, 249, 100, 32, 27, 219, 96, 119, 89, 37, 40,
-  176, 255, 8, 17, 18, 5, 74, 202, 240, 154, 235, 158, 238, 238, 112, 206, 121, 247, 37, 206, 60, 103, 194, 63, 208,
-  111, 116, 133, 197, 69, 144, 153, 91, 73, 13, 9, 47, 72, 86, 85, 128, 165, 102, 69, 69, 81, 185, 147, 18, 53, 101, 45,
-  86, 173, 128, 33, 83, 195, 46, 70, 125, 202, 226, 190, 94, 16, 166, 103, 108, 13, 203, 151, 254, 245, 233, 224, 1, 1,
-  52, 166, 127, 120, 1, 0, 0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 173, 144, 75, 14, 128, 32, 12, 68, 249, 120, 160, 150, 182, 208, 238, 188, 138, 68,
+  184, 255, 17, 140, 9, 11, 162, 238, 228, 109, 102, 247, 50, 51, 155, 115, 206, 187, 55, 113, 228, 62, 18, 254, 129,
+  126, 161, 43, 76, 46, 130, 204, 220, 74, 106, 72, 120, 64, 178, 170, 2, 44, 53, 43, 42, 138, 202, 153, 148, 168, 41,
+  107, 177, 106, 5, 12, 153, 26, 118, 49, 234, 67, 22, 215, 245, 130, 48, 60, 247, 214, 48, 125, 233, 31, 159, 126, 113,
+  1, 75, 107, 89, 47, 136, 1, 0, 0,
 ]);
 
 export const initialWitnessMap: WitnessMap = new Map([
@@ -3,10 +3,10 @@ import { WitnessMap } from '@noir-lang/acvm_js';
 // See `complex_brillig_foreign_call` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
   31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 213, 83, 219, 10, 128, 48, 8, 117, 174, 139, 159, 179, 254, 160, 127, 137, 222,
-  138, 122, 236, 243, 19, 114, 32, 22, 244, 144, 131, 118, 64, 156, 178, 29, 14, 59, 74, 0, 16, 224, 66, 228, 64, 57, 7,
-  169, 53, 242, 189, 81, 114, 250, 134, 33, 248, 113, 165, 82, 26, 177, 2, 141, 177, 128, 198, 60, 15, 63, 245, 219,
-  211, 23, 215, 255, 139, 15, 251, 211, 112, 180, 28, 157, 212, 189, 100, 82, 179, 64, 170, 63, 109, 235, 190, 204, 135,
-  166, 178, 150, 216, 62, 154, 252, 250, 70, 147, 35, 220, 119, 93, 227, 4, 182, 131, 81, 25, 36, 4, 0, 0,
+  138, 122, 236, 243, 19, 114, 32, 22, 245, 144, 131, 58, 32, 78, 217, 14, 135, 29, 37, 0, 8, 112, 32, 114, 160, 156,
+  131, 212, 26, 249, 94, 47, 57, 189, 67, 23, 252, 184, 82, 41, 141, 248, 3, 141, 177, 128, 198, 60, 15, 31, 245, 219,
+  211, 23, 215, 255, 139, 23, 251, 83, 113, 212, 28, 141, 212, 173, 100, 82, 179, 64, 170, 63, 44, 243, 58, 141, 155,
+  166, 178, 150, 216, 62, 154, 252, 248, 70, 147, 35, 156, 119, 253, 14, 59, 189, 150, 163, 196, 52, 4, 0, 0,
 ]);
 export const initialWitnessMap: WitnessMap = new Map([
   [1, '0x0000000000000000000000000000000000000000000000000000000000000001'],
@@ -1,8 +1,8 @@
 // See `fixed_base_scalar_mul_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 77, 138, 91, 10, 0, 48, 12, 194, 178, 215, 215, 46, 189, 163, 175, 165, 10, 21, 36,
-  10, 57, 192, 160, 146, 188, 226, 139, 78, 113, 69, 183, 190, 61, 111, 218, 182, 231, 124, 122, 8, 177, 65, 92, 0, 0,
-  0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 117, 138, 73, 10, 0, 32, 12, 3, 199, 237, 228, 167, 125, 186, 21, 19, 232, 165,
+  129, 48, 9, 204, 2, 26, 63, 143, 91, 60, 209, 46, 142, 232, 212, 183, 231, 77, 218, 246, 170, 92, 233, 140, 86, 221,
+  108, 0, 0, 0,
 ]);
 export const initialWitnessMap = new Map([
   [1, '0x0000000000000000000000000000000000000000000000000000000000000001'],
@@ -1,9 +1,9 @@
 // See `memory_op_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 213, 146, 49, 14, 0, 32, 8, 3, 139, 192, 127, 240, 7, 254, 255, 85, 198, 136, 9,
-  131, 155, 48, 216, 165, 76, 77, 57, 80, 0, 140, 45, 117, 111, 238, 228, 179, 224, 174, 225, 110, 111, 234, 213, 185,
-  148, 156, 203, 121, 89, 86, 13, 215, 126, 131, 43, 153, 187, 115, 40, 185, 62, 153, 3, 136, 83, 60, 30, 96, 2, 12,
-  235, 225, 124, 14, 3, 0, 0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 213, 146, 49, 14, 0, 32, 8, 3, 171, 200, 127, 240, 7, 254, 255, 85, 134, 136, 9,
+  131, 78, 194, 224, 45, 101, 106, 74, 129, 1, 16, 22, 108, 90, 77, 139, 205, 13, 103, 134, 169, 188, 209, 179, 125, 75,
+  176, 47, 197, 121, 73, 118, 185, 242, 91, 185, 45, 114, 119, 114, 33, 245, 147, 201, 21, 177, 131, 223, 14, 160, 76,
+  249, 207, 59, 43, 30, 3, 0, 0,
 ]);
 
 export const initialWitnessMap = new Map([
@@ -1,7 +1,7 @@
 // See `pedersen_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 93, 138, 9, 10, 0, 64, 8, 2, 103, 15, 250, 255, 139, 163, 162, 130, 72, 16, 149,
-  241, 3, 135, 84, 164, 172, 173, 213, 175, 251, 45, 198, 96, 243, 211, 50, 152, 67, 220, 211, 92, 0, 0, 0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 117, 138, 75, 10, 0, 80, 8, 2, 231, 125, 232, 254, 39, 142, 138, 90, 68, 144, 32,
+  42, 227, 7, 14, 169, 72, 25, 91, 171, 95, 247, 27, 140, 198, 250, 103, 147, 1, 142, 221, 83, 100, 108, 0, 0, 0,
 ]);
 
 export const initialWitnessMap = new Map([[1, '0x0000000000000000000000000000000000000000000000000000000000000001']]);
@@ -1,17 +1,17 @@
 // See `schnorr_verify_circuit` integration test in `acir/tests/test_program_serialization.rs`.
 export const bytecode = Uint8Array.from([
-  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 77, 210, 87, 78, 2, 1, 20, 134, 209, 177, 247, 222, 123, 71, 68, 68, 68, 68, 68,
-  68, 68, 68, 68, 221, 133, 251, 95, 130, 145, 27, 206, 36, 78, 50, 57, 16, 94, 200, 253, 191, 159, 36, 73, 134, 146,
-  193, 19, 142, 241, 183, 255, 14, 179, 233, 247, 145, 254, 59, 217, 127, 71, 57, 198, 113, 78, 48, 125, 167, 56, 205,
-  25, 206, 114, 142, 243, 92, 224, 34, 151, 184, 204, 21, 174, 114, 141, 235, 220, 224, 38, 183, 184, 205, 29, 238, 114,
-  143, 251, 60, 224, 33, 143, 120, 204, 19, 158, 242, 140, 25, 158, 51, 203, 11, 230, 120, 201, 60, 175, 88, 224, 53,
-  139, 188, 97, 137, 183, 44, 243, 142, 21, 222, 179, 202, 7, 214, 248, 200, 58, 159, 216, 224, 51, 155, 124, 97, 235,
-  223, 142, 241, 188, 250, 222, 230, 27, 59, 124, 103, 151, 31, 236, 241, 147, 95, 252, 246, 57, 158, 104, 47, 186, 139,
-  214, 162, 179, 104, 44, 250, 74, 219, 154, 242, 63, 162, 165, 232, 40, 26, 138, 126, 162, 157, 232, 38, 154, 137, 94,
-  162, 149, 232, 36, 26, 137, 62, 162, 141, 232, 34, 154, 136, 30, 162, 133, 232, 32, 26, 136, 253, 99, 251, 195, 100,
-  176, 121, 236, 29, 91, 159, 218, 56, 99, 219, 172, 77, 115, 182, 204, 219, 176, 96, 187, 162, 205, 74, 182, 42, 219,
-  168, 98, 155, 170, 77, 106, 182, 168, 219, 160, 225, 246, 77, 55, 111, 185, 113, 219, 109, 59, 110, 218, 117, 203,
-  158, 27, 166, 55, 75, 239, 150, 184, 101, 250, 252, 1, 19, 89, 159, 101, 220, 3, 0, 0,
+  31, 139, 8, 0, 0, 0, 0, 0, 0, 255, 117, 210, 87, 78, 2, 1, 20, 134, 209, 177, 247, 222, 123, 67, 68, 68, 68, 68, 68,
+  68, 68, 68, 68, 212, 93, 184, 255, 37, 24, 185, 225, 144, 248, 226, 36, 147, 3, 225, 133, 220, 255, 251, 78, 146, 100,
+  40, 233, 63, 225, 24, 127, 122, 239, 48, 27, 126, 31, 233, 189, 147, 189, 119, 148, 99, 28, 231, 4, 7, 239, 20, 167,
+  57, 195, 89, 206, 113, 158, 11, 92, 228, 18, 151, 185, 194, 85, 174, 113, 157, 27, 220, 228, 22, 183, 185, 195, 93,
+  238, 113, 159, 7, 60, 228, 17, 143, 121, 194, 20, 79, 153, 230, 25, 51, 60, 103, 150, 23, 204, 241, 146, 121, 94, 177,
+  192, 107, 22, 121, 195, 18, 111, 89, 230, 29, 43, 188, 103, 149, 15, 172, 241, 145, 117, 62, 177, 193, 103, 54, 255,
+  236, 24, 207, 139, 239, 45, 190, 178, 205, 55, 118, 248, 206, 46, 63, 248, 201, 47, 159, 227, 137, 246, 162, 187, 104,
+  45, 58, 139, 198, 162, 175, 65, 91, 83, 254, 71, 180, 20, 29, 69, 67, 209, 79, 180, 19, 221, 68, 51, 209, 75, 180, 18,
+  157, 68, 35, 209, 71, 180, 17, 93, 68, 19, 209, 67, 180, 16, 29, 68, 3, 177, 127, 108, 127, 152, 244, 55, 143, 189,
+  99, 235, 148, 141, 211, 182, 205, 216, 52, 107, 203, 156, 13, 243, 182, 43, 216, 172, 104, 171, 146, 141, 202, 182,
+  169, 216, 164, 106, 139, 154, 13, 234, 110, 223, 112, 243, 166, 27, 183, 220, 182, 237, 166, 29, 183, 236, 186, 225,
+  224, 102, 131, 187, 37, 110, 249, 223, 243, 11, 81, 60, 54, 123, 236, 3, 0, 0,
 ]);
 
 export const initialWitnessMap = new Map([
@@ -20,14 +20,14 @@ impl From<usize> for RegisterIndex {
 }
 
 /// A fixed-sized array starting from a Brillig register memory location.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 pub struct HeapArray {
     pub pointer: RegisterIndex,
     pub size: usize,
 }
 
 /// A register-sized vector passed starting from a Brillig register memory location and with a register-held size
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 pub struct HeapVector {
     pub pointer: RegisterIndex,
     pub size: RegisterIndex,
@@ -39,7 +39,7 @@ pub struct HeapVector {
 /// While we are usually agnostic to how memory is passed within Brillig,
 /// this needs to be encoded somehow when dealing with an external system.
 /// For simplicity, the extra type information is given right in the ForeignCall instructions.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 pub enum RegisterOrMemory {
     /// A single register value passed to or from an external call
     /// It is an 'immediate' value - used without dereferencing memory.
@@ -56,7 +56,7 @@ pub enum RegisterOrMemory {
     HeapVector(HeapVector),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BrilligOpcode {
     /// Takes the fields in registers `lhs` and `rhs`
     /// Performs the specified binary operation
@@ -156,7 +156,7 @@ impl BrilligOpcode {
 }
 
 /// Binary fixed-length field expressions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BinaryFieldOp {
     Add,
     Sub,
@@ -167,7 +167,7 @@ pub enum BinaryFieldOp {
 }
 
 /// Binary fixed-length integer expressions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum BinaryIntOp {
     Add,
     Sub,
@@ -11,7 +11,7 @@ pub enum Typ {
 }
 
 /// `Value` represents the base descriptor for a value in the VM.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Value {
     inner: FieldElement,
 }
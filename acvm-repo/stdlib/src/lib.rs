@@ -5,3 +5,4 @@
 
 pub mod blackbox_fallbacks;
 pub mod helpers;
+pub mod lookup_fallback;
@@ -0,0 +1,186 @@
+//! Lowering of [`Opcode::Lookup`][acir::circuit::Opcode::Lookup] into arithmetic opcodes, for backends
+//! which do not support lookups natively.
+//!
+//! A lookup of `key` in a table with keys `k_0, ..., k_{n-1}` is replaced by:
+//! - A membership check `(key - k_0) * (key - k_1) * ... * (key - k_{n-1}) == 0`.
+//! - For each value column, the evaluation at `key` of the polynomial interpolating the column,
+//!   i.e. the unique polynomial `P` of degree less than `n` with `P(k_i) == v_i` for every row.
+//!
+//! Both are evaluated incrementally, introducing an intermediate witness whenever a product
+//! would exceed the degree of an [`Expression`], so that every opcode can be solved in turn.
+use acir::{
+    circuit::{lookup::LookupTable, Opcode},
+    native_types::{Expression, Witness},
+    FieldElement,
+};
+
+use crate::helpers::VariableStore;
+
+/// Returns a set of opcodes which constrain `key` to be a key of `table` and `values`
+/// to be the values associated with it, unless `predicate` is false.
+///
+/// `table` is assumed to be [well formed][LookupTable::is_well_formed].
+///
+/// # Panics
+///
+/// If there isn't exactly one `values` witness per value column of `table`, as any extra
+/// witnesses would be left unconstrained.
+pub fn lookup(
+    table: &LookupTable,
+    key: &Expression,
+    values: &[Witness],
+    predicate: &Option<Expression>,
+    mut num_witness: u32,
+) -> (u32, Vec<Opcode>) {
+    assert!(
+        table.accepts_values(values.len()),
+        "lookup must assign one value per column of the table"
+    );
+    let mut variables = VariableStore::new(&mut num_witness);
+    let mut opcodes = Vec::new();
+
+    let key = reduce_to_linear(key, &mut variables, &mut opcodes);
+    let predicate = predicate
+        .as_ref()
+        .map(|predicate| reduce_to_linear(predicate, &mut variables, &mut opcodes));
+
+    // The product of `(key - k_i)` over every row is zero if and only if `key` is one of the keys.
+    let mut membership = Expression::one();
+    for row_key in &table.keys {
+        let factor = &key - &Expression::from_field(*row_key);
+        membership = multiply(&membership, &factor, &mut variables, &mut opcodes);
+    }
+    let membership = match &predicate {
+        Some(predicate) => multiply(predicate, &membership, &mut variables, &mut opcodes),
+        None => membership,
+    };
+    opcodes.push(Opcode::Arithmetic(membership));
+
+    for (column, value) in table.value_columns.iter().zip(values) {
+        // Evaluate the interpolating polynomial at `key` using Horner's method.
+        let mut evaluation = Expression::zero();
+        for coefficient in interpolate(&table.keys, column).into_iter().rev() {
+            evaluation = multiply(&evaluation, &key, &mut variables, &mut opcodes);
+            evaluation = evaluation + coefficient;
+        }
+        let evaluation = match &predicate {
+            Some(predicate) => multiply(predicate, &evaluation, &mut variables, &mut opcodes),
+            None => evaluation,
+        };
+        opcodes.push(Opcode::Arithmetic(&evaluation - *value));
+    }
+
+    (variables.finalize(), opcodes)
+}
+
+/// Returns an expression equal to `expr` which is of degree at most one,
+/// adding an opcode which assigns `expr` to a new witness if necessary.
+fn reduce_to_linear(
+    expr: &Expression,
+    variables: &mut VariableStore,
+    opcodes: &mut Vec<Opcode>,
+) -> Expression {
+    if expr.is_linear() {
+        return expr.clone();
+    }
+    let witness = variables.new_variable();
+    opcodes.push(Opcode::Arithmetic(expr - witness));
+    witness.into()
+}
+
+/// Returns an expression equal to `lhs * rhs`, where both are of degree at most one.
+///
+/// The result is reduced to degree one so that it can be multiplied again.
+fn multiply(
+    lhs: &Expression,
+    rhs: &Expression,
+    variables: &mut VariableStore,
+    opcodes: &mut Vec<Opcode>,
+) -> Expression {
+    let mut product = (lhs * rhs).expect("both operands are of degree at most one");
+    // Multiplying by a constant scales every term, so zero coefficients in the interpolated
+    // polynomials would otherwise leave behind terms which later passes do not expect.
+    product.mul_terms.retain(|(coefficient, _, _)| !coefficient.is_zero());
+    product.linear_combinations.retain(|(coefficient, _)| !coefficient.is_zero());
+    reduce_to_linear(&product, variables, opcodes)
+}
+
+/// Returns the coefficients, from the constant term upwards, of the polynomial of degree
+/// less than `keys.len()` which takes the value `values[i]` at `keys[i]`.
+fn interpolate(keys: &[FieldElement], values: &[FieldElement]) -> Vec<FieldElement> {
+    // The coefficients of the polynomial which is zero at every key.
+    let mut vanishing = vec![FieldElement::one()];
+    for key in keys {
+        vanishing = multiply_by_linear(&vanishing, *key);
+    }
+
+    let mut coefficients = vec![FieldElement::zero(); keys.len()];
+    for (i, (key, value)) in keys.iter().zip(values).enumerate() {
+        if value.is_zero() {
+            continue;
+        }
+        // The Lagrange basis polynomial for `key` is the vanishing polynomial divided by
+        // `(x - key)`, scaled so that it is one at `key`.
+        let basis = divide_by_linear(&vanishing, *key);
+        let denominator = keys
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(FieldElement::one(), |acc, (_, other_key)| acc * (*key - *other_key));
+        let scale = *value / denominator;
+        for (coefficient, basis_coefficient) in coefficients.iter_mut().zip(basis) {
+            *coefficient += scale * basis_coefficient;
+        }
+    }
+    coefficients
+}
+
+/// Multiplies the polynomial with the given coefficients by `(x - root)`.
+fn multiply_by_linear(coefficients: &[FieldElement], root: FieldElement) -> Vec<FieldElement> {
+    let mut product = vec![FieldElement::zero(); coefficients.len() + 1];
+    for (i, coefficient) in coefficients.iter().enumerate() {
+        product[i + 1] += *coefficient;
+        product[i] -= root * *coefficient;
+    }
+    product
+}
+
+/// Divides the polynomial with the given coefficients by `(x - root)`, which must be one of its roots.
+fn divide_by_linear(coefficients: &[FieldElement], root: FieldElement) -> Vec<FieldElement> {
+    let mut quotient = vec![FieldElement::zero(); coefficients.len() - 1];
+    let mut remainder = FieldElement::zero();
+    for i in (0..coefficients.len()).rev() {
+        remainder = coefficients[i] + remainder * root;
+        if i > 0 {
+            quotient[i - 1] = remainder;
+        }
+    }
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use acir::FieldElement;
+
+    use super::interpolate;
+
+    fn evaluate(coefficients: &[FieldElement], x: FieldElement) -> FieldElement {
+        coefficients
+            .iter()
+            .rev()
+            .fold(FieldElement::zero(), |acc, coefficient| acc * x + *coefficient)
+    }
+
+    #[test]
+    fn interpolates_values_at_keys() {
+        let keys: Vec<_> = [3u128, 7, 11, 200].into_iter().map(FieldElement::from).collect();
+        let values: Vec<_> = [5u128, 0, 42, 1].into_iter().map(FieldElement::from).collect();
+
+        let coefficients = interpolate(&keys, &values);
+
+        assert_eq!(coefficients.len(), keys.len());
+        for (key, value) in keys.iter().zip(&values) {
+            assert_eq!(evaluate(&coefficients, *key), *value);
+        }
+    }
+}
@@ -3,12 +3,11 @@ use iter_extended::vecmap;
 use noirc_frontend::macros_api::FieldElement;
 use noirc_frontend::macros_api::{
     BlockExpression, CallExpression, CastExpression, Distinctness, Expression, ExpressionKind,
-    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, FunctionVisibility,
-    HirContext, HirExpression, HirLiteral, HirStatement, Ident, ImportStatement, IndexExpression,
-    LetStatement, Literal, MemberAccessExpression, MethodCallExpression, NoirFunction, NoirStruct,
-    Param, Path, PathKind, Pattern, PrefixExpression, SecondaryAttribute, Signedness, Span,
-    Statement, StatementKind, StructType, Type, TypeImpl, UnaryOp, UnresolvedType,
-    UnresolvedTypeData, Visibility,
+    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, HirContext, HirExpression,
+    HirLiteral, HirStatement, Ident, ImportStatement, IndexExpression, ItemVisibility, Literal,
+    MemberAccessExpression, MethodCallExpression, NoirFunction, NoirStruct, Param, Path, PathKind,
+    Pattern, PrefixExpression, SecondaryAttribute, Signedness, Span, Statement, StatementKind,
+    StructType, Type, TypeImpl, UnaryOp, UnresolvedType, UnresolvedTypeData, Visibility,
 };
 use noirc_frontend::macros_api::{CrateId, FileId};
 use noirc_frontend::macros_api::{MacroError, MacroProcessor};
@@ -106,11 +105,10 @@ fn mutable(name: &str) -> Pattern {
 }
 
 fn mutable_assignment(name: &str, assigned_to: Expression) -> Statement {
-    make_statement(StatementKind::Let(LetStatement {
-        pattern: mutable(name),
-        r#type: make_type(UnresolvedTypeData::Unspecified),
-        expression: assigned_to,
-    }))
+    make_statement(StatementKind::new_let((
+        (mutable(name), make_type(UnresolvedTypeData::Unspecified)),
+        assigned_to,
+    )))
 }
 
 fn mutable_reference(variable_name: &str) -> Expression {
@@ -121,11 +119,10 @@ fn mutable_reference(variable_name: &str) -> Expression {
 }
 
 fn assignment(name: &str, assigned_to: Expression) -> Statement {
-    make_statement(StatementKind::Let(LetStatement {
-        pattern: pattern(name),
-        r#type: make_type(UnresolvedTypeData::Unspecified),
-        expression: assigned_to,
-    }))
+    make_statement(StatementKind::new_let((
+        (pattern(name), make_type(UnresolvedTypeData::Unspecified)),
+        assigned_to,
+    )))
 }
 
 fn member_access(lhs: &str, rhs: &str) -> Expression {
@@ -183,7 +180,12 @@ fn index_array_variable(array: Expression, index: &str) -> Expression {
 }
 
 fn import(path: Path) -> ImportStatement {
-    ImportStatement { path, alias: None }
+    ImportStatement {
+        visibility: ItemVisibility::Private,
+        path,
+        alias: None,
+        attributes: Vec::new(),
+    }
 }
 
 //
@@ -509,7 +511,7 @@ fn generate_selector_impl(structure: &NoirStruct) -> TypeImpl {
         &FunctionReturnType::Ty(make_type(UnresolvedTypeData::FieldElement)),
     );
 
-    selector_fn_def.visibility = FunctionVisibility::Public;
+    selector_fn_def.visibility = ItemVisibility::Public;
 
     // Seems to be necessary on contract modules
     selector_fn_def.return_visibility = Visibility::Public;
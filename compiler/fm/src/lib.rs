@@ -23,6 +23,9 @@ pub struct FileManager {
     id_to_path: HashMap<FileId, PathBuf>,
     path_to_id: HashMap<PathBuf, FileId>,
     file_reader: Box<FileReader>,
+    /// Sources which take precedence over the contents read by `file_reader`, e.g. the unsaved
+    /// contents of files open in an editor.
+    overlays: HashMap<PathBuf, String>,
 }
 
 impl std::fmt::Debug for FileManager {
@@ -32,6 +35,7 @@ impl std::fmt::Debug for FileManager {
             .field("file_map", &self.file_map)
             .field("id_to_path", &self.id_to_path)
             .field("path_to_id", &self.path_to_id)
+            .field("overlays", &self.overlays.keys().collect::<Vec<_>>())
             .finish()
     }
 }
@@ -44,9 +48,17 @@ impl FileManager {
             id_to_path: Default::default(),
             path_to_id: Default::default(),
             file_reader,
+            overlays: Default::default(),
         }
     }
 
+    /// Sets the source of the file at `file_name` to `source` rather than what can be read
+    /// from it. This only affects files which are added after the overlay.
+    pub fn add_overlay(&mut self, file_name: &Path, source: String) {
+        let resolved_path = self.root.join(file_name).normalize();
+        self.overlays.insert(resolved_path, source);
+    }
+
     pub fn as_file_map(&self) -> &FileMap {
         &self.file_map
     }
@@ -66,8 +78,11 @@ impl FileManager {
             return Some(*file_id);
         }
 
-        // Otherwise we add the file
-        let source = file_reader::read_file_to_string(&resolved_path, &self.file_reader).ok()?;
+        // Otherwise we add the file, preferring its overlay to what is on disk
+        let source = match self.overlays.get(&resolved_path) {
+            Some(source) => source.clone(),
+            None => file_reader::read_file_to_string(&resolved_path, &self.file_reader).ok()?,
+        };
         let file_id = self.file_map.add_file(resolved_path.clone().into(), source);
         self.register_path(file_id, resolved_path);
         Some(file_id)
@@ -94,6 +109,14 @@ impl FileManager {
         self.id_to_path.get(&file_id).unwrap().as_path()
     }
 
+    /// Returns the ids and paths of all files, in the order in which they were added.
+    pub fn files(&self) -> Vec<(FileId, &Path)> {
+        let mut files: Vec<_> =
+            self.id_to_path.iter().map(|(file_id, path)| (*file_id, path.as_path())).collect();
+        files.sort_by_key(|(file_id, _)| *file_id);
+        files
+    }
+
     pub fn find_module(&mut self, anchor: FileId, mod_name: &str) -> Result<FileId, String> {
         let anchor_path = self.path(anchor).with_extension("");
         let anchor_dir = anchor_path.parent().unwrap();
@@ -210,6 +233,7 @@ mod path_normalization {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use iter_extended::vecmap;
     use tempfile::{tempdir, TempDir};
 
     fn create_dummy_file(dir: &TempDir, file_name: &Path) {
@@ -285,6 +309,41 @@ mod tests {
         fm.find_module(sub_dir_file_id, "foo").unwrap();
     }
 
+    #[test]
+    fn overlay_takes_precedence_over_file_contents() {
+        let dir = tempdir().unwrap();
+        let file_name = Path::new("lib.nr");
+        create_dummy_file(&dir, file_name);
+
+        let mut fm = FileManager::new(dir.path(), Box::new(|path| std::fs::read_to_string(path)));
+        fm.add_overlay(&dir.path().join(file_name), "fn main() {}".to_string());
+
+        let file_id = fm.add_file(file_name).unwrap();
+        assert_eq!(fm.fetch_file(file_id).source(), "fn main() {}");
+
+        // Overlays also stand in for files which do not exist yet.
+        fm.add_overlay(Path::new("unsaved.nr"), "fn foo() {}".to_string());
+        let file_id = fm.add_file(Path::new("unsaved.nr")).unwrap();
+        assert_eq!(fm.fetch_file(file_id).source(), "fn foo() {}");
+    }
+
+    #[test]
+    fn files_are_listed_in_the_order_they_were_added() {
+        let dir = tempdir().unwrap();
+        for file_name in ["lib.nr", "foo.nr", "bar.nr"] {
+            create_dummy_file(&dir, Path::new(file_name));
+        }
+
+        let mut fm = FileManager::new(dir.path(), Box::new(|path| std::fs::read_to_string(path)));
+        let file_ids = vecmap(["lib.nr", "foo.nr", "bar.nr"], |file_name| {
+            fm.add_file(Path::new(file_name)).unwrap()
+        });
+
+        let files = fm.files();
+        assert_eq!(vecmap(&files, |(file_id, _)| *file_id), file_ids);
+        assert!(files[2].1.ends_with("bar.nr"));
+    }
+
     /// Tests that two identical files that have different paths are treated as the same file
     /// e.g. if we start in the dir ./src and have a file ../../foo.nr
     /// that should be treated as the same file as ../ starting in ./
@@ -1,13 +1,16 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::fmt::Display;
 
 use acvm::acir::circuit::Circuit;
 use fm::FileId;
-use noirc_abi::{Abi, ContractEvent};
+use iter_extended::vecmap;
+use noirc_abi::{Abi, AbiType, ContractEvent, Sign};
 use noirc_errors::debug_info::DebugInfo;
 use noirc_evaluator::errors::SsaReport;
 
 use super::debug::DebugFile;
+use super::CompiledProgram;
 
 /// Describes the types of smart contract functions that are allowed.
 /// Unlike the similar enum in noirc_frontend, 'open' and 'unconstrained'
@@ -45,6 +48,23 @@ pub struct CompiledContract {
     pub warnings: Vec<SsaReport>,
 }
 
+impl CompiledContract {
+    /// Takes the function `name` out of the contract as a standalone program, so that it can be
+    /// executed and proven in the same way as the `main` function of a binary package.
+    pub fn into_function_program(self, name: &str) -> Option<CompiledProgram> {
+        let function = self.functions.into_iter().find(|function| function.name == name)?;
+        Some(CompiledProgram {
+            noir_version: self.noir_version,
+            hash: function.hash,
+            circuit: function.bytecode,
+            abi: function.abi,
+            debug: function.debug,
+            file_map: self.file_map,
+            warnings: self.warnings,
+        })
+    }
+}
+
 /// Each function in the contract will be compiled
 /// as a separate noir program.
 ///
@@ -55,6 +75,8 @@ pub struct CompiledContract {
 pub struct ContractFunction {
     pub name: String,
 
+    pub selector: FunctionSelector,
+
     pub function_type: ContractFunctionType,
 
     pub is_internal: bool,
@@ -68,6 +90,9 @@ pub struct ContractFunction {
     pub bytecode: Circuit,
 
     pub debug: DebugInfo,
+
+    /// Hash of the monomorphized program from which the function was compiled, see [`CompiledProgram::hash`].
+    pub hash: u64,
 }
 
 impl ContractFunctionType {
@@ -79,3 +104,127 @@ impl ContractFunctionType {
         }
     }
 }
+
+/// Identifies a contract function by the first four bytes of the keccak256 hash of its signature,
+/// e.g. `transfer(Field,u64)`.
+///
+/// The signature is made of the function's name followed by the types of its parameters, in which
+/// structs and tuples are written out as the tuple of their fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct FunctionSelector([u8; 4]);
+
+impl FunctionSelector {
+    pub fn new(name: &str, abi: &Abi) -> Self {
+        let hash = acvm::blackbox_solver::keccak256(function_signature(name, abi).as_bytes())
+            .expect("keccak256 can hash any input");
+        FunctionSelector([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.0)
+    }
+}
+
+impl Display for FunctionSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x{:08x}", self.to_u32())
+    }
+}
+
+impl From<FunctionSelector> for String {
+    fn from(selector: FunctionSelector) -> Self {
+        selector.to_string()
+    }
+}
+
+impl TryFrom<String> for FunctionSelector {
+    type Error = String;
+
+    fn try_from(selector: String) -> Result<Self, Self::Error> {
+        selector
+            .strip_prefix("0x")
+            .filter(|digits| digits.len() == 8)
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .map(|selector| FunctionSelector(selector.to_be_bytes()))
+            .ok_or_else(|| format!("invalid function selector: {selector}"))
+    }
+}
+
+/// Returns the signature from which the selector of the function `name` with the given `abi` is computed.
+pub fn function_signature(name: &str, abi: &Abi) -> String {
+    fn type_signature(typ: &AbiType) -> String {
+        match typ {
+            AbiType::Field => "Field".to_string(),
+            AbiType::Integer { sign: Sign::Unsigned, width } => format!("u{width}"),
+            AbiType::Integer { sign: Sign::Signed, width } => format!("i{width}"),
+            AbiType::Boolean => "bool".to_string(),
+            AbiType::String { length } => format!("str<{length}>"),
+            AbiType::Array { length, typ } => format!("[{};{length}]", type_signature(typ)),
+            AbiType::Struct { fields, .. } => {
+                format!("({})", vecmap(fields, |(_, typ)| type_signature(typ)).join(","))
+            }
+            AbiType::Tuple { fields } => {
+                format!("({})", vecmap(fields, type_signature).join(","))
+            }
+        }
+    }
+
+    let parameters = vecmap(&abi.parameters, |parameter| type_signature(&parameter.typ));
+    format!("{name}({})", parameters.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use noirc_abi::{Abi, AbiParameter, AbiType, AbiVisibility, Sign};
+
+    use super::{function_signature, FunctionSelector};
+
+    fn abi(parameters: Vec<AbiType>) -> Abi {
+        let parameters = parameters
+            .into_iter()
+            .enumerate()
+            .map(|(index, typ)| AbiParameter {
+                name: format!("param{index}"),
+                typ,
+                visibility: AbiVisibility::Private,
+            })
+            .collect();
+        Abi {
+            parameters,
+            param_witnesses: BTreeMap::new(),
+            return_type: None,
+            return_witnesses: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn signature_flattens_structs() {
+        let point = AbiType::Struct {
+            path: "Point".to_string(),
+            fields: vec![("x".to_string(), AbiType::Field), ("y".to_string(), AbiType::Field)],
+        };
+        let abi = abi(vec![
+            AbiType::Array { length: 2, typ: Box::new(point) },
+            AbiType::Integer { sign: Sign::Unsigned, width: 64 },
+            AbiType::Boolean,
+        ]);
+        assert_eq!(function_signature("transfer", &abi), "transfer([(Field,Field);2],u64,bool)");
+    }
+
+    #[test]
+    fn selector_round_trips_through_hex() {
+        let selector = FunctionSelector::new("transfer", &abi(vec![AbiType::Field]));
+        // The selector doesn't depend on the names of the parameters.
+        let mut renamed = abi(vec![AbiType::Field]);
+        renamed.parameters[0].name = "amount".to_string();
+        assert_eq!(selector, FunctionSelector::new("transfer", &renamed));
+
+        let hex = String::from(selector);
+        assert_eq!(hex.len(), 10);
+        assert_eq!(FunctionSelector::try_from(hex), Ok(selector));
+        assert!(FunctionSelector::try_from("0x1234".to_string()).is_err());
+    }
+}
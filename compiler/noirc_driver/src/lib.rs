@@ -3,21 +3,27 @@
 #![warn(unreachable_pub)]
 #![warn(clippy::semicolon_if_nothing_returned)]
 
+use acvm::FieldElement;
 use clap::Args;
 use fm::FileId;
 use iter_extended::vecmap;
-use noirc_abi::{AbiParameter, AbiType, ContractEvent};
-use noirc_errors::{CustomDiagnostic, FileDiagnostic};
-use noirc_evaluator::create_circuit;
+use noirc_abi::input_parser::Format;
+use noirc_abi::{Abi, AbiParameter, AbiType, ContractEvent};
+use noirc_errors::{CustomDiagnostic, FileDiagnostic, MessageFormat, Span};
 use noirc_evaluator::errors::RuntimeError;
+use noirc_evaluator::{check_underconstrained_values, create_circuit, SsaEvaluatorOptions};
 use noirc_frontend::graph::{CrateId, CrateName};
 use noirc_frontend::hir::def_map::{Contract, CrateDefMap};
+use noirc_frontend::hir::resolution::find_dead_code;
 use noirc_frontend::hir::Context;
 use noirc_frontend::macros_api::MacroProcessor;
 use noirc_frontend::monomorphization::monomorphize;
 use noirc_frontend::node_interner::FuncId;
+use noirc_frontend::token::SecondaryAttribute;
+use noirc_frontend::FunctionKind;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 mod abi_gen;
 mod contract;
@@ -26,7 +32,9 @@ mod program;
 
 use debug::filter_relevant_files;
 
-pub use contract::{CompiledContract, ContractFunction, ContractFunctionType};
+pub use contract::{
+    function_signature, CompiledContract, ContractFunction, ContractFunctionType, FunctionSelector,
+};
 pub use debug::DebugFile;
 pub use program::CompiledProgram;
 
@@ -41,7 +49,7 @@ pub const NOIRC_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const NOIR_ARTIFACT_VERSION_STRING: &str =
     concat!(env!("CARGO_PKG_VERSION"), "+", env!("GIT_COMMIT"));
 
-#[derive(Args, Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Args, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompileOptions {
     /// Emit debug information for the intermediate SSA IR
     #[arg(long, hide = true)]
@@ -50,10 +58,45 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub show_brillig: bool,
 
+    /// Interpret the SSA after each optimization pass, panicking if a pass changes the results of the program
+    #[arg(long, hide = true)]
+    pub validate_ssa: bool,
+
+    /// The file holding inputs to `main`, in the `Prover.toml` format, with which to interpret
+    /// the SSA when validating its passes
+    #[arg(skip)]
+    pub validate_ssa_inputs: Option<PathBuf>,
+
+    /// Limit the number of registers holding variables in each unconstrained function,
+    /// spilling the remaining variables to memory
+    ///
+    /// Temporaries and the registers used to pass arguments between functions are not counted.
+    #[arg(long)]
+    pub brillig_register_file_size: Option<usize>,
+
+    /// Emit the Brillig bytecode of each function without running the peephole optimizer over it
+    #[arg(long, hide = true)]
+    pub skip_brillig_optimizer: bool,
+
+    /// Compile without the loop invariant code motion and common subexpression elimination passes
+    #[arg(long, hide = true)]
+    pub skip_redundancy_elimination: bool,
+
     /// Display the ACIR for compiled circuit
     #[arg(long)]
     pub print_acir: bool,
 
+    /// Compile `main` as an unconstrained function, so that the whole program runs in the Brillig VM
+    #[arg(long, hide = true)]
+    pub force_brillig: bool,
+
+    /// Make every integer overflow a constraint failure, including left shifts which discard set bits
+    ///
+    /// Integer `+`, `-` and `*` always fail on overflow. Use `std::wrapping_add` and similar
+    /// functions for arithmetic which is meant to wrap around.
+    #[arg(long)]
+    pub strict_overflow: bool,
+
     /// Treat all warnings as errors
     #[arg(long, conflicts_with = "silence_warnings")]
     pub deny_warnings: bool,
@@ -61,6 +104,10 @@ pub struct CompileOptions {
     /// Suppress warnings
     #[arg(long, conflicts_with = "deny_warnings")]
     pub silence_warnings: bool,
+
+    /// Format of the reported diagnostics: `human` or `json`
+    #[arg(long, default_value = "human")]
+    pub message_format: MessageFormat,
 }
 
 /// Helper type used to signify where only warnings are expected in file diagnostics
@@ -114,6 +161,7 @@ pub fn add_dep(
 }
 
 /// Run the lexing, parsing, name resolution, and type checking passes.
+/// If these succeed, the crate is then checked for unused items.
 ///
 /// This returns a (possibly empty) vector of any warnings found on success.
 /// On error, this returns a non-empty vector of warnings and error messages, with at least one error.
@@ -134,6 +182,15 @@ pub fn check_crate(
         diagnostic.in_file(file_id)
     }));
 
+    // Whether an item is used is only meaningful once the whole crate resolves and type checks.
+    if !has_errors(&errors, false) {
+        errors.extend(find_dead_code(context, crate_id).into_iter().map(|(error, file_id)| {
+            let diagnostic: CustomDiagnostic = error.into();
+            diagnostic.in_file(file_id)
+        }));
+    }
+    remove_allowed_warnings(context, &mut errors);
+
     if has_errors(&errors, deny_warnings) {
         Err(errors)
     } else {
@@ -174,7 +231,8 @@ pub fn compile_main(
 
     let compiled_program = compile_no_check(context, options, main, cached_program, force_compile)
         .map_err(FileDiagnostic::from)?;
-    let compilation_warnings = vecmap(compiled_program.warnings.clone(), FileDiagnostic::from);
+    let mut compilation_warnings = vecmap(compiled_program.warnings.clone(), FileDiagnostic::from);
+    remove_allowed_warnings(context, &mut compilation_warnings);
     if options.deny_warnings && !compilation_warnings.is_empty() {
         return Err(compilation_warnings);
     }
@@ -241,6 +299,86 @@ pub fn compile_contract(
     }
 }
 
+/// Run the SSA passes over the crate's entry points to look for results of unconstrained
+/// function calls which are never constrained against the arguments of the call.
+///
+/// These are reported as warnings, which are only produced on request as the check is a
+/// heuristic. This function assumes [`check_crate`] is called beforehand.
+pub fn lint_crate(context: &Context, crate_id: CrateId) -> CompilationResult<()> {
+    let mut entry_points: Vec<FuncId> = context.get_main_function(&crate_id).into_iter().collect();
+    for contract in context.get_all_contracts(&crate_id) {
+        entry_points.extend(
+            contract
+                .functions
+                .iter()
+                .filter(|function| function.is_entry_point)
+                .map(|function| function.function_id),
+        );
+    }
+
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    for entry_point in entry_points {
+        let program = monomorphize(entry_point, &context.def_interner);
+        match check_underconstrained_values(program) {
+            Ok(reports) => warnings.extend(reports.into_iter().map(FileDiagnostic::from)),
+            Err(error) => errors.push(FileDiagnostic::from(error)),
+        }
+    }
+    remove_allowed_warnings(context, &mut warnings);
+
+    if errors.is_empty() {
+        Ok(((), warnings))
+    } else {
+        errors.extend(warnings);
+        Err(errors)
+    }
+}
+
+/// Removes the warnings reported within functions which allow their code with an `#[allow(...)]`
+/// attribute.
+fn remove_allowed_warnings(context: &Context, diagnostics: &mut Vec<FileDiagnostic>) {
+    let interner = &context.def_interner;
+    let allowed_regions: Vec<(FileId, Span, &[String])> = interner
+        .function_ids()
+        .filter_map(|func_id| {
+            let codes =
+                interner.function_attributes(&func_id).secondary.iter().find_map(|attribute| {
+                    match attribute {
+                        SecondaryAttribute::Allow(codes) => Some(codes.as_slice()),
+                        _ => None,
+                    }
+                })?;
+
+            let meta = interner.function_meta(&func_id);
+            if meta.kind != FunctionKind::Normal {
+                return None;
+            }
+            // The region starts at the name so that it covers the function's signature as well.
+            let body = interner.expr_location(interner.function(&func_id).as_expr());
+            let region = meta.name.location.span.merge(body.span);
+            Some((body.file, region, codes))
+        })
+        .collect();
+
+    if allowed_regions.is_empty() {
+        return;
+    }
+
+    diagnostics.retain(|FileDiagnostic { file_id, diagnostic, .. }| {
+        let (Some(code), Some(label)) = (diagnostic.code, diagnostic.secondaries.first()) else {
+            return true;
+        };
+        let is_allowed = allowed_regions.iter().any(|(file, region, codes)| {
+            file == file_id
+                && region.start() <= label.span.start()
+                && label.span.end() <= region.end()
+                && codes.iter().any(|allowed| allowed == code.as_str())
+        });
+        diagnostic.is_error() || !is_allowed
+    });
+}
+
 /// True if there are (non-warning) errors present and we should halt compilation
 fn has_errors(errors: &[FileDiagnostic], deny_warnings: bool) -> bool {
     if deny_warnings {
@@ -289,12 +427,14 @@ fn compile_contract_inner(
         let function_type = ContractFunctionType::new(func_type, modifiers.is_unconstrained);
 
         functions.push(ContractFunction {
+            selector: FunctionSelector::new(&name, &function.abi),
             name,
             function_type,
             is_internal: modifiers.is_internal.unwrap_or(false),
             abi: function.abi,
             bytecode: function.circuit,
             debug: function.debug,
+            hash: function.hash,
         });
     }
 
@@ -333,22 +473,46 @@ pub fn compile_no_check(
     cached_program: Option<CompiledProgram>,
     force_compile: bool,
 ) -> Result<CompiledProgram, RuntimeError> {
-    let program = monomorphize(main_function, &context.def_interner);
+    let mut program = monomorphize(main_function, &context.def_interner);
+    if options.force_brillig {
+        program.main_mut().unconstrained = true;
+    }
 
     let hash = fxhash::hash64(&program);
     let hashes_match = cached_program.as_ref().map_or(false, |program| program.hash == hash);
 
     // If user has specified that they want to see intermediate steps printed then we should
     // force compilation even if the program hasn't changed.
-    let force_compile =
-        force_compile || options.print_acir || options.show_brillig || options.show_ssa;
+    // The same goes for options changing the generated code, as the cache doesn't record them.
+    let force_compile = force_compile
+        || options.print_acir
+        || options.show_brillig
+        || options.show_ssa
+        || options.validate_ssa
+        || options.strict_overflow
+        || options.brillig_register_file_size.is_some()
+        || options.skip_brillig_optimizer
+        || options.skip_redundancy_elimination;
 
     if !force_compile && hashes_match {
         return Ok(cached_program.expect("cache must exist for hashes to match"));
     }
 
+    let ssa_validation_inputs = options.validate_ssa.then(|| {
+        read_ssa_validation_inputs(context, &main_function, options.validate_ssa_inputs.as_deref())
+    });
+
+    let ssa_options = SsaEvaluatorOptions {
+        enable_ssa_logging: options.show_ssa,
+        validate_ssa_passes: ssa_validation_inputs.as_deref(),
+        enable_brillig_logging: options.show_brillig,
+        brillig_register_file_size: options.brillig_register_file_size,
+        optimize_brillig: !options.skip_brillig_optimizer,
+        strict_overflow: options.strict_overflow,
+        eliminate_redundancy: !options.skip_redundancy_elimination,
+    };
     let (circuit, debug, input_witnesses, return_witnesses, warnings) =
-        create_circuit(program, options.show_ssa, options.show_brillig)?;
+        create_circuit(program, &ssa_options)?;
 
     let abi = abi_gen::gen_abi(context, &main_function, input_witnesses, return_witnesses);
     let file_map = filter_relevant_files(&[debug.clone()], &context.file_manager);
@@ -363,3 +527,32 @@ pub fn compile_no_check(
         warnings,
     })
 }
+
+/// Reads the inputs to `main` in the file at `inputs_path` as the field elements of its parameters,
+/// to interpret the SSA with when validating its passes.
+///
+/// There are no inputs if the file doesn't exist. Panics if the file holds invalid inputs.
+fn read_ssa_validation_inputs(
+    context: &Context,
+    main_function: &FuncId,
+    inputs_path: Option<&Path>,
+) -> Vec<Vec<FieldElement>> {
+    let Some(inputs) = inputs_path.and_then(|path| std::fs::read_to_string(path).ok()) else {
+        return Vec::new();
+    };
+
+    let (parameters, return_type) = abi_gen::compute_function_abi(context, main_function);
+    let abi = Abi {
+        parameters,
+        param_witnesses: BTreeMap::new(),
+        return_type,
+        return_witnesses: Vec::new(),
+    };
+    let input_map = Format::Toml.parse(&inputs, &abi).unwrap_or_else(|error| {
+        panic!("Failed to read the inputs to validate the SSA with: {error}")
+    });
+    let fields = abi.encode_parameters(&input_map).unwrap_or_else(|error| {
+        panic!("Failed to read the inputs to validate the SSA with: {error}")
+    });
+    vec![fields]
+}
@@ -1,4 +1,5 @@
 use acvm::acir::circuit::OpcodeLocation;
+use acvm::acir::native_types::Expression;
 use acvm::compiler::AcirTransformationMap;
 
 use serde_with::serde_as;
@@ -18,6 +19,11 @@ pub struct DebugInfo {
     /// that they should be serialized to/from strings.
     #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
     pub locations: BTreeMap<OpcodeLocation, Vec<Location>>,
+    /// Map opcode index of an ACIR circuit into the side effects predicate under which it was generated.
+    /// The opcode only has an effect when its predicate evaluates to a non-zero value.
+    #[serde(default)]
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    pub predicates: BTreeMap<OpcodeLocation, Expression>,
 }
 
 /// Holds OpCodes Counts for Acir and Brillig Opcodes
@@ -29,8 +35,11 @@ pub struct OpCodesCount {
 }
 
 impl DebugInfo {
-    pub fn new(locations: BTreeMap<OpcodeLocation, Vec<Location>>) -> Self {
-        DebugInfo { locations }
+    pub fn new(
+        locations: BTreeMap<OpcodeLocation, Vec<Location>>,
+        predicates: BTreeMap<OpcodeLocation, Expression>,
+    ) -> Self {
+        DebugInfo { locations, predicates }
     }
 
     /// Updates the locations map when the [`Circuit`][acvm::acir::circuit::Circuit] is modified.
@@ -46,12 +55,24 @@ impl DebugInfo {
                 self.locations.insert(new_opcode_location, source_locations.clone());
             });
         }
+
+        let old_predicates = mem::take(&mut self.predicates);
+
+        for (old_opcode_location, predicate) in old_predicates {
+            update_map.new_locations(old_opcode_location).for_each(|new_opcode_location| {
+                self.predicates.insert(new_opcode_location, predicate.clone());
+            });
+        }
     }
 
     pub fn opcode_location(&self, loc: &OpcodeLocation) -> Option<Vec<Location>> {
         self.locations.get(loc).cloned()
     }
 
+    pub fn opcode_predicate(&self, loc: &OpcodeLocation) -> Option<&Expression> {
+        self.predicates.get(loc)
+    }
+
     pub fn count_span_opcodes(&self) -> HashMap<Location, OpCodesCount> {
         let mut accumulator: HashMap<Location, Vec<&OpcodeLocation>> = HashMap::new();
 
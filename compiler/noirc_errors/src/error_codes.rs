@@ -0,0 +1,121 @@
+//! Stable codes identifying each kind of diagnostic reported by the compiler.
+//!
+//! Codes are grouped by the compiler pass reporting them:
+//!
+//! - `E00xx`: lexing
+//! - `E01xx`: parsing
+//! - `E02xx`: definition collection and path resolution
+//! - `E03xx`: name resolution
+//! - `E04xx`: type checking
+//! - `E05xx`: SSA evaluation
+//!
+//! A code must never be reused for a different kind of error once released, even if the error
+//! it was assigned to is removed. Each code has a long-form explanation in `error_codes/`,
+//! which `nargo explain` prints.
+
+use std::fmt;
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorCode {
+    code: &'static str,
+    explanation: &'static str,
+}
+
+impl ErrorCode {
+    /// Returns the code as displayed to users, e.g. `E0301`.
+    pub fn as_str(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the long-form explanation of the error, in markdown.
+    pub fn explanation(&self) -> &'static str {
+        self.explanation
+    }
+
+    /// Looks up the error code with the given name, ignoring case.
+    pub fn find(code: &str) -> Option<ErrorCode> {
+        ALL.iter().find(|error_code| error_code.code.eq_ignore_ascii_case(code)).copied()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+// Error codes are serialized as the code alone, e.g. `"E0301"`.
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        ErrorCode::find(&code)
+            .ok_or_else(|| D::Error::custom(format!("{code} is not a valid error code")))
+    }
+}
+
+macro_rules! error_codes {
+    ($($code:ident,)*) => {
+        $(
+            pub const $code: ErrorCode = ErrorCode {
+                code: stringify!($code),
+                explanation: include_str!(concat!("error_codes/", stringify!($code), ".md")),
+            };
+        )*
+
+        /// Every error code, in ascending order.
+        pub const ALL: &[ErrorCode] = &[$($code,)*];
+    };
+}
+
+error_codes! {
+    E0001, E0002, E0003, E0004, E0005, E0006, E0007, E0008, E0009,
+
+    E0100, E0101, E0102, E0103, E0104, E0105, E0106, E0107, E0108, E0109, E0110, E0111, E0112,
+
+    E0200, E0201, E0202, E0203, E0204, E0205, E0206, E0207, E0208, E0209, E0210, E0211, E0212,
+    E0213, E0214, E0220, E0221, E0222, E0223, E0224,
+
+    E0300, E0301, E0302, E0303, E0304, E0305, E0306, E0307, E0308, E0309, E0310, E0311, E0312,
+    E0313, E0314, E0315, E0316, E0317, E0318, E0319, E0320, E0321, E0322, E0323, E0324, E0325,
+    E0326, E0327, E0328, E0329, E0330, E0331, E0332, E0333,
+
+    E0400, E0401, E0402, E0403, E0404, E0405, E0406, E0407, E0408, E0409, E0410, E0411, E0412,
+    E0413, E0414, E0415, E0416, E0417, E0418, E0419, E0420, E0421, E0422, E0423, E0424, E0425,
+    E0426, E0427, E0428, E0429, E0430, E0431, E0432, E0433,
+
+    E0500, E0501, E0502, E0503, E0504, E0505, E0506, E0507, E0508, E0509, E0510, E0511, E0512,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorCode, ALL, E0301};
+
+    #[test]
+    fn codes_are_unique_and_sorted() {
+        for pair in ALL.windows(2) {
+            assert!(pair[0].as_str() < pair[1].as_str(), "{} is out of order", pair[1]);
+        }
+    }
+
+    #[test]
+    fn every_code_is_explained() {
+        for code in ALL {
+            assert!(!code.explanation().trim().is_empty(), "{code} has no explanation");
+        }
+    }
+
+    #[test]
+    fn finds_codes_ignoring_case() {
+        assert_eq!(ErrorCode::find("E0301"), Some(E0301));
+        assert_eq!(ErrorCode::find("e0301"), Some(E0301));
+        assert_eq!(ErrorCode::find("E9999"), None);
+    }
+}
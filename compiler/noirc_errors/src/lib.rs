@@ -4,12 +4,17 @@
 #![warn(clippy::semicolon_if_nothing_returned)]
 
 pub mod debug_info;
+pub mod error_codes;
 mod position;
 pub mod reporter;
+pub use error_codes::ErrorCode;
 pub use position::{Location, Position, Span, Spanned};
-pub use reporter::{CustomDiagnostic, DiagnosticKind};
+pub use reporter::{
+    Applicability, CustomDiagnostic, DiagnosticKind, MessageFormat, Replacement, Suggestion,
+};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FileDiagnostic {
     pub file_id: fm::FileId,
     pub diagnostic: CustomDiagnostic,
@@ -1,23 +1,99 @@
-use crate::{FileDiagnostic, Location, Span};
+use crate::{ErrorCode, FileDiagnostic, Location, Span};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::files::Files;
 use codespan_reporting::term;
 use codespan_reporting::term::termcolor::{ColorChoice, StandardStream};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CustomDiagnostic {
     pub message: String,
     pub secondaries: Vec<CustomLabel>,
     notes: Vec<String>,
     pub kind: DiagnosticKind,
+    pub code: Option<ErrorCode>,
+    pub suggestions: Vec<Suggestion>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DiagnosticKind {
     Error,
     Warning,
 }
 
+/// A change to the source code which would fix a diagnostic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub message: String,
+    /// The replacements making up this suggestion, which do not overlap
+    pub replacements: Vec<Replacement>,
+    pub applicability: Applicability,
+}
+
+/// Replaces the source code within `span` with `text`.
+/// An empty span inserts `text` at its position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replacement {
+    pub span: Span,
+    pub text: String,
+}
+
+/// How confident we are that a [Suggestion] is what the user wants
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Applicability {
+    /// The suggestion is definitely correct and can be applied automatically, e.g. by `nargo fix`
+    MachineApplicable,
+    /// The suggestion may not be what the user intended and should be reviewed before applying it
+    MaybeIncorrect,
+}
+
+impl Suggestion {
+    pub fn new(
+        message: String,
+        span: Span,
+        text: String,
+        applicability: Applicability,
+    ) -> Suggestion {
+        Suggestion::multipart(message, vec![Replacement { span, text }], applicability)
+    }
+
+    pub fn multipart(
+        message: String,
+        replacements: Vec<Replacement>,
+        applicability: Applicability,
+    ) -> Suggestion {
+        Suggestion { message, replacements, applicability }
+    }
+
+    pub fn is_machine_applicable(&self) -> bool {
+        matches!(self.applicability, Applicability::MachineApplicable)
+    }
+}
+
+/// The format in which diagnostics are reported
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageFormat {
+    /// Rendered for humans, with colours and source snippets, to stderr
+    #[default]
+    Human,
+    /// One JSON object per diagnostic and per line, to stdout
+    Json,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => Err(format!("unknown message format `{format}`, expected `human` or `json`")),
+        }
+    }
+}
+
 /// A count of errors that have been already reported to stderr
 #[derive(Debug, Copy, Clone)]
 pub struct ReportedErrors {
@@ -31,6 +107,8 @@ impl CustomDiagnostic {
             secondaries: Vec::new(),
             notes: Vec::new(),
             kind: DiagnosticKind::Error,
+            code: None,
+            suggestions: Vec::new(),
         }
     }
 
@@ -44,6 +122,8 @@ impl CustomDiagnostic {
             secondaries: vec![CustomLabel::new(secondary_message, secondary_span)],
             notes: Vec::new(),
             kind: DiagnosticKind::Error,
+            code: None,
+            suggestions: Vec::new(),
         }
     }
 
@@ -57,9 +137,16 @@ impl CustomDiagnostic {
             secondaries: vec![CustomLabel::new(secondary_message, secondary_span)],
             notes: Vec::new(),
             kind: DiagnosticKind::Warning,
+            code: None,
+            suggestions: Vec::new(),
         }
     }
 
+    pub fn with_code(mut self, code: ErrorCode) -> CustomDiagnostic {
+        self.code = Some(code);
+        self
+    }
+
     pub fn in_file(self, file_id: fm::FileId) -> FileDiagnostic {
         FileDiagnostic::new(file_id, self)
     }
@@ -72,6 +159,10 @@ impl CustomDiagnostic {
         self.secondaries.push(CustomLabel::new(message, span));
     }
 
+    pub fn add_suggestion(&mut self, suggestion: Suggestion) {
+        self.suggestions.push(suggestion);
+    }
+
     pub fn is_error(&self) -> bool {
         matches!(self.kind, DiagnosticKind::Error)
     }
@@ -93,11 +184,15 @@ impl std::fmt::Display for CustomDiagnostic {
             write!(f, "\nnote: {note}")?;
         }
 
+        for suggestion in &self.suggestions {
+            write!(f, "\nhelp: {}", suggestion.message)?;
+        }
+
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CustomLabel {
     pub message: String,
     pub span: Span,
@@ -109,13 +204,14 @@ impl CustomLabel {
     }
 }
 
-/// Writes the given diagnostics to stderr and returns the count
+/// Writes the given diagnostics to stderr, or to stdout as JSON, and returns the count
 /// of diagnostics that were errors.
 pub fn report_all<'files>(
     files: &'files impl Files<'files, FileId = fm::FileId>,
     diagnostics: &[FileDiagnostic],
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> ReportedErrors {
     // Report warnings before any errors
     let (warnings, mut errors): (Vec<_>, _) =
@@ -124,8 +220,13 @@ pub fn report_all<'files>(
     let mut diagnostics = if silence_warnings { Vec::new() } else { warnings };
     diagnostics.append(&mut errors);
 
-    let error_count =
-        diagnostics.iter().map(|error| error.report(files, deny_warnings) as u32).sum();
+    let error_count = diagnostics
+        .iter()
+        .map(|error| match message_format {
+            MessageFormat::Human => error.report(files, deny_warnings),
+            MessageFormat::Json => error.report_json(files, deny_warnings),
+        } as u32)
+        .sum();
 
     ReportedErrors { error_count }
 }
@@ -138,6 +239,153 @@ impl FileDiagnostic {
     ) -> bool {
         report(files, &self.diagnostic, Some(self.file_id), &self.call_stack, deny_warnings)
     }
+
+    /// Writes this diagnostic to stdout as a single line of JSON,
+    /// and returns true if it was an error
+    pub fn report_json<'files>(
+        &self,
+        files: &'files impl Files<'files, FileId = fm::FileId>,
+        deny_warnings: bool,
+    ) -> bool {
+        println!("{}", self.to_json(files, deny_warnings));
+        deny_warnings || self.diagnostic.is_error()
+    }
+
+    /// Serializes this diagnostic to JSON, resolving its spans to lines and columns in `files`.
+    pub fn to_json<'files>(
+        &self,
+        files: &'files impl Files<'files, FileId = fm::FileId>,
+        deny_warnings: bool,
+    ) -> String {
+        let diagnostic = &self.diagnostic;
+        let severity = match (diagnostic.kind, deny_warnings) {
+            (DiagnosticKind::Warning, false) => "warning",
+            _ => "error",
+        };
+
+        let spans = diagnostic
+            .secondaries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, label)| {
+                Some(JsonSpan {
+                    location: JsonLocation::new(files, self.file_id, label.span)?,
+                    // Secondary labels are what the terminal output highlights,
+                    // the first one being where the diagnostic points to.
+                    is_primary: i == 0,
+                    label: &label.message,
+                })
+            })
+            .collect();
+
+        let call_stack = self
+            .call_stack
+            .iter()
+            .filter_map(|location| JsonLocation::new(files, location.file, location.span))
+            .collect();
+
+        let suggestions = diagnostic
+            .suggestions
+            .iter()
+            // A suggestion is only useful if all of its replacements can be applied.
+            .filter_map(|suggestion| {
+                let replacements = suggestion
+                    .replacements
+                    .iter()
+                    .map(|replacement| {
+                        Some(JsonReplacement {
+                            location: JsonLocation::new(files, self.file_id, replacement.span)?,
+                            text: &replacement.text,
+                        })
+                    })
+                    .collect::<Option<_>>()?;
+                Some(JsonSuggestion {
+                    message: &suggestion.message,
+                    applicability: suggestion.applicability,
+                    replacements,
+                })
+            })
+            .collect();
+
+        let json = JsonDiagnostic {
+            severity,
+            message: &diagnostic.message,
+            code: diagnostic.code.map(|code| code.as_str()),
+            spans,
+            notes: &diagnostic.notes,
+            suggestions,
+            call_stack,
+        };
+        serde_json::to_string(&json).expect("diagnostics should serialize to JSON")
+    }
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    severity: &'static str,
+    message: &'a str,
+    code: Option<&'static str>,
+    spans: Vec<JsonSpan<'a>>,
+    notes: &'a [String],
+    suggestions: Vec<JsonSuggestion<'a>>,
+    call_stack: Vec<JsonLocation>,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion<'a> {
+    message: &'a str,
+    applicability: Applicability,
+    replacements: Vec<JsonReplacement<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonReplacement<'a> {
+    #[serde(flatten)]
+    location: JsonLocation,
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonSpan<'a> {
+    #[serde(flatten)]
+    location: JsonLocation,
+    is_primary: bool,
+    label: &'a str,
+}
+
+/// A span within a file. Lines and columns are 1-based, byte offsets are 0-based.
+#[derive(Serialize)]
+struct JsonLocation {
+    file: String,
+    byte_start: u32,
+    byte_end: u32,
+    line_start: usize,
+    column_start: usize,
+    line_end: usize,
+    column_end: usize,
+}
+
+impl JsonLocation {
+    /// Resolves `span` to lines and columns, returning `None` if it isn't within the file `file_id`.
+    fn new<'files>(
+        files: &'files impl Files<'files, FileId = fm::FileId>,
+        file_id: fm::FileId,
+        span: Span,
+    ) -> Option<JsonLocation> {
+        let file = files.name(file_id).ok()?.to_string();
+        let start = files.location(file_id, span.start() as usize).ok()?;
+        let end = files.location(file_id, span.end() as usize).ok()?;
+
+        Some(JsonLocation {
+            file,
+            byte_start: span.start(),
+            byte_end: span.end(),
+            line_start: start.line_number,
+            column_start: start.column_number,
+            line_end: end.line_number,
+            column_end: end.column_number,
+        })
+    }
 }
 
 /// Report the given diagnostic, and return true if it was an error
@@ -164,10 +412,13 @@ fn convert_diagnostic(
     stack_trace: String,
     deny_warnings: bool,
 ) -> Diagnostic<fm::FileId> {
-    let diagnostic = match (cd.kind, deny_warnings) {
+    let mut diagnostic = match (cd.kind, deny_warnings) {
         (DiagnosticKind::Warning, false) => Diagnostic::warning(),
         _ => Diagnostic::error(),
     };
+    if let Some(code) = cd.code {
+        diagnostic = diagnostic.with_code(code.as_str());
+    }
 
     let secondary_labels = if let Some(file_id) = file {
         cd.secondaries
@@ -183,6 +434,7 @@ fn convert_diagnostic(
     };
 
     let mut notes = cd.notes.clone();
+    notes.extend(cd.suggestions.iter().map(|suggestion| format!("help: {}", suggestion.message)));
     notes.push(stack_trace);
 
     diagnostic.with_message(&cd.message).with_labels(secondary_labels).with_notes(notes)
@@ -228,3 +480,68 @@ fn location(source: &str, span_start: u32) -> (u32, u32) {
 
     (line, column)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use fm::{FileMap, PathString};
+
+    use super::{Applicability, CustomDiagnostic, Suggestion};
+    use crate::{error_codes::E0301, Location, Span};
+
+    #[test]
+    fn reports_diagnostics_as_json() {
+        let mut files = FileMap::default();
+        let source = "fn main() {\n    let x = 1;\n}\n".to_string();
+        let file_id = files.add_file(PathString::from_path(PathBuf::from("src/main.nr")), source);
+
+        let x_span = Span::from(20..21);
+        let mut diagnostic = CustomDiagnostic::simple_warning(
+            "unused variable x".to_string(),
+            "unused variable".to_string(),
+            x_span,
+        )
+        .with_code(E0301);
+        diagnostic.add_suggestion(Suggestion::new(
+            "prefix it with an underscore".to_string(),
+            x_span,
+            "_x".to_string(),
+            Applicability::MachineApplicable,
+        ));
+        let diagnostic =
+            diagnostic.in_file(file_id).with_call_stack(vec![Location::new(x_span, file_id)]);
+
+        let location = r#""file":"src/main.nr","byte_start":20,"byte_end":21,"line_start":2,"column_start":9,"line_end":2,"column_end":10"#;
+        let expected = format!(
+            r#"{{"severity":"warning","message":"unused variable x","code":"E0301","spans":[{{{location},"is_primary":true,"label":"unused variable"}}],"notes":[],"suggestions":[{{"message":"prefix it with an underscore","applicability":"machine_applicable","replacements":[{{{location},"text":"_x"}}]}}],"call_stack":[{{{location}}}]}}"#
+        );
+        assert_eq!(diagnostic.to_json(&files, false), expected);
+        assert!(diagnostic.to_json(&files, true).starts_with(r#"{"severity":"error""#));
+    }
+
+    #[test]
+    fn leaves_out_spans_outside_of_their_file_from_json() {
+        let mut files = FileMap::default();
+        let source = "fn main() {}\n".to_string();
+        let file_id = files.add_file(PathString::from_path(PathBuf::from("src/main.nr")), source);
+
+        let span = Span::from(100..101);
+        let mut diagnostic = CustomDiagnostic::simple_error(
+            "expected an expression".to_string(),
+            "here".to_string(),
+            span,
+        );
+        diagnostic.add_suggestion(Suggestion::new(
+            "add an expression".to_string(),
+            span,
+            "0".to_string(),
+            Applicability::MaybeIncorrect,
+        ));
+        let diagnostic =
+            diagnostic.in_file(file_id).with_call_stack(vec![Location::new(span, file_id)]);
+
+        let expected = r#"{"severity":"error","message":"expected an expression","code":null,"spans":[],"notes":[],"suggestions":[],"call_stack":[]}"#;
+        assert_eq!(diagnostic.to_json(&files, false), expected);
+    }
+}
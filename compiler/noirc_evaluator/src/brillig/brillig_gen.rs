@@ -4,23 +4,61 @@ pub(crate) mod brillig_block_variables;
 pub(crate) mod brillig_directive;
 pub(crate) mod brillig_fn;
 pub(crate) mod brillig_slice_ops;
+mod register_allocation;
 mod variable_liveness;
 
-use self::{brillig_block::BrilligBlock, brillig_fn::FunctionContext};
-use super::brillig_ir::{artifact::BrilligArtifact, BrilligContext};
+use acvm::acir::brillig::RegisterIndex;
+
+use self::{
+    brillig_block::BrilligBlock, brillig_block_variables::allocate_value_with,
+    brillig_fn::FunctionContext,
+};
+use super::brillig_ir::{artifact::BrilligArtifact, BrilligContext, ReservedRegisters};
 use crate::ssa::ir::function::Function;
 
 /// Converting an SSA function into Brillig bytecode.
-pub(crate) fn convert_ssa_function(func: &Function, enable_debug_trace: bool) -> BrilligArtifact {
+///
+/// If `register_file_size` is set, at most that many registers are used to hold the function's
+/// variables and the remaining ones are spilled to memory.
+pub(crate) fn convert_ssa_function(
+    func: &Function,
+    enable_debug_trace: bool,
+    register_file_size: Option<usize>,
+) -> BrilligArtifact {
     let mut brillig_context = BrilligContext::new(enable_debug_trace);
 
-    let mut function_context = FunctionContext::new(func, &mut brillig_context);
+    let mut function_context = FunctionContext::new(func, register_file_size);
 
     brillig_context.enter_context(FunctionContext::function_id_to_function_label(func.id()));
 
+    if function_context.spill_slots_count > 0 {
+        spill_parameters(func, &function_context, &mut brillig_context);
+    }
+
     for block in function_context.blocks.clone() {
         BrilligBlock::compile(&mut function_context, &mut brillig_context, block, &func.dfg);
     }
 
     brillig_context.artifact()
 }
+
+/// Reserves the spill slots of the function and moves the spilled parameters into them.
+fn spill_parameters(
+    func: &Function,
+    function_context: &FunctionContext,
+    brillig_context: &mut BrilligContext,
+) {
+    brillig_context.set_first_free_register(function_context.first_temporary_register);
+    brillig_context.allocate_spill_slots_instruction(function_context.spill_slots_count);
+
+    let mut next_parameter_register = ReservedRegisters::len();
+    for parameter in func.parameters() {
+        let variable = allocate_value_with(*parameter, &func.dfg, || {
+            next_parameter_register += 1;
+            RegisterIndex::from(next_parameter_register - 1)
+        });
+        if let Some(slot) = function_context.spill_slot(*parameter) {
+            brillig_context.spill_variable_instruction(variable, slot);
+        }
+    }
+}
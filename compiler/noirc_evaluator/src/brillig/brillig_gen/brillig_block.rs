@@ -35,6 +35,9 @@ pub(crate) struct BrilligBlock<'block> {
     pub(crate) variables: BlockVariables,
     /// For each instruction, the set of values that are not used anymore after it.
     pub(crate) last_uses: HashMap<InstructionId, HashSet<ValueId>>,
+    /// The results of unsigned arithmetic in the block which are range checked against
+    /// overflows, with the message of each check.
+    pub(crate) overflow_checks: HashMap<ValueId, Option<String>>,
 }
 
 impl<'block> BrilligBlock<'block> {
@@ -47,19 +50,22 @@ impl<'block> BrilligBlock<'block> {
     ) {
         let live_in = function_context.liveness.get_live_in(&block_id);
         let variables =
-            BlockVariables::new(live_in.clone(), function_context.all_block_parameters());
-
-        brillig_context.set_allocated_registers(
-            variables
-                .get_available_variables(function_context)
-                .into_iter()
-                .flat_map(|variable| variable.extract_registers())
-                .collect(),
-        );
-        let last_uses = function_context.liveness.get_last_uses(&block_id).clone();
+            BlockVariables::new(live_in.clone(), &function_context.block_parameters[&block_id]);
 
-        let mut brillig_block =
-            BrilligBlock { function_context, block_id, brillig_context, variables, last_uses };
+        // Variables have been assigned registers for the whole function, so only the registers
+        // after them are used for temporaries.
+        brillig_context.set_first_free_register(function_context.first_temporary_register);
+        let last_uses = function_context.liveness.get_last_uses(&block_id).clone();
+        let overflow_checks = collect_overflow_checks(&dfg[block_id], dfg);
+
+        let mut brillig_block = BrilligBlock {
+            function_context,
+            block_id,
+            brillig_context,
+            variables,
+            last_uses,
+            overflow_checks,
+        };
 
         brillig_block.convert_block(dfg);
     }
@@ -140,6 +146,11 @@ impl<'block> BrilligBlock<'block> {
             } => {
                 let target_block = &dfg[*destination_block];
                 for (src, dest) in arguments.iter().zip(target_block.parameters()) {
+                    let source = self.convert_ssa_value(*src, dfg);
+                    if let Some(slot) = self.function_context.spill_slot(*dest) {
+                        self.brillig_context.spill_variable_instruction(source, slot);
+                        continue;
+                    }
                     // Destinations are block parameters so they should have been allocated previously.
                     let destination = self.variables.get_block_param(
                         self.function_context,
@@ -147,7 +158,6 @@ impl<'block> BrilligBlock<'block> {
                         *dest,
                         dfg,
                     );
-                    let source = self.convert_ssa_value(*src, dfg);
                     self.pass_variable(source, destination);
                 }
                 self.brillig_context.jump_instruction(
@@ -214,8 +224,13 @@ impl<'block> BrilligBlock<'block> {
     }
 
     /// Converts SSA Block parameters into Brillig Registers.
+    ///
+    /// Spilled parameters are left in memory, where they have been stored by the jumping block.
     fn convert_block_params(&mut self, block: &BasicBlock, dfg: &DataFlowGraph) {
         for param_id in block.parameters() {
+            if self.function_context.spill_slot(*param_id).is_some() {
+                continue;
+            }
             let value = &dfg[*param_id];
             let param_type = match value {
                 Value::Param { typ, .. } => typ,
@@ -248,13 +263,17 @@ impl<'block> BrilligBlock<'block> {
 
         match instruction {
             Instruction::Binary(binary) => {
+                let result = dfg.instruction_results(instruction_id)[0];
                 let result_register = self.variables.define_register_variable(
                     self.function_context,
                     self.brillig_context,
-                    dfg.instruction_results(instruction_id)[0],
+                    result,
                     dfg,
                 );
                 self.convert_ssa_binary(binary, dfg, result_register);
+                if let Some(assert_message) = self.overflow_checks.get(&result).cloned() {
+                    self.convert_overflow_check(binary, dfg, result_register, assert_message);
+                }
             }
             Instruction::Constrain(lhs, rhs, assert_message) => {
                 let condition = self.brillig_context.allocate_register();
@@ -440,6 +459,13 @@ impl<'block> BrilligBlock<'block> {
                         arguments,
                     );
                 }
+                Value::Intrinsic(
+                    intrinsic @ (Intrinsic::WrappingOp(_)
+                    | Intrinsic::OverflowingOp(_)
+                    | Intrinsic::SaturatingOp(_)),
+                ) => {
+                    self.convert_ssa_integer_op(*intrinsic, arguments, dfg, instruction_id);
+                }
                 Value::Intrinsic(Intrinsic::ToRadix(endianness)) => {
                     let source = self.convert_ssa_register_value(arguments[0], dfg);
                     let radix = self.convert_ssa_register_value(arguments[1], dfg);
@@ -591,6 +617,11 @@ impl<'block> BrilligBlock<'block> {
                     value_variable,
                 );
             }
+            Instruction::RangeCheck { value, .. }
+                if self.overflow_checks.contains_key(&dfg.resolve(*value)) =>
+            {
+                // The operation was checked for overflows when it was converted.
+            }
             Instruction::RangeCheck { value, max_bit_size, assert_message } => {
                 let left = self.convert_ssa_register_value(*value, dfg);
                 let max = BigUint::from(2_u128).pow(*max_bit_size);
@@ -600,9 +631,11 @@ impl<'block> BrilligBlock<'block> {
                     FieldElement::from_be_bytes_reduce(&max.to_bytes_be()).into(),
                 );
 
+                // The value may be a field element which is wider than the range it is checked
+                // against, so it is compared without being reduced.
                 let brillig_binary_op = BrilligBinaryOp::Integer {
                     op: BinaryIntOp::LessThan,
-                    bit_size: max_bit_size + 1,
+                    bit_size: FieldElement::max_num_bits(),
                 };
                 let condition = self.brillig_context.allocate_register();
                 self.brillig_context.binary_instruction(left, right, condition, brillig_binary_op);
@@ -611,16 +644,24 @@ impl<'block> BrilligBlock<'block> {
                 self.brillig_context.deallocate_register(right);
             }
             Instruction::IncrementRc { value } => {
-                let rc_register = match self.convert_ssa_value(*value, dfg) {
+                let variable = self.convert_ssa_value(*value, dfg);
+                let rc_register = match variable {
                     BrilligVariable::BrilligArray(BrilligArray { rc, .. })
                     | BrilligVariable::BrilligVector(BrilligVector { rc, .. }) => rc,
                     _ => unreachable!("ICE: increment rc on non-array"),
                 };
                 self.brillig_context.usize_op_in_place(rc_register, BinaryIntOp::Add, 1);
+
+                // A spilled variable was reloaded into temporary registers, so the new rc is written back.
+                if let Some(slot) = self.function_context.spill_slot(dfg.resolve(*value)) {
+                    self.brillig_context.spill_variable_instruction(variable, slot);
+                }
             }
             _ => todo!("ICE: Instruction not supported {instruction:?}"),
         };
 
+        self.variables.spill_variables(self.function_context, self.brillig_context);
+
         let dead_variables = self
             .last_uses
             .get(&instruction_id)
@@ -1150,6 +1191,278 @@ impl<'block> BrilligBlock<'block> {
         self.brillig_context.binary_instruction(left, right, result_register, brillig_binary_op);
     }
 
+    /// Checks that the unsigned arithmetic `binary`, whose result is in `result`, did not overflow.
+    fn convert_overflow_check(
+        &mut self,
+        binary: &Binary,
+        dfg: &DataFlowGraph,
+        result: RegisterIndex,
+        assert_message: Option<String>,
+    ) {
+        let binary_type =
+            type_of_binary_operation(dfg[binary.lhs].get_type(), dfg[binary.rhs].get_type());
+        let bit_size = Self::get_bit_size_from_ssa_type(binary_type);
+
+        let left = self.convert_ssa_register_value(binary.lhs, dfg);
+        let right = self.convert_ssa_register_value(binary.rhs, dfg);
+        let condition =
+            self.unsigned_overflow_condition(binary.operator, left, right, result, bit_size);
+        self.brillig_context.constrain_instruction(condition, assert_message);
+        self.brillig_context.deallocate_register(condition);
+    }
+
+    /// Emits `lhs op rhs` on `bit_size`-bit integers into a newly allocated register.
+    fn integer_binary(
+        &mut self,
+        op: BinaryIntOp,
+        lhs: RegisterIndex,
+        rhs: RegisterIndex,
+        bit_size: u32,
+    ) -> RegisterIndex {
+        let result = self.brillig_context.allocate_register();
+        let op = BrilligBinaryOp::Integer { op, bit_size };
+        self.brillig_context.binary_instruction(lhs, rhs, result, op);
+        result
+    }
+
+    /// Returns a register which is `1` if the unsigned arithmetic `left operator right`, whose
+    /// result is in `result`, did not overflow.
+    ///
+    /// Brillig integer arithmetic wraps around the bit size of its operands, so the overflow is
+    /// detected from the operands rather than by range checking the result.
+    fn unsigned_overflow_condition(
+        &mut self,
+        operator: BinaryOp,
+        left: RegisterIndex,
+        right: RegisterIndex,
+        result: RegisterIndex,
+        bit_size: u32,
+    ) -> RegisterIndex {
+        match operator {
+            // An addition overflowed if its result wrapped around below its lhs.
+            BinaryOp::Add => {
+                self.integer_binary(BinaryIntOp::LessThanEquals, left, result, bit_size)
+            }
+            // A subtraction overflowed if its rhs is greater than its lhs.
+            BinaryOp::Sub => {
+                self.integer_binary(BinaryIntOp::LessThanEquals, right, left, bit_size)
+            }
+            // A multiplication did not overflow if its rhs is zero or if dividing its result by
+            // its rhs gives back its lhs. A zero rhs is replaced with one to avoid dividing by zero.
+            BinaryOp::Mul => {
+                let zero = self.brillig_context.make_constant(FieldElement::zero().into());
+                let rhs_is_zero = self.integer_binary(BinaryIntOp::Equals, right, zero, bit_size);
+                let divisor = self.integer_binary(BinaryIntOp::Add, right, rhs_is_zero, bit_size);
+                let quotient =
+                    self.integer_binary(BinaryIntOp::UnsignedDiv, result, divisor, bit_size);
+                let condition = self.integer_binary(BinaryIntOp::Equals, quotient, left, bit_size);
+                self.brillig_context.binary_instruction(
+                    condition,
+                    rhs_is_zero,
+                    condition,
+                    BrilligBinaryOp::Integer { op: BinaryIntOp::Or, bit_size: 1 },
+                );
+                for register in [zero, rhs_is_zero, divisor, quotient] {
+                    self.brillig_context.deallocate_register(register);
+                }
+                condition
+            }
+            _ => unreachable!("ICE: {operator} cannot overflow"),
+        }
+    }
+
+    /// Returns a register which is `1` if the signed arithmetic `left operator right`, whose
+    /// wrapped result is in `result`, did not overflow, along with a register holding the bound
+    /// of the type which it would have overflowed past.
+    fn signed_overflow_condition(
+        &mut self,
+        operator: BinaryOp,
+        left: RegisterIndex,
+        right: RegisterIndex,
+        result: RegisterIndex,
+        bit_size: u32,
+    ) -> (RegisterIndex, RegisterIndex) {
+        let bool_op = |op| BrilligBinaryOp::Integer { op, bit_size: 1 };
+        // The minimum is represented as 2^{bit_size-1}, one above the maximum.
+        let half = self.brillig_context.make_constant(
+            FieldElement::from(2_u128).pow(&FieldElement::from((bit_size - 1) as u128)).into(),
+        );
+        let lhs_positive = self.integer_binary(BinaryIntOp::LessThan, left, half, bit_size);
+        let rhs_positive = self.integer_binary(BinaryIntOp::LessThan, right, half, bit_size);
+        let same_sign = self.integer_binary(BinaryIntOp::Equals, lhs_positive, rhs_positive, 1);
+
+        let (condition, bound, temporaries) = match operator {
+            BinaryOp::Add | BinaryOp::Sub => {
+                // The operation overflowed if both operands (after negating the rhs of a
+                // subtraction) have the same sign, but the result does not.
+                let result_positive =
+                    self.integer_binary(BinaryIntOp::LessThan, result, half, bit_size);
+                let condition =
+                    self.integer_binary(BinaryIntOp::Equals, result_positive, lhs_positive, 1);
+                let cannot_overflow = self.brillig_context.allocate_register();
+                if operator == BinaryOp::Add {
+                    self.brillig_context.not_instruction(same_sign, 1, cannot_overflow);
+                } else {
+                    self.brillig_context.mov_instruction(cannot_overflow, same_sign);
+                }
+                self.brillig_context.binary_instruction(
+                    condition,
+                    cannot_overflow,
+                    condition,
+                    bool_op(BinaryIntOp::Or),
+                );
+                // The operation overflows on the side of its lhs.
+                let bound = self.integer_binary(BinaryIntOp::Sub, half, lhs_positive, bit_size);
+                (condition, bound, vec![result_positive, cannot_overflow])
+            }
+            BinaryOp::Mul => {
+                // The absolute value of the product must fit in the type, bearing in mind that
+                // the minimum has a greater absolute value than the maximum.
+                let zero = self.brillig_context.make_constant(FieldElement::zero().into());
+                let mut absolute_value = |value, positive| {
+                    // negated + positive * (value - negated)
+                    let negated = self.integer_binary(BinaryIntOp::Sub, zero, value, bit_size);
+                    let difference =
+                        self.integer_binary(BinaryIntOp::Sub, value, negated, bit_size);
+                    let offset =
+                        self.integer_binary(BinaryIntOp::Mul, positive, difference, bit_size);
+                    self.brillig_context.binary_instruction(
+                        negated,
+                        offset,
+                        negated,
+                        BrilligBinaryOp::Integer { op: BinaryIntOp::Add, bit_size },
+                    );
+                    self.brillig_context.deallocate_register(difference);
+                    self.brillig_context.deallocate_register(offset);
+                    negated
+                };
+                let lhs_abs = absolute_value(left, lhs_positive);
+                let rhs_abs = absolute_value(right, rhs_positive);
+                let product = self.integer_binary(BinaryIntOp::Mul, lhs_abs, rhs_abs, bit_size);
+                let condition = self.unsigned_overflow_condition(
+                    BinaryOp::Mul,
+                    lhs_abs,
+                    rhs_abs,
+                    product,
+                    bit_size,
+                );
+
+                let negative = self.brillig_context.allocate_register();
+                self.brillig_context.not_instruction(same_sign, 1, negative);
+                let limit = self.integer_binary(BinaryIntOp::Add, half, negative, bit_size);
+                let product_fits =
+                    self.integer_binary(BinaryIntOp::LessThan, product, limit, bit_size);
+                self.brillig_context.binary_instruction(
+                    condition,
+                    product_fits,
+                    condition,
+                    bool_op(BinaryIntOp::And),
+                );
+                let bound = self.integer_binary(BinaryIntOp::Sub, half, same_sign, bit_size);
+                (
+                    condition,
+                    bound,
+                    vec![zero, lhs_abs, rhs_abs, product, negative, limit, product_fits],
+                )
+            }
+            _ => unreachable!("ICE: {operator} cannot overflow"),
+        };
+        for register in [half, lhs_positive, rhs_positive, same_sign].into_iter().chain(temporaries)
+        {
+            self.brillig_context.deallocate_register(register);
+        }
+        (condition, bound)
+    }
+
+    /// Converts a call to a wrapping, overflowing or saturating integer operation.
+    ///
+    /// Brillig integer arithmetic already wraps around the bit size of its operands, so only the
+    /// overflowing and saturating operations need to check for overflow.
+    fn convert_ssa_integer_op(
+        &mut self,
+        intrinsic: Intrinsic,
+        arguments: &[ValueId],
+        dfg: &DataFlowGraph,
+        instruction_id: InstructionId,
+    ) {
+        let (Intrinsic::WrappingOp(operator)
+        | Intrinsic::OverflowingOp(operator)
+        | Intrinsic::SaturatingOp(operator)) = intrinsic
+        else {
+            unreachable!("ICE: {intrinsic} is not an integer operation")
+        };
+        let typ = dfg.type_of_value(arguments[0]);
+        let bit_size = Self::get_bit_size_from_ssa_type(typ.clone());
+        let results = dfg.instruction_results(instruction_id);
+
+        let left = self.convert_ssa_register_value(arguments[0], dfg);
+        let right = self.convert_ssa_register_value(arguments[1], dfg);
+        let result = self.variables.define_register_variable(
+            self.function_context,
+            self.brillig_context,
+            results[0],
+            dfg,
+        );
+        let op = match operator {
+            BinaryOp::Add => BinaryIntOp::Add,
+            BinaryOp::Sub => BinaryIntOp::Sub,
+            BinaryOp::Mul => BinaryIntOp::Mul,
+            _ => unreachable!("ICE: {operator} cannot overflow"),
+        };
+        let integer_op = |op| BrilligBinaryOp::Integer { op, bit_size };
+        self.brillig_context.binary_instruction(left, right, result, integer_op(op));
+        if let Intrinsic::WrappingOp(_) = intrinsic {
+            return;
+        }
+
+        let (condition, bound) = if typ.is_unsigned() {
+            let condition =
+                self.unsigned_overflow_condition(operator, left, right, result, bit_size);
+            let bound = match operator {
+                BinaryOp::Sub => FieldElement::zero(),
+                _ => {
+                    FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128))
+                        - FieldElement::one()
+                }
+            };
+            (condition, self.brillig_context.make_constant(bound.into()))
+        } else {
+            self.signed_overflow_condition(operator, left, right, result, bit_size)
+        };
+
+        if let Intrinsic::OverflowingOp(_) = intrinsic {
+            let overflow = self.variables.define_register_variable(
+                self.function_context,
+                self.brillig_context,
+                results[1],
+                dfg,
+            );
+            self.brillig_context.not_instruction(condition, 1, overflow);
+        } else {
+            // bound + condition * (result - bound)
+            self.brillig_context.binary_instruction(
+                result,
+                bound,
+                result,
+                integer_op(BinaryIntOp::Sub),
+            );
+            self.brillig_context.binary_instruction(
+                condition,
+                result,
+                result,
+                integer_op(BinaryIntOp::Mul),
+            );
+            self.brillig_context.binary_instruction(
+                bound,
+                result,
+                result,
+                integer_op(BinaryIntOp::Add),
+            );
+        }
+        self.brillig_context.deallocate_register(condition);
+        self.brillig_context.deallocate_register(bound);
+    }
+
     /// Converts an SSA `ValueId` into a `RegisterOrMemory`. Initializes if necessary.
     fn convert_ssa_value(&mut self, value_id: ValueId, dfg: &DataFlowGraph) -> BrilligVariable {
         let value_id = dfg.resolve(value_id);
@@ -1159,7 +1472,12 @@ impl<'block> BrilligBlock<'block> {
             Value::Param { .. } | Value::Instruction { .. } => {
                 // All block parameters and instruction results should have already been
                 // converted to registers so we fetch from the cache.
-                self.variables.get_allocation(self.function_context, value_id, dfg)
+                self.variables.get_allocation(
+                    self.function_context,
+                    self.brillig_context,
+                    value_id,
+                    dfg,
+                )
             }
             Value::NumericConstant { constant, .. } => {
                 // Constants might have been converted previously or not, so we get or create and
@@ -1325,6 +1643,43 @@ impl<'block> BrilligBlock<'block> {
     }
 }
 
+/// Returns the results of unsigned arithmetic in `block` which are range checked to their own bit
+/// size later in the block, along with the message of each range check.
+///
+/// These range checks guard the arithmetic against overflows, which Brillig has to detect
+/// differently as its integer arithmetic wraps around.
+fn collect_overflow_checks(
+    block: &BasicBlock,
+    dfg: &DataFlowGraph,
+) -> HashMap<ValueId, Option<String>> {
+    let mut arithmetic = HashSet::default();
+    let mut overflow_checks = HashMap::default();
+    for instruction_id in block.instructions() {
+        match &dfg[*instruction_id] {
+            Instruction::Binary(binary)
+                if matches!(binary.operator, BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul) =>
+            {
+                arithmetic.insert(*instruction_id);
+            }
+            Instruction::RangeCheck { value, max_bit_size, assert_message } => {
+                let value = dfg.resolve(*value);
+                let Value::Instruction { instruction, .. } = &dfg[value] else { continue };
+                let Instruction::Binary(binary) = &dfg[*instruction] else { continue };
+                let binary_type = type_of_binary_operation(
+                    dfg[binary.lhs].get_type(),
+                    dfg[binary.rhs].get_type(),
+                );
+                if arithmetic.contains(instruction) && binary_type == Type::unsigned(*max_bit_size)
+                {
+                    overflow_checks.insert(value, assert_message.clone());
+                }
+            }
+            _ => (),
+        }
+    }
+    overflow_checks
+}
+
 /// Returns the type of the operation considering the types of the operands
 /// TODO: SSA issues binary operations between fields and integers.
 /// This probably should be explicitly casted in SSA to avoid having to coerce at this level.
@@ -1425,3 +1780,89 @@ pub(crate) fn convert_ssa_binary_op_to_brillig_binary_op(
         None => binary_op_to_field_op(ssa_op),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::brillig_vm::{brillig::Value, VMStatus};
+
+    use crate::{
+        brillig::brillig_ir::tests::execute_brillig_function,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                function::RuntimeType,
+                instruction::{BinaryOp, Instruction},
+                map::Id,
+                types::Type,
+            },
+            ssa_gen::Ssa,
+        },
+    };
+
+    /// Builds a function applying `operator` to its two unsigned parameters, whose result is
+    /// range checked against overflows as in the SSA generated for unsigned arithmetic.
+    ///
+    /// ```text
+    /// brillig fn main f0 {
+    ///   b0(v0: u{bit_size}, v1: u{bit_size}):
+    ///     v2 = {operator} v0, v1
+    ///     range_check v2 to {bit_size} bits
+    ///     return v2
+    /// }
+    /// ```
+    fn checked_arithmetic(operator: BinaryOp, bit_size: u32) -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Brillig);
+
+        let lhs = builder.add_parameter(Type::unsigned(bit_size));
+        let rhs = builder.add_parameter(Type::unsigned(bit_size));
+        let result = builder.insert_binary(lhs, operator, rhs);
+        let range_check = Instruction::RangeCheck {
+            value: result,
+            max_bit_size: bit_size,
+            assert_message: Some("attempt to overflow".to_string()),
+        };
+        builder.insert_instruction(range_check, None);
+        builder.terminate_with_return(vec![result]);
+
+        builder.finish()
+    }
+
+    fn execute(operator: BinaryOp, bit_size: u32, lhs: u128, rhs: u128) -> Option<u128> {
+        let ssa = checked_arithmetic(operator, bit_size);
+        match execute_brillig_function(ssa.main(), vec![Value::from(lhs), Value::from(rhs)]) {
+            (VMStatus::Finished, result) => Some(result.to_u128()),
+            (VMStatus::Failure { .. }, _) => None,
+            (status, _) => panic!("Brillig execution did not finish: {status:?}"),
+        }
+    }
+
+    #[test]
+    fn checks_additions_for_overflows() {
+        assert_eq!(execute(BinaryOp::Add, 8, 200, 55), Some(255));
+        assert_eq!(execute(BinaryOp::Add, 8, 200, 56), None);
+        assert_eq!(execute(BinaryOp::Add, 128, u128::MAX - 1, 1), Some(u128::MAX));
+        assert_eq!(execute(BinaryOp::Add, 128, u128::MAX, 1), None);
+        assert_eq!(execute(BinaryOp::Add, 128, u128::MAX, u128::MAX), None);
+    }
+
+    #[test]
+    fn checks_subtractions_for_underflows() {
+        assert_eq!(execute(BinaryOp::Sub, 8, 5, 5), Some(0));
+        assert_eq!(execute(BinaryOp::Sub, 8, 5, 6), None);
+        assert_eq!(execute(BinaryOp::Sub, 128, u128::MAX, u128::MAX), Some(0));
+        assert_eq!(execute(BinaryOp::Sub, 128, 0, u128::MAX), None);
+    }
+
+    #[test]
+    fn checks_multiplications_for_overflows() {
+        assert_eq!(execute(BinaryOp::Mul, 8, 15, 17), Some(255));
+        assert_eq!(execute(BinaryOp::Mul, 8, 16, 16), None);
+        // A zero rhs never overflows, and must not be divided by.
+        assert_eq!(execute(BinaryOp::Mul, 8, 255, 0), Some(0));
+        assert_eq!(execute(BinaryOp::Mul, 128, u128::MAX, 1), Some(u128::MAX));
+        assert_eq!(execute(BinaryOp::Mul, 128, 1 << 64, 1 << 63), Some(1 << 127));
+        assert_eq!(execute(BinaryOp::Mul, 128, 1 << 64, 1 << 64), None);
+        assert_eq!(execute(BinaryOp::Mul, 128, u128::MAX, u128::MAX), None);
+    }
+}
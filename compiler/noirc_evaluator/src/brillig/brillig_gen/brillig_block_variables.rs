@@ -20,24 +20,32 @@ use super::brillig_fn::FunctionContext;
 pub(crate) struct BlockVariables {
     available_variables: HashSet<ValueId>,
     available_constants: HashMap<ValueId, BrilligVariable>,
+    /// Spilled variables defined by the current instruction, along with the temporary registers holding them.
+    spilled_definitions: Vec<(ValueId, BrilligVariable)>,
+    /// Temporary registers which spilled variables have been reloaded into by the current instruction.
+    reloaded_variables: Vec<BrilligVariable>,
 }
 
 impl BlockVariables {
-    /// Creates a BlockVariables instance. It uses the variables that are live in to the block and the global available variables (block parameters)
-    pub(crate) fn new(live_in: HashSet<ValueId>, all_block_parameters: HashSet<ValueId>) -> Self {
+    /// Creates a BlockVariables instance. It uses the variables that are live in to the block and the parameters of the block.
+    pub(crate) fn new(live_in: HashSet<ValueId>, block_parameters: &[ValueId]) -> Self {
         BlockVariables {
-            available_variables: live_in.into_iter().chain(all_block_parameters).collect(),
+            available_variables: live_in
+                .into_iter()
+                .chain(block_parameters.iter().copied())
+                .collect(),
             ..Default::default()
         }
     }
 
-    /// Returns all non-constant variables that have not been removed at this point.
+    /// Returns all non-constant variables that have not been removed at this point and are held in registers.
     pub(crate) fn get_available_variables(
         &self,
         function_context: &FunctionContext,
     ) -> Vec<BrilligVariable> {
         self.available_variables
             .iter()
+            .filter(|value_id| function_context.spill_slot(**value_id).is_none())
             .map(|value_id| {
                 function_context
                     .ssa_value_allocations
@@ -48,7 +56,9 @@ impl BlockVariables {
             .collect()
     }
 
-    /// For a given SSA non constant value id, define the variable and return the corresponding cached allocation.
+    /// For a given SSA non constant value id, define the variable and return the corresponding allocation.
+    ///
+    /// Spilled variables are defined in temporary registers, which are stored to memory once the instruction is converted.
     pub(crate) fn define_variable(
         &mut self,
         function_context: &mut FunctionContext,
@@ -57,15 +67,20 @@ impl BlockVariables {
         dfg: &DataFlowGraph,
     ) -> BrilligVariable {
         let value_id = dfg.resolve(value_id);
-        let variable = allocate_value(value_id, brillig_context, dfg);
-
-        if function_context.ssa_value_allocations.insert(value_id, variable).is_some() {
-            unreachable!("ICE: ValueId {value_id:?} was already in cache");
+        if !self.available_variables.insert(value_id) {
+            unreachable!("ICE: ValueId {value_id:?} was already defined");
         }
 
-        self.available_variables.insert(value_id);
-
-        variable
+        if function_context.spill_slot(value_id).is_some() {
+            let variable = allocate_value(value_id, brillig_context, dfg);
+            self.spilled_definitions.push((value_id, variable));
+            variable
+        } else {
+            *function_context
+                .ssa_value_allocations
+                .get(&value_id)
+                .unwrap_or_else(|| panic!("ICE: Value not found in cache {value_id}"))
+        }
     }
 
     /// Defines a variable that fits in a single register and returns the allocated register.
@@ -86,25 +101,59 @@ impl BlockVariables {
     }
 
     /// For a given SSA value id, return the corresponding cached allocation.
+    ///
+    /// Spilled variables are reloaded from memory into temporary registers.
     pub(crate) fn get_allocation(
         &mut self,
         function_context: &FunctionContext,
+        brillig_context: &mut BrilligContext,
         value_id: ValueId,
         dfg: &DataFlowGraph,
     ) -> BrilligVariable {
         let value_id = dfg.resolve(value_id);
         if let Some(constant) = self.available_constants.get(&value_id) {
-            *constant
-        } else {
-            assert!(
-                self.available_variables.contains(&value_id),
-                "ICE: ValueId {value_id:?} is not available"
-            );
+            return *constant;
+        }
 
-            *function_context
-                .ssa_value_allocations
-                .get(&value_id)
-                .unwrap_or_else(|| panic!("ICE: Value not found in cache {value_id}"))
+        assert!(
+            self.available_variables.contains(&value_id),
+            "ICE: ValueId {value_id:?} is not available"
+        );
+
+        if let Some(variable) = function_context.ssa_value_allocations.get(&value_id) {
+            return *variable;
+        }
+        if let Some((_, variable)) =
+            self.spilled_definitions.iter().find(|(defined_value, _)| *defined_value == value_id)
+        {
+            return *variable;
+        }
+
+        let slot = function_context
+            .spill_slot(value_id)
+            .unwrap_or_else(|| panic!("ICE: Value not found in cache {value_id}"));
+        let variable = allocate_value(value_id, brillig_context, dfg);
+        brillig_context.reload_variable_instruction(variable, slot);
+        self.reloaded_variables.push(variable);
+        variable
+    }
+
+    /// Stores the spilled variables defined by the current instruction to memory and
+    /// frees the temporary registers used for spilled variables.
+    pub(crate) fn spill_variables(
+        &mut self,
+        function_context: &FunctionContext,
+        brillig_context: &mut BrilligContext,
+    ) {
+        for (value_id, variable) in std::mem::take(&mut self.spilled_definitions) {
+            let slot = function_context.spill_slot(value_id).expect("ICE: Value was not spilled");
+            brillig_context.spill_variable_instruction(variable, slot);
+            self.reloaded_variables.push(variable);
+        }
+        for variable in std::mem::take(&mut self.reloaded_variables) {
+            for register in variable.extract_registers() {
+                brillig_context.deallocate_register(register);
+            }
         }
     }
 
@@ -169,17 +218,26 @@ pub(crate) fn allocate_value(
     value_id: ValueId,
     brillig_context: &mut BrilligContext,
     dfg: &DataFlowGraph,
+) -> BrilligVariable {
+    allocate_value_with(value_id, dfg, || brillig_context.allocate_register())
+}
+
+/// For a given value_id, creates a variable to hold it using the registers returned by `allocate_register`.
+pub(crate) fn allocate_value_with(
+    value_id: ValueId,
+    dfg: &DataFlowGraph,
+    mut allocate_register: impl FnMut() -> RegisterIndex,
 ) -> BrilligVariable {
     let typ = dfg.type_of_value(value_id);
 
     match typ {
         Type::Numeric(_) | Type::Reference(_) => {
-            let register = brillig_context.allocate_register();
+            let register = allocate_register();
             BrilligVariable::Simple(register)
         }
         Type::Array(item_typ, elem_count) => {
-            let pointer_register = brillig_context.allocate_register();
-            let rc_register = brillig_context.allocate_register();
+            let pointer_register = allocate_register();
+            let rc_register = allocate_register();
             let size = compute_array_length(&item_typ, elem_count);
 
             BrilligVariable::BrilligArray(BrilligArray {
@@ -189,9 +247,9 @@ pub(crate) fn allocate_value(
             })
         }
         Type::Slice(_) => {
-            let pointer_register = brillig_context.allocate_register();
-            let size_register = brillig_context.allocate_register();
-            let rc_register = brillig_context.allocate_register();
+            let pointer_register = allocate_register();
+            let size_register = allocate_register();
+            let rc_register = allocate_register();
 
             BrilligVariable::BrilligVector(BrilligVector {
                 pointer: pointer_register,
@@ -4,7 +4,6 @@ use crate::{
     brillig::brillig_ir::{
         artifact::{BrilligParameter, Label},
         brillig_variable::BrilligVariable,
-        BrilligContext,
     },
     ssa::ir::{
         basic_block::BasicBlockId,
@@ -14,15 +13,21 @@ use crate::{
         value::ValueId,
     },
 };
-use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use fxhash::FxHashMap as HashMap;
 
-use super::{brillig_block_variables::allocate_value, variable_liveness::VariableLiveness};
+use super::{register_allocation::RegisterAllocation, variable_liveness::VariableLiveness};
 
 pub(crate) struct FunctionContext {
     pub(crate) function_id: FunctionId,
-    /// Map from SSA values its allocation. Since values can be only defined once in SSA form, we insert them here on when we allocate them at their definition.
+    /// Map from SSA values to the registers assigned to them by the register allocation.
     pub(crate) ssa_value_allocations: HashMap<ValueId, BrilligVariable>,
-    /// Block parameters are pre allocated at the function level.
+    /// Map from spilled SSA values to their first spill slot, relative to the previous stack pointer.
+    pub(crate) spill_slots: HashMap<ValueId, usize>,
+    /// The number of spill slots reserved at the start of the function's stack frame.
+    pub(crate) spill_slots_count: usize,
+    /// The first register which is free to be used as a temporary.
+    pub(crate) first_temporary_register: usize,
+    /// The parameters of each block.
     pub(crate) block_parameters: HashMap<BasicBlockId, Vec<ValueId>>,
     /// The block ids of the function in reverse post order.
     pub(crate) blocks: Vec<BasicBlockId>,
@@ -31,38 +36,39 @@ pub(crate) struct FunctionContext {
 }
 
 impl FunctionContext {
-    /// Creates a new function context. It will allocate parameters for all blocks and compute the liveness of every variable.
-    pub(crate) fn new(function: &Function, brillig_context: &mut BrilligContext) -> Self {
+    /// Creates a new function context. It will compute the liveness of every variable and assign them registers,
+    /// using at most `register_file_size` registers to hold variables if it is set.
+    pub(crate) fn new(function: &Function, register_file_size: Option<usize>) -> Self {
         let id = function.id();
 
         let mut reverse_post_order = Vec::new();
         reverse_post_order.extend_from_slice(PostOrder::with_function(function).as_slice());
         reverse_post_order.reverse();
 
-        let mut block_parameters = HashMap::default();
-        let mut ssa_variable_to_register_or_memory = HashMap::default();
+        let block_parameters = reverse_post_order
+            .iter()
+            .map(|block_id| (*block_id, function.dfg[*block_id].parameters().to_vec()))
+            .collect();
 
-        for &block_id in &reverse_post_order {
-            let block = &function.dfg[block_id];
-            let parameters = block.parameters().to_vec();
-            parameters.iter().for_each(|&value_id| {
-                let variable = allocate_value(value_id, brillig_context, &function.dfg);
-                ssa_variable_to_register_or_memory.insert(value_id, variable);
-            });
-            block_parameters.insert(block_id, parameters);
-        }
+        let liveness = VariableLiveness::from_function(function);
+        let allocation =
+            RegisterAllocation::new(function, &reverse_post_order, &liveness, register_file_size);
 
         Self {
             function_id: id,
-            ssa_value_allocations: ssa_variable_to_register_or_memory,
+            ssa_value_allocations: allocation.registers,
+            spill_slots: allocation.spill_slots,
+            spill_slots_count: allocation.spill_slots_count,
+            first_temporary_register: allocation.first_temporary_register,
             block_parameters,
             blocks: reverse_post_order,
-            liveness: VariableLiveness::from_function(function),
+            liveness,
         }
     }
 
-    pub(crate) fn all_block_parameters(&self) -> HashSet<ValueId> {
-        self.block_parameters.values().flat_map(|parameters| parameters.iter()).cloned().collect()
+    /// Returns the first spill slot of a value, if it has been spilled to memory.
+    pub(crate) fn spill_slot(&self, value_id: ValueId) -> Option<usize> {
+        self.spill_slots.get(&value_id).copied()
     }
 
     /// Creates a function label from a given SSA function id.
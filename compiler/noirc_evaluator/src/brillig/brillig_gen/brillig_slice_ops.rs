@@ -350,9 +350,9 @@ mod tests {
         let builder =
             FunctionBuilder::new("main".to_string(), Id::test_new(0), RuntimeType::Brillig);
         let ssa = builder.finish();
-        let mut brillig_context = create_context();
+        let brillig_context = create_context();
 
-        let function_context = FunctionContext::new(ssa.main(), &mut brillig_context);
+        let function_context = FunctionContext::new(ssa.main(), None);
         (ssa, function_context, brillig_context)
     }
 
@@ -367,6 +367,7 @@ mod tests {
             brillig_context,
             variables,
             last_uses: Default::default(),
+            overflow_checks: Default::default(),
         }
     }
 
@@ -0,0 +1,596 @@
+//! This module assigns registers to the variables of a function before it is converted into Brillig.
+//!
+//! It uses linear scan register allocation, as described in https://dl.acm.org/doi/10.1145/330249.330250:
+//! - The blocks of the function are laid out in reverse post-order, giving a position to the start of
+//!   each block, each of its instructions and its terminator.
+//! - Each variable is given a live interval, covering every position where it is available according to
+//!   the [`VariableLiveness`] of the function. Block parameters are also live at the terminators which
+//!   pass them.
+//! - Intervals are visited by increasing start position. The registers of intervals which have ended are
+//!   freed, and the variable is assigned the lowest free registers.
+//!
+//! If the size of the register file is limited and there are not enough free registers for a variable,
+//! either the variable or the active variable whose interval ends last is spilled to memory. Spilled
+//! variables are kept in spill slots at the start of the function's stack frame, which are shared between
+//! variables whose intervals don't overlap.
+//!
+//! The parameters of the function are passed in the first registers after the reserved ones, so they are
+//! always assigned those registers unless they are spilled.
+use std::collections::BTreeSet;
+
+use acvm::acir::brillig::RegisterIndex;
+use fxhash::FxHashMap as HashMap;
+
+use crate::{
+    brillig::brillig_ir::{
+        brillig_variable::{BrilligArray, BrilligVariable, BrilligVector},
+        ReservedRegisters,
+    },
+    ssa::ir::{
+        basic_block::BasicBlockId,
+        function::Function,
+        instruction::{Instruction, TerminatorInstruction},
+        types::Type,
+        value::{Value, ValueId},
+    },
+};
+
+use super::{brillig_block_variables::allocate_value_with, variable_liveness::VariableLiveness};
+
+/// The locations assigned to the variables of a function.
+pub(crate) struct RegisterAllocation {
+    /// The registers holding each variable which has not been spilled.
+    pub(crate) registers: HashMap<ValueId, BrilligVariable>,
+    /// The first spill slot of each spilled variable. Each register of the variable is stored in its own slot.
+    pub(crate) spill_slots: HashMap<ValueId, usize>,
+    /// The number of spill slots used by the function.
+    pub(crate) spill_slots_count: usize,
+    /// The first register which is not used by variables nor by the calling convention.
+    ///
+    /// Registers from this one onwards are free to be used as temporaries during codegen.
+    pub(crate) first_temporary_register: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LiveInterval {
+    value: ValueId,
+    start: usize,
+    end: usize,
+}
+
+impl RegisterAllocation {
+    /// Allocates registers for the variables of `func`, whose reachable blocks are given in reverse post-order.
+    ///
+    /// If `register_file_size` is set, at most that many registers are used to hold variables,
+    /// with the remaining variables being spilled to memory.
+    pub(crate) fn new(
+        func: &Function,
+        blocks: &[BasicBlockId],
+        liveness: &VariableLiveness,
+        register_file_size: Option<usize>,
+    ) -> Self {
+        let intervals = compute_live_intervals(func, blocks, liveness);
+
+        let mut register_file = RegisterFile::new(register_file_size);
+        let mut registers: HashMap<ValueId, Vec<usize>> = HashMap::default();
+        let mut spilled = Vec::new();
+        let mut active: Vec<LiveInterval> = Vec::new();
+
+        // Parameters are passed in the registers following the reserved ones.
+        let mut parameter_registers: HashMap<ValueId, Vec<usize>> = HashMap::default();
+        let mut next_parameter_register = ReservedRegisters::len();
+        for parameter in func.parameters() {
+            let registers_count = registers_count(&func.dfg.type_of_value(*parameter));
+            let first_register = next_parameter_register;
+            next_parameter_register += registers_count;
+            parameter_registers
+                .insert(*parameter, (first_register..next_parameter_register).collect());
+        }
+
+        for interval in intervals {
+            // Free the registers of the variables which are no longer live.
+            active.retain(|active_interval| {
+                let has_ended = active_interval.end < interval.start;
+                if has_ended {
+                    register_file.free(&registers[&active_interval.value]);
+                }
+                !has_ended
+            });
+
+            if let Some(parameter_registers) = parameter_registers.remove(&interval.value) {
+                if register_file.take_exactly(&parameter_registers) {
+                    registers.insert(interval.value, parameter_registers);
+                    active.push(interval);
+                } else {
+                    spilled.push(interval);
+                }
+                continue;
+            }
+
+            let registers_count = registers_count(&func.dfg.type_of_value(interval.value));
+            loop {
+                if let Some(free_registers) = register_file.take(registers_count) {
+                    registers.insert(interval.value, free_registers);
+                    active.push(interval);
+                    break;
+                }
+
+                // Spill whichever variable stays live for the longest.
+                let (furthest_index, furthest) = active
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, active_interval)| active_interval.end)
+                    .map(|(index, active_interval)| (index, *active_interval))
+                    .unwrap_or((usize::MAX, interval));
+                if furthest.end > interval.end {
+                    active.remove(furthest_index);
+                    let evicted_registers =
+                        registers.remove(&furthest.value).expect("active variables have registers");
+                    register_file.free(&evicted_registers);
+                    spilled.push(furthest);
+                } else {
+                    spilled.push(interval);
+                    break;
+                }
+            }
+        }
+
+        let (spill_slots, spill_slots_count) = assign_spill_slots(func, spilled);
+
+        let first_temporary_register =
+            register_file.used_registers_end().max(calling_convention_registers_end(func, blocks));
+
+        let registers = registers
+            .into_iter()
+            .map(|(value, registers)| {
+                let mut registers = registers.into_iter().map(RegisterIndex::from);
+                let variable = allocate_value_with(value, &func.dfg, || {
+                    registers.next().expect("ICE: variable was assigned too few registers")
+                });
+                (value, variable)
+            })
+            .collect();
+
+        RegisterAllocation { registers, spill_slots, spill_slots_count, first_temporary_register }
+    }
+}
+
+/// Returns the number of registers needed to hold a value of type `typ`.
+fn registers_count(typ: &Type) -> usize {
+    match typ {
+        Type::Numeric(_) | Type::Reference(_) => 1,
+        Type::Array(..) => BrilligArray::registers_count(),
+        Type::Slice(_) => BrilligVector::registers_count(),
+        Type::Function => {
+            unreachable!("ICE: Function values should have been removed from the SSA")
+        }
+    }
+}
+
+/// Computes the live interval of each variable in the function, sorted by their start position.
+fn compute_live_intervals(
+    func: &Function,
+    blocks: &[BasicBlockId],
+    liveness: &VariableLiveness,
+) -> Vec<LiveInterval> {
+    let dfg = &func.dfg;
+
+    let mut block_starts = HashMap::default();
+    let mut block_ends = HashMap::default();
+    let mut instruction_positions = HashMap::default();
+    let mut position = 0;
+    for block_id in blocks {
+        block_starts.insert(*block_id, position);
+        for instruction_id in dfg[*block_id].instructions() {
+            position += 1;
+            instruction_positions.insert(*instruction_id, position);
+        }
+        position += 1;
+        block_ends.insert(*block_id, position);
+        position += 1;
+    }
+
+    let mut intervals: Vec<LiveInterval> = Vec::new();
+    let mut interval_indices: HashMap<ValueId, usize> = HashMap::default();
+    let mut extend_interval = |value: ValueId, start: usize, end: usize| {
+        let value = dfg.resolve(value);
+        if let Some(index) = interval_indices.get(&value) {
+            let interval = &mut intervals[*index];
+            interval.start = interval.start.min(start);
+            interval.end = interval.end.max(end);
+        } else {
+            interval_indices.insert(value, intervals.len());
+            intervals.push(LiveInterval { value, start, end });
+        }
+    };
+
+    for block_id in blocks {
+        let block = &dfg[*block_id];
+        let block_start = block_starts[block_id];
+        let block_end = block_ends[block_id];
+
+        // A variable is available until its last use in the block, or until the end of the block
+        // if it is used in the terminator, by a successor or not at all.
+        let live_out = liveness.get_live_out(block_id);
+        let mut last_uses = HashMap::default();
+        for (instruction_id, dead_variables) in liveness.get_last_uses(block_id) {
+            for variable in dead_variables {
+                last_uses.insert(*variable, instruction_positions[instruction_id]);
+            }
+        }
+        let end_in_block = |value: ValueId| {
+            if live_out.contains(&value) {
+                block_end
+            } else {
+                last_uses.get(&value).copied().unwrap_or(block_end)
+            }
+        };
+
+        for parameter in block.parameters() {
+            extend_interval(*parameter, block_start, end_in_block(dfg.resolve(*parameter)));
+        }
+
+        let mut live_in: Vec<_> = liveness.get_live_in(block_id).iter().copied().collect();
+        live_in.sort();
+        for value in live_in {
+            extend_interval(value, block_start, end_in_block(value));
+        }
+
+        for instruction_id in block.instructions() {
+            let position = instruction_positions[instruction_id];
+            for result in dfg.instruction_results(*instruction_id) {
+                extend_interval(*result, position, end_in_block(dfg.resolve(*result)));
+            }
+        }
+
+        // Block parameters are assigned by the terminators jumping to their block.
+        if let Some(TerminatorInstruction::Jmp { destination, .. }) = block.terminator() {
+            for parameter in dfg[*destination].parameters() {
+                extend_interval(*parameter, block_end, block_end);
+            }
+        }
+    }
+
+    // The sort is stable, so the function's parameters stay first and in order.
+    intervals.sort_by_key(|interval| interval.start);
+    intervals
+}
+
+/// Returns the first register which is not used to pass arguments or return values by the function,
+/// either as a caller or as a callee.
+fn calling_convention_registers_end(func: &Function, blocks: &[BasicBlockId]) -> usize {
+    let dfg = &func.dfg;
+    let values_registers_count = |values: &[ValueId]| -> usize {
+        values.iter().map(|value| registers_count(&dfg.type_of_value(*value))).sum()
+    };
+
+    let mut max_registers_count = values_registers_count(func.parameters());
+    for block_id in blocks {
+        let block = &dfg[*block_id];
+        for instruction_id in block.instructions() {
+            if let Instruction::Call { func: callee, arguments } = &dfg[*instruction_id] {
+                if matches!(dfg[*callee], Value::Function(_)) {
+                    max_registers_count = max_registers_count
+                        .max(values_registers_count(arguments))
+                        .max(values_registers_count(dfg.instruction_results(*instruction_id)));
+                }
+            }
+        }
+        if let Some(TerminatorInstruction::Return { return_values, .. }) = block.terminator() {
+            max_registers_count = max_registers_count.max(values_registers_count(return_values));
+        }
+    }
+
+    ReservedRegisters::len() + max_registers_count
+}
+
+/// Assigns spill slots to the spilled variables, reusing the slots of variables whose intervals have ended.
+///
+/// Returns the first slot of each spilled variable along with the total number of slots.
+fn assign_spill_slots(
+    func: &Function,
+    mut spilled: Vec<LiveInterval>,
+) -> (HashMap<ValueId, usize>, usize) {
+    spilled.sort_by_key(|interval| interval.start);
+
+    let mut spill_slots = HashMap::default();
+    let mut spill_slots_count = 0;
+    // Free slots are kept separately for each size of variable so they can be reused as a whole.
+    let mut free_slots: HashMap<usize, BTreeSet<usize>> = HashMap::default();
+    let mut active: Vec<(LiveInterval, usize)> = Vec::new();
+
+    for interval in spilled {
+        active.retain(|(active_interval, registers_count)| {
+            let has_ended = active_interval.end < interval.start;
+            if has_ended {
+                free_slots
+                    .entry(*registers_count)
+                    .or_default()
+                    .insert(spill_slots[&active_interval.value]);
+            }
+            !has_ended
+        });
+
+        let registers_count = registers_count(&func.dfg.type_of_value(interval.value));
+        let free_slot = free_slots.get_mut(&registers_count).and_then(|slots| slots.pop_first());
+        let slot = free_slot.unwrap_or_else(|| {
+            spill_slots_count += registers_count;
+            spill_slots_count - registers_count
+        });
+
+        spill_slots.insert(interval.value, slot);
+        active.push((interval, registers_count));
+    }
+
+    (spill_slots, spill_slots_count)
+}
+
+/// The registers available to hold variables.
+struct RegisterFile {
+    /// Registers which have been used before but are currently free.
+    free_registers: BTreeSet<usize>,
+    /// The first register which has never been used.
+    next_register: usize,
+    /// The first register past the end of the register file, if it is limited.
+    end: Option<usize>,
+}
+
+impl RegisterFile {
+    fn new(size: Option<usize>) -> Self {
+        RegisterFile {
+            free_registers: BTreeSet::new(),
+            next_register: ReservedRegisters::len(),
+            end: size.map(|size| ReservedRegisters::len() + size),
+        }
+    }
+
+    fn fits(&self, register: usize) -> bool {
+        !matches!(self.end, Some(end) if register >= end)
+    }
+
+    /// Takes the `count` lowest free registers, if there are enough of them.
+    fn take(&mut self, count: usize) -> Option<Vec<usize>> {
+        while self.free_registers.len() < count && self.fits(self.next_register) {
+            self.free_registers.insert(self.next_register);
+            self.next_register += 1;
+        }
+        if self.free_registers.len() < count {
+            return None;
+        }
+        Some((0..count).filter_map(|_| self.free_registers.pop_first()).collect())
+    }
+
+    /// Takes the given registers, if they are all free.
+    fn take_exactly(&mut self, registers: &[usize]) -> bool {
+        let last_register = registers.iter().max().copied().unwrap_or_default();
+        if !self.fits(last_register) {
+            return false;
+        }
+        while self.next_register <= last_register {
+            self.free_registers.insert(self.next_register);
+            self.next_register += 1;
+        }
+        if !registers.iter().all(|register| self.free_registers.contains(register)) {
+            return false;
+        }
+        for register in registers {
+            self.free_registers.remove(register);
+        }
+        true
+    }
+
+    fn free(&mut self, registers: &[usize]) {
+        self.free_registers.extend(registers);
+    }
+
+    /// Returns the first register which has never been used to hold a variable.
+    fn used_registers_end(&self) -> usize {
+        self.next_register
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::brillig_vm::brillig::Value;
+    use fxhash::FxHashSet as HashSet;
+
+    use crate::{
+        brillig::{
+            brillig_gen::variable_liveness::VariableLiveness,
+            brillig_ir::tests::run_brillig_function_with_register_file_size,
+        },
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                function::{Function, RuntimeType},
+                instruction::BinaryOp,
+                map::Id,
+                post_order::PostOrder,
+                types::Type,
+            },
+            ssa_gen::Ssa,
+        },
+    };
+
+    use super::RegisterAllocation;
+
+    fn allocate(func: &Function, register_file_size: Option<usize>) -> RegisterAllocation {
+        let mut blocks = PostOrder::with_function(func).as_slice().to_vec();
+        blocks.reverse();
+        let liveness = VariableLiveness::from_function(func);
+        RegisterAllocation::new(func, &blocks, &liveness, register_file_size)
+    }
+
+    /// Builds a function which sums the products of every pair of its parameters, so that all
+    /// of the parameters stay live until the last product is computed.
+    ///
+    /// ```text
+    /// brillig fn main f0 {
+    ///   b0(v0: Field, v1: Field, ..., vn: Field):
+    ///     v_a = mul v0, v1
+    ///     v_b = mul v0, v2
+    ///     ...
+    ///     v_sum = add ...
+    ///     return v_sum
+    /// }
+    /// ```
+    fn sum_of_products(parameters_count: usize) -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Brillig);
+
+        let parameters: Vec<_> =
+            (0..parameters_count).map(|_| builder.add_parameter(Type::field())).collect();
+
+        let mut sum = builder.field_constant(0u128);
+        for (i, lhs) in parameters.iter().enumerate() {
+            for rhs in &parameters[i + 1..] {
+                let product = builder.insert_binary(*lhs, BinaryOp::Mul, *rhs);
+                sum = builder.insert_binary(sum, BinaryOp::Add, product);
+            }
+        }
+        builder.terminate_with_return(vec![sum]);
+
+        builder.finish()
+    }
+
+    /// Builds a function which adds the product of its first two parameters and its third
+    /// parameter to an accumulator on each of four loop iterations, so that the parameters
+    /// stay live across the loop's back edge.
+    ///
+    /// ```text
+    /// brillig fn main f0 {
+    ///   b0(v0: Field, v1: Field, v2: Field):
+    ///     jmp b1(u32 0, Field 0)
+    ///   b1(v3: u32, v4: Field):
+    ///     v5 = lt v3, u32 4
+    ///     jmpif v5, then: b2, else: b3
+    ///   b2():
+    ///     v6 = mul v0, v1
+    ///     v7 = add v4, v6
+    ///     v8 = add v7, v2
+    ///     v9 = add v3, u32 1
+    ///     jmp b1(v9, v8)
+    ///   b3():
+    ///     return v4
+    /// }
+    /// ```
+    fn accumulate_in_loop() -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Brillig);
+
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+        let b3 = builder.insert_block();
+
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let v2 = builder.add_parameter(Type::field());
+        let v3 = builder.add_block_parameter(b1, Type::unsigned(32));
+        let v4 = builder.add_block_parameter(b1, Type::field());
+
+        let zero = builder.numeric_constant(0u128, Type::unsigned(32));
+        let zero_field = builder.field_constant(0u128);
+        builder.terminate_with_jmp(b1, vec![zero, zero_field]);
+
+        builder.switch_to_block(b1);
+        let four = builder.numeric_constant(4u128, Type::unsigned(32));
+        let v5 = builder.insert_binary(v3, BinaryOp::Lt, four);
+        builder.terminate_with_jmpif(v5, b2, b3);
+
+        builder.switch_to_block(b2);
+        let v6 = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        let v7 = builder.insert_binary(v4, BinaryOp::Add, v6);
+        let v8 = builder.insert_binary(v7, BinaryOp::Add, v2);
+        let one = builder.numeric_constant(1u128, Type::unsigned(32));
+        let v9 = builder.insert_binary(v3, BinaryOp::Add, one);
+        builder.terminate_with_jmp(b1, vec![v9, v8]);
+
+        builder.switch_to_block(b3);
+        builder.terminate_with_return(vec![v4]);
+
+        builder.finish()
+    }
+
+    #[test]
+    fn reuses_registers_of_dead_variables() {
+        let ssa = sum_of_products(4);
+        let allocation = allocate(ssa.main(), None);
+
+        assert!(allocation.spill_slots.is_empty());
+        let registers: HashSet<_> = allocation
+            .registers
+            .values()
+            .flat_map(|variable| variable.extract_registers())
+            .collect();
+        // Products and partial sums die as soon as they are added, so their registers are reused.
+        assert!(registers.len() < allocation.registers.len());
+        assert!(registers
+            .iter()
+            .all(|register| register.to_usize() < allocation.first_temporary_register));
+    }
+
+    #[test]
+    fn assigns_parameters_to_calling_convention_registers() {
+        let ssa = sum_of_products(3);
+        let main = ssa.main();
+        let allocation = allocate(main, Some(2));
+
+        // Only the first two parameters fit in the register file when entering the function,
+        // and any parameter kept in a register must be in the one it is passed in.
+        assert!(allocation.spill_slots.contains_key(&main.parameters()[2]));
+        for (index, parameter) in main.parameters().iter().enumerate() {
+            if let Some(variable) = allocation.registers.get(parameter) {
+                assert_eq!(variable.extract_register().to_usize(), 2 + index);
+            }
+        }
+    }
+
+    #[test]
+    fn spills_variables_to_memory() {
+        let arguments: Vec<_> = (1..=6_usize).map(Value::from).collect();
+        let expected: usize =
+            (1..=6_usize).flat_map(|lhs| (lhs + 1..=6).map(move |rhs| lhs * rhs)).sum();
+
+        for register_file_size in [None, Some(8), Some(3), Some(1), Some(0)] {
+            let ssa = sum_of_products(6);
+            let allocation = allocate(ssa.main(), register_file_size);
+            if let Some(size) = register_file_size {
+                assert!(allocation.first_temporary_register >= 2);
+                assert!(allocation
+                    .registers
+                    .values()
+                    .flat_map(|variable| variable.extract_registers())
+                    .all(|register| register.to_usize() < 2 + size));
+                assert!(!allocation.spill_slots.is_empty());
+            }
+
+            let ssa = sum_of_products(6);
+            let result = run_brillig_function_with_register_file_size(
+                ssa.main(),
+                arguments.clone(),
+                register_file_size,
+            );
+            assert_eq!(result, Value::from(expected), "register file size {register_file_size:?}");
+        }
+    }
+
+    #[test]
+    fn spills_variables_live_across_loops() {
+        let arguments = vec![Value::from(3_usize), Value::from(5_usize), Value::from(7_usize)];
+        let expected = 4 * (3 * 5 + 7_usize);
+
+        for register_file_size in [None, Some(3), Some(1), Some(0)] {
+            let ssa = accumulate_in_loop();
+            let allocation = allocate(ssa.main(), register_file_size);
+            // Besides the parameters, the loop counter and accumulator are live around the loop.
+            assert_eq!(allocation.spill_slots.is_empty(), register_file_size.is_none());
+
+            let result = run_brillig_function_with_register_file_size(
+                ssa.main(),
+                arguments.clone(),
+                register_file_size,
+            );
+            assert_eq!(result, Value::from(expected), "register file size {register_file_size:?}");
+        }
+    }
+}
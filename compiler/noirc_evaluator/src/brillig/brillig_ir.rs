@@ -10,6 +10,7 @@ pub(crate) mod debug_show;
 pub(crate) mod registers;
 
 mod entry_point;
+mod optimizer;
 
 use crate::ssa::ir::dfg::CallStack;
 
@@ -27,7 +28,7 @@ use acvm::{
 };
 use debug_show::DebugShow;
 
-/// Integer arithmetic in Brillig is limited to 127 bit
+/// Integer arithmetic in Brillig is limited to 128 bit
 /// integers.
 ///
 /// We could lift this in the future and have Brillig
@@ -37,7 +38,7 @@ use debug_show::DebugShow;
 /// Since constrained functions do not have this property, it
 /// would mean that unconstrained functions will differ from
 /// constrained functions in terms of syntax compatibility.
-pub(crate) const BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE: u32 = 127;
+pub(crate) const BRILLIG_INTEGER_ARITHMETIC_BIT_SIZE: u32 = 128;
 /// The Brillig VM does not apply a limit to the memory address space,
 /// As a convention, we take use 64 bits. This means that we assume that
 /// memory has 2^64 memory slots.
@@ -113,6 +114,11 @@ impl BrilligContext {
         self.registers = BrilligRegistersContext::from_preallocated_registers(allocated_registers);
     }
 
+    /// Resets the register allocation so that only registers from `first_free_register` onwards are allocated.
+    pub(crate) fn set_first_free_register(&mut self, first_free_register: usize) {
+        self.registers = BrilligRegistersContext::starting_at(first_free_register);
+    }
+
     /// Adds a brillig instruction to the brillig byte code
     pub(crate) fn push_opcode(&mut self, opcode: BrilligOpcode) {
         self.obj.push_opcode(opcode);
@@ -465,6 +471,7 @@ impl BrilligContext {
             sources.push(*return_register);
             destinations.push(destination_register);
         }
+        self.obj.set_return_registers(destinations.clone());
         self.mov_registers_to_registers_instruction(sources, destinations);
         self.stop_instruction();
     }
@@ -675,6 +682,47 @@ impl BrilligContext {
         }
     }
 
+    /// Reserves `count` spill slots at the start of the current stack frame.
+    ///
+    /// The previous stack pointer is set to the start of the frame, so that spill slots can be addressed from it.
+    pub(crate) fn allocate_spill_slots_instruction(&mut self, count: usize) {
+        self.mov_instruction(
+            ReservedRegisters::previous_stack_pointer(),
+            ReservedRegisters::stack_pointer(),
+        );
+        self.usize_op_in_place(ReservedRegisters::stack_pointer(), BinaryIntOp::Add, count);
+    }
+
+    /// Stores a variable in the spill slots of the current stack frame, starting at `slot`.
+    pub(crate) fn spill_variable_instruction(&mut self, source: BrilligVariable, slot: usize) {
+        let slot_pointer = self.allocate_register();
+        self.usize_op(
+            ReservedRegisters::previous_stack_pointer(),
+            slot_pointer,
+            BinaryIntOp::Add,
+            slot,
+        );
+        self.store_variable_instruction(slot_pointer, source);
+        self.deallocate_register(slot_pointer);
+    }
+
+    /// Loads a variable from the spill slots of the current stack frame, starting at `slot`.
+    pub(crate) fn reload_variable_instruction(
+        &mut self,
+        destination: BrilligVariable,
+        slot: usize,
+    ) {
+        let slot_pointer = self.allocate_register();
+        self.usize_op(
+            ReservedRegisters::previous_stack_pointer(),
+            slot_pointer,
+            BinaryIntOp::Add,
+            slot,
+        );
+        self.load_variable_instruction(destination, slot_pointer);
+        self.deallocate_register(slot_pointer);
+    }
+
     /// Emits a truncate instruction.
     ///
     /// Note: Truncation is used as an optimization in the SSA IR
@@ -1058,8 +1106,11 @@ pub(crate) mod tests {
     };
     use acvm::brillig_vm::{Registers, VMStatus, VM};
     use acvm::{BlackBoxFunctionSolver, BlackBoxResolutionError, FieldElement};
+    use iter_extended::vecmap;
 
+    use crate::brillig::brillig_gen::{brillig_fn::FunctionContext, convert_ssa_function};
     use crate::brillig::brillig_ir::BrilligContext;
+    use crate::ssa::ir::function::Function;
 
     use super::artifact::{BrilligParameter, GeneratedBrillig};
     use super::{BrilligOpcode, ReservedRegisters};
@@ -1135,6 +1186,77 @@ pub(crate) mod tests {
         vm
     }
 
+    /// Compiles an SSA function which only takes and returns simple values and runs it to completion.
+    ///
+    /// Returns the number of opcodes executed by the VM.
+    pub(crate) fn run_brillig_function(func: &Function, arguments: Vec<Value>) -> usize {
+        let bytecode = compile_brillig_function(func, None);
+        let mut vm = create_brillig_function_vm(&bytecode, arguments);
+
+        let mut executed_opcodes = 0;
+        loop {
+            executed_opcodes += 1;
+            match vm.process_opcode() {
+                VMStatus::InProgress => (),
+                VMStatus::Finished => return executed_opcodes,
+                status => panic!("Brillig execution did not finish: {status:?}"),
+            }
+        }
+    }
+
+    /// Compiles an SSA function which only takes and returns simple values, using at most
+    /// `register_file_size` registers for its variables, and runs it to completion.
+    ///
+    /// Returns the first value returned by the function.
+    pub(crate) fn run_brillig_function_with_register_file_size(
+        func: &Function,
+        arguments: Vec<Value>,
+        register_file_size: Option<usize>,
+    ) -> Value {
+        let bytecode = compile_brillig_function(func, register_file_size);
+        let mut vm = create_brillig_function_vm(&bytecode, arguments);
+
+        let status = vm.process_opcodes();
+        assert_eq!(status, VMStatus::Finished);
+        vm.get_registers().get(RegisterIndex::from(0))
+    }
+
+    /// Compiles an SSA function which only takes and returns simple values and runs it until it
+    /// finishes or fails.
+    ///
+    /// Returns the final status of the VM along with the first value returned by the function.
+    pub(crate) fn execute_brillig_function(
+        func: &Function,
+        arguments: Vec<Value>,
+    ) -> (VMStatus, Value) {
+        let bytecode = compile_brillig_function(func, None);
+        let mut vm = create_brillig_function_vm(&bytecode, arguments);
+
+        let status = vm.process_opcodes();
+        (status, vm.get_registers().get(RegisterIndex::from(0)))
+    }
+
+    fn compile_brillig_function(
+        func: &Function,
+        register_file_size: Option<usize>,
+    ) -> Vec<BrilligOpcode> {
+        let artifact = convert_ssa_function(func, false, register_file_size);
+        let mut entry_point_artifact = BrilligContext::new_entry_point_artifact(
+            vecmap(func.parameters(), |_| BrilligParameter::Simple),
+            vecmap(func.returns(), |_| BrilligParameter::Simple),
+            FunctionContext::function_id_to_function_label(func.id()),
+        );
+        entry_point_artifact.link_with(&artifact);
+        entry_point_artifact.finish().byte_code
+    }
+
+    fn create_brillig_function_vm(
+        bytecode: &[BrilligOpcode],
+        arguments: Vec<Value>,
+    ) -> VM<'_, DummyBlackBoxSolver> {
+        VM::new(Registers { inner: arguments }, vec![], bytecode, vec![], &DummyBlackBoxSolver)
+    }
+
     /// Test a Brillig foreign call returning a vector
     #[test]
     fn test_brillig_ir_foreign_call_return_vector() {
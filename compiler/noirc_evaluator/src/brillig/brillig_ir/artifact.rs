@@ -1,4 +1,4 @@
-use acvm::acir::brillig::Opcode as BrilligOpcode;
+use acvm::acir::brillig::{Opcode as BrilligOpcode, RegisterIndex};
 use std::collections::{BTreeMap, HashMap};
 
 use crate::ssa::ir::dfg::CallStack;
@@ -29,18 +29,23 @@ pub(crate) struct BrilligArtifact {
     pub(crate) assert_messages: BTreeMap<OpcodeLocation, String>,
     /// The set of jumps that need to have their locations
     /// resolved.
-    unresolved_jumps: Vec<(JumpInstructionPosition, UnresolvedJumpLocation)>,
+    pub(super) unresolved_jumps: Vec<(JumpInstructionPosition, UnresolvedJumpLocation)>,
     /// A map of labels to their position in byte code.
-    labels: HashMap<Label, OpcodeLocation>,
+    pub(super) labels: HashMap<Label, OpcodeLocation>,
     /// Set of labels which are external to the bytecode.
     ///
     /// This will most commonly contain the labels of functions
     /// which are defined in other bytecode, that this bytecode has called.
     /// TODO: perhaps we should combine this with the `unresolved_jumps` field
     /// TODO: and have an enum which indicates whether the jump is internal or external
-    unresolved_external_call_labels: Vec<(JumpInstructionPosition, UnresolvedJumpLocation)>,
+    pub(super) unresolved_external_call_labels:
+        Vec<(JumpInstructionPosition, UnresolvedJumpLocation)>,
     /// Maps the opcodes that are associated with a callstack to it.
-    locations: BTreeMap<OpcodeLocation, CallStack>,
+    pub(super) locations: BTreeMap<OpcodeLocation, CallStack>,
+    /// The registers holding the return values of the function once it stops, if known.
+    ///
+    /// This is used by the optimizer to determine which registers are still needed by the caller.
+    pub(super) return_registers: Option<Vec<RegisterIndex>>,
     /// The current call stack. All opcodes that are pushed will be associated with this call stack.
     call_stack: CallStack,
 }
@@ -254,6 +259,10 @@ impl BrilligArtifact {
         self.call_stack = call_stack;
     }
 
+    pub(crate) fn set_return_registers(&mut self, return_registers: Vec<RegisterIndex>) {
+        self.return_registers = Some(return_registers);
+    }
+
     pub(crate) fn add_assert_message_to_last_opcode(&mut self, message: String) {
         let position = self.index_of_next_opcode() - 1;
         self.assert_messages.insert(position, message);
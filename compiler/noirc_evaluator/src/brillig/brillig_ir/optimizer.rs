@@ -0,0 +1,731 @@
+//! Peephole optimizations over the [`BrilligArtifact`] of a single function, applied before linking.
+//!
+//! Brillig codegen emits opcodes directly for each SSA instruction, which leaves behind redundant
+//! moves and constants, jumps to the opcode which immediately follows them and jumps to other jumps.
+//! The optimizer repeatedly applies the following until the bytecode stops changing:
+//! - Jump threading: jumps to an unconditional jump are redirected to that jump's destination, and a
+//!   conditional jump over an unconditional jump is replaced by the inverted conditional jump.
+//! - Unreachable opcodes and jumps to the next opcode are removed.
+//! - Move coalescing and constant propagation: within each basic block, the registers known to hold
+//!   a copy of another register or a constant value are tracked. Moves and constants which would not
+//!   change the value of their destination are removed, and reads of a copied register are replaced
+//!   with reads of the original register.
+//! - Dead register elimination: writes to registers which are not read before being overwritten or
+//!   before the function stops are removed, based on a liveness analysis over the function's blocks.
+//!
+//! Removing opcodes shifts the positions of the ones following them, so the artifact's labels,
+//! unresolved jumps and calls, `locations` and `assert_messages` are all remapped accordingly.
+//!
+//! `cargo bench --bench brillig_opcodes` in `tooling/nargo_cli` compares the number of opcodes the VM
+//! executes for the `execution_success` programs with and without these optimizations.
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use acvm::acir::brillig::{
+    BinaryFieldOp, BinaryIntOp, Opcode as BrilligOpcode, RegisterIndex, Value,
+};
+
+use super::{
+    artifact::{BrilligArtifact, Label, OpcodeLocation},
+    ReservedRegisters,
+};
+
+type RegisterSet = HashSet<RegisterIndex>;
+
+impl BrilligArtifact {
+    /// Optimizes the bytecode of this artifact.
+    ///
+    /// This must be called on the artifact of a single function, before it is linked with any other artifacts.
+    pub(crate) fn optimize(&mut self) {
+        let Some(mut jumps) = self.collect_unresolved_jumps() else {
+            // The artifact contains jumps which were resolved during codegen and so can't be safely moved.
+            return;
+        };
+
+        loop {
+            let mut changed = thread_jumps(&self.byte_code, &self.labels, &mut jumps);
+
+            let blocks = BasicBlocks::new(&self.byte_code, &self.labels, &jumps);
+            let mut removed = vec![false; self.byte_code.len()];
+
+            changed |= remove_unreachable_opcodes(&self.byte_code, &blocks, &mut removed);
+            changed |= remove_jumps_to_next_opcode(
+                &mut self.byte_code,
+                &self.labels,
+                &mut jumps,
+                &mut removed,
+            );
+            changed |= propagate_copies_and_constants(&mut self.byte_code, &blocks, &mut removed);
+            changed |= remove_dead_register_writes(
+                &self.byte_code,
+                &blocks,
+                self.return_registers.as_deref(),
+                &mut removed,
+            );
+
+            if removed.contains(&true) {
+                self.remove_opcodes(&removed, &mut jumps);
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        self.unresolved_jumps = jumps.into_iter().collect();
+    }
+
+    /// Returns the label each jump within this artifact is waiting to be resolved to.
+    ///
+    /// Returns `None` if any jump has already been resolved to a fixed position.
+    fn collect_unresolved_jumps(&self) -> Option<BTreeMap<OpcodeLocation, Label>> {
+        let jumps: BTreeMap<_, _> = self.unresolved_jumps.iter().cloned().collect();
+        let calls: HashSet<_> =
+            self.unresolved_external_call_labels.iter().map(|(position, _)| *position).collect();
+
+        let all_jumps_unresolved =
+            self.byte_code.iter().enumerate().all(|(position, opcode)| match opcode {
+                BrilligOpcode::Jump { .. }
+                | BrilligOpcode::JumpIf { .. }
+                | BrilligOpcode::JumpIfNot { .. } => jumps.contains_key(&position),
+                BrilligOpcode::Call { .. } => calls.contains(&position),
+                _ => true,
+            });
+        all_jumps_unresolved.then_some(jumps)
+    }
+
+    /// Removes the opcodes marked in `removed` and updates every position referring into the bytecode.
+    fn remove_opcodes(&mut self, removed: &[bool], jumps: &mut BTreeMap<OpcodeLocation, Label>) {
+        // Maps each position to the new position of the first opcode kept at or after it.
+        // This includes the position one past the end, which labels may refer to.
+        let mut new_positions = Vec::with_capacity(removed.len() + 1);
+        let mut next_position = 0;
+        for is_removed in removed {
+            new_positions.push(next_position);
+            if !is_removed {
+                next_position += 1;
+            }
+        }
+        new_positions.push(next_position);
+
+        let remap =
+            |position: &OpcodeLocation| (!removed[*position]).then(|| new_positions[*position]);
+
+        let mut position = 0;
+        self.byte_code.retain(|_| {
+            position += 1;
+            !removed[position - 1]
+        });
+
+        for label_position in self.labels.values_mut() {
+            *label_position = new_positions[*label_position];
+        }
+
+        *jumps = std::mem::take(jumps)
+            .into_iter()
+            .filter_map(|(position, label)| Some((remap(&position)?, label)))
+            .collect();
+        self.unresolved_external_call_labels =
+            std::mem::take(&mut self.unresolved_external_call_labels)
+                .into_iter()
+                .filter_map(|(position, label)| Some((remap(&position)?, label)))
+                .collect();
+        self.locations = std::mem::take(&mut self.locations)
+            .into_iter()
+            .filter_map(|(position, call_stack)| Some((remap(&position)?, call_stack)))
+            .collect();
+        self.assert_messages = std::mem::take(&mut self.assert_messages)
+            .into_iter()
+            .filter_map(|(position, message)| Some((remap(&position)?, message)))
+            .collect();
+    }
+}
+
+/// Returns the position which the jump at `position` will jump to, if it is a jump within the artifact.
+fn jump_destination(
+    labels: &HashMap<Label, OpcodeLocation>,
+    jumps: &BTreeMap<OpcodeLocation, Label>,
+    position: OpcodeLocation,
+) -> Option<OpcodeLocation> {
+    jumps.get(&position).and_then(|label| labels.get(label)).copied()
+}
+
+/// Redirects jumps whose destination is an unconditional jump to the destination of that jump.
+fn thread_jumps(
+    byte_code: &[BrilligOpcode],
+    labels: &HashMap<Label, OpcodeLocation>,
+    jumps: &mut BTreeMap<OpcodeLocation, Label>,
+) -> bool {
+    let mut changed = false;
+    let positions: Vec<_> = jumps.keys().copied().collect();
+    for position in positions {
+        let mut visited = HashSet::from([position]);
+        let mut label = jumps[&position].clone();
+
+        while let Some(destination) = labels.get(&label).copied() {
+            let is_unconditional_jump =
+                matches!(byte_code.get(destination), Some(BrilligOpcode::Jump { .. }));
+            if !is_unconditional_jump || !visited.insert(destination) {
+                break;
+            }
+            match jumps.get(&destination) {
+                Some(next_label) => label = next_label.clone(),
+                None => break,
+            }
+        }
+
+        if jumps[&position] != label {
+            jumps.insert(position, label);
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Removes jumps to the next opcode, which have no effect.
+///
+/// A conditional jump over an unconditional jump is also replaced with the inverse conditional jump
+/// to the destination of the unconditional jump.
+fn remove_jumps_to_next_opcode(
+    byte_code: &mut [BrilligOpcode],
+    labels: &HashMap<Label, OpcodeLocation>,
+    jumps: &mut BTreeMap<OpcodeLocation, Label>,
+    removed: &mut [bool],
+) -> bool {
+    let label_positions: HashSet<_> = labels.values().copied().collect();
+
+    let mut changed = false;
+    for position in 0..byte_code.len() {
+        if removed[position] {
+            continue;
+        }
+        let Some(destination) = jump_destination(labels, jumps, position) else {
+            continue;
+        };
+
+        if destination == position + 1 {
+            removed[position] = true;
+            changed = true;
+            continue;
+        }
+
+        // `jump_if c, L1; jump L2; L1:` is equivalent to `jump_if_not c, L2; L1:`
+        // as long as nothing else jumps to the unconditional jump.
+        let next_is_unconditional_jump = !removed.get(position + 1).copied().unwrap_or(true)
+            && !label_positions.contains(&(position + 1))
+            && matches!(byte_code[position + 1], BrilligOpcode::Jump { .. });
+        if destination == position + 2 && next_is_unconditional_jump {
+            let inverted_jump = match byte_code[position] {
+                BrilligOpcode::JumpIf { condition, location } => {
+                    BrilligOpcode::JumpIfNot { condition, location }
+                }
+                BrilligOpcode::JumpIfNot { condition, location } => {
+                    BrilligOpcode::JumpIf { condition, location }
+                }
+                _ => continue,
+            };
+            let next_label = jumps[&(position + 1)].clone();
+            byte_code[position] = inverted_jump;
+            jumps.insert(position, next_label);
+            removed[position + 1] = true;
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// The basic blocks of an artifact's bytecode along with the control flow between them.
+struct BasicBlocks {
+    /// The range of positions covered by each block.
+    ranges: Vec<std::ops::Range<OpcodeLocation>>,
+    /// The indices of the blocks which control may flow to from each block.
+    successors: Vec<Vec<usize>>,
+}
+
+impl BasicBlocks {
+    fn new(
+        byte_code: &[BrilligOpcode],
+        labels: &HashMap<Label, OpcodeLocation>,
+        jumps: &BTreeMap<OpcodeLocation, Label>,
+    ) -> Self {
+        let mut block_starts: Vec<OpcodeLocation> = std::iter::once(0)
+            .chain(labels.values().copied())
+            .chain(
+                byte_code
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, opcode)| ends_block(opcode))
+                    .map(|(position, _)| position + 1),
+            )
+            .filter(|position| *position < byte_code.len())
+            .collect();
+        block_starts.sort_unstable();
+        block_starts.dedup();
+
+        let block_ends =
+            block_starts.iter().skip(1).copied().chain(std::iter::once(byte_code.len()));
+        let ranges: Vec<_> =
+            block_starts.iter().zip(block_ends).map(|(start, end)| *start..end).collect();
+
+        let block_of = |position: OpcodeLocation| block_starts.binary_search(&position).ok();
+
+        let successors = ranges
+            .iter()
+            .enumerate()
+            .map(|(index, range)| {
+                let last_position = range.end - 1;
+                let fallthrough = (index + 1 < ranges.len()).then_some(index + 1);
+                let destination = jump_destination(labels, jumps, last_position).and_then(block_of);
+                match byte_code[last_position] {
+                    BrilligOpcode::Jump { .. } => destination.into_iter().collect(),
+                    BrilligOpcode::JumpIf { .. } | BrilligOpcode::JumpIfNot { .. } => {
+                        destination.into_iter().chain(fallthrough).collect()
+                    }
+                    BrilligOpcode::Return | BrilligOpcode::Stop | BrilligOpcode::Trap => Vec::new(),
+                    _ => fallthrough.into_iter().collect(),
+                }
+            })
+            .collect();
+
+        BasicBlocks { ranges, successors }
+    }
+}
+
+/// Returns true if control flow never continues directly to the opcode following `opcode`
+/// or may continue elsewhere.
+fn ends_block(opcode: &BrilligOpcode) -> bool {
+    matches!(
+        opcode,
+        BrilligOpcode::Jump { .. }
+            | BrilligOpcode::JumpIf { .. }
+            | BrilligOpcode::JumpIfNot { .. }
+            | BrilligOpcode::Return
+            | BrilligOpcode::Stop
+            | BrilligOpcode::Trap
+    )
+}
+
+/// Removes all opcodes which can't be reached from the start of the function.
+///
+/// Linking requires each function to contain a `Stop` opcode, so unreachable `Stop` opcodes are
+/// only removed if a reachable one remains.
+fn remove_unreachable_opcodes(
+    byte_code: &[BrilligOpcode],
+    blocks: &BasicBlocks,
+    removed: &mut [bool],
+) -> bool {
+    let mut reachable = vec![false; blocks.ranges.len()];
+    let mut stack = vec![0];
+    while let Some(block) = stack.pop() {
+        if block < reachable.len() && !reachable[block] {
+            reachable[block] = true;
+            stack.extend(&blocks.successors[block]);
+        }
+    }
+
+    let is_stop = |position: &OpcodeLocation| matches!(byte_code[*position], BrilligOpcode::Stop);
+    let has_reachable_stop =
+        blocks.ranges.iter().zip(&reachable).any(|(range, reachable)| {
+            *reachable && range.clone().any(|position| is_stop(&position))
+        });
+
+    let mut changed = false;
+    for (range, _) in blocks.ranges.iter().zip(reachable).filter(|(_, reachable)| !reachable) {
+        for position in range.clone() {
+            if has_reachable_stop || !is_stop(&position) {
+                removed[position] = true;
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// Tracks the registers known to hold copies of other registers or constant values within a block.
+#[derive(Default)]
+struct KnownValues {
+    /// Maps registers to the register they hold a copy of.
+    copies: HashMap<RegisterIndex, RegisterIndex>,
+    constants: HashMap<RegisterIndex, Value>,
+}
+
+impl KnownValues {
+    /// Returns the register which `register` is a copy of, or `register` itself if it is not a copy.
+    fn resolve(&self, register: RegisterIndex) -> RegisterIndex {
+        self.copies.get(&register).copied().unwrap_or(register)
+    }
+
+    /// Returns true if `destination` already holds the same value as `source`.
+    fn holds_same_value(&self, destination: RegisterIndex, source: RegisterIndex) -> bool {
+        destination == source
+            || self.copies.get(&destination) == Some(&source)
+            || matches!(
+                (self.constants.get(&destination), self.constants.get(&source)),
+                (Some(a), Some(b)) if a == b
+            )
+    }
+
+    /// Forgets everything known about the value of `register`, as it is being overwritten.
+    fn write(&mut self, register: RegisterIndex) {
+        self.constants.remove(&register);
+        self.copies.remove(&register);
+        self.copies.retain(|_, source| *source != register);
+    }
+
+    fn clear(&mut self) {
+        self.copies.clear();
+        self.constants.clear();
+    }
+}
+
+/// Removes moves and constants which don't change the value of their destination register,
+/// and replaces reads of copied registers with reads of the original register.
+fn propagate_copies_and_constants(
+    byte_code: &mut [BrilligOpcode],
+    blocks: &BasicBlocks,
+    removed: &mut [bool],
+) -> bool {
+    let mut changed = false;
+    for range in &blocks.ranges {
+        let mut known_values = KnownValues::default();
+
+        for position in range.clone() {
+            if removed[position] {
+                continue;
+            }
+            let opcode = &mut byte_code[position];
+            let original_opcode = opcode.clone();
+
+            match opcode {
+                BrilligOpcode::Mov { destination, source } => {
+                    *source = known_values.resolve(*source);
+                    if known_values.holds_same_value(*destination, *source) {
+                        removed[position] = true;
+                        changed = true;
+                        continue;
+                    }
+                    known_values.write(*destination);
+                    known_values.copies.insert(*destination, *source);
+                    if let Some(value) = known_values.constants.get(source).copied() {
+                        known_values.constants.insert(*destination, value);
+                    }
+                }
+                BrilligOpcode::Const { destination, value } => {
+                    if known_values.constants.get(destination) == Some(value) {
+                        removed[position] = true;
+                        changed = true;
+                        continue;
+                    }
+                    known_values.write(*destination);
+                    known_values.constants.insert(*destination, *value);
+                }
+                BrilligOpcode::BinaryFieldOp { destination, lhs, rhs, .. }
+                | BrilligOpcode::BinaryIntOp { destination, lhs, rhs, .. } => {
+                    *lhs = known_values.resolve(*lhs);
+                    *rhs = known_values.resolve(*rhs);
+                    known_values.write(*destination);
+                }
+                BrilligOpcode::Load { destination, source_pointer } => {
+                    *source_pointer = known_values.resolve(*source_pointer);
+                    known_values.write(*destination);
+                }
+                BrilligOpcode::Store { destination_pointer, source } => {
+                    *destination_pointer = known_values.resolve(*destination_pointer);
+                    *source = known_values.resolve(*source);
+                }
+                BrilligOpcode::JumpIf { condition, .. }
+                | BrilligOpcode::JumpIfNot { condition, .. } => {
+                    *condition = known_values.resolve(*condition);
+                }
+                // These may write to any register.
+                BrilligOpcode::Call { .. }
+                | BrilligOpcode::ForeignCall { .. }
+                | BrilligOpcode::BlackBox(_) => known_values.clear(),
+                BrilligOpcode::Jump { .. }
+                | BrilligOpcode::Return
+                | BrilligOpcode::Stop
+                | BrilligOpcode::Trap => (),
+            }
+
+            changed |= *opcode != original_opcode;
+        }
+    }
+    changed
+}
+
+/// Returns the registers read by `opcode`, or `None` if it may read any register.
+fn registers_read(opcode: &BrilligOpcode) -> Option<Vec<RegisterIndex>> {
+    match opcode {
+        BrilligOpcode::BinaryFieldOp { lhs, rhs, .. }
+        | BrilligOpcode::BinaryIntOp { lhs, rhs, .. } => Some(vec![*lhs, *rhs]),
+        BrilligOpcode::JumpIf { condition, .. } | BrilligOpcode::JumpIfNot { condition, .. } => {
+            Some(vec![*condition])
+        }
+        BrilligOpcode::Mov { source, .. } => Some(vec![*source]),
+        BrilligOpcode::Load { source_pointer, .. } => Some(vec![*source_pointer]),
+        BrilligOpcode::Store { destination_pointer, source } => {
+            Some(vec![*destination_pointer, *source])
+        }
+        BrilligOpcode::Jump { .. } | BrilligOpcode::Const { .. } | BrilligOpcode::Trap => {
+            Some(Vec::new())
+        }
+        BrilligOpcode::Call { .. }
+        | BrilligOpcode::ForeignCall { .. }
+        | BrilligOpcode::BlackBox(_)
+        | BrilligOpcode::Return
+        | BrilligOpcode::Stop => None,
+    }
+}
+
+/// Returns the register written by `opcode` if it writes to exactly one known register.
+fn register_written(opcode: &BrilligOpcode) -> Option<RegisterIndex> {
+    match opcode {
+        BrilligOpcode::BinaryFieldOp { destination, .. }
+        | BrilligOpcode::BinaryIntOp { destination, .. }
+        | BrilligOpcode::Const { destination, .. }
+        | BrilligOpcode::Mov { destination, .. }
+        | BrilligOpcode::Load { destination, .. } => Some(*destination),
+        _ => None,
+    }
+}
+
+/// Returns true if `opcode` only writes to a register and can be removed if that register is unused.
+///
+/// Divisions are kept as they fail when dividing by zero, as are loads from memory.
+fn is_removable_if_unused(opcode: &BrilligOpcode) -> bool {
+    match opcode {
+        BrilligOpcode::Const { .. } | BrilligOpcode::Mov { .. } => true,
+        BrilligOpcode::BinaryFieldOp { op, .. } => !matches!(op, BinaryFieldOp::Div),
+        BrilligOpcode::BinaryIntOp { op, .. } => {
+            !matches!(op, BinaryIntOp::UnsignedDiv | BinaryIntOp::SignedDiv)
+        }
+        _ => false,
+    }
+}
+
+/// Removes writes to registers which are never read afterwards.
+fn remove_dead_register_writes(
+    byte_code: &[BrilligOpcode],
+    blocks: &BasicBlocks,
+    return_registers: Option<&[RegisterIndex]>,
+    removed: &mut [bool],
+) -> bool {
+    // The only registers whose liveness matters are those which could be removed.
+    // Opcodes which may read any register therefore read all of these.
+    let all_registers: RegisterSet = byte_code
+        .iter()
+        .zip(removed.iter())
+        .filter(|(opcode, is_removed)| !**is_removed && is_removable_if_unused(opcode))
+        .filter_map(|(opcode, _)| register_written(opcode))
+        .collect();
+
+    // When the function stops, the caller only reads the return values and the stack pointers.
+    let live_on_stop: RegisterSet = match return_registers {
+        Some(return_registers) => return_registers
+            .iter()
+            .copied()
+            .chain([
+                ReservedRegisters::stack_pointer(),
+                ReservedRegisters::previous_stack_pointer(),
+            ])
+            .collect(),
+        None => all_registers.clone(),
+    };
+
+    let transfer = |position: OpcodeLocation, live: &mut RegisterSet| {
+        let opcode = &byte_code[position];
+        if matches!(opcode, BrilligOpcode::Stop) {
+            *live = live_on_stop.clone();
+            return;
+        }
+        match registers_read(opcode) {
+            Some(reads) => {
+                if let Some(written) = register_written(opcode) {
+                    live.remove(&written);
+                }
+                live.extend(reads);
+            }
+            None => live.extend(all_registers.iter().copied()),
+        }
+    };
+
+    // Compute the registers live at the end of each block until a fixed point is reached.
+    let mut live_out = vec![RegisterSet::new(); blocks.ranges.len()];
+    let mut live_in = vec![RegisterSet::new(); blocks.ranges.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for block in (0..blocks.ranges.len()).rev() {
+            let mut live: RegisterSet = blocks.successors[block]
+                .iter()
+                .flat_map(|successor| live_in[*successor].iter().copied())
+                .collect();
+            live_out[block] = live.clone();
+
+            for position in blocks.ranges[block].clone().rev() {
+                if !removed[position] {
+                    transfer(position, &mut live);
+                }
+            }
+
+            if live != live_in[block] {
+                live_in[block] = live;
+                changed = true;
+            }
+        }
+    }
+
+    let mut removed_any = false;
+    for (range, mut live) in blocks.ranges.iter().zip(live_out) {
+        for position in range.clone().rev() {
+            if removed[position] {
+                continue;
+            }
+            let opcode = &byte_code[position];
+            let is_dead = is_removable_if_unused(opcode)
+                && matches!(register_written(opcode), Some(written) if !live.contains(&written));
+            if is_dead {
+                removed[position] = true;
+                removed_any = true;
+            } else {
+                transfer(position, &mut live);
+            }
+        }
+    }
+    removed_any
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::acir::brillig::{BinaryIntOp, Opcode as BrilligOpcode, RegisterIndex, Value};
+
+    use crate::brillig::brillig_ir::{
+        artifact::{BrilligArtifact, BrilligParameter},
+        tests::{create_and_run_vm, create_context},
+        BrilligContext, ReservedRegisters, BRILLIG_MEMORY_ADDRESSING_BIT_SIZE,
+    };
+
+    /// Optimizes the artifact of `context` and links it with an entry point taking and returning a single value.
+    fn optimize_and_link(context: BrilligContext) -> (BrilligArtifact, Vec<BrilligOpcode>) {
+        let mut artifact = context.artifact();
+        artifact.optimize();
+
+        let mut entry_point_artifact = BrilligContext::new_entry_point_artifact(
+            vec![BrilligParameter::Simple],
+            vec![BrilligParameter::Simple],
+            "test",
+        );
+        entry_point_artifact.link_with(&artifact);
+        (artifact, entry_point_artifact.finish().byte_code)
+    }
+
+    #[test]
+    fn removes_redundant_moves_and_constants() {
+        let mut context = create_context();
+        let r_input = context.allocate_register();
+        let r_constant = context.allocate_register();
+        let r_copy = context.allocate_register();
+
+        context.const_instruction(r_constant, Value::from(5_usize));
+        // Already holds this value
+        context.const_instruction(r_constant, Value::from(5_usize));
+        context.mov_instruction(r_copy, r_input);
+        // Already a copy of `r_input`
+        context.mov_instruction(r_copy, r_input);
+        // `r_input` already holds the value of `r_copy`
+        context.mov_instruction(r_input, r_copy);
+        context.memory_op(r_copy, r_constant, r_copy, BinaryIntOp::Add);
+        context.return_instruction(&[r_copy]);
+
+        let (artifact, bytecode) = optimize_and_link(context);
+
+        // The addition reads `r_input` directly and its result is moved straight into the return register.
+        assert_eq!(
+            artifact.byte_code,
+            vec![
+                BrilligOpcode::Const { destination: r_constant, value: Value::from(5_usize) },
+                BrilligOpcode::BinaryIntOp {
+                    destination: r_copy,
+                    op: BinaryIntOp::Add,
+                    bit_size: BRILLIG_MEMORY_ADDRESSING_BIT_SIZE,
+                    lhs: r_input,
+                    rhs: r_constant,
+                },
+                BrilligOpcode::Mov {
+                    destination: ReservedRegisters::user_register_index(0),
+                    source: r_copy,
+                },
+                BrilligOpcode::Stop,
+            ]
+        );
+
+        let vm = create_and_run_vm(vec![], vec![Value::from(3_usize)], &bytecode);
+        assert_eq!(vm.get_registers().get(RegisterIndex(0)), Value::from(8_usize));
+    }
+
+    #[test]
+    fn removes_jumps_to_next_opcode() {
+        let mut context = create_context();
+        let r_condition = context.allocate_register();
+        let r_result = context.allocate_register();
+
+        context.jump_instruction("next");
+        context.enter_context("next");
+        context.jump_if_instruction(r_condition, "then");
+        context.jump_instruction("else");
+        context.enter_context("then");
+        context.const_instruction(r_result, Value::from(1_usize));
+        context.jump_instruction("end");
+        context.enter_context("else");
+        context.const_instruction(r_result, Value::from(2_usize));
+        context.jump_instruction("end");
+        context.enter_context("end");
+        context.return_instruction(&[r_result]);
+
+        let (artifact, bytecode) = optimize_and_link(context);
+
+        // Only the jump from the `then` branch over the `else` branch is left,
+        // with the conditional jump inverted to target the `else` branch directly.
+        let jumps: Vec<_> = artifact
+            .byte_code
+            .iter()
+            .filter(|opcode| {
+                matches!(
+                    opcode,
+                    BrilligOpcode::Jump { .. }
+                        | BrilligOpcode::JumpIf { .. }
+                        | BrilligOpcode::JumpIfNot { .. }
+                )
+            })
+            .collect();
+        assert_eq!(jumps.len(), 2);
+        assert!(matches!(jumps[0], BrilligOpcode::JumpIfNot { .. }));
+        assert!(matches!(jumps[1], BrilligOpcode::Jump { .. }));
+
+        for (condition, expected) in [(1_usize, 1_usize), (0, 2)] {
+            let vm = create_and_run_vm(vec![], vec![Value::from(condition)], &bytecode);
+            assert_eq!(vm.get_registers().get(RegisterIndex(0)), Value::from(expected));
+        }
+    }
+
+    #[test]
+    fn removes_overwritten_register_writes() {
+        let mut context = create_context();
+        let r_input = context.allocate_register();
+        let r_temporary = context.allocate_register();
+
+        // Overwritten before being read
+        context.const_instruction(r_temporary, Value::from(1_usize));
+        context.const_instruction(r_temporary, Value::from(2_usize));
+        context.memory_op(r_input, r_temporary, r_temporary, BinaryIntOp::Mul);
+        context.return_instruction(&[r_temporary]);
+
+        let (artifact, bytecode) = optimize_and_link(context);
+        assert_eq!(
+            artifact.byte_code[0],
+            BrilligOpcode::Const { destination: r_temporary, value: Value::from(2_usize) }
+        );
+
+        let vm = create_and_run_vm(vec![], vec![Value::from(7_usize)], &bytecode);
+        assert_eq!(vm.get_registers().get(RegisterIndex(0)), Value::from(14_usize));
+    }
+}
@@ -44,6 +44,14 @@ impl BrilligRegistersContext {
         Self { deallocated_registers, next_free_register_index }
     }
 
+    /// Creates a new register context where every register before `first_free_register` is reserved.
+    pub(crate) fn starting_at(first_free_register: usize) -> Self {
+        Self {
+            deallocated_registers: Vec::new(),
+            next_free_register_index: first_free_register.max(ReservedRegisters::len()),
+        }
+    }
+
     /// Ensures a register is allocated.
     pub(crate) fn ensure_register_is_allocated(&mut self, register: RegisterIndex) {
         let index = register.to_usize();
@@ -21,8 +21,17 @@ pub struct Brillig {
 
 impl Brillig {
     /// Compiles a function into brillig and store the compilation artifacts
-    pub(crate) fn compile(&mut self, func: &Function, enable_debug_trace: bool) {
-        let obj = convert_ssa_function(func, enable_debug_trace);
+    pub(crate) fn compile(
+        &mut self,
+        func: &Function,
+        enable_debug_trace: bool,
+        register_file_size: Option<usize>,
+        optimize: bool,
+    ) {
+        let mut obj = convert_ssa_function(func, enable_debug_trace, register_file_size);
+        if optimize {
+            obj.optimize();
+        }
         self.ssa_function_to_brillig.insert(func.id(), obj);
     }
 
@@ -47,7 +56,15 @@ impl std::ops::Index<FunctionId> for Brillig {
 
 impl Ssa {
     /// Compile to brillig brillig functions and ACIR functions reachable from them
-    pub(crate) fn to_brillig(&self, enable_debug_trace: bool) -> Brillig {
+    ///
+    /// If `register_file_size` is set, at most that many registers hold variables in each function.
+    /// If `optimize` is set, the bytecode of each function is run through the peephole optimizer.
+    pub(crate) fn to_brillig(
+        &self,
+        enable_debug_trace: bool,
+        register_file_size: Option<usize>,
+        optimize: bool,
+    ) -> Brillig {
         // Collect all the function ids that are reachable from brillig
         // That means all the functions marked as brillig and ACIR functions called by them
         let brillig_reachable_function_ids = self
@@ -59,7 +76,7 @@ impl Ssa {
         let mut brillig = Brillig::default();
         for brillig_function_id in brillig_reachable_function_ids {
             let func = &self.functions[&brillig_function_id];
-            brillig.compile(func, enable_debug_trace);
+            brillig.compile(func, enable_debug_trace, register_file_size, optimize);
         }
 
         brillig
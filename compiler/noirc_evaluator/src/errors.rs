@@ -9,7 +9,8 @@
 //! An Error of the latter is an error in the implementation of the compiler
 use acvm::{acir::native_types::Expression, FieldElement};
 use iter_extended::vecmap;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic};
+use noirc_errors::error_codes::*;
+use noirc_errors::{CustomDiagnostic as Diagnostic, ErrorCode, FileDiagnostic};
 use thiserror::Error;
 
 use crate::ssa::ir::{dfg::CallStack, types::NumericType};
@@ -64,6 +65,7 @@ impl From<SsaReport> for FileDiagnostic {
         match error {
             SsaReport::Warning(warning) => {
                 let message = warning.to_string();
+                let code = warning.code();
                 let (secondary_message, call_stack) = match warning {
                     InternalWarning::ReturnConstant { call_stack } => {
                         ("constant value".to_string(), call_stack)
@@ -71,12 +73,16 @@ impl From<SsaReport> for FileDiagnostic {
                     InternalWarning::VerifyProof { call_stack } => {
                         ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
                     },
+                    InternalWarning::UnderconstrainedValue { call_stack } => {
+                        ("the result of this unconstrained call is never constrained against its arguments, so the prover may replace it with any value".to_string(), call_stack)
+                    },
                 };
                 let call_stack = vecmap(call_stack, |location| location);
                 let file_id = call_stack.last().map(|location| location.file).unwrap_or_default();
                 let location = call_stack.last().expect("Expected RuntimeError to have a location");
                 let diagnostic =
-                    Diagnostic::simple_warning(message, secondary_message, location.span);
+                    Diagnostic::simple_warning(message, secondary_message, location.span)
+                        .with_code(code);
                 diagnostic.in_file(file_id).with_call_stack(call_stack)
             }
         }
@@ -89,6 +95,18 @@ pub enum InternalWarning {
     ReturnConstant { call_stack: CallStack },
     #[error("Calling std::verify_proof(...) does not verify a proof")]
     VerifyProof { call_stack: CallStack },
+    #[error("Result of an unconstrained function call is not constrained")]
+    UnderconstrainedValue { call_stack: CallStack },
+}
+
+impl InternalWarning {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            InternalWarning::ReturnConstant { .. } => E0510,
+            InternalWarning::VerifyProof { .. } => E0511,
+            InternalWarning::UnderconstrainedValue { .. } => E0512,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Error)]
@@ -110,6 +128,21 @@ pub enum InternalError {
 }
 
 impl RuntimeError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            RuntimeError::FailedConstraint { .. } => E0500,
+            RuntimeError::InternalError(_) => E0501,
+            RuntimeError::IndexOutOfBounds { .. } => E0502,
+            RuntimeError::InvalidRangeConstraint { .. } => E0503,
+            RuntimeError::IntegerOutOfBounds { .. } => E0504,
+            RuntimeError::TypeConversion { .. } => E0505,
+            RuntimeError::UnInitialized { .. } => E0506,
+            RuntimeError::UnsupportedIntegerSize { .. } => E0507,
+            RuntimeError::UnknownLoopBound { .. } => E0508,
+            RuntimeError::AssertConstantFailed { .. } => E0509,
+        }
+    }
+
     fn call_stack(&self) -> &CallStack {
         match self {
             RuntimeError::InternalError(
@@ -145,7 +178,8 @@ impl From<RuntimeError> for FileDiagnostic {
 
 impl RuntimeError {
     fn into_diagnostic(self) -> Diagnostic {
-        match self {
+        let code = self.code();
+        let diagnostic = match self {
             RuntimeError::InternalError(cause) => {
                 Diagnostic::simple_error(
                     "Internal Consistency Evaluators Errors: \n
@@ -161,6 +195,7 @@ impl RuntimeError {
 
                 Diagnostic::simple_error(message, String::new(), location.span)
             }
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
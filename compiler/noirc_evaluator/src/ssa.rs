@@ -13,9 +13,12 @@ use crate::{
     brillig::Brillig,
     errors::{RuntimeError, SsaReport},
 };
-use acvm::acir::{
-    circuit::{Circuit, PublicInputs},
-    native_types::Witness,
+use acvm::{
+    acir::{
+        circuit::{Circuit, PublicInputs},
+        native_types::Witness,
+    },
+    FieldElement,
 };
 
 use noirc_errors::debug_info::DebugInfo;
@@ -24,44 +27,56 @@ use noirc_frontend::{
     hir_def::function::FunctionSignature, monomorphization::ast::Program, Visibility,
 };
 
-use self::{acir_gen::GeneratedAcir, ssa_gen::Ssa};
+use self::{acir_gen::GeneratedAcir, interpreter::PassValidator, ssa_gen::Ssa};
 
 mod acir_gen;
 pub(super) mod function_builder;
+mod interpreter;
 pub mod ir;
 mod opt;
 pub mod ssa_gen;
 
+/// Options controlling how a [`Program`] is optimized and compiled into ACIR and Brillig.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SsaEvaluatorOptions<'a> {
+    /// Print the SSA after each optimization pass
+    pub enable_ssa_logging: bool,
+
+    /// Interpret the SSA after each optimization pass with each of these inputs, see [`create_circuit`]
+    pub validate_ssa_passes: Option<&'a [Vec<FieldElement>]>,
+
+    /// Print the Brillig bytecode generated for each unconstrained function
+    pub enable_brillig_logging: bool,
+
+    /// Limit the number of registers holding variables in each unconstrained function
+    pub brillig_register_file_size: Option<usize>,
+
+    /// Run the peephole optimizer over the generated Brillig bytecode
+    pub optimize_brillig: bool,
+
+    /// Make every integer overflow a constraint failure
+    pub strict_overflow: bool,
+
+    /// Run the loop invariant code motion and common subexpression elimination passes
+    pub eliminate_redundancy: bool,
+}
+
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
 /// convert the final SSA into ACIR and return it.
 pub(crate) fn optimize_into_acir(
     program: Program,
-    print_ssa_passes: bool,
-    print_brillig_trace: bool,
+    options: &SsaEvaluatorOptions,
 ) -> Result<GeneratedAcir, RuntimeError> {
     let abi_distinctness = program.return_distinctness;
 
-    let ssa_builder = SsaBuilder::new(program, print_ssa_passes)?
-        .run_pass(Ssa::defunctionalize, "After Defunctionalization:")
-        .run_pass(Ssa::inline_functions, "After Inlining:")
-        // Run mem2reg with the CFG separated into blocks
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
-        .try_run_pass(Ssa::unroll_loops, "After Unrolling:")?
-        .run_pass(Ssa::simplify_cfg, "After Simplifying:")
-        // Run mem2reg before flattening to handle any promotion
-        // of values that can be accessed after loop unrolling.
-        // If there are slice mergers uncovered by loop unrolling
-        // and this pass is missed, slice merging will fail inside of flattening.
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .run_pass(Ssa::flatten_cfg, "After Flattening:")
-        // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
-        .run_pass(Ssa::mem2reg, "After Mem2Reg:")
-        .run_pass(Ssa::fold_constants, "After Constant Folding:")
-        .run_pass(Ssa::dead_instruction_elimination, "After Dead Instruction Elimination:");
-
-    let brillig = ssa_builder.to_brillig(print_brillig_trace);
+    let ssa_builder = optimize_ssa(program, options)?;
+
+    let brillig = ssa_builder.to_brillig(
+        options.enable_brillig_logging,
+        options.brillig_register_file_size,
+        options.optimize_brillig,
+    );
 
     // Split off any passes the are not necessary for Brillig generation but are necessary for ACIR generation.
     // We only need to fill out nested slices as we need to have a known length when dealing with memory operations
@@ -74,25 +89,85 @@ pub(crate) fn optimize_into_acir(
     ssa.into_acir(brillig, abi_distinctness, &last_array_uses)
 }
 
+/// Converts the given program into SSA form and runs the optimization passes shared by
+/// ACIR and Brillig generation.
+fn optimize_ssa(
+    program: Program,
+    options: &SsaEvaluatorOptions,
+) -> Result<SsaBuilder, RuntimeError> {
+    Ok(SsaBuilder::new(
+        program,
+        options.enable_ssa_logging,
+        options.validate_ssa_passes,
+        options.strict_overflow,
+    )?
+    .run_pass(Ssa::defunctionalize, "After Defunctionalization:")
+    .run_pass(Ssa::inline_functions, "After Inlining:")
+    // Run mem2reg with the CFG separated into blocks
+    .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+    .try_run_pass(Ssa::evaluate_assert_constant, "After Assert Constant:")?
+    // Hoist loop invariants before unrolling so they are not duplicated into each iteration.
+    // Brillig functions are not unrolled and so benefit from these passes on every loop iteration.
+    // Common subexpressions are only eliminated here as, once the CFG is flattened, constant folding
+    // removes the duplicated instructions which can't fail, while instructions which can fail
+    // (e.g. divisions) may then be under different side effects predicates.
+    .run_pass_if(
+        options.eliminate_redundancy,
+        Ssa::loop_invariant_code_motion,
+        "After Loop Invariant Code Motion:",
+    )
+    .run_pass_if(
+        options.eliminate_redundancy,
+        Ssa::common_subexpression_elimination,
+        "After Common Subexpression Elimination:",
+    )
+    .try_run_pass(Ssa::unroll_loops, "After Unrolling:")?
+    .run_pass(Ssa::simplify_cfg, "After Simplifying:")
+    // Run mem2reg before flattening to handle any promotion
+    // of values that can be accessed after loop unrolling.
+    // If there are slice mergers uncovered by loop unrolling
+    // and this pass is missed, slice merging will fail inside of flattening.
+    .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+    .run_pass(Ssa::flatten_cfg, "After Flattening:")
+    // Run mem2reg once more with the flattened CFG to catch any remaining loads/stores
+    .run_pass(Ssa::mem2reg, "After Mem2Reg:")
+    .run_pass(Ssa::fold_constants, "After Constant Folding:")
+    .run_pass(Ssa::dead_instruction_elimination, "After Dead Instruction Elimination:"))
+}
+
+/// Checks the [`Program`] for results of unconstrained function calls, including oracle calls,
+/// which are never constrained against the arguments of the call.
+///
+/// This runs the same SSA passes as [`create_circuit`] without generating any ACIR.
+pub fn check_underconstrained_values(program: Program) -> Result<Vec<SsaReport>, RuntimeError> {
+    let options = SsaEvaluatorOptions { eliminate_redundancy: true, ..Default::default() };
+    let ssa = optimize_ssa(program, &options)?.finish();
+    Ok(ssa.check_for_underconstrained_values())
+}
+
 /// Compiles the [`Program`] into [`ACIR`][acvm::acir::circuit::Circuit].
 ///
 /// The output ACIR is is backend-agnostic and so must go through a transformation pass before usage in proof generation.
+///
+/// If `options.validate_ssa_passes` is set, the SSA is interpreted after each optimization pass with each of
+/// the given inputs, which are the field elements of the arguments to `main` laid out as in its witnesses,
+/// as well as with a few sample arguments.
 #[allow(clippy::type_complexity)]
 pub fn create_circuit(
     program: Program,
-    enable_ssa_logging: bool,
-    enable_brillig_logging: bool,
+    options: &SsaEvaluatorOptions,
 ) -> Result<(Circuit, DebugInfo, Vec<Witness>, Vec<Witness>, Vec<SsaReport>), RuntimeError> {
     let func_sig = program.main_function_signature.clone();
-    let mut generated_acir =
-        optimize_into_acir(program, enable_ssa_logging, enable_brillig_logging)?;
+    let mut generated_acir = optimize_into_acir(program, options)?;
     let opcodes = generated_acir.take_opcodes();
     let GeneratedAcir {
         current_witness_index,
         return_witnesses,
         locations,
+        predicates,
         input_witnesses,
         assert_messages,
+        unconstrained_functions,
         warnings,
         ..
     } = generated_acir;
@@ -110,6 +185,8 @@ pub fn create_circuit(
         public_parameters,
         return_values,
         assert_messages: assert_messages.into_iter().collect(),
+        unconstrained_functions,
+        lookup_tables: Vec::new(),
     };
 
     // This converts each im::Vector in the BTreeMap to a Vec
@@ -118,7 +195,7 @@ pub fn create_circuit(
         .map(|(index, locations)| (index, locations.into_iter().collect()))
         .collect();
 
-    let mut debug_info = DebugInfo::new(locations);
+    let mut debug_info = DebugInfo::new(locations, predicates);
 
     // Perform any ACIR-level optimizations
     let (optimized_circuit, transformation_map) = acvm::compiler::optimize(circuit);
@@ -165,12 +242,20 @@ fn split_public_and_private_inputs(
 struct SsaBuilder {
     ssa: Ssa,
     print_ssa_passes: bool,
+    /// When set, each pass is checked not to change the results of the program by interpreting it.
+    validator: Option<PassValidator>,
 }
 
 impl SsaBuilder {
-    fn new(program: Program, print_ssa_passes: bool) -> Result<SsaBuilder, RuntimeError> {
-        let ssa = ssa_gen::generate_ssa(program)?;
-        Ok(SsaBuilder { print_ssa_passes, ssa }.print("Initial SSA:"))
+    fn new(
+        program: Program,
+        print_ssa_passes: bool,
+        validate_ssa_passes: Option<&[Vec<FieldElement>]>,
+        strict_overflow: bool,
+    ) -> Result<SsaBuilder, RuntimeError> {
+        let ssa = ssa_gen::generate_ssa(program, strict_overflow)?;
+        let validator = validate_ssa_passes.map(|inputs| PassValidator::new(&ssa, inputs));
+        Ok(SsaBuilder { print_ssa_passes, ssa, validator }.print("Initial SSA:"))
     }
 
     fn finish(self) -> Ssa {
@@ -178,9 +263,19 @@ impl SsaBuilder {
     }
 
     /// Runs the given SSA pass and prints the SSA afterward if `print_ssa_passes` is true.
+    /// Panics if the pass changes the results of the program while validating passes.
     fn run_pass(mut self, pass: fn(Ssa) -> Ssa, msg: &str) -> Self {
         self.ssa = pass(self.ssa);
-        self.print(msg)
+        self.finish_pass(msg)
+    }
+
+    /// The same as `run_pass` but skips the pass unless `condition` is true.
+    fn run_pass_if(self, condition: bool, pass: fn(Ssa) -> Ssa, msg: &str) -> Self {
+        if condition {
+            self.run_pass(pass, msg)
+        } else {
+            self
+        }
     }
 
     /// The same as `run_pass` but for passes that may fail
@@ -190,11 +285,16 @@ impl SsaBuilder {
         msg: &str,
     ) -> Result<Self, RuntimeError> {
         self.ssa = pass(self.ssa)?;
-        Ok(self.print(msg))
+        Ok(self.finish_pass(msg))
     }
 
-    fn to_brillig(&self, print_brillig_trace: bool) -> Brillig {
-        self.ssa.to_brillig(print_brillig_trace)
+    fn to_brillig(
+        &self,
+        print_brillig_trace: bool,
+        register_file_size: Option<usize>,
+        optimize: bool,
+    ) -> Brillig {
+        self.ssa.to_brillig(print_brillig_trace, register_file_size, optimize)
     }
 
     fn print(self, msg: &str) -> Self {
@@ -203,4 +303,13 @@ impl SsaBuilder {
         }
         self
     }
+
+    /// Prints the SSA after a pass, then checks that the pass preserved the program's results.
+    fn finish_pass(self, msg: &str) -> Self {
+        let builder = self.print(msg);
+        if let Some(validator) = &builder.validator {
+            validator.validate(&builder.ssa, msg);
+        }
+        builder
+    }
 }
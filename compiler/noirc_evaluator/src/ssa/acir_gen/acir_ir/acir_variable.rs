@@ -5,7 +5,10 @@ use crate::errors::{InternalError, RuntimeError, SsaReport};
 use crate::ssa::acir_gen::{AcirDynamicArray, AcirValue};
 use crate::ssa::ir::dfg::CallStack;
 use crate::ssa::ir::types::Type as SsaType;
-use crate::ssa::ir::{instruction::Endian, types::NumericType};
+use crate::ssa::ir::{
+    instruction::{BinaryOp, Endian},
+    types::NumericType,
+};
 use acvm::acir::circuit::brillig::{BrilligInputs, BrilligOutputs};
 use acvm::acir::circuit::opcodes::{BlockId, MemOp};
 use acvm::acir::circuit::Opcode;
@@ -212,6 +215,19 @@ impl AcirContext {
         self.acir_ir.call_stack = call_stack;
     }
 
+    /// Records `predicate` as the side effects predicate of the opcodes generated from now on.
+    pub(crate) fn set_side_effects_predicate(
+        &mut self,
+        predicate: AcirVar,
+    ) -> Result<(), InternalError> {
+        self.acir_ir.side_effects_predicate = if self.is_constant_one(&predicate) {
+            None
+        } else {
+            Some(self.var_to_expression(predicate)?)
+        };
+        Ok(())
+    }
+
     fn get_or_create_witness_var(&mut self, var: AcirVar) -> Result<AcirVar, InternalError> {
         if self.var_to_expression(var)?.to_witness().is_some() {
             // If called with a variable which is already a witness then return the same variable.
@@ -410,7 +426,7 @@ impl AcirContext {
             // max - ((max - a) AND (max -b))
             // Subtracting from max flips the bits, so this is effectively:
             // (NOT a) NAND (NOT b)
-            let max = self.add_constant((1_u128 << bit_size) - 1);
+            let max = self.add_constant(u128::MAX >> (128 - bit_size));
             let a = self.sub_var(max, lhs)?;
             let b = self.sub_var(max, rhs)?;
             let inputs = vec![AcirValue::Var(a, typ.clone()), AcirValue::Var(b, typ)];
@@ -518,6 +534,244 @@ impl AcirContext {
         Ok(result)
     }
 
+    /// Returns the low and high `bit_size` bits of the product of the `bit_size`-bit unsigned
+    /// integers `lhs` and `rhs`, such that `lhs * rhs == high * 2^{bit_size} + low` holds over
+    /// the integers.
+    ///
+    /// This is needed when the product may not fit in a field element. Both operands are split
+    /// into a low limb of `half = ceil(bit_size / 2)` bits and a high limb of the remaining bits,
+    /// so that each product of limbs fits in the field:
+    /// `lhs * rhs = lhs_hi * rhs_hi * 2^{2 * half} + (lhs_hi * rhs_lo + lhs_lo * rhs_hi) * 2^{half} + lhs_lo * rhs_lo`
+    pub(crate) fn wide_mul_var(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let half = (bit_size + 1) / 2;
+        let one = self.add_constant(FieldElement::one());
+        let power_of_two =
+            |exponent: u32| FieldElement::from(2_u128).pow(&(exponent as u128).into());
+
+        let half_limb = self.add_constant(power_of_two(half));
+        let (lhs_hi, lhs_lo) = self.euclidean_division_var(lhs, half_limb, bit_size, one)?;
+        let (rhs_hi, rhs_lo) = self.euclidean_division_var(rhs, half_limb, bit_size, one)?;
+
+        let low_product = self.mul_var(lhs_lo, rhs_lo)?;
+        let high_product = self.mul_var(lhs_hi, rhs_hi)?;
+        let lhs_cross = self.mul_var(lhs_hi, rhs_lo)?;
+        let rhs_cross = self.mul_var(lhs_lo, rhs_hi)?;
+        let cross_product = self.add_var(lhs_cross, rhs_cross)?;
+
+        // Only the bits of the cross product below `bit_size - half` end up in the low bits of
+        // the product. The cross product is less than 2^{bit_size + 1}.
+        let cross_limb = self.add_constant(power_of_two(bit_size - half));
+        let (cross_hi, cross_lo) =
+            self.euclidean_division_var(cross_product, cross_limb, bit_size + 1, one)?;
+
+        // `low_product + cross_lo * 2^{half}` is less than 2^{bit_size + 2}, leaving a carry of at
+        // most two bits over the low bits of the product.
+        let low_sum = self.add_mul_var(low_product, power_of_two(half), cross_lo)?;
+        let modulus = self.add_constant(power_of_two(bit_size));
+        let (carry, low) = self.euclidean_division_var(low_sum, modulus, bit_size + 2, one)?;
+
+        let high = self.add_var(cross_hi, carry)?;
+        let high = self.add_mul_var(high, power_of_two(2 * half - bit_size), high_product)?;
+        Ok((low, high))
+    }
+
+    /// Returns the low and high `bit_size` bits of the product of the `bit_size`-bit unsigned
+    /// integers `lhs` and `rhs`, decomposing the product directly when it fits in a field element.
+    fn full_mul_var(
+        &mut self,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        if (NumericType::Unsigned { bit_size }).is_wide() {
+            return self.wide_mul_var(lhs, rhs, bit_size);
+        }
+        let one = self.add_constant(FieldElement::one());
+        let modulus = self
+            .add_constant(FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)));
+        let product = self.mul_var(lhs, rhs)?;
+        let (high, low) = self.euclidean_division_var(product, modulus, 2 * bit_size, one)?;
+        Ok((low, high))
+    }
+
+    /// Returns `1` if exactly one of the booleans `lhs` and `rhs` is `1`, and `0` otherwise.
+    fn bool_xor_var(&mut self, lhs: AcirVar, rhs: AcirVar) -> Result<AcirVar, RuntimeError> {
+        // lhs + rhs - 2 * lhs * rhs
+        let sum = self.add_var(lhs, rhs)?;
+        let product = self.mul_var(lhs, rhs)?;
+        self.add_mul_var(sum, -FieldElement::from(2_u128), product)
+    }
+
+    /// Computes the addition, subtraction or multiplication of the `bit_size`-bit integers `lhs`
+    /// and `rhs`, returning the result reduced modulo 2^{bit_size} along with a boolean which is
+    /// `1` if the exact result of the unsigned operation was out of range.
+    ///
+    /// The reduced result is obtained from a single decomposition of the exact result, whose
+    /// quotient doubles as the overflow flag. Signed integers are represented in two's
+    /// complement, so they are reduced in the same way as unsigned integers.
+    fn reduced_op_var(
+        &mut self,
+        operator: BinaryOp,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        bit_size: u32,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let one = self.add_constant(FieldElement::one());
+        let modulus = self
+            .add_constant(FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)));
+        match operator {
+            BinaryOp::Add => {
+                let sum = self.add_var(lhs, rhs)?;
+                let (carry, wrapped) =
+                    self.euclidean_division_var(sum, modulus, bit_size + 1, one)?;
+                Ok((wrapped, carry))
+            }
+            BinaryOp::Sub => {
+                // The modulus is added to avoid underflow, and is borrowed from when rhs > lhs.
+                let difference = self.sub_var(lhs, rhs)?;
+                let difference = self.add_var(difference, modulus)?;
+                let (no_borrow, wrapped) =
+                    self.euclidean_division_var(difference, modulus, bit_size + 1, one)?;
+                Ok((wrapped, self.sub_var(one, no_borrow)?))
+            }
+            BinaryOp::Mul => {
+                let (low, high) = self.full_mul_var(lhs, rhs, bit_size)?;
+                let zero = self.add_constant(FieldElement::zero());
+                let high_is_zero = self.eq_var(high, zero)?;
+                Ok((low, self.sub_var(one, high_is_zero)?))
+            }
+            _ => unreachable!("ICE: {operator} is not an overflowing operation"),
+        }
+    }
+
+    /// Computes the addition, subtraction or multiplication of the integers `lhs` and `rhs` of
+    /// type `typ`, returning the result reduced modulo the type, a boolean which is `1` if the
+    /// exact result overflowed the type, and the bound of the type which it overflowed past.
+    fn integer_op_var(
+        &mut self,
+        operator: BinaryOp,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        typ: NumericType,
+    ) -> Result<(AcirVar, AcirVar, AcirVar), RuntimeError> {
+        let bit_size = match typ {
+            NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => bit_size,
+            NumericType::NativeField => unreachable!("ICE: fields do not overflow"),
+        };
+        let power_of_two =
+            |exponent: u32| FieldElement::from(2_u128).pow(&(exponent as u128).into());
+        let zero = self.add_constant(FieldElement::zero());
+        let one = self.add_constant(FieldElement::one());
+        let modulus = self.add_constant(power_of_two(bit_size));
+
+        if let NumericType::Unsigned { .. } = typ {
+            let (wrapped, carry) = self.reduced_op_var(operator, lhs, rhs, bit_size)?;
+            let bound = match operator {
+                BinaryOp::Sub => zero,
+                _ => self.add_constant(power_of_two(bit_size) - FieldElement::one()),
+            };
+            return Ok((wrapped, carry, bound));
+        }
+
+        let wrapped = self.wrapping_op_var(operator, lhs, rhs, typ)?;
+        // The minimum of the signed type is represented as 2^{bit_size-1}, one above its maximum.
+        let half = self.add_constant(power_of_two(bit_size - 1));
+        let maximum = self.sub_var(half, one)?;
+        let mut sign_bit =
+            |value| self.euclidean_division_var(value, half, bit_size, one).map(|(sign, _)| sign);
+        let lhs_sign = sign_bit(lhs)?;
+        let rhs_sign = sign_bit(rhs)?;
+
+        match operator {
+            BinaryOp::Add | BinaryOp::Sub => {
+                // The operation overflowed if both operands (after negating the rhs of a
+                // subtraction) have the same sign, but the result does not.
+                let result_sign = sign_bit(wrapped)?;
+                let mut signs_differ = self.bool_xor_var(lhs_sign, rhs_sign)?;
+                if operator == BinaryOp::Add {
+                    signs_differ = self.sub_var(one, signs_differ)?;
+                }
+                let sign_changed = self.bool_xor_var(lhs_sign, result_sign)?;
+                let overflow = self.mul_var(signs_differ, sign_changed)?;
+                let bound = self.add_var(maximum, lhs_sign)?;
+                Ok((wrapped, overflow, bound))
+            }
+            _ => {
+                // The absolute value of the product must fit in the type, bearing in mind that
+                // the minimum has a greater absolute value than the maximum.
+                let mut absolute_value = |value, sign| -> Result<AcirVar, RuntimeError> {
+                    // value + sign * (2^{bit_size} - 2 * value)
+                    let negated = self.add_mul_var(modulus, -FieldElement::from(2_u128), value)?;
+                    let offset = self.mul_var(sign, negated)?;
+                    self.add_var(value, offset)
+                };
+                let lhs_abs = absolute_value(lhs, lhs_sign)?;
+                let rhs_abs = absolute_value(rhs, rhs_sign)?;
+                let (low, high) = self.full_mul_var(lhs_abs, rhs_abs, bit_size)?;
+
+                let negative = self.bool_xor_var(lhs_sign, rhs_sign)?;
+                let limit = self.add_var(half, negative)?;
+                let low_fits = self.less_than_var(low, limit, bit_size + 1, one)?;
+                let high_is_zero = self.eq_var(high, zero)?;
+                let fits = self.mul_var(low_fits, high_is_zero)?;
+                let overflow = self.sub_var(one, fits)?;
+                let bound = self.add_var(maximum, negative)?;
+                Ok((wrapped, overflow, bound))
+            }
+        }
+    }
+
+    /// Returns `lhs operator rhs` reduced modulo the integer type `typ`.
+    pub(crate) fn wrapping_op_var(
+        &mut self,
+        operator: BinaryOp,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        typ: NumericType,
+    ) -> Result<AcirVar, RuntimeError> {
+        let (NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size }) = typ else {
+            unreachable!("ICE: fields do not overflow")
+        };
+        if operator == BinaryOp::Mul {
+            // The high bits of the product are discarded, so they are not checked for zero.
+            return Ok(self.full_mul_var(lhs, rhs, bit_size)?.0);
+        }
+        Ok(self.reduced_op_var(operator, lhs, rhs, bit_size)?.0)
+    }
+
+    /// Returns `lhs operator rhs` reduced modulo the integer type `typ`, along with a boolean
+    /// which is `1` if the exact result overflowed the type.
+    pub(crate) fn overflowing_op_var(
+        &mut self,
+        operator: BinaryOp,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        typ: NumericType,
+    ) -> Result<(AcirVar, AcirVar), RuntimeError> {
+        let (wrapped, overflow, _) = self.integer_op_var(operator, lhs, rhs, typ)?;
+        Ok((wrapped, overflow))
+    }
+
+    /// Returns `lhs operator rhs` clamped to the bounds of the integer type `typ`.
+    pub(crate) fn saturating_op_var(
+        &mut self,
+        operator: BinaryOp,
+        lhs: AcirVar,
+        rhs: AcirVar,
+        typ: NumericType,
+    ) -> Result<AcirVar, RuntimeError> {
+        let (wrapped, overflow, bound) = self.integer_op_var(operator, lhs, rhs, typ)?;
+        // wrapped + overflow * (bound - wrapped)
+        let difference = self.sub_var(bound, wrapped)?;
+        let offset = self.mul_var(overflow, difference)?;
+        self.add_var(wrapped, offset)
+    }
+
     /// Adds a new Variable to context whose value will
     /// be constrained to be the subtraction of `lhs` and `rhs`
     pub(crate) fn sub_var(&mut self, lhs: AcirVar, rhs: AcirVar) -> Result<AcirVar, RuntimeError> {
@@ -553,7 +807,7 @@ impl AcirContext {
     pub(crate) fn not_var(&mut self, x: AcirVar, typ: AcirType) -> Result<AcirVar, RuntimeError> {
         let bit_size = typ.bit_size();
         // Subtracting from max flips the bits
-        let max = self.add_constant((1_u128 << bit_size) - 1);
+        let max = self.add_constant(u128::MAX >> (128 - bit_size));
         self.sub_var(max, x)
     }
 
@@ -577,11 +831,14 @@ impl AcirContext {
             // If `lhs` and `rhs` are known constants then we can calculate the result at compile time.
             // `rhs` must be non-zero.
             (Some(lhs_const), Some(rhs_const)) if rhs_const != FieldElement::zero() => {
-                let quotient = lhs_const.to_u128() / rhs_const.to_u128();
-                let remainder = lhs_const.to_u128() - quotient * rhs_const.to_u128();
+                let lhs_big = BigUint::from_bytes_be(&lhs_const.to_be_bytes());
+                let rhs_big = BigUint::from_bytes_be(&rhs_const.to_be_bytes());
+                let quotient = (&lhs_big / &rhs_big).to_bytes_be();
+                let remainder = (lhs_big % rhs_big).to_bytes_be();
 
-                let quotient_var = self.add_constant(quotient);
-                let remainder_var = self.add_constant(remainder);
+                let quotient_var = self.add_constant(FieldElement::from_be_bytes_reduce(&quotient));
+                let remainder_var =
+                    self.add_constant(FieldElement::from_be_bytes_reduce(&remainder));
                 return Ok((quotient_var, remainder_var));
             }
 
@@ -635,13 +892,14 @@ impl AcirContext {
 
         // Avoids overflow: 'q*b+r < 2^max_q_bits*2^max_rhs_bits'
         let mut avoid_overflow = false;
+        let mut wide_product = false;
         if max_q_bits + max_rhs_bits >= FieldElement::max_num_bits() - 1 {
-            // q*b+r can overflow; we avoid this when b is constant
+            // q*b+r can overflow; we avoid this when b is constant, and otherwise compute q*b
+            // over the integers by splitting both operands into limbs.
             if self.var_to_expression(rhs)?.is_const() {
                 avoid_overflow = true;
             } else {
-                // we do not support unbounded division
-                unreachable!("overflow in unbounded division");
+                wide_product = true;
             }
         }
 
@@ -687,7 +945,15 @@ impl AcirContext {
         // When the predicate is 0, the equation always passes.
         // When the predicate is 1, the euclidean division needs to be
         // true.
-        let rhs_constraint = self.mul_var(rhs, quotient_var)?;
+        let rhs_constraint = if wide_product {
+            // `q * b <= a` must hold over the integers so its high bits must be zero, which
+            // leaves `q * b + r < 2^{bit_size + 1}` within the field.
+            let (low, high) = self.wide_mul_var(rhs, quotient_var, bit_size)?;
+            self.assert_eq_var(high, zero, None)?;
+            low
+        } else {
+            self.mul_var(rhs, quotient_var)?
+        };
         let rhs_constraint = self.add_var(rhs_constraint, remainder_var)?;
         let rhs_constraint = self.mul_var(rhs_constraint, predicate)?;
 
@@ -758,9 +1024,9 @@ impl AcirContext {
 
         let mut lhs_offset = self.add_var(lhs, offset)?;
 
-        // Optimization when rhs is const and fits within a u128
+        // Optimization when rhs is const and 2^{bits of rhs} fits within a u128
         let rhs_expr = self.var_to_expression(rhs)?;
-        if rhs_expr.is_const() && rhs_expr.q_c.fits_in_u128() {
+        if rhs_expr.is_const() && rhs_expr.q_c.num_bits() < 128 {
             // We try to move the offset to rhs
             let rhs_offset = if self.is_constant_one(&offset) && rhs_expr.q_c.to_u128() >= 1 {
                 lhs_offset = lhs;
@@ -1678,3 +1944,220 @@ fn execute_brillig(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::FieldElement;
+    use num_bigint::BigUint;
+
+    use crate::ssa::ir::{instruction::BinaryOp, types::NumericType};
+
+    use super::AcirContext;
+
+    /// Multiplies the constants `lhs` and `rhs` as `bit_size`-bit integers, returning the low and
+    /// high bits of the product, which are evaluated at compile time as the operands are constant.
+    fn wide_mul_constants(lhs: u128, rhs: u128, bit_size: u32) -> (FieldElement, FieldElement) {
+        let mut context = AcirContext::default();
+        let lhs = context.add_constant(lhs);
+        let rhs = context.add_constant(rhs);
+        let (low, high) = context.wide_mul_var(lhs, rhs, bit_size).unwrap();
+
+        let constant = |var| context.var_to_expression(var).unwrap().to_const().unwrap();
+        (constant(low), constant(high))
+    }
+
+    fn expected_product(lhs: u128, rhs: u128, bit_size: u32) -> (FieldElement, FieldElement) {
+        let product = BigUint::from(lhs) * BigUint::from(rhs);
+        let modulus = BigUint::from(1u32) << bit_size;
+        let to_field = |value: BigUint| FieldElement::from_be_bytes_reduce(&value.to_bytes_be());
+        (to_field(&product % &modulus), to_field(product / modulus))
+    }
+
+    #[test]
+    fn wide_mul_of_maximum_values() {
+        // (2^128 - 1)^2 = (2^128 - 2) * 2^128 + 1, close to 2^256 and above the field modulus.
+        // Both the cross sum and the low sum carry into the high bits.
+        let (low, high) = wide_mul_constants(u128::MAX, u128::MAX, 128);
+        assert_eq!(low, FieldElement::one());
+        assert_eq!(high, FieldElement::from(u128::MAX - 1));
+    }
+
+    #[test]
+    fn wide_mul_carries_into_high_bits() {
+        let cases = [
+            // The cross sum is exactly 2^64, so only its carry makes up the high bits.
+            (1 << 127, 2),
+            // Only the high limbs are set and multiplied.
+            (1 << 64, 1 << 64),
+            // The cross sum is 2^65 - 2 and 3 * (2^64 - 1), carrying one and two bits.
+            (u128::MAX, (1 << 64) + 1),
+            (u128::MAX, 3),
+            // Only the low limbs are set, so the product stays below 2^128.
+            (u64::MAX as u128, u64::MAX as u128),
+            // The low sum reaches 2^128 and carries.
+            (u128::MAX, u128::MAX >> 1),
+            (0, u128::MAX),
+            (1, u128::MAX),
+        ];
+        for (lhs, rhs) in cases {
+            assert_eq!(
+                wide_mul_constants(lhs, rhs, 128),
+                expected_product(lhs, rhs, 128),
+                "{lhs} * {rhs}"
+            );
+        }
+    }
+
+    #[test]
+    fn wide_mul_of_odd_bit_sizes() {
+        // With an odd bit size the high limbs are one bit narrower than the low limbs, and the
+        // low sum of the maximum values carries two bits.
+        let max = u128::MAX >> 1;
+        for (lhs, rhs) in [(max, max), (max, 2), (1 << 126, 1 << 126), (max, (1 << 64) + 1)] {
+            assert_eq!(
+                wide_mul_constants(lhs, rhs, 127),
+                expected_product(lhs, rhs, 127),
+                "{lhs} * {rhs}"
+            );
+        }
+    }
+
+    /// Converts `value` to its two's complement representation as a `bit_size`-bit integer.
+    fn twos_complement(value: i128, bit_size: u32) -> u128 {
+        (value as u128) & (u128::MAX >> (128 - bit_size))
+    }
+
+    /// Multiplies the constants `lhs` and `rhs` as `bit_size`-bit integers with `full_mul_var`,
+    /// returning the low and high bits of the product.
+    fn full_mul_constants(lhs: u128, rhs: u128, bit_size: u32) -> (FieldElement, FieldElement) {
+        let mut context = AcirContext::default();
+        let lhs = context.add_constant(lhs);
+        let rhs = context.add_constant(rhs);
+        let (low, high) = context.full_mul_var(lhs, rhs, bit_size).unwrap();
+
+        let constant = |var| context.var_to_expression(var).unwrap().to_const().unwrap();
+        (constant(low), constant(high))
+    }
+
+    /// Applies `operator` to the constants `lhs` and `rhs` as `bit_size`-bit integers with
+    /// `reduced_op_var`, returning the reduced result and the overflow flag.
+    fn reduced_op_constants(
+        operator: BinaryOp,
+        lhs: u128,
+        rhs: u128,
+        bit_size: u32,
+    ) -> (u128, u128) {
+        let mut context = AcirContext::default();
+        let lhs = context.add_constant(lhs);
+        let rhs = context.add_constant(rhs);
+        let (result, overflow) = context.reduced_op_var(operator, lhs, rhs, bit_size).unwrap();
+
+        let constant = |var| context.var_to_expression(var).unwrap().to_const().unwrap().to_u128();
+        (constant(result), constant(overflow))
+    }
+
+    /// Applies `operator` to the constants `lhs` and `rhs` of type `typ` with `integer_op_var`,
+    /// returning the reduced result, the overflow flag and the bound to saturate to.
+    fn integer_op_constants(
+        operator: BinaryOp,
+        lhs: u128,
+        rhs: u128,
+        typ: NumericType,
+    ) -> (u128, u128, u128) {
+        let mut context = AcirContext::default();
+        let lhs = context.add_constant(lhs);
+        let rhs = context.add_constant(rhs);
+        let (result, overflow, bound) = context.integer_op_var(operator, lhs, rhs, typ).unwrap();
+
+        let constant = |var| context.var_to_expression(var).unwrap().to_const().unwrap().to_u128();
+        (constant(result), constant(overflow), constant(bound))
+    }
+
+    #[test]
+    fn full_mul_splits_the_product() {
+        // 255 * 255 = 254 * 2^8 + 1
+        assert_eq!(
+            full_mul_constants(255, 255, 8),
+            (FieldElement::one(), FieldElement::from(254_u128))
+        );
+        for (lhs, rhs, bit_size) in [(3, 5, 8), (u64::MAX as u128, u64::MAX as u128, 64)] {
+            assert_eq!(
+                full_mul_constants(lhs, rhs, bit_size),
+                expected_product(lhs, rhs, bit_size),
+                "{lhs} * {rhs}"
+            );
+        }
+        // The product of 128-bit integers may not fit in a field element, so it's split in limbs.
+        assert_eq!(
+            full_mul_constants(u128::MAX, u128::MAX, 128),
+            expected_product(u128::MAX, u128::MAX, 128)
+        );
+    }
+
+    #[test]
+    fn reduced_op_flags_unsigned_overflow() {
+        let cases = [
+            (BinaryOp::Add, 100, 100, (200, 0)),
+            (BinaryOp::Add, 200, 100, (44, 1)),
+            (BinaryOp::Add, 255, 255, (254, 1)),
+            (BinaryOp::Sub, 10, 5, (5, 0)),
+            (BinaryOp::Sub, 5, 5, (0, 0)),
+            (BinaryOp::Sub, 5, 10, (251, 1)),
+            (BinaryOp::Mul, 15, 17, (255, 0)),
+            (BinaryOp::Mul, 16, 16, (0, 1)),
+        ];
+        for (operator, lhs, rhs, expected) in cases {
+            assert_eq!(
+                reduced_op_constants(operator, lhs, rhs, 8),
+                expected,
+                "{lhs} {operator} {rhs}"
+            );
+        }
+    }
+
+    #[test]
+    fn integer_op_saturates_unsigned_integers_to_their_bounds() {
+        let u8 = NumericType::Unsigned { bit_size: 8 };
+        let cases = [
+            (BinaryOp::Add, 200, 100, (44, 1, 255)),
+            (BinaryOp::Add, 100, 100, (200, 0, 255)),
+            (BinaryOp::Sub, 5, 10, (251, 1, 0)),
+            (BinaryOp::Mul, 16, 16, (0, 1, 255)),
+        ];
+        for (operator, lhs, rhs, expected) in cases {
+            assert_eq!(
+                integer_op_constants(operator, lhs, rhs, u8),
+                expected,
+                "{lhs} {operator} {rhs}"
+            );
+        }
+    }
+
+    #[test]
+    fn integer_op_saturates_signed_integers_to_their_bounds() {
+        let i8 = NumericType::Signed { bit_size: 8 };
+        let max = twos_complement(127, 8);
+        let min = twos_complement(-128, 8);
+        let cases = [
+            (BinaryOp::Add, 100, 100, (-56, 1, max)),
+            (BinaryOp::Add, -100, -100, (56, 1, min)),
+            (BinaryOp::Add, 100, -100, (0, 0, max)),
+            (BinaryOp::Sub, -100, 100, (56, 1, min)),
+            (BinaryOp::Sub, 100, -100, (-56, 1, max)),
+            (BinaryOp::Sub, -100, -28, (-72, 0, min)),
+            (BinaryOp::Mul, 16, 8, (-128, 1, max)),
+            (BinaryOp::Mul, -16, 8, (-128, 0, min)),
+            (BinaryOp::Mul, -128, 1, (-128, 0, min)),
+            (BinaryOp::Mul, -128, -1, (-128, 1, max)),
+        ];
+        for (operator, lhs, rhs, (result, overflow, bound)) in cases {
+            let expected = (twos_complement(result, 8), overflow, bound);
+            let (lhs_bits, rhs_bits) = (twos_complement(lhs, 8), twos_complement(rhs, 8));
+            assert_eq!(
+                integer_op_constants(operator, lhs_bits, rhs_bits, i8),
+                expected,
+                "{lhs} {operator} {rhs}"
+            );
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! `GeneratedAcir` is constructed as part of the `acir_gen` pass to accumulate all of the ACIR
 //! program as it is being converted from SSA form.
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::{
     brillig::{brillig_gen::brillig_directive, brillig_ir::artifact::GeneratedBrillig},
@@ -10,7 +10,7 @@ use crate::{
 
 use acvm::acir::{
     circuit::{
-        brillig::{Brillig as AcvmBrillig, BrilligInputs, BrilligOutputs},
+        brillig::{BrilligBytecode, BrilligInputs, BrilligOutputs},
         opcodes::{BlackBoxFuncCall, FunctionInput, Opcode as AcirOpcode},
         OpcodeLocation,
     },
@@ -18,7 +18,9 @@ use acvm::acir::{
     BlackBoxFunc,
 };
 use acvm::{
-    acir::{circuit::directives::Directive, native_types::Expression},
+    acir::{
+        brillig::Opcode as BrilligOpcode, circuit::directives::Directive, native_types::Expression,
+    },
     FieldElement,
 };
 use iter_extended::vecmap;
@@ -51,9 +53,26 @@ pub(crate) struct GeneratedAcir {
     /// None if we do not know the location
     pub(crate) call_stack: CallStack,
 
+    /// Correspondence between an opcode index and the side effects predicate which was enabled
+    /// when the opcode was generated. Opcodes generated while side effects are always enabled have no entry.
+    pub(crate) predicates: BTreeMap<OpcodeLocation, Expression>,
+
+    /// The side effects predicate of the current instruction being processed
+    /// None if side effects are always enabled
+    pub(crate) side_effects_predicate: Option<Expression>,
+
     /// Correspondence between an opcode index and the error message associated with it.
     pub(crate) assert_messages: BTreeMap<OpcodeLocation, String>,
 
+    /// The unconstrained functions called from the ACIR opcodes.
+    ///
+    /// Each distinct piece of Brillig bytecode is stored only once and referenced
+    /// by its index from every [`AcirOpcode::BrilligCall`] which executes it.
+    pub(crate) unconstrained_functions: Vec<BrilligBytecode>,
+
+    /// The index in `unconstrained_functions` of each distinct piece of Brillig bytecode.
+    unconstrained_function_ids: HashMap<Vec<BrilligOpcode>, u32>,
+
     pub(crate) warnings: Vec<SsaReport>,
 }
 
@@ -69,6 +88,9 @@ impl GeneratedAcir {
         if !self.call_stack.is_empty() {
             self.locations.insert(self.last_acir_opcode_location(), self.call_stack.clone());
         }
+        if let Some(predicate) = &self.side_effects_predicate {
+            self.predicates.insert(self.last_acir_opcode_location(), predicate.clone());
+        }
     }
 
     pub(crate) fn take_opcodes(&mut self) -> Vec<AcirOpcode> {
@@ -502,12 +524,8 @@ impl GeneratedAcir {
         inputs: Vec<BrilligInputs>,
         outputs: Vec<BrilligOutputs>,
     ) {
-        let opcode = AcirOpcode::Brillig(AcvmBrillig {
-            inputs,
-            outputs,
-            bytecode: generated_brillig.byte_code,
-            predicate,
-        });
+        let id = self.unconstrained_function_id(generated_brillig.byte_code);
+        let opcode = AcirOpcode::BrilligCall { id, inputs, outputs, predicate };
         self.push_opcode(opcode);
         for (brillig_index, call_stack) in generated_brillig.locations {
             self.locations.insert(
@@ -523,6 +541,18 @@ impl GeneratedAcir {
         }
     }
 
+    /// Returns the id of the unconstrained function with the given bytecode,
+    /// registering it as a new unconstrained function if it has not been seen before.
+    fn unconstrained_function_id(&mut self, bytecode: Vec<BrilligOpcode>) -> u32 {
+        if let Some(id) = self.unconstrained_function_ids.get(&bytecode) {
+            return *id;
+        }
+        let id = self.unconstrained_functions.len() as u32;
+        self.unconstrained_function_ids.insert(bytecode.clone(), id);
+        self.unconstrained_functions.push(BrilligBytecode { bytecode });
+        id
+    }
+
     /// Generate gates and control bits witnesses which ensure that out_expr is a permutation of in_expr
     /// Add the control bits of the sorting network used to generate the constrains
     /// into the PermutationSort directive for solving in ACVM.
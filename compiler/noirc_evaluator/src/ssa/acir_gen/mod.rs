@@ -90,6 +90,12 @@ struct Context {
     /// Maps SSA array values to their slice size and any nested slices internal to the parent slice.
     /// This enables us to maintain the slice structure of a slice when performing an array get.
     slice_sizes: HashMap<Id<Value>, Vec<usize>>,
+
+    /// Maps the products of wide integers to their high bits and their bit size.
+    ///
+    /// Wide integers may not be multiplied within the field, so their products are represented
+    /// by their low bits only. The high bits are kept for the range checks which detect overflows.
+    wide_products: HashMap<AcirVar, (AcirVar, u32)>,
 }
 
 #[derive(Clone)]
@@ -199,6 +205,7 @@ impl Context {
             internal_mem_block_lengths: HashMap::default(),
             max_block_id: 0,
             slice_sizes: HashMap::default(),
+            wide_products: HashMap::default(),
         }
     }
 
@@ -560,6 +567,7 @@ impl Context {
             }
             Instruction::EnableSideEffects { condition } => {
                 let acir_var = self.convert_numeric_value(*condition, dfg)?;
+                self.acir_context.set_side_effects_predicate(acir_var)?;
                 self.current_side_effects_enabled_var = acir_var;
             }
             Instruction::ArrayGet { .. } | Instruction::ArraySet { .. } => {
@@ -579,11 +587,7 @@ impl Context {
             }
             Instruction::RangeCheck { value, max_bit_size, assert_message } => {
                 let acir_var = self.convert_numeric_value(*value, dfg)?;
-                self.acir_context.range_constrain_var(
-                    acir_var,
-                    &NumericType::Unsigned { bit_size: *max_bit_size },
-                    assert_message.clone(),
-                )?;
+                self.convert_ssa_range_check(acir_var, *max_bit_size, assert_message.clone())?;
             }
         }
         self.acir_context.set_call_stack(CallStack::new());
@@ -1516,10 +1520,9 @@ impl Context {
         match &binary_type {
             Type::Numeric(NumericType::Unsigned { bit_size })
             | Type::Numeric(NumericType::Signed { bit_size }) => {
-                // Conservative max bit size that is small enough such that two operands can be
-                // multiplied and still fit within the field modulus. This is necessary for the
-                // truncation technique: result % 2^bit_size to be valid.
-                let max_integer_bit_size = FieldElement::max_num_bits() / 2;
+                // Wide integers are multiplied limb by limb, with partial sums of up to
+                // `bit_size + 3` bits which must stay below the field modulus.
+                let max_integer_bit_size = FieldElement::max_num_bits() - 5;
                 if *bit_size > max_integer_bit_size {
                     return Err(RuntimeError::UnsupportedIntegerSize {
                         num_bits: *bit_size,
@@ -1531,12 +1534,18 @@ impl Context {
             _ => {}
         }
 
+        let is_wide = binary_type.is_wide_integer();
         let binary_type = AcirType::from(binary_type);
         let bit_count = binary_type.bit_size();
 
         match binary.operator {
             BinaryOp::Add => self.acir_context.add_var(lhs, rhs),
             BinaryOp::Sub => self.acir_context.sub_var(lhs, rhs),
+            BinaryOp::Mul if is_wide => {
+                let (low, high) = self.acir_context.wide_mul_var(lhs, rhs, bit_count)?;
+                self.wide_products.insert(low, (high, bit_count));
+                Ok(low)
+            }
             BinaryOp::Mul => self.acir_context.mul_var(lhs, rhs),
             BinaryOp::Div => self.acir_context.div_var(
                 lhs,
@@ -1645,6 +1654,34 @@ impl Context {
         }
     }
 
+    /// Constrains `value` to fit in `max_bit_size` bits.
+    fn convert_ssa_range_check(
+        &mut self,
+        value: AcirVar,
+        max_bit_size: u32,
+        assert_message: Option<String>,
+    ) -> Result<(), RuntimeError> {
+        let Some(&(high, bit_size)) = self.wide_products.get(&value) else {
+            let numeric_type = NumericType::Unsigned { bit_size: max_bit_size };
+            self.acir_context.range_constrain_var(value, &numeric_type, assert_message)?;
+            return Ok(());
+        };
+
+        // The low bits of a wide product are already constrained to its bit size.
+        if max_bit_size > bit_size {
+            let numeric_type = NumericType::Unsigned { bit_size: max_bit_size - bit_size };
+            self.acir_context.range_constrain_var(high, &numeric_type, assert_message)?;
+        } else {
+            let zero = self.acir_context.add_constant(FieldElement::zero());
+            self.acir_context.assert_eq_var(high, zero, assert_message.clone())?;
+            if max_bit_size < bit_size {
+                let numeric_type = NumericType::Unsigned { bit_size: max_bit_size };
+                self.acir_context.range_constrain_var(value, &numeric_type, assert_message)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns an `AcirVar`that is constrained to be result of the truncation.
     fn convert_ssa_truncate(
         &mut self,
@@ -1654,6 +1691,13 @@ impl Context {
         dfg: &DataFlowGraph,
     ) -> Result<AcirVar, RuntimeError> {
         let mut var = self.convert_numeric_value(value_id, dfg)?;
+        if let Some(&(_, product_bit_size)) = self.wide_products.get(&var) {
+            // Wide products are already reduced to their bit size.
+            if bit_size >= product_bit_size {
+                return Ok(var);
+            }
+            return self.acir_context.truncate_var(var, bit_size, product_bit_size);
+        }
         match &dfg[value_id] {
             Value::Instruction { instruction, .. } => {
                 if matches!(
@@ -1662,7 +1706,9 @@ impl Context {
                 ) {
                     // Subtractions must first have the integer modulus added before truncation can be
                     // applied. This is done in order to prevent underflow.
-                    let integer_modulus = self.acir_context.add_constant(2_u128.pow(bit_size));
+                    let integer_modulus = self.acir_context.add_constant(
+                        FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)),
+                    );
                     var = self.acir_context.add_var(var, integer_modulus)?;
                 }
             }
@@ -2338,6 +2384,35 @@ impl Context {
 
                 Ok(result)
             }
+            Intrinsic::WrappingOp(operator)
+            | Intrinsic::OverflowingOp(operator)
+            | Intrinsic::SaturatingOp(operator) => {
+                let lhs = self.convert_numeric_value(arguments[0], dfg)?;
+                let rhs = self.convert_numeric_value(arguments[1], dfg)?;
+                let Type::Numeric(typ) = dfg.type_of_value(arguments[0]) else {
+                    unreachable!("ICE: {intrinsic} on a non-numeric type")
+                };
+                let result_type = AcirType::new(typ);
+                match intrinsic {
+                    Intrinsic::WrappingOp(_) => {
+                        let result = self.acir_context.wrapping_op_var(operator, lhs, rhs, typ)?;
+                        Ok(vec![AcirValue::Var(result, result_type)])
+                    }
+                    Intrinsic::OverflowingOp(_) => {
+                        let (result, overflow) =
+                            self.acir_context.overflowing_op_var(operator, lhs, rhs, typ)?;
+                        Ok(vec![
+                            AcirValue::Var(result, result_type),
+                            AcirValue::Var(overflow, AcirType::unsigned(1)),
+                        ])
+                    }
+                    _ => {
+                        let result =
+                            self.acir_context.saturating_op_var(operator, lhs, rhs, typ)?;
+                        Ok(vec![AcirValue::Var(result, result_type)])
+                    }
+                }
+            }
             _ => todo!("expected a black box function"),
         }
     }
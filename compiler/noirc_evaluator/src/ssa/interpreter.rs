@@ -0,0 +1,804 @@
+//! An interpreter executing the SSA directly, without compiling it to ACIR or Brillig.
+//!
+//! Every optimization pass is expected to preserve the behavior of the program, so executing the
+//! SSA before and after a pass must give the same results. This lets a miscompilation be traced back
+//! to the pass which introduced it rather than showing up, if at all, in the final circuit.
+//!
+//! The interpreter follows the semantics of ACIR generation: arithmetic on integers is performed in
+//! the field, with any overflow being caught by the `truncate` and `range_check` instructions which
+//! follow it, and instructions with side effects are disabled by `enable_side_effects` instructions
+//! in the same way as in ACIR. Products of integers too wide to be multiplied in the field are
+//! reduced to the width of their type, with the bits above it only kept for range checks.
+use acvm::FieldElement;
+use fxhash::FxHashMap as HashMap;
+use iter_extended::{try_vecmap, vecmap};
+use num_bigint::BigUint;
+use thiserror::Error;
+
+use super::{
+    ir::{
+        basic_block::BasicBlockId,
+        function::{Function, FunctionId, RuntimeType},
+        instruction::{Binary, BinaryOp, Instruction, InstructionId, TerminatorInstruction},
+        types::{NumericType, Type},
+        value::{Value as SsaValue, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+mod intrinsics;
+mod value;
+
+pub(crate) use value::Value;
+
+/// The number of instructions after which the interpreter gives up on executing a program.
+///
+/// Programs may loop for a long time over their inputs in unconstrained functions.
+const MAX_STEPS: usize = 10_000_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Error)]
+pub(crate) enum InterpreterError {
+    #[error("Failed constraint{}", .message.as_ref().map(|message| format!(": '{message}'")).unwrap_or_default())]
+    FailedConstraint { message: Option<String> },
+    #[error("{value} does not fit within {max_bit_size} bits")]
+    FailedRangeCheck { value: FieldElement, max_bit_size: u32 },
+    #[error("Attempted to divide by zero")]
+    DivisionByZero,
+    #[error("Index out of bounds, array has size {length}, but index was {index}")]
+    IndexOutOfBounds { index: usize, length: usize },
+    #[error("Loaded from a reference which was never stored to")]
+    UninitializedLoad,
+    #[error("{value} cannot be decomposed into {limb_count} limbs of radix {radix}")]
+    InvalidRadixDecomposition { value: FieldElement, radix: u32, limb_count: u32 },
+    #[error("Interpreting {0} is not supported")]
+    Unsupported(String),
+    #[error("Gave up after executing {} instructions", MAX_STEPS)]
+    StepLimitReached,
+    #[error("ICE: {0}")]
+    Internal(String),
+}
+
+impl InterpreterError {
+    /// True if the interpreter couldn't decide whether the program fails.
+    pub(crate) fn is_inconclusive(&self) -> bool {
+        matches!(self, InterpreterError::Unsupported(_) | InterpreterError::StepLimitReached)
+    }
+}
+
+impl Ssa {
+    /// Executes the program with the given arguments to `main`, returning the values it returns.
+    pub(crate) fn interpret(&self, arguments: Vec<Value>) -> Result<Vec<Value>, InterpreterError> {
+        let mut interpreter = Interpreter { ssa: self, memory: Vec::new(), steps: 0 };
+        interpreter.call_function(self.main_id, arguments)
+    }
+
+    /// Returns sets of arguments to `main` with which to check that passes preserve the results of
+    /// the program, or nothing if the arguments can't be made up.
+    ///
+    /// The arguments don't need to be valid inputs to the program: a program which fails must keep
+    /// failing after each pass. As every numeric argument is either zero or one, they only exercise
+    /// a small part of the program; [`Ssa::arguments_from_fields`] gives arguments for actual inputs.
+    pub(crate) fn sample_arguments(&self) -> Vec<Vec<Value>> {
+        let main = self.main();
+        let sample = |value: FieldElement| {
+            main.parameters()
+                .iter()
+                .map(|parameter| Value::uniform(&main.dfg.type_of_value(*parameter), value))
+                .collect::<Option<Vec<_>>>()
+        };
+        [FieldElement::zero(), FieldElement::one()].into_iter().filter_map(sample).collect()
+    }
+
+    /// Returns the arguments to `main` made of `fields`, the field elements of its parameters in the
+    /// order in which they are assigned to witnesses.
+    ///
+    /// Panics if `fields` doesn't hold the field elements of every parameter of `main`.
+    pub(crate) fn arguments_from_fields(&self, fields: &[FieldElement]) -> Vec<Value> {
+        let main = self.main();
+        let mut fields = fields.iter().copied();
+        let arguments = main
+            .parameters()
+            .iter()
+            .map(|parameter| Value::from_fields(&main.dfg.type_of_value(*parameter), &mut fields))
+            .collect::<Option<Vec<_>>>();
+        match arguments {
+            Some(arguments) if fields.next().is_none() => arguments,
+            _ => panic!(
+                "ICE: The inputs to validate the SSA with don't match the parameters of main"
+            ),
+        }
+    }
+}
+
+/// Checks that each optimization pass preserves the results of the program on the given inputs and
+/// a few sample arguments.
+pub(crate) struct PassValidator {
+    /// The arguments to `main` along with the results they gave before any pass was run.
+    expected: Vec<(Vec<Value>, Result<Vec<Value>, InterpreterError>)>,
+}
+
+impl PassValidator {
+    /// Creates a validator running the program with each of `inputs`, the field elements of the
+    /// arguments to `main` as given to [`Ssa::arguments_from_fields`], and with sample arguments.
+    pub(crate) fn new(ssa: &Ssa, inputs: &[Vec<FieldElement>]) -> PassValidator {
+        let expected = inputs
+            .iter()
+            .map(|fields| ssa.arguments_from_fields(fields))
+            .chain(ssa.sample_arguments())
+            .map(|arguments| {
+                let results = ssa.interpret(arguments.clone());
+                (arguments, results)
+            })
+            .filter(|(_, results)| !matches!(results, Err(error) if error.is_inconclusive()))
+            .collect();
+        PassValidator { expected }
+    }
+
+    /// Panics if the program gives different results after the pass `msg` than it did initially.
+    ///
+    /// Programs which failed initially only need to keep failing, as passes may move a failure
+    /// ahead of another one.
+    pub(crate) fn validate(&self, ssa: &Ssa, msg: &str) {
+        for (arguments, expected) in &self.expected {
+            let results = ssa.interpret(arguments.clone());
+            let is_consistent = match (expected, &results) {
+                (_, Err(error)) if error.is_inconclusive() => true,
+                (Ok(expected), Ok(results)) => expected == results,
+                (Err(_), Err(_)) => true,
+                _ => false,
+            };
+            assert!(
+                is_consistent,
+                "ICE: SSA pass changed the results of the program.\n\
+                 Pass: {msg}\nArguments: {}\nExpected: {}\nFound: {}",
+                vecmap(arguments, ToString::to_string).join(", "),
+                display_results(expected),
+                display_results(&results),
+            );
+        }
+    }
+}
+
+fn display_results(results: &Result<Vec<Value>, InterpreterError>) -> String {
+    match results {
+        Ok(values) => vecmap(values, ToString::to_string).join(", "),
+        Err(error) => error.to_string(),
+    }
+}
+
+struct Interpreter<'ssa> {
+    ssa: &'ssa Ssa,
+    memory: Vec<Option<Value>>,
+    steps: usize,
+}
+
+/// The values computed by a function call which is being interpreted.
+struct Frame<'ssa> {
+    function: &'ssa Function,
+    values: HashMap<ValueId, Value>,
+    /// The bits above the integer width of each product of wide integers.
+    wide_products: HashMap<ValueId, FieldElement>,
+    side_effects_enabled: bool,
+}
+
+impl<'ssa> Frame<'ssa> {
+    fn lookup(&self, value: ValueId) -> Result<Value, InterpreterError> {
+        let dfg = &self.function.dfg;
+        let value = dfg.resolve(value);
+        match &dfg[value] {
+            SsaValue::Instruction { .. } | SsaValue::Param { .. } => {
+                self.values.get(&value).cloned().ok_or_else(|| {
+                    InterpreterError::Internal(format!("{value} is used before it is defined"))
+                })
+            }
+            SsaValue::NumericConstant { constant, typ } => match typ {
+                Type::Numeric(typ) => Ok(Value::Numeric { value: *constant, typ: *typ }),
+                other => Err(InterpreterError::Internal(format!("constant of type {other}"))),
+            },
+            SsaValue::Array { array, typ } => {
+                let elements = try_vecmap(array, |element| self.lookup(*element))?;
+                Ok(Value::Array { elements: elements.into(), typ: typ.clone() })
+            }
+            SsaValue::Function(id) => Ok(Value::Function(*id)),
+            SsaValue::Intrinsic(intrinsic) => Ok(Value::Intrinsic(*intrinsic)),
+            SsaValue::ForeignFunction(name) => Ok(Value::ForeignFunction(name.clone())),
+        }
+    }
+
+    fn lookup_all(&self, values: &[ValueId]) -> Result<Vec<Value>, InterpreterError> {
+        try_vecmap(values, |value| self.lookup(*value))
+    }
+}
+
+impl<'ssa> Interpreter<'ssa> {
+    fn call_function(
+        &mut self,
+        id: FunctionId,
+        mut arguments: Vec<Value>,
+    ) -> Result<Vec<Value>, InterpreterError> {
+        let ssa = self.ssa;
+        let function = &ssa.functions[&id];
+        let mut frame = Frame {
+            function,
+            values: HashMap::default(),
+            wide_products: HashMap::default(),
+            side_effects_enabled: true,
+        };
+
+        let mut block = function.entry_block();
+        loop {
+            self.enter_block(&mut frame, block, arguments)?;
+            match function.dfg[block].unwrap_terminator() {
+                TerminatorInstruction::Jmp { destination, arguments: jmp_arguments, .. } => {
+                    arguments = frame.lookup_all(jmp_arguments)?;
+                    block = *destination;
+                }
+                TerminatorInstruction::JmpIf { condition, then_destination, else_destination } => {
+                    arguments = Vec::new();
+                    block = if frame.lookup(*condition)?.as_bool()? {
+                        *then_destination
+                    } else {
+                        *else_destination
+                    };
+                }
+                TerminatorInstruction::Return { return_values, .. } => {
+                    return frame.lookup_all(return_values);
+                }
+            }
+        }
+    }
+
+    /// Binds the parameters of `block` to `arguments` then executes its instructions.
+    fn enter_block(
+        &mut self,
+        frame: &mut Frame<'ssa>,
+        block: BasicBlockId,
+        arguments: Vec<Value>,
+    ) -> Result<(), InterpreterError> {
+        let function = frame.function;
+        let dfg = &function.dfg;
+        let parameters = dfg.block_parameters(block);
+        if parameters.len() != arguments.len() {
+            return Err(InterpreterError::Internal(format!(
+                "{block} takes {} arguments but was given {}",
+                parameters.len(),
+                arguments.len()
+            )));
+        }
+        frame.values.extend(parameters.iter().copied().zip(arguments));
+
+        for instruction in dfg[block].instructions() {
+            self.steps += 1;
+            if self.steps > MAX_STEPS {
+                return Err(InterpreterError::StepLimitReached);
+            }
+
+            let results = self.execute_instruction(frame, *instruction)?;
+            let result_ids = dfg.instruction_results(*instruction);
+            if results.len() != result_ids.len() {
+                return Err(InterpreterError::Internal(format!(
+                    "{instruction} has {} results but {} values were computed",
+                    result_ids.len(),
+                    results.len()
+                )));
+            }
+            frame.values.extend(result_ids.iter().copied().zip(results));
+        }
+        Ok(())
+    }
+
+    fn execute_instruction(
+        &mut self,
+        frame: &mut Frame<'ssa>,
+        instruction_id: InstructionId,
+    ) -> Result<Vec<Value>, InterpreterError> {
+        let function = frame.function;
+        let dfg = &function.dfg;
+        let result = match &dfg[instruction_id] {
+            Instruction::Binary(binary @ Binary { operator: BinaryOp::Mul, .. })
+                if dfg.type_of_value(binary.lhs).is_wide_integer() =>
+            {
+                let (low, high) = wide_multiplication(frame, binary)?;
+                let result = dfg.instruction_results(instruction_id)[0];
+                frame.wide_products.insert(result, high);
+                low
+            }
+            Instruction::Binary(binary) => binary_op(frame, binary)?,
+            Instruction::Cast(value, typ) => match typ {
+                Type::Numeric(typ) => cast(frame.lookup(*value)?, *typ)?,
+                other => return Err(InterpreterError::Internal(format!("cast to {other}"))),
+            },
+            Instruction::Not(value) => {
+                let (value, typ) = frame.lookup(*value)?.as_numeric()?;
+                let bit_size = integer_bit_size(typ)?;
+                let max = FieldElement::from(u128::MAX >> (128 - bit_size));
+                Value::Numeric { value: max - value, typ }
+            }
+            Instruction::Truncate { value, bit_size, .. } => {
+                let (mut field, typ) = frame.lookup(*value)?.as_numeric()?;
+                // Subtractions are allowed to underflow before they are truncated, as in ACIR.
+                if is_subtraction(function, *value) {
+                    field += FieldElement::from(2_u128).pow(&FieldElement::from(*bit_size as u128));
+                }
+                Value::Numeric { value: truncate(field, *bit_size), typ }
+            }
+            Instruction::Constrain(lhs, rhs, message) => {
+                if frame.lookup(*lhs)? != frame.lookup(*rhs)? {
+                    return Err(InterpreterError::FailedConstraint { message: message.clone() });
+                }
+                return Ok(Vec::new());
+            }
+            Instruction::RangeCheck { value, max_bit_size, .. } => {
+                let high = frame.wide_products.get(&dfg.resolve(*value)).copied();
+                let (value, _) = frame.lookup(*value)?.as_numeric()?;
+                if value.num_bits() > *max_bit_size || high.map_or(false, |high| !high.is_zero()) {
+                    let max_bit_size = *max_bit_size;
+                    return Err(InterpreterError::FailedRangeCheck { value, max_bit_size });
+                }
+                return Ok(Vec::new());
+            }
+            Instruction::Call { func, arguments } => {
+                let function = frame.lookup(*func)?;
+                let arguments = frame.lookup_all(arguments)?;
+                let result_types = vecmap(dfg.instruction_results(instruction_id), |result| {
+                    dfg.type_of_value(*result)
+                });
+                return self.call(function, arguments, result_types, frame.side_effects_enabled);
+            }
+            Instruction::Allocate => {
+                self.memory.push(None);
+                Value::Reference(self.memory.len() - 1)
+            }
+            Instruction::Load { address } => {
+                let address = frame.lookup(*address)?.as_reference()?;
+                self.memory[address].clone().ok_or(InterpreterError::UninitializedLoad)?
+            }
+            Instruction::Store { address, value } => {
+                let address = frame.lookup(*address)?.as_reference()?;
+                self.memory[address] = Some(frame.lookup(*value)?);
+                return Ok(Vec::new());
+            }
+            Instruction::EnableSideEffects { condition } => {
+                frame.side_effects_enabled = frame.lookup(*condition)?.as_bool()?;
+                return Ok(Vec::new());
+            }
+            Instruction::ArrayGet { array, index } => {
+                let array = frame.lookup(*array)?;
+                let (elements, _) = array.as_array()?;
+                let index = array_index(frame, *index, elements.len())?;
+                elements[index].clone()
+            }
+            Instruction::ArraySet { array, index, value } => {
+                let array = frame.lookup(*array)?;
+                let (elements, typ) = array.as_array()?;
+                let index = array_index(frame, *index, elements.len())?;
+                let elements = elements.update(index, frame.lookup(*value)?);
+                Value::Array { elements, typ: typ.clone() }
+            }
+            Instruction::IncrementRc { .. } => return Ok(Vec::new()),
+        };
+        Ok(vec![result])
+    }
+
+    fn call(
+        &mut self,
+        function: Value,
+        arguments: Vec<Value>,
+        result_types: Vec<Type>,
+        side_effects_enabled: bool,
+    ) -> Result<Vec<Value>, InterpreterError> {
+        match function {
+            Value::Function(id) => {
+                // As in ACIR, the unconstrained functions called while side effects are disabled
+                // are not run and return zeroes.
+                let is_unconstrained = self.ssa.functions[&id].runtime() == RuntimeType::Brillig;
+                if !side_effects_enabled && is_unconstrained {
+                    try_vecmap(result_types, |typ| {
+                        Value::uniform(&typ, FieldElement::zero()).ok_or_else(|| {
+                            InterpreterError::Unsupported(format!("returning {typ} from {id}"))
+                        })
+                    })
+                } else {
+                    self.call_function(id, arguments)
+                }
+            }
+            Value::Intrinsic(intrinsic) => {
+                intrinsics::call_intrinsic(intrinsic, arguments, result_types)
+            }
+            // The output of `println` doesn't affect the results of the program.
+            Value::ForeignFunction(name) if name == "print" => Ok(Vec::new()),
+            Value::ForeignFunction(name) => {
+                Err(InterpreterError::Unsupported(format!("the foreign function `{name}`")))
+            }
+            other => Err(InterpreterError::Internal(format!("called {other}"))),
+        }
+    }
+}
+
+/// Returns `index` as an index into an array of `length` elements.
+///
+/// As in ACIR, indexing out of bounds while side effects are disabled reads or writes the first
+/// element instead of failing.
+fn array_index(frame: &Frame, index: ValueId, length: usize) -> Result<usize, InterpreterError> {
+    let index = frame.lookup(index)?.as_usize()?;
+    if index < length {
+        Ok(index)
+    } else if !frame.side_effects_enabled && length > 0 {
+        Ok(0)
+    } else {
+        Err(InterpreterError::IndexOutOfBounds { index, length })
+    }
+}
+
+fn is_subtraction(function: &Function, value: ValueId) -> bool {
+    let dfg = &function.dfg;
+    match &dfg[dfg.resolve(value)] {
+        SsaValue::Instruction { instruction, .. } => matches!(
+            &dfg[*instruction],
+            Instruction::Binary(Binary { operator: BinaryOp::Sub, .. })
+        ),
+        _ => false,
+    }
+}
+
+fn binary_op(frame: &Frame, binary: &Binary) -> Result<Value, InterpreterError> {
+    let (lhs, typ) = frame.lookup(binary.lhs)?.as_numeric()?;
+    let (rhs, _) = frame.lookup(binary.rhs)?.as_numeric()?;
+
+    let value = match (binary.operator, typ) {
+        // Overflows are caught by the instructions following integer arithmetic.
+        (BinaryOp::Add, _) => lhs + rhs,
+        (BinaryOp::Sub, _) => lhs - rhs,
+        (BinaryOp::Mul, _) => lhs * rhs,
+        (BinaryOp::Eq, _) => return Ok(Value::bool(lhs == rhs)),
+        (BinaryOp::Div | BinaryOp::Mod, _) if rhs.is_zero() => {
+            if frame.side_effects_enabled {
+                return Err(InterpreterError::DivisionByZero);
+            }
+            FieldElement::zero()
+        }
+        (BinaryOp::Div, NumericType::NativeField) => lhs / rhs,
+        (BinaryOp::Lt, NumericType::NativeField) => return Ok(Value::bool(lhs < rhs)),
+        (
+            BinaryOp::Mod | BinaryOp::And | BinaryOp::Or | BinaryOp::Xor,
+            NumericType::NativeField,
+        ) => {
+            let operator = binary.operator;
+            return Err(InterpreterError::Internal(format!("{operator} on fields")));
+        }
+        (operator, NumericType::Unsigned { bit_size }) => {
+            let lhs = to_u128(lhs, bit_size)?;
+            let rhs = to_u128(rhs, bit_size)?;
+            let result = match operator {
+                BinaryOp::Div => lhs / rhs,
+                BinaryOp::Mod => lhs % rhs,
+                BinaryOp::And => lhs & rhs,
+                BinaryOp::Or => lhs | rhs,
+                BinaryOp::Xor => lhs ^ rhs,
+                BinaryOp::Lt => return Ok(Value::bool(lhs < rhs)),
+                BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Eq => unreachable!(),
+            };
+            result.into()
+        }
+        (operator, NumericType::Signed { bit_size }) => {
+            let lhs = to_i128(lhs, bit_size)?;
+            let rhs = to_i128(rhs, bit_size)?;
+            let result = match operator {
+                BinaryOp::Div => lhs.wrapping_div(rhs),
+                BinaryOp::Mod => lhs.wrapping_rem(rhs),
+                BinaryOp::And => lhs & rhs,
+                BinaryOp::Or => lhs | rhs,
+                BinaryOp::Xor => lhs ^ rhs,
+                BinaryOp::Lt => return Ok(Value::bool(lhs < rhs)),
+                BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Eq => unreachable!(),
+            };
+            // Negative results are represented in two's complement.
+            ((result as u128) & (u128::MAX >> (128 - bit_size))).into()
+        }
+    };
+    Ok(Value::Numeric { value, typ })
+}
+
+/// Multiplies two integers whose product may not fit in a field element, returning the low bits of
+/// the product up to the width of their type and the bits above them.
+fn wide_multiplication(
+    frame: &Frame,
+    binary: &Binary,
+) -> Result<(Value, FieldElement), InterpreterError> {
+    let (lhs, typ) = frame.lookup(binary.lhs)?.as_numeric()?;
+    let (rhs, _) = frame.lookup(binary.rhs)?.as_numeric()?;
+    let bit_size = integer_bit_size(typ)?;
+
+    let to_biguint = |value: FieldElement| BigUint::from_bytes_be(&value.to_be_bytes());
+    let to_field = |value: BigUint| FieldElement::from_be_bytes_reduce(&value.to_bytes_be());
+    let product = to_biguint(lhs) * to_biguint(rhs);
+    let low = &product % (BigUint::from(1u128) << bit_size);
+    let high = product >> bit_size;
+    Ok((Value::Numeric { value: to_field(low), typ }, to_field(high)))
+}
+
+/// Casts `value` to `typ`, truncating it to the bit size of integer types.
+fn cast(value: Value, typ: NumericType) -> Result<Value, InterpreterError> {
+    let (value, _) = value.as_numeric()?;
+    let value = match typ {
+        NumericType::NativeField => value,
+        NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => {
+            truncate(value, bit_size)
+        }
+    };
+    Ok(Value::Numeric { value, typ })
+}
+
+/// Returns `value` modulo 2^`bit_size`.
+fn truncate(value: FieldElement, bit_size: u32) -> FieldElement {
+    let value = BigUint::from_bytes_be(&value.to_be_bytes());
+    let truncated = value % (BigUint::from(1u128) << bit_size);
+    FieldElement::from_be_bytes_reduce(&truncated.to_bytes_be())
+}
+
+fn integer_bit_size(typ: NumericType) -> Result<u32, InterpreterError> {
+    match typ {
+        NumericType::Unsigned { bit_size } | NumericType::Signed { bit_size } => Ok(bit_size),
+        NumericType::NativeField => {
+            Err(InterpreterError::Internal("bitwise operation on a field".to_string()))
+        }
+    }
+}
+
+fn to_u128(value: FieldElement, bit_size: u32) -> Result<u128, InterpreterError> {
+    value
+        .try_into_u128()
+        .filter(|value| bit_size >= 128 || *value < 2u128.pow(bit_size))
+        .ok_or_else(|| {
+            InterpreterError::Internal(format!("{value} does not fit in {bit_size} bits"))
+        })
+}
+
+/// Interprets `value` as a signed integer of `bit_size` bits in two's complement.
+fn to_i128(value: FieldElement, bit_size: u32) -> Result<i128, InterpreterError> {
+    let shift = 128 - bit_size;
+    // Shifting the sign bit into place then back sign-extends the value.
+    Ok(((to_u128(value, bit_size)? << shift) as i128) >> shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use acvm::FieldElement;
+
+    use super::{InterpreterError, PassValidator, Value};
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{
+            function::RuntimeType,
+            instruction::{BinaryOp, Endian, Intrinsic, TerminatorInstruction},
+            map::Id,
+            types::{NumericType, Type},
+        },
+        ssa_gen::Ssa,
+    };
+
+    fn field(value: u128) -> Value {
+        Value::field(value)
+    }
+
+    /// Builds a program which sums the numbers from 0 to `v0` in a loop, storing the sum in memory:
+    ///
+    /// ```text
+    /// acir fn main f0 {
+    ///   b0(v0: Field):
+    ///     v1 = allocate
+    ///     store Field 0 at v1
+    ///     jmp b1(Field 0)
+    ///   b1(v2: Field):
+    ///     v3 = lt v2, v0
+    ///     jmpif v3 then: b2, else: b3
+    ///   b2():
+    ///     v4 = load v1
+    ///     v5 = add v4, v2
+    ///     store v5 at v1
+    ///     v6 = add v2, Field 1
+    ///     jmp b1(v6)
+    ///   b3():
+    ///     v7 = load v1
+    ///     return v7
+    /// }
+    /// ```
+    fn sum_program() -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::field());
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+        let b3 = builder.insert_block();
+
+        let zero = builder.field_constant(0u128);
+        let one = builder.field_constant(1u128);
+        let v1 = builder.insert_allocate(Type::field());
+        builder.insert_store(v1, zero);
+        builder.terminate_with_jmp(b1, vec![zero]);
+
+        builder.switch_to_block(b1);
+        let v2 = builder.add_block_parameter(b1, Type::field());
+        let v3 = builder.insert_binary(v2, BinaryOp::Lt, v0);
+        builder.terminate_with_jmpif(v3, b2, b3);
+
+        builder.switch_to_block(b2);
+        let v4 = builder.insert_load(v1, Type::field());
+        let v5 = builder.insert_binary(v4, BinaryOp::Add, v2);
+        builder.insert_store(v1, v5);
+        let v6 = builder.insert_binary(v2, BinaryOp::Add, one);
+        builder.terminate_with_jmp(b1, vec![v6]);
+
+        builder.switch_to_block(b3);
+        let v7 = builder.insert_load(v1, Type::field());
+        builder.terminate_with_return(vec![v7]);
+
+        builder.finish()
+    }
+
+    #[test]
+    fn interprets_loops_and_memory() {
+        let ssa = sum_program();
+        assert_eq!(ssa.interpret(vec![field(4)]), Ok(vec![field(6)]));
+        assert_eq!(ssa.interpret(vec![field(0)]), Ok(vec![field(0)]));
+    }
+
+    #[test]
+    fn passes_preserve_results() {
+        let ssa = sum_program();
+        let expected = ssa.interpret(vec![field(4)]);
+        let ssa = ssa.mem2reg();
+        assert_eq!(ssa.interpret(vec![field(4)]), expected);
+    }
+
+    /// Builds a program returning the square of its argument:
+    ///
+    /// ```text
+    /// acir fn main f0 {
+    ///   b0(v0: Field):
+    ///     v1 = mul v0, v0
+    ///     return v1
+    /// }
+    /// ```
+    fn square_program() -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.insert_binary(v0, BinaryOp::Mul, v0);
+        builder.terminate_with_return(vec![v1]);
+        builder.finish()
+    }
+
+    /// A deliberately broken pass which makes `main` return its argument rather than its square.
+    ///
+    /// Its results only differ from those of the original program for arguments other than
+    /// zero and one, so the sample arguments alone don't catch it.
+    fn return_argument_instead_of_square(mut ssa: Ssa) -> Ssa {
+        let main = ssa.main_mut();
+        let entry = main.entry_block();
+        let argument = main.parameters()[0];
+        match main.dfg[entry].unwrap_terminator_mut() {
+            TerminatorInstruction::Return { return_values, .. } => *return_values = vec![argument],
+            _ => unreachable!("main returns from its entry block"),
+        }
+        ssa
+    }
+
+    #[test]
+    fn builds_arguments_from_fields() {
+        let ssa = square_program();
+        let arguments = ssa.arguments_from_fields(&[FieldElement::from(3u128)]);
+        assert_eq!(arguments, vec![field(3)]);
+        assert_eq!(ssa.interpret(arguments), Ok(vec![field(9)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "SSA pass changed the results of the program")]
+    fn inputs_catch_broken_pass() {
+        let ssa = square_program();
+        let validator = PassValidator::new(&ssa, &[vec![FieldElement::from(3u128)]]);
+        validator.validate(&return_argument_instead_of_square(ssa), "Broken pass");
+    }
+
+    #[test]
+    fn reports_failed_constraints() {
+        // acir fn main f0 {
+        //   b0(v0: u8, v1: u8):
+        //     v2 = sub v0, v1
+        //     v3 = truncate v2 to 8 bits, max_bit_size: 9
+        //     constrain v3 == u8 255 'underflow'
+        //     return v3
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::unsigned(8));
+        let v1 = builder.add_parameter(Type::unsigned(8));
+        let v2 = builder.insert_binary(v0, BinaryOp::Sub, v1);
+        let v3 = builder.insert_truncate(v2, 8, 9);
+        let max = builder.numeric_constant(255u128, Type::unsigned(8));
+        builder.insert_constrain(v3, max, Some("underflow".to_string()));
+        builder.terminate_with_return(vec![v3]);
+        let ssa = builder.finish();
+
+        let u8_value = |value: u128| Value::Numeric {
+            value: FieldElement::from(value),
+            typ: NumericType::Unsigned { bit_size: 8 },
+        };
+        assert_eq!(ssa.interpret(vec![u8_value(0), u8_value(1)]), Ok(vec![u8_value(255)]));
+        assert_eq!(
+            ssa.interpret(vec![u8_value(2), u8_value(1)]),
+            Err(InterpreterError::FailedConstraint { message: Some("underflow".to_string()) })
+        );
+    }
+
+    #[test]
+    fn interprets_intrinsics() {
+        // acir fn main f0 {
+        //   b0(v0: Field):
+        //     v2, v3 = call to_le_bits(v0, u32 4)
+        //     v4, v5 = call slice_push_back(v2, v3, u1 1)
+        //     v6 = array_get v5, index u32 4
+        //     return v4, v6
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::field());
+        let to_bits = builder.import_intrinsic_id(Intrinsic::ToBits(Endian::Little));
+        let four = builder.numeric_constant(4u128, Type::unsigned(32));
+        let bits_type = Type::Slice(Rc::new(vec![Type::bool()]));
+        let bits =
+            builder.insert_call(to_bits, vec![v0, four], vec![Type::field(), bits_type.clone()]);
+        let (v2, v3) = (bits[0], bits[1]);
+        let push_back = builder.import_intrinsic_id(Intrinsic::SlicePushBack);
+        let one = builder.numeric_constant(1u128, Type::bool());
+        let pushed =
+            builder.insert_call(push_back, vec![v2, v3, one], vec![Type::field(), bits_type]);
+        let (v4, v5) = (pushed[0], pushed[1]);
+        let v6 = builder.insert_array_get(v5, four, Type::bool());
+        builder.terminate_with_return(vec![v4, v6]);
+        let ssa = builder.finish();
+
+        assert_eq!(ssa.interpret(vec![field(5)]), Ok(vec![field(5), Value::bool(true)]));
+        assert!(matches!(
+            ssa.interpret(vec![field(16)]),
+            Err(InterpreterError::InvalidRadixDecomposition { .. })
+        ));
+    }
+
+    #[test]
+    fn interprets_integer_operations() {
+        // acir fn main f0 {
+        //   b0(v0: i8, v1: i8):
+        //     v3, v4 = call overflowing_mul(v0, v1)
+        //     v6 = call saturating_add(v0, v1)
+        //     return v3, v4, v6
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::signed(8));
+        let v1 = builder.add_parameter(Type::signed(8));
+        let overflowing_mul = builder.import_intrinsic_id(Intrinsic::OverflowingOp(BinaryOp::Mul));
+        let product =
+            builder.insert_call(overflowing_mul, vec![v0, v1], vec![Type::signed(8), Type::bool()]);
+        let (v3, v4) = (product[0], product[1]);
+        let saturating_add = builder.import_intrinsic_id(Intrinsic::SaturatingOp(BinaryOp::Add));
+        let v6 = builder.insert_call(saturating_add, vec![v0, v1], vec![Type::signed(8)])[0];
+        builder.terminate_with_return(vec![v3, v4, v6]);
+        let ssa = builder.finish();
+
+        // Negative values are represented in two's complement.
+        let i8_value = |value: i128| Value::Numeric {
+            value: FieldElement::from((value as u8) as u128),
+            typ: NumericType::Signed { bit_size: 8 },
+        };
+        let interpret = |lhs, rhs| ssa.interpret(vec![i8_value(lhs), i8_value(rhs)]);
+        assert_eq!(interpret(-3, 5), Ok(vec![i8_value(-15), Value::bool(false), i8_value(2)]));
+        assert_eq!(interpret(100, 2), Ok(vec![i8_value(-56), Value::bool(true), i8_value(102)]));
+        assert_eq!(
+            interpret(-128, -1),
+            Ok(vec![i8_value(-128), Value::bool(true), i8_value(-128)])
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use acvm::{acir::BlackBoxFunc, blackbox_solver, FieldElement};
+use iter_extended::{try_vecmap, vecmap};
+use num_bigint::BigUint;
+
+use crate::ssa::ir::{
+    instruction::{evaluate_overflowing_op, saturation_bound, BinaryOp, Endian, Intrinsic},
+    types::Type,
+};
+
+use super::{InterpreterError, Value};
+
+/// Calls `intrinsic` with the given arguments, returning values of the given types.
+pub(super) fn call_intrinsic(
+    intrinsic: Intrinsic,
+    arguments: Vec<Value>,
+    result_types: Vec<Type>,
+) -> Result<Vec<Value>, InterpreterError> {
+    match intrinsic {
+        Intrinsic::Sort => {
+            let (elements, typ) = arguments[0].as_array()?;
+            let mut sorted = try_vecmap(elements, Value::as_numeric)?;
+            sorted.sort_unstable_by_key(|(value, _)| *value);
+            let elements = sorted.into_iter().map(|(value, typ)| Value::Numeric { value, typ });
+            Ok(vec![Value::Array { elements: elements.collect(), typ: typ.clone() }])
+        }
+        Intrinsic::ArrayLen => match &arguments[0] {
+            Value::Array { typ: Type::Array(_, length), .. } => {
+                Ok(vec![numeric(&result_types[0], *length)?])
+            }
+            // The length of a slice is passed alongside it.
+            length => Ok(vec![length.clone()]),
+        },
+        Intrinsic::AssertConstant => Ok(Vec::new()),
+        Intrinsic::StrAsBytes => Ok(vec![arguments[0].clone()]),
+        Intrinsic::ToBits(endian) => {
+            let limb_count = arguments[1].as_usize()? as u32;
+            to_radix(endian, &arguments[0], 2, limb_count, &result_types)
+        }
+        Intrinsic::ToRadix(endian) => {
+            let radix = arguments[1].as_usize()? as u32;
+            let limb_count = arguments[2].as_usize()? as u32;
+            to_radix(endian, &arguments[0], radix, limb_count, &result_types)
+        }
+        Intrinsic::SlicePushBack
+        | Intrinsic::SlicePushFront
+        | Intrinsic::SlicePopBack
+        | Intrinsic::SlicePopFront
+        | Intrinsic::SliceInsert
+        | Intrinsic::SliceRemove => slice_operation(intrinsic, arguments),
+        Intrinsic::BlackBox(func) => black_box(func, &arguments, &result_types),
+        Intrinsic::WrappingOp(operator)
+        | Intrinsic::OverflowingOp(operator)
+        | Intrinsic::SaturatingOp(operator) => integer_op(intrinsic, operator, &arguments),
+    }
+}
+
+/// Performs a wrapping, overflowing or saturating integer operation.
+fn integer_op(
+    intrinsic: Intrinsic,
+    operator: BinaryOp,
+    arguments: &[Value],
+) -> Result<Vec<Value>, InterpreterError> {
+    let (lhs, typ) = arguments[0].as_numeric()?;
+    let (rhs, _) = arguments[1].as_numeric()?;
+    let (wrapped, overflow) = evaluate_overflowing_op(operator, lhs, rhs, typ)
+        .ok_or_else(|| InterpreterError::Internal(format!("{intrinsic} on {typ}")))?;
+
+    let result = |value| Value::Numeric { value, typ };
+    Ok(match intrinsic {
+        Intrinsic::OverflowingOp(_) => vec![result(wrapped), Value::bool(overflow)],
+        Intrinsic::SaturatingOp(_) if overflow => {
+            vec![result(saturation_bound(operator, lhs, rhs, typ))]
+        }
+        _ => vec![result(wrapped)],
+    })
+}
+
+fn numeric(typ: &Type, value: impl Into<FieldElement>) -> Result<Value, InterpreterError> {
+    match typ {
+        Type::Numeric(typ) => Ok(Value::Numeric { value: value.into(), typ: *typ }),
+        other => Err(InterpreterError::Internal(format!("expected a numeric type, found {other}"))),
+    }
+}
+
+/// Decomposes `value` into `limb_count` limbs of `radix`, returning the length of the resulting
+/// slice followed by its contents.
+fn to_radix(
+    endian: Endian,
+    value: &Value,
+    radix: u32,
+    limb_count: u32,
+    result_types: &[Type],
+) -> Result<Vec<Value>, InterpreterError> {
+    let (field, _) = value.as_numeric()?;
+    let digits = BigUint::from_bytes_be(&field.to_be_bytes()).to_radix_le(radix);
+    // As in ACIR, the decomposition is constrained to be exact.
+    if digits.len() > limb_count as usize {
+        return Err(InterpreterError::InvalidRadixDecomposition {
+            value: field,
+            radix,
+            limb_count,
+        });
+    }
+
+    let Type::Slice(element_types) = &result_types[1] else {
+        let typ = &result_types[1];
+        return Err(InterpreterError::Internal(format!("to_radix returning {typ}")));
+    };
+    let mut limbs = try_vecmap(0..limb_count as usize, |i| {
+        numeric(&element_types[0], u128::from(digits.get(i).copied().unwrap_or(0)))
+    })?;
+    if endian == Endian::Big {
+        limbs.reverse();
+    }
+    let length = numeric(&result_types[0], u128::from(limb_count))?;
+    Ok(vec![length, Value::Array { elements: limbs.into(), typ: result_types[1].clone() }])
+}
+
+/// Performs a slice intrinsic on its `(length, contents)` arguments, returning the results in the
+/// order the SSA expects them.
+fn slice_operation(
+    intrinsic: Intrinsic,
+    arguments: Vec<Value>,
+) -> Result<Vec<Value>, InterpreterError> {
+    let (_, length_type) = arguments[0].as_numeric()?;
+    let length = arguments[0].as_usize()?;
+    let (contents, typ) = arguments[1].as_array()?;
+    let mut contents = contents.clone();
+    let element_size = match typ {
+        Type::Slice(element_types) => element_types.len(),
+        other => return Err(InterpreterError::Internal(format!("slice operation on {other}"))),
+    };
+    let slice = |contents| Value::Array { elements: contents, typ: typ.clone() };
+    let length_value =
+        |length: usize| Value::Numeric { value: FieldElement::from(length), typ: length_type };
+
+    let index = |index: usize| index * element_size;
+    let check_bounds = |position: usize| {
+        if position < length {
+            Ok(())
+        } else {
+            Err(InterpreterError::IndexOutOfBounds { index: position, length })
+        }
+    };
+
+    match intrinsic {
+        Intrinsic::SlicePushBack => {
+            // Merged slices may hold more elements than their length, which are overwritten.
+            contents.truncate(index(length));
+            contents.extend(arguments[2..].iter().cloned());
+            Ok(vec![length_value(length + 1), slice(contents)])
+        }
+        Intrinsic::SlicePushFront => {
+            contents.truncate(index(length));
+            for element in arguments[2..].iter().rev() {
+                contents.push_front(element.clone());
+            }
+            Ok(vec![length_value(length + 1), slice(contents)])
+        }
+        Intrinsic::SlicePopBack => {
+            check_bounds(length.wrapping_sub(1))?;
+            contents.truncate(index(length));
+            let elements = contents.split_off(index(length - 1));
+            let mut results = vec![length_value(length - 1), slice(contents)];
+            results.extend(elements);
+            Ok(results)
+        }
+        Intrinsic::SlicePopFront => {
+            check_bounds(0)?;
+            contents.truncate(index(length));
+            let rest = contents.split_off(element_size);
+            let mut results: Vec<_> = contents.into_iter().collect();
+            results.extend([length_value(length - 1), slice(rest)]);
+            Ok(results)
+        }
+        Intrinsic::SliceInsert => {
+            let position = arguments[2].as_usize()?;
+            if position > length {
+                return Err(InterpreterError::IndexOutOfBounds { index: position, length });
+            }
+            contents.truncate(index(length));
+            for (offset, element) in arguments[3..].iter().enumerate() {
+                contents.insert(index(position) + offset, element.clone());
+            }
+            Ok(vec![length_value(length + 1), slice(contents)])
+        }
+        Intrinsic::SliceRemove => {
+            let position = arguments[2].as_usize()?;
+            check_bounds(position)?;
+            contents.truncate(index(length));
+            let mut rest = contents.split_off(index(position));
+            let elements = vecmap(0..element_size, |_| rest.pop_front().unwrap());
+            contents.append(rest);
+            let mut results = vec![length_value(length - 1), slice(contents)];
+            results.extend(elements);
+            Ok(results)
+        }
+        _ => unreachable!("{intrinsic} is not a slice operation"),
+    }
+}
+
+fn black_box(
+    func: BlackBoxFunc,
+    arguments: &[Value],
+    result_types: &[Type],
+) -> Result<Vec<Value>, InterpreterError> {
+    let inputs = byte_arrays(arguments)?;
+    let result = match func {
+        BlackBoxFunc::SHA256 => blackbox_solver::sha256(&inputs[0]),
+        BlackBoxFunc::Blake2s => blackbox_solver::blake2s(&inputs[0]),
+        BlackBoxFunc::Keccak256 => {
+            // The number of bytes to hash is passed after the message.
+            let num_bytes = arguments.last().unwrap().as_usize()?;
+            let message = inputs[0].get(..num_bytes).unwrap_or(&inputs[0]);
+            blackbox_solver::keccak256(message)
+        }
+        BlackBoxFunc::HashToField128Security => {
+            let result = blackbox_solver::hash_to_field_128_security(&inputs[0])
+                .map_err(|error| InterpreterError::Internal(error.to_string()))?;
+            return Ok(vec![numeric(&result_types[0], result)?]);
+        }
+        BlackBoxFunc::EcdsaSecp256k1 | BlackBoxFunc::EcdsaSecp256r1 => {
+            let (Ok(public_key_x), Ok(public_key_y), Ok(signature)) = (
+                inputs[0].as_slice().try_into(),
+                inputs[1].as_slice().try_into(),
+                inputs[2].as_slice().try_into(),
+            ) else {
+                return Err(InterpreterError::Internal(format!("invalid inputs to {func}")));
+            };
+            let verify = if func == BlackBoxFunc::EcdsaSecp256k1 {
+                blackbox_solver::ecdsa_secp256k1_verify
+            } else {
+                blackbox_solver::ecdsa_secp256r1_verify
+            };
+            let valid = verify(&inputs[3], public_key_x, public_key_y, signature)
+                .map_err(|error| InterpreterError::Internal(error.to_string()))?;
+            return Ok(vec![numeric(&result_types[0], valid)?]);
+        }
+        _ => return Err(InterpreterError::Unsupported(format!("the black box function {func}"))),
+    };
+
+    let digest = result.map_err(|error| InterpreterError::Internal(error.to_string()))?;
+    let Type::Array(element_types, _) = &result_types[0] else {
+        let typ = &result_types[0];
+        return Err(InterpreterError::Internal(format!("{func} returning {typ}")));
+    };
+    let elements = try_vecmap(digest, |byte| numeric(&element_types[0], u128::from(byte)))?;
+    Ok(vec![Value::Array { elements: elements.into(), typ: result_types[0].clone() }])
+}
+
+/// Returns the bytes of each array argument. Slices are cut to the length passed before them.
+fn byte_arrays(arguments: &[Value]) -> Result<Vec<Vec<u8>>, InterpreterError> {
+    let mut arrays = Vec::new();
+    for (i, argument) in arguments.iter().enumerate() {
+        let Value::Array { elements, typ } = argument else {
+            continue;
+        };
+        let mut bytes = try_vecmap(elements, |element| {
+            let (value, _) = element.as_numeric()?;
+            value
+                .try_to_u64()
+                .and_then(|byte| u8::try_from(byte).ok())
+                .ok_or_else(|| InterpreterError::Internal(format!("{value} is not a byte")))
+        })?;
+        if let (Type::Slice(_), Some(length)) = (typ, i.checked_sub(1)) {
+            bytes.truncate(arguments[length].as_usize()?);
+        }
+        arrays.push(bytes);
+    }
+    Ok(arrays)
+}
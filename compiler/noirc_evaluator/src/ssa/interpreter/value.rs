@@ -0,0 +1,145 @@
+use std::fmt;
+
+use acvm::FieldElement;
+use iter_extended::vecmap;
+
+use crate::ssa::ir::{
+    function::FunctionId,
+    instruction::Intrinsic,
+    types::{NumericType, Type},
+};
+
+use super::InterpreterError;
+
+/// A value computed while interpreting the SSA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Value {
+    Numeric {
+        value: FieldElement,
+        typ: NumericType,
+    },
+
+    /// A reference to a slot of the interpreter's memory, created by an `allocate` instruction.
+    Reference(usize),
+
+    /// An array or slice. As in the SSA, the elements of arrays of tuples are flattened so that
+    /// each field of each tuple is an element of its own.
+    Array {
+        elements: im::Vector<Value>,
+        typ: Type,
+    },
+
+    Function(FunctionId),
+    Intrinsic(Intrinsic),
+    ForeignFunction(String),
+}
+
+impl Value {
+    pub(crate) fn field(value: impl Into<FieldElement>) -> Value {
+        Value::Numeric { value: value.into(), typ: NumericType::NativeField }
+    }
+
+    pub(crate) fn bool(value: bool) -> Value {
+        Value::Numeric { value: value.into(), typ: NumericType::Unsigned { bit_size: 1 } }
+    }
+
+    /// Returns a value of type `typ` whose numeric values are all `value`, or `None` if the type
+    /// holds references or functions which can't be made up.
+    pub(crate) fn uniform(typ: &Type, value: FieldElement) -> Option<Value> {
+        match typ {
+            Type::Numeric(numeric_type) => {
+                let value = if numeric_type.value_is_within_limits(value) {
+                    value
+                } else {
+                    FieldElement::zero()
+                };
+                Some(Value::Numeric { value, typ: *numeric_type })
+            }
+            Type::Array(element_types, length) => {
+                let mut elements = im::Vector::new();
+                for _ in 0..*length {
+                    for element_type in element_types.iter() {
+                        elements.push_back(Value::uniform(element_type, value)?);
+                    }
+                }
+                Some(Value::Array { elements, typ: typ.clone() })
+            }
+            Type::Slice(_) => Some(Value::Array { elements: im::Vector::new(), typ: typ.clone() }),
+            Type::Reference(_) | Type::Function => None,
+        }
+    }
+
+    /// Returns a value of type `typ` made of the next elements of `fields`, or `None` if there are
+    /// too few of them or the type holds slices, references or functions which can't be inputs.
+    pub(crate) fn from_fields(
+        typ: &Type,
+        fields: &mut impl Iterator<Item = FieldElement>,
+    ) -> Option<Value> {
+        match typ {
+            Type::Numeric(numeric_type) => {
+                Some(Value::Numeric { value: fields.next()?, typ: *numeric_type })
+            }
+            Type::Array(element_types, length) => {
+                let mut elements = im::Vector::new();
+                for _ in 0..*length {
+                    for element_type in element_types.iter() {
+                        elements.push_back(Value::from_fields(element_type, fields)?);
+                    }
+                }
+                Some(Value::Array { elements, typ: typ.clone() })
+            }
+            Type::Slice(_) | Type::Reference(_) | Type::Function => None,
+        }
+    }
+
+    pub(crate) fn as_numeric(&self) -> Result<(FieldElement, NumericType), InterpreterError> {
+        match self {
+            Value::Numeric { value, typ } => Ok((*value, *typ)),
+            other => Err(InterpreterError::Internal(format!("expected a number, found {other}"))),
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> Result<bool, InterpreterError> {
+        Ok(!self.as_numeric()?.0.is_zero())
+    }
+
+    pub(crate) fn as_usize(&self) -> Result<usize, InterpreterError> {
+        let (value, _) = self.as_numeric()?;
+        value
+            .try_to_u64()
+            .and_then(|value| value.try_into().ok())
+            .ok_or_else(|| InterpreterError::Internal(format!("{value} is not a valid index")))
+    }
+
+    pub(crate) fn as_array(&self) -> Result<(&im::Vector<Value>, &Type), InterpreterError> {
+        match self {
+            Value::Array { elements, typ } => Ok((elements, typ)),
+            other => Err(InterpreterError::Internal(format!("expected an array, found {other}"))),
+        }
+    }
+
+    pub(crate) fn as_reference(&self) -> Result<usize, InterpreterError> {
+        match self {
+            Value::Reference(address) => Ok(*address),
+            other => {
+                Err(InterpreterError::Internal(format!("expected a reference, found {other}")))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Numeric { value, typ } => write!(f, "{typ} {value}"),
+            Value::Reference(address) => write!(f, "reference {address}"),
+            Value::Array { elements, .. } => {
+                let elements = vecmap(elements, ToString::to_string);
+                write!(f, "[{}]", elements.join(", "))
+            }
+            Value::Function(id) => write!(f, "{id}"),
+            Value::Intrinsic(intrinsic) => write!(f, "{intrinsic}"),
+            Value::ForeignFunction(name) => write!(f, "{name}"),
+        }
+    }
+}
@@ -44,8 +44,12 @@ pub(crate) enum Intrinsic {
     ToBits(Endian),
     ToRadix(Endian),
     BlackBox(BlackBoxFunc),
-    FromField,
-    AsField,
+    /// Integer addition, subtraction or multiplication whose result is reduced modulo its type.
+    WrappingOp(BinaryOp),
+    /// As `WrappingOp`, additionally returning whether the exact result overflowed its type.
+    OverflowingOp(BinaryOp),
+    /// Integer addition, subtraction or multiplication which is clamped to the bounds of its type.
+    SaturatingOp(BinaryOp),
 }
 
 impl std::fmt::Display for Intrinsic {
@@ -66,8 +70,9 @@ impl std::fmt::Display for Intrinsic {
             Intrinsic::ToRadix(Endian::Big) => write!(f, "to_be_radix"),
             Intrinsic::ToRadix(Endian::Little) => write!(f, "to_le_radix"),
             Intrinsic::BlackBox(function) => write!(f, "{function}"),
-            Intrinsic::FromField => write!(f, "from_field"),
-            Intrinsic::AsField => write!(f, "as_field"),
+            Intrinsic::WrappingOp(operator) => write!(f, "wrapping_{operator}"),
+            Intrinsic::OverflowingOp(operator) => write!(f, "overflowing_{operator}"),
+            Intrinsic::SaturatingOp(operator) => write!(f, "saturating_{operator}"),
         }
     }
 }
@@ -91,8 +96,9 @@ impl Intrinsic {
             | Intrinsic::StrAsBytes
             | Intrinsic::ToBits(_)
             | Intrinsic::ToRadix(_)
-            | Intrinsic::FromField
-            | Intrinsic::AsField => false,
+            | Intrinsic::WrappingOp(_)
+            | Intrinsic::OverflowingOp(_)
+            | Intrinsic::SaturatingOp(_) => false,
 
             // Some black box functions have side-effects
             Intrinsic::BlackBox(func) => matches!(func, BlackBoxFunc::RecursiveAggregation),
@@ -117,8 +123,15 @@ impl Intrinsic {
             "to_be_radix" => Some(Intrinsic::ToRadix(Endian::Big)),
             "to_le_bits" => Some(Intrinsic::ToBits(Endian::Little)),
             "to_be_bits" => Some(Intrinsic::ToBits(Endian::Big)),
-            "from_field" => Some(Intrinsic::FromField),
-            "as_field" => Some(Intrinsic::AsField),
+            "wrapping_add" => Some(Intrinsic::WrappingOp(BinaryOp::Add)),
+            "wrapping_sub" => Some(Intrinsic::WrappingOp(BinaryOp::Sub)),
+            "wrapping_mul" => Some(Intrinsic::WrappingOp(BinaryOp::Mul)),
+            "overflowing_add" => Some(Intrinsic::OverflowingOp(BinaryOp::Add)),
+            "overflowing_sub" => Some(Intrinsic::OverflowingOp(BinaryOp::Sub)),
+            "overflowing_mul" => Some(Intrinsic::OverflowingOp(BinaryOp::Mul)),
+            "saturating_add" => Some(Intrinsic::SaturatingOp(BinaryOp::Add)),
+            "saturating_sub" => Some(Intrinsic::SaturatingOp(BinaryOp::Sub)),
+            "saturating_mul" => Some(Intrinsic::SaturatingOp(BinaryOp::Mul)),
             other => BlackBoxFunc::lookup(other).map(Intrinsic::BlackBox),
         }
     }
@@ -399,12 +412,7 @@ impl Instruction {
     ///
     /// The `block` parameter indicates the block this new instruction will be inserted into
     /// after this call.
-    pub(crate) fn simplify(
-        &self,
-        dfg: &mut DataFlowGraph,
-        block: BasicBlockId,
-        ctrl_typevars: Option<Vec<Type>>,
-    ) -> SimplifyResult {
+    pub(crate) fn simplify(&self, dfg: &mut DataFlowGraph, block: BasicBlockId) -> SimplifyResult {
         use SimplifyResult::*;
         match self {
             Instruction::Binary(binary) => binary.simplify(dfg),
@@ -465,16 +473,13 @@ impl Instruction {
             }
             Instruction::Truncate { value, bit_size, .. } => {
                 if let Some((numeric_constant, typ)) = dfg.get_numeric_constant_with_type(*value) {
-                    let integer_modulus = 2_u128.pow(*bit_size);
-                    let truncated = numeric_constant.to_u128() % integer_modulus;
+                    let truncated = truncate(numeric_constant.to_u128(), *bit_size);
                     SimplifiedTo(dfg.make_constant(truncated.into(), typ))
                 } else {
                     None
                 }
             }
-            Instruction::Call { func, arguments } => {
-                simplify_call(*func, arguments, dfg, block, ctrl_typevars)
-            }
+            Instruction::Call { func, arguments } => simplify_call(*func, arguments, dfg, block),
             Instruction::EnableSideEffects { condition } => {
                 if let Some(last) = dfg[block].instructions().last().copied() {
                     let last = &mut dfg[last];
@@ -860,7 +865,7 @@ fn eval_constant_binary_op(
             }
             let result = function(lhs, rhs)?;
             // Check for overflow
-            if result >= 2u128.pow(*bit_size) {
+            if truncate(result, *bit_size) != result {
                 return None;
             }
             result.into()
@@ -868,12 +873,8 @@ fn eval_constant_binary_op(
         Type::Numeric(NumericType::Signed { bit_size }) => {
             let function = operator.get_i128_function();
 
-            let lhs = truncate(lhs.try_into_u128()?, *bit_size);
-            let rhs = truncate(rhs.try_into_u128()?, *bit_size);
-            let l_pos = lhs < 2u128.pow(bit_size - 1);
-            let r_pos = rhs < 2u128.pow(bit_size - 1);
-            let lhs = if l_pos { lhs as i128 } else { -((2u128.pow(*bit_size) - lhs) as i128) };
-            let rhs = if r_pos { rhs as i128 } else { -((2u128.pow(*bit_size) - rhs) as i128) };
+            let lhs = to_signed(truncate(lhs.try_into_u128()?, *bit_size), *bit_size);
+            let rhs = to_signed(truncate(rhs.try_into_u128()?, *bit_size), *bit_size);
             // The divisor is being truncated into the type of the operand, which can potentially
             // lead to the rhs being zero.
             // If the rhs of a division is zero, attempting to evaluate the division will cause a compiler panic.
@@ -884,13 +885,13 @@ fn eval_constant_binary_op(
             }
 
             let result = function(lhs, rhs)?;
+            // Negative results are represented in two's complement.
+            let unsigned_result = truncate(result as u128, *bit_size);
             // Check for overflow
-            if result >= 2i128.pow(*bit_size - 1) || result < -(2i128.pow(*bit_size - 1)) {
+            if to_signed(unsigned_result, *bit_size) != result {
                 return None;
             }
-            let result =
-                if result >= 0 { result as u128 } else { (2i128.pow(*bit_size) + result) as u128 };
-            result.into()
+            unsigned_result.into()
         }
         _ => return None,
     };
@@ -903,8 +904,81 @@ fn eval_constant_binary_op(
 }
 
 fn truncate(int: u128, bit_size: u32) -> u128 {
-    let max = 2u128.pow(bit_size);
-    int % max
+    match 2u128.checked_pow(bit_size) {
+        Some(max) => int % max,
+        None => int,
+    }
+}
+
+/// Evaluates the addition, subtraction or multiplication of two integer constants of type `typ`,
+/// returning the result reduced modulo the type and whether the exact result overflowed it.
+///
+/// Operations on fields never overflow. Returns `None` for any other operator.
+pub(crate) fn evaluate_overflowing_op(
+    operator: BinaryOp,
+    lhs: FieldElement,
+    rhs: FieldElement,
+    typ: NumericType,
+) -> Option<(FieldElement, bool)> {
+    if !matches!(operator, BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul) {
+        return None;
+    }
+    let bit_size = match typ {
+        NumericType::NativeField => return Some((operator.get_field_function()?(lhs, rhs), false)),
+        NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => bit_size,
+    };
+    let lhs = truncate(lhs.try_into_u128()?, bit_size);
+    let rhs = truncate(rhs.try_into_u128()?, bit_size);
+
+    let overflow = if let NumericType::Signed { .. } = typ {
+        let exact =
+            operator.get_i128_function()(to_signed(lhs, bit_size), to_signed(rhs, bit_size));
+        exact.map_or(true, |exact| to_signed(truncate(exact as u128, bit_size), bit_size) != exact)
+    } else {
+        let exact = operator.get_u128_function()(lhs, rhs);
+        exact.map_or(true, |exact| truncate(exact, bit_size) != exact)
+    };
+
+    // Two's complement arithmetic is the same for signed and unsigned integers.
+    let wrapped = match operator {
+        BinaryOp::Add => lhs.wrapping_add(rhs),
+        BinaryOp::Sub => lhs.wrapping_sub(rhs),
+        _ => lhs.wrapping_mul(rhs),
+    };
+    Some((truncate(wrapped, bit_size).into(), overflow))
+}
+
+/// Returns the bound of the integer type `typ` which `lhs operator rhs` is clamped to when it
+/// overflows.
+pub(crate) fn saturation_bound(
+    operator: BinaryOp,
+    lhs: FieldElement,
+    rhs: FieldElement,
+    typ: NumericType,
+) -> FieldElement {
+    match typ {
+        NumericType::Unsigned { .. } if operator == BinaryOp::Sub => FieldElement::zero(),
+        NumericType::Unsigned { bit_size } => truncate(u128::MAX, bit_size).into(),
+        NumericType::Signed { bit_size } => {
+            let is_negative = |value: FieldElement| value.num_bits() == bit_size;
+            // Additions and subtractions only overflow away from zero on the side of `lhs`.
+            let negative = match operator {
+                BinaryOp::Mul => is_negative(lhs) != is_negative(rhs),
+                _ => is_negative(lhs),
+            };
+            // The minimum is represented as 2^(bit_size-1), one above the maximum.
+            let maximum = (1u128 << (bit_size - 1)) - 1;
+            (maximum + negative as u128).into()
+        }
+        NumericType::NativeField => unreachable!("fields do not overflow"),
+    }
+}
+
+/// Interprets the `bit_size`-bit two's complement integer `int` as a signed integer.
+fn to_signed(int: u128, bit_size: u32) -> i128 {
+    let shift = 128 - bit_size;
+    // Shifting the sign bit into place then back sign-extends the value.
+    ((int << shift) as i128) >> shift
 }
 
 impl BinaryOp {
@@ -1037,3 +1111,86 @@ impl SimplifyResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use acvm::FieldElement;
+
+    use super::{eval_constant_binary_op, to_signed, truncate, BinaryOp};
+    use crate::ssa::ir::types::Type;
+
+    /// Evaluates `lhs operator rhs` on the `bit_size`-bit two's complement encodings of the operands.
+    fn eval(lhs: u128, operator: BinaryOp, rhs: u128, typ: Type) -> Option<u128> {
+        let (result, _) = eval_constant_binary_op(lhs.into(), rhs.into(), operator, typ)?;
+        Some(result.to_u128())
+    }
+
+    #[test]
+    fn truncates_to_128_bits() {
+        assert_eq!(truncate(u128::MAX, 128), u128::MAX);
+        assert_eq!(truncate(u128::MAX, 127), u128::MAX >> 1);
+        assert_eq!(truncate(1 << 127, 127), 0);
+    }
+
+    #[test]
+    fn interprets_128_bit_integers_as_signed() {
+        assert_eq!(to_signed(u128::MAX, 128), -1);
+        assert_eq!(to_signed(1 << 127, 128), i128::MIN);
+        assert_eq!(to_signed(u128::MAX >> 1, 128), i128::MAX);
+        assert_eq!(to_signed(u128::MAX >> 1, 127), -1);
+        assert_eq!(to_signed(0b11, 2), -1);
+    }
+
+    #[test]
+    fn evaluates_u128_arithmetic() {
+        let u128_type = Type::unsigned(128);
+        assert_eq!(eval(u128::MAX - 1, BinaryOp::Add, 1, u128_type.clone()), Some(u128::MAX));
+        assert_eq!(eval(1 << 64, BinaryOp::Mul, 1 << 63, u128_type.clone()), Some(1 << 127));
+        assert_eq!(eval(u128::MAX, BinaryOp::Div, 3, u128_type.clone()), Some(u128::MAX / 3));
+
+        // Overflowing operations are left for code generation to fail on.
+        assert_eq!(eval(u128::MAX, BinaryOp::Add, 1, u128_type.clone()), None);
+        assert_eq!(eval(0, BinaryOp::Sub, 1, u128_type.clone()), None);
+        assert_eq!(eval(1 << 64, BinaryOp::Mul, 1 << 64, u128_type.clone()), None);
+        assert_eq!(eval(1, BinaryOp::Div, 0, u128_type), None);
+    }
+
+    #[test]
+    fn evaluates_i128_arithmetic() {
+        let i128_type = Type::signed(128);
+        let minus_one = u128::MAX;
+        let min = 1 << 127;
+        assert_eq!(
+            eval(minus_one, BinaryOp::Add, minus_one, i128_type.clone()),
+            Some(u128::MAX - 1)
+        );
+        assert_eq!(eval(min, BinaryOp::Div, 2, i128_type.clone()), Some(3 << 126));
+        assert_eq!(eval(minus_one, BinaryOp::Mul, min - 1, i128_type.clone()), Some(min + 1));
+
+        // i128::MIN has no positive counterpart.
+        assert_eq!(eval(min, BinaryOp::Sub, 1, i128_type.clone()), None);
+        assert_eq!(eval(min, BinaryOp::Mul, minus_one, i128_type.clone()), None);
+        assert_eq!(eval(min, BinaryOp::Div, minus_one, i128_type), None);
+    }
+
+    #[test]
+    fn compares_128_bit_integers() {
+        let (result, typ) = eval_constant_binary_op(
+            u128::MAX.into(),
+            0u128.into(),
+            BinaryOp::Lt,
+            Type::signed(128),
+        )
+        .unwrap();
+        assert_eq!((result, typ), (FieldElement::one(), Type::bool()));
+
+        let (result, _) = eval_constant_binary_op(
+            u128::MAX.into(),
+            0u128.into(),
+            BinaryOp::Lt,
+            Type::unsigned(128),
+        )
+        .unwrap();
+        assert_eq!(result, FieldElement::zero());
+    }
+}
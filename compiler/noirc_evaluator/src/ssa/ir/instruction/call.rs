@@ -10,13 +10,16 @@ use crate::ssa::{
         dfg::{CallStack, DataFlowGraph},
         instruction::Intrinsic,
         map::Id,
-        types::Type,
+        types::{NumericType, Type},
         value::{Value, ValueId},
     },
     opt::flatten_cfg::value_merger::ValueMerger,
 };
 
-use super::{Binary, BinaryOp, Endian, Instruction, SimplifyResult};
+use super::{
+    evaluate_overflowing_op, saturation_bound, Binary, BinaryOp, Endian, Instruction,
+    SimplifyResult,
+};
 
 /// Try to simplify this call instruction. If the instruction can be simplified to a known value,
 /// that value is returned. Otherwise None is returned.
@@ -30,7 +33,6 @@ pub(super) fn simplify_call(
     arguments: &[ValueId],
     dfg: &mut DataFlowGraph,
     block: BasicBlockId,
-    ctrl_typevars: Option<Vec<Type>>,
 ) -> SimplifyResult {
     let intrinsic = match &dfg[func] {
         Value::Intrinsic(intrinsic) => *intrinsic,
@@ -234,17 +236,63 @@ pub(super) fn simplify_call(
         }
         Intrinsic::BlackBox(bb_func) => simplify_black_box_func(bb_func, arguments, dfg),
         Intrinsic::Sort => simplify_sort(dfg, arguments),
-        Intrinsic::AsField => {
-            let instruction = Instruction::Cast(
-                arguments[0],
-                Type::Numeric(crate::ssa::ir::types::NumericType::NativeField),
-            );
-            SimplifyResult::SimplifiedToInstruction(instruction)
+        Intrinsic::WrappingOp(_) | Intrinsic::OverflowingOp(_) | Intrinsic::SaturatingOp(_) => {
+            simplify_integer_op(intrinsic, arguments, dfg, block)
         }
-        Intrinsic::FromField => {
-            let instruction = Instruction::Cast(arguments[0], ctrl_typevars.unwrap().remove(0));
-            SimplifyResult::SimplifiedToInstruction(instruction)
+    }
+}
+
+/// Simplifies a wrapping, overflowing or saturating integer operation.
+///
+/// Constant operands are evaluated, and operations on fields, which never overflow, are replaced
+/// with the plain binary operation.
+fn simplify_integer_op(
+    intrinsic: Intrinsic,
+    arguments: &[ValueId],
+    dfg: &mut DataFlowGraph,
+    block: BasicBlockId,
+) -> SimplifyResult {
+    let (Intrinsic::WrappingOp(operator)
+    | Intrinsic::OverflowingOp(operator)
+    | Intrinsic::SaturatingOp(operator)) = intrinsic
+    else {
+        unreachable!("{intrinsic} is not an integer operation")
+    };
+    let Type::Numeric(typ) = dfg.type_of_value(arguments[0]) else {
+        return SimplifyResult::None;
+    };
+
+    let constants =
+        (dfg.get_numeric_constant(arguments[0]), dfg.get_numeric_constant(arguments[1]));
+    let (result, overflow) = match constants {
+        (Some(lhs), Some(rhs)) => {
+            let Some((wrapped, overflow)) = evaluate_overflowing_op(operator, lhs, rhs, typ)
+            else {
+                return SimplifyResult::None;
+            };
+            let result = match intrinsic {
+                Intrinsic::SaturatingOp(_) if overflow => saturation_bound(operator, lhs, rhs, typ),
+                _ => wrapped,
+            };
+            (dfg.make_constant(result, Type::Numeric(typ)), overflow)
+        }
+        _ if typ == NumericType::NativeField => {
+            let instruction =
+                Instruction::Binary(Binary { lhs: arguments[0], operator, rhs: arguments[1] });
+            let call_stack = dfg.get_value_call_stack(arguments[0]);
+            (
+                dfg.insert_instruction_and_results(instruction, block, None, call_stack).first(),
+                false,
+            )
         }
+        _ => return SimplifyResult::None,
+    };
+
+    if let Intrinsic::OverflowingOp(_) = intrinsic {
+        let overflow = dfg.make_constant(overflow.into(), Type::bool());
+        SimplifyResult::SimplifiedToMultiple(vec![result, overflow])
+    } else {
+        SimplifyResult::SimplifiedTo(result)
     }
 }
 
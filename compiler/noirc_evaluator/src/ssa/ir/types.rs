@@ -43,6 +43,11 @@ impl Type {
         matches!(self, Type::Numeric(NumericType::Unsigned { .. }))
     }
 
+    /// Returns whether the `Type` is an integer type whose products may not fit in a field.
+    pub(crate) fn is_wide_integer(&self) -> bool {
+        matches!(self, Type::Numeric(numeric_type) if numeric_type.is_wide())
+    }
+
     /// Create a new signed integer type with the given amount of bits.
     pub(crate) fn signed(bit_size: u32) -> Type {
         Type::Numeric(NumericType::Signed { bit_size })
@@ -128,18 +133,28 @@ impl NumericType {
     pub(crate) fn value_is_within_limits(self, field: FieldElement) -> bool {
         match self {
             NumericType::Signed { bit_size } => {
-                let min = -(2i128.pow(bit_size - 1));
-                let max = 2u128.pow(bit_size - 1) - 1;
+                let half =
+                    FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128 - 1));
                 // Signed integers are odd since they will overflow the field value
-                field <= max.into() || field >= min.into()
-            }
-            NumericType::Unsigned { bit_size } => {
-                let max = 2u128.pow(bit_size) - 1;
-                field <= max.into()
+                field < half || field >= -half
             }
+            NumericType::Unsigned { bit_size } => field.num_bits() <= bit_size,
             NumericType::NativeField => true,
         }
     }
+
+    /// Returns true if this is an integer type whose values can be multiplied together with a
+    /// result which does not fit in a field element.
+    ///
+    /// ACIR generation splits the operands of such multiplications into limbs.
+    pub(crate) fn is_wide(self) -> bool {
+        match self {
+            NumericType::Signed { bit_size } | NumericType::Unsigned { bit_size } => {
+                2 * bit_size >= FieldElement::max_num_bits()
+            }
+            NumericType::NativeField => false,
+        }
+    }
 }
 
 /// Composite Types are essentially flattened struct or tuple types.
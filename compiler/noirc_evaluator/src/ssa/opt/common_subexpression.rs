@@ -0,0 +1,255 @@
+//! The common subexpression elimination (CSE) pass removes instructions which recompute a value that
+//! is already available from an identical instruction in a dominating block.
+//!
+//! Unlike [constant folding][super::constant_folding], which only removes duplicated instructions
+//! within a single block, this pass works across blocks using the function's [`DominatorTree`]:
+//! - Blocks are visited in reverse post-order, so every dominator of a block is visited before the block itself.
+//! - Each [pure][Instruction::is_pure()] instruction is resolved and looked up in a cache of previously
+//!   seen instructions. If an identical instruction exists in a block which dominates the current one,
+//!   the instruction is removed and its results are replaced with the results of the dominating instruction.
+//! - Otherwise the instruction is kept and added to the cache.
+//!
+//! This is mostly useful for functions which still contain multiple blocks, i.e. before flattening
+//! for ACIR functions and throughout the pipeline for Brillig functions.
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        dfg::DataFlowGraph,
+        dom::DominatorTree,
+        function::Function,
+        instruction::{Instruction, InstructionId},
+        post_order::PostOrder,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+use fxhash::FxHashMap as HashMap;
+
+impl Ssa {
+    /// Removes instructions which are duplicates of an instruction in a dominating block.
+    ///
+    /// See [`common_subexpression`][self] module for more information.
+    pub(crate) fn common_subexpression_elimination(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            common_subexpression_elimination(function);
+        }
+        self
+    }
+}
+
+fn common_subexpression_elimination(function: &mut Function) {
+    let post_order = PostOrder::with_function(function);
+    let mut dom_tree = DominatorTree::with_function(function);
+
+    // Each cached instruction maps to the blocks it has been found in, along with its results in that block.
+    let mut cached_instructions: HashMap<Instruction, Vec<(BasicBlockId, Vec<ValueId>)>> =
+        HashMap::default();
+
+    for block in post_order.as_slice().iter().rev() {
+        let instructions = function.dfg[*block].take_instructions();
+        let mut remaining_instructions = Vec::with_capacity(instructions.len());
+
+        for instruction_id in instructions {
+            let instruction = resolve_instruction(instruction_id, &function.dfg);
+            if !can_be_deduplicated(&instruction, &function.dfg) {
+                remaining_instructions.push(instruction_id);
+                continue;
+            }
+
+            let cached_results = cached_instructions.entry(instruction).or_default();
+            let dominating_results = cached_results
+                .iter()
+                .find(|(cached_block, _)| dom_tree.dominates(*cached_block, *block))
+                .map(|(_, results)| results);
+
+            if let Some(dominating_results) = dominating_results {
+                let old_results = function.dfg.instruction_results(instruction_id).to_vec();
+                for (old_result, new_result) in old_results.into_iter().zip(dominating_results) {
+                    function.dfg.set_value_from_id(old_result, *new_result);
+                }
+            } else {
+                let results = function.dfg.instruction_results(instruction_id).to_vec();
+                cached_results.push((*block, results));
+                remaining_instructions.push(instruction_id);
+            }
+        }
+
+        *function.dfg[*block].instructions_mut() = remaining_instructions;
+    }
+}
+
+/// Fetches an [`Instruction`] by its [`InstructionId`] and fully resolves its inputs.
+fn resolve_instruction(instruction_id: InstructionId, dfg: &DataFlowGraph) -> Instruction {
+    dfg[instruction_id].map_values(|value_id| dfg.resolve(value_id))
+}
+
+/// Returns true if later copies of `instruction` can reuse the results of an earlier copy.
+///
+/// Array sets and calls are excluded even when pure as they may produce arrays which Brillig mutates
+/// in place, in which case sharing a single array between what were separate values is unsafe.
+fn can_be_deduplicated(instruction: &Instruction, dfg: &DataFlowGraph) -> bool {
+    match instruction {
+        Instruction::ArraySet { .. } | Instruction::Call { .. } => false,
+        _ => instruction.is_pure(dfg),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use acvm::brillig_vm::brillig::Value;
+
+    use crate::{
+        brillig::{brillig_gen::convert_ssa_function, brillig_ir::tests::run_brillig_function},
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                basic_block::BasicBlockId, function::RuntimeType, instruction::BinaryOp, map::Id,
+                types::Type,
+            },
+            opt::tests::acir_opcode_count,
+            ssa_gen::Ssa,
+        },
+    };
+
+    /// Builds the following function, either as an ACIR or a Brillig function.
+    ///
+    /// ```text
+    /// fn main f0 {
+    ///   b0(v0: Field, v1: Field, v2: u1):
+    ///     v3 = mul v0, v1
+    ///     jmpif v2, then: b1, else: b2
+    ///   b1():
+    ///     v4 = mul v0, v1
+    ///     jmp b3(v4)
+    ///   b2():
+    ///     v5 = mul v0, v1
+    ///     jmp b3(v5)
+    ///   b3(v6: Field):
+    ///     v7 = add v6, Field 1
+    ///     return v7
+    /// }
+    /// ```
+    fn branches_repeating_multiplication(runtime: RuntimeType) -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, runtime);
+
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+        let b3 = builder.insert_block();
+
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::field());
+        let v2 = builder.add_parameter(Type::bool());
+        let v6 = builder.add_block_parameter(b3, Type::field());
+
+        let one = builder.field_constant(1u128);
+
+        builder.insert_binary(v0, BinaryOp::Mul, v1);
+        builder.terminate_with_jmpif(v2, b1, b2);
+
+        builder.switch_to_block(b1);
+        let v4 = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        builder.terminate_with_jmp(b3, vec![v4]);
+
+        builder.switch_to_block(b2);
+        let v5 = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        builder.terminate_with_jmp(b3, vec![v5]);
+
+        builder.switch_to_block(b3);
+        let v7 = builder.insert_binary(v6, BinaryOp::Add, one);
+        builder.terminate_with_return(vec![v7]);
+
+        builder.finish()
+    }
+
+    #[test]
+    fn removes_instructions_computed_in_dominating_block() {
+        // After the pass, the multiplications in b1 and b2 are replaced by v3:
+        //
+        // fn main f0 {
+        //   b0(v0: Field, v1: Field, v2: u1):
+        //     v3 = mul v0, v1
+        //     jmpif v2, then: b1, else: b2
+        //   b1():
+        //     jmp b3(v3)
+        //   b2():
+        //     jmp b3(v3)
+        //   b3(v6: Field):
+        //     v7 = add v6, Field 1
+        //     return v7
+        // }
+        let ssa = branches_repeating_multiplication(RuntimeType::Acir);
+        let ssa = ssa.common_subexpression_elimination();
+
+        let main = ssa.main();
+        let then_block: BasicBlockId = Id::test_new(1);
+        let else_block: BasicBlockId = Id::test_new(2);
+        assert_eq!(main.dfg[main.entry_block()].instructions().len(), 1);
+        assert!(main.dfg[then_block].instructions().is_empty());
+        assert!(main.dfg[else_block].instructions().is_empty());
+
+        let v3 = main.dfg.instruction_results(main.dfg[main.entry_block()].instructions()[0])[0];
+        for block in [then_block, else_block] {
+            let arguments = main.dfg[block].terminator_arguments();
+            assert_eq!(main.dfg.resolve(arguments[0]), v3);
+        }
+    }
+
+    #[test]
+    fn does_not_remove_instructions_from_sibling_blocks() {
+        // Without the multiplication in b0 neither of the remaining multiplications dominates the other.
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+
+        let v0 = builder.add_parameter(Type::field());
+        let v1 = builder.add_parameter(Type::bool());
+        builder.terminate_with_jmpif(v1, b1, b2);
+
+        builder.switch_to_block(b1);
+        let v2 = builder.insert_binary(v0, BinaryOp::Mul, v0);
+        builder.terminate_with_return(vec![v2]);
+
+        builder.switch_to_block(b2);
+        let v3 = builder.insert_binary(v0, BinaryOp::Mul, v0);
+        builder.terminate_with_return(vec![v3]);
+
+        let ssa = builder.finish().common_subexpression_elimination();
+        let main = ssa.main();
+        assert_eq!(main.dfg[b1].instructions().len(), 1);
+        assert_eq!(main.dfg[b2].instructions().len(), 1);
+    }
+
+    #[test]
+    fn reduces_acir_opcode_count() {
+        let ssa = branches_repeating_multiplication(RuntimeType::Acir);
+        let unoptimized_opcodes = acir_opcode_count(ssa);
+
+        let ssa = branches_repeating_multiplication(RuntimeType::Acir);
+        let optimized_opcodes = acir_opcode_count(ssa.common_subexpression_elimination());
+
+        // Both branches now return the same value so merging them after flattening is free.
+        assert!(
+            optimized_opcodes < unoptimized_opcodes,
+            "expected fewer than {unoptimized_opcodes} opcodes, got {optimized_opcodes}"
+        );
+    }
+
+    #[test]
+    fn reduces_brillig_instruction_count() {
+        let ssa = branches_repeating_multiplication(RuntimeType::Brillig);
+        let unoptimized_bytecode = convert_ssa_function(ssa.main(), false, None).byte_code.len();
+        let arguments = vec![Value::from(3_usize), Value::from(5_usize), Value::from(1_usize)];
+        let unoptimized_executed = run_brillig_function(ssa.main(), arguments.clone());
+
+        let ssa = branches_repeating_multiplication(RuntimeType::Brillig);
+        let ssa = ssa.common_subexpression_elimination();
+        let optimized_bytecode = convert_ssa_function(ssa.main(), false, None).byte_code.len();
+        let optimized_executed = run_brillig_function(ssa.main(), arguments);
+
+        assert!(optimized_bytecode < unoptimized_bytecode);
+        assert!(optimized_executed < unoptimized_executed);
+    }
+}
@@ -765,6 +765,62 @@ mod test {
         assert_eq!(ssa.main().reachable_blocks().len(), 1);
     }
 
+    #[test]
+    fn jmpif_with_identical_values_is_not_merged() {
+        // fn main f0 {
+        //   b0(v0: u1, v1: Field):
+        //     jmpif v0, then: b1, else: b2
+        //   b1():
+        //     jmp b3(v1)
+        //   b2():
+        //     jmp b3(v1)
+        //   b3(v2: Field):
+        //     return v2
+        // }
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+        let b3 = builder.insert_block();
+
+        let v0 = builder.add_parameter(Type::bool());
+        let v1 = builder.add_parameter(Type::field());
+        let v2 = builder.add_block_parameter(b3, Type::field());
+
+        builder.terminate_with_jmpif(v0, b1, b2);
+
+        builder.switch_to_block(b1);
+        builder.terminate_with_jmp(b3, vec![v1]);
+
+        builder.switch_to_block(b2);
+        builder.terminate_with_jmp(b3, vec![v1]);
+
+        builder.switch_to_block(b3);
+        builder.terminate_with_return(vec![v2]);
+
+        // Expected output:
+        // fn main f0 {
+        //   b0(v0: u1, v1: Field):
+        //     enable_side_effects v0
+        //     v3 = not v0
+        //     enable_side_effects v3
+        //     enable_side_effects u1 1
+        //     return v1
+        // }
+        let ssa = builder.finish().flatten_cfg();
+        let main = ssa.main();
+        assert_eq!(main.reachable_blocks().len(), 1);
+        assert_eq!(count_instruction(main, |ins| matches!(ins, Instruction::Binary(_))), 0);
+
+        match main.dfg[main.entry_block()].terminator() {
+            Some(TerminatorInstruction::Return { return_values, .. }) => {
+                assert_eq!(return_values, &vec![v1]);
+            }
+            _ => unreachable!("Should have terminated with a return"),
+        }
+    }
+
     #[test]
     fn modify_constrain() {
         // fn main f0 {
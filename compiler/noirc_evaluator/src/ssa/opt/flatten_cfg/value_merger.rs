@@ -41,6 +41,8 @@ impl<'a> ValueMerger<'a> {
     /// Otherwise, if the values being merged are arrays, a new array will be made
     /// recursively from combining each element of both input arrays.
     ///
+    /// If both branches produce the same value, that value is returned without merging.
+    ///
     /// It is currently an error to call this function on reference or function values
     /// as it is less clear how to merge these.
     pub(crate) fn merge_values(
@@ -50,6 +52,12 @@ impl<'a> ValueMerger<'a> {
         then_value: ValueId,
         else_value: ValueId,
     ) -> ValueId {
+        let then_value = self.dfg.resolve(then_value);
+        let else_value = self.dfg.resolve(else_value);
+        if then_value == else_value {
+            return then_value;
+        }
+
         match self.dfg.type_of_value(then_value) {
             Type::Numeric(_) => {
                 self.merge_numeric_values(then_condition, else_condition, then_value, else_value)
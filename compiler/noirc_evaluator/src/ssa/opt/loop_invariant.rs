@@ -0,0 +1,308 @@
+//! The loop invariant code motion pass moves instructions whose results do not change between
+//! iterations of a loop into the loop's pre-header, so that they are only evaluated once.
+//!
+//! The pass works as follows:
+//! - Find all loops in the function (reusing the loop analysis from [`unrolling`][super::unrolling]).
+//!   Inner loops are processed before the loops containing them so that instructions can be
+//!   hoisted through several levels of nesting.
+//! - For each loop with a single pre-header, collect every value defined within the loop.
+//! - Visit the blocks of the loop in reverse post-order. Any instruction which is safe to hoist and
+//!   whose arguments are all defined outside of the loop is moved to the end of the pre-header.
+//!   Its results are then no longer considered to be defined within the loop, so instructions
+//!   depending on them may be hoisted as well.
+//!
+//! This pass runs before loop unrolling: for ACIR functions this avoids re-evaluating the
+//! invariant instructions in each unrolled iteration, while Brillig functions keep their loops
+//! rolled and so save these instructions on every iteration at runtime.
+use std::collections::HashSet;
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        cfg::ControlFlowGraph,
+        dfg::DataFlowGraph,
+        dom::DominatorTree,
+        function::Function,
+        instruction::{Instruction, InstructionId},
+        post_order::PostOrder,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+use super::unrolling::{find_all_loops, Loop};
+
+impl Ssa {
+    /// Moves loop invariant instructions out of each loop and into the loop's pre-header.
+    ///
+    /// See [`loop_invariant`][self] module for more information.
+    pub(crate) fn loop_invariant_code_motion(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            loop_invariant_code_motion(function);
+        }
+        self
+    }
+}
+
+fn loop_invariant_code_motion(function: &mut Function) {
+    let loops = find_all_loops(function);
+
+    let post_order = PostOrder::with_function(function);
+    let dom_tree = DominatorTree::with_cfg_and_post_order(&loops.cfg, &post_order);
+
+    // Loops are sorted by the number of blocks they contain so inner loops are handled first.
+    for loop_ in &loops.yet_to_unroll {
+        if let Some(pre_header) = get_pre_header(&loops.cfg, loop_) {
+            hoist_loop_invariants(function, &dom_tree, loop_, pre_header);
+        }
+    }
+}
+
+/// Returns the single block outside of the loop which jumps to the loop header.
+///
+/// Returns `None` if there are multiple such blocks as there is then no single block
+/// which the invariant instructions could be moved into.
+fn get_pre_header(cfg: &ControlFlowGraph, loop_: &Loop) -> Option<BasicBlockId> {
+    let mut predecessors =
+        cfg.predecessors(loop_.header).filter(|predecessor| !loop_.blocks.contains(predecessor));
+
+    let pre_header = predecessors.next()?;
+    predecessors.next().is_none().then_some(pre_header)
+}
+
+fn hoist_loop_invariants(
+    function: &mut Function,
+    dom_tree: &DominatorTree,
+    loop_: &Loop,
+    pre_header: BasicBlockId,
+) {
+    let mut blocks: Vec<_> = loop_.blocks.iter().copied().collect();
+    blocks.sort_by(|a, b| dom_tree.reverse_post_order_cmp(*a, *b));
+
+    let mut defined_in_loop = values_defined_in_loop(&function.dfg, &blocks);
+
+    for block in blocks {
+        let instructions = function.dfg[block].take_instructions();
+        let mut remaining_instructions = Vec::with_capacity(instructions.len());
+
+        for instruction_id in instructions {
+            if can_hoist_instruction(&function.dfg, instruction_id, &defined_in_loop) {
+                for result in function.dfg.instruction_results(instruction_id) {
+                    defined_in_loop.remove(result);
+                }
+                function.dfg[pre_header].insert_instruction(instruction_id);
+            } else {
+                remaining_instructions.push(instruction_id);
+            }
+        }
+
+        *function.dfg[block].instructions_mut() = remaining_instructions;
+    }
+}
+
+/// Collects the block parameters and instruction results of each block in the loop.
+fn values_defined_in_loop(dfg: &DataFlowGraph, blocks: &[BasicBlockId]) -> HashSet<ValueId> {
+    let mut defined_in_loop = HashSet::new();
+    for block in blocks {
+        defined_in_loop.extend(dfg[*block].parameters());
+        for instruction in dfg[*block].instructions() {
+            defined_in_loop.extend(dfg.instruction_results(*instruction));
+        }
+    }
+    defined_in_loop
+}
+
+/// An instruction can be hoisted if it is invariant, i.e. none of its arguments are defined within
+/// the loop, and if evaluating it unconditionally is safe.
+///
+/// As the instruction may be moved out of a conditional block within the loop or out of a loop which
+/// is never entered, only instructions which can never fail are considered. Instructions which
+/// interact with memory or have side-effects are always left in place.
+fn can_hoist_instruction(
+    dfg: &DataFlowGraph,
+    instruction_id: InstructionId,
+    defined_in_loop: &HashSet<ValueId>,
+) -> bool {
+    let instruction = &dfg[instruction_id];
+
+    let can_be_evaluated_unconditionally = match instruction {
+        Instruction::Binary(_)
+        | Instruction::Cast(_, _)
+        | Instruction::Not(_)
+        | Instruction::Truncate { .. } => !instruction.has_side_effects(dfg),
+        _ => false,
+    };
+    if !can_be_evaluated_unconditionally {
+        return false;
+    }
+
+    let mut is_invariant = true;
+    instruction.for_each_value(|value| {
+        is_invariant &= !defined_in_loop.contains(&dfg.resolve(value));
+    });
+    is_invariant
+}
+
+#[cfg(test)]
+mod test {
+    use acvm::brillig_vm::brillig::Value;
+
+    use crate::{
+        brillig::brillig_ir::tests::run_brillig_function,
+        ssa::{
+            function_builder::FunctionBuilder,
+            ir::{
+                basic_block::BasicBlockId,
+                function::RuntimeType,
+                instruction::{BinaryOp, Instruction},
+                map::Id,
+                types::Type,
+            },
+            opt::tests::acir_opcode_count,
+            ssa_gen::Ssa,
+        },
+    };
+
+    /// Builds the following function, either as an ACIR or a Brillig function.
+    ///
+    /// ```text
+    /// fn main f0 {
+    ///   b0(v0: u32, v1: u32):
+    ///     jmp b1(u32 0)
+    ///   b1(v2: u32):
+    ///     v3 = lt v2, u32 4
+    ///     jmpif v3, then: b2, else: b3
+    ///   b2():
+    ///     v4 = mul v0, v1
+    ///     v5 = truncate v4 to 32 bits, max_bit_size: 64
+    ///     v6 = lt v2, v5
+    ///     constrain v6 == u1 1
+    ///     v7 = add v2, u32 1
+    ///     jmp b1(v7)
+    ///   b3():
+    ///     return
+    /// }
+    /// ```
+    fn loop_with_invariant_multiplication(runtime: RuntimeType) -> Ssa {
+        let main_id = Id::test_new(0);
+        let mut builder = FunctionBuilder::new("main".into(), main_id, runtime);
+
+        let b1 = builder.insert_block();
+        let b2 = builder.insert_block();
+        let b3 = builder.insert_block();
+
+        let v0 = builder.add_parameter(Type::unsigned(32));
+        let v1 = builder.add_parameter(Type::unsigned(32));
+        let v2 = builder.add_block_parameter(b1, Type::unsigned(32));
+
+        let zero = builder.numeric_constant(0u128, Type::unsigned(32));
+        let one = builder.numeric_constant(1u128, Type::unsigned(32));
+        let four = builder.numeric_constant(4u128, Type::unsigned(32));
+        let true_ = builder.numeric_constant(1u128, Type::bool());
+
+        builder.terminate_with_jmp(b1, vec![zero]);
+
+        builder.switch_to_block(b1);
+        let v3 = builder.insert_binary(v2, BinaryOp::Lt, four);
+        builder.terminate_with_jmpif(v3, b2, b3);
+
+        builder.switch_to_block(b2);
+        let v4 = builder.insert_binary(v0, BinaryOp::Mul, v1);
+        let v5 = builder.insert_truncate(v4, 32, 64);
+        let v6 = builder.insert_binary(v2, BinaryOp::Lt, v5);
+        builder.insert_constrain(v6, true_, None);
+        let v7 = builder.insert_binary(v2, BinaryOp::Add, one);
+        builder.terminate_with_jmp(b1, vec![v7]);
+
+        builder.switch_to_block(b3);
+        builder.terminate_with_return(vec![]);
+
+        builder.finish()
+    }
+
+    #[test]
+    fn hoists_invariant_instructions() {
+        // After the pass, the multiplication and truncation are moved into b0:
+        //
+        // fn main f0 {
+        //   b0(v0: u32, v1: u32):
+        //     v4 = mul v0, v1
+        //     v5 = truncate v4 to 32 bits, max_bit_size: 64
+        //     jmp b1(u32 0)
+        //   b1(v2: u32):
+        //     v3 = lt v2, u32 4
+        //     jmpif v3, then: b2, else: b3
+        //   b2():
+        //     v6 = lt v2, v5
+        //     constrain v6 == u1 1
+        //     v7 = add v2, u32 1
+        //     jmp b1(v7)
+        //   b3():
+        //     return
+        // }
+        let ssa = loop_with_invariant_multiplication(RuntimeType::Acir);
+        let ssa = ssa.loop_invariant_code_motion();
+
+        let main = ssa.main();
+        let entry_instructions = main.dfg[main.entry_block()].instructions();
+        assert_eq!(entry_instructions.len(), 2);
+        assert!(matches!(main.dfg[entry_instructions[0]], Instruction::Binary(_)));
+        assert!(matches!(main.dfg[entry_instructions[1]], Instruction::Truncate { .. }));
+
+        let loop_body: BasicBlockId = Id::test_new(2);
+        assert_eq!(main.dfg[loop_body].instructions().len(), 3);
+    }
+
+    #[test]
+    fn does_not_hoist_instructions_depending_on_the_loop() {
+        let ssa = loop_with_invariant_multiplication(RuntimeType::Acir);
+        let instruction_count = |ssa: &Ssa| {
+            let main = ssa.main();
+            main.reachable_blocks()
+                .iter()
+                .map(|block| main.dfg[*block].instructions().len())
+                .sum::<usize>()
+        };
+        let before = instruction_count(&ssa);
+
+        let ssa = ssa.loop_invariant_code_motion();
+        // Only the instructions are moved, none are added or removed.
+        assert_eq!(instruction_count(&ssa), before);
+
+        // The header's comparison against the induction variable must stay in the header.
+        let header: BasicBlockId = Id::test_new(1);
+        assert_eq!(ssa.main().dfg[header].instructions().len(), 1);
+    }
+
+    #[test]
+    fn reduces_acir_opcode_count() {
+        let ssa = loop_with_invariant_multiplication(RuntimeType::Acir);
+        let unoptimized_opcodes = acir_opcode_count(ssa);
+
+        let ssa = loop_with_invariant_multiplication(RuntimeType::Acir);
+        let optimized_opcodes = acir_opcode_count(ssa.loop_invariant_code_motion());
+
+        // The truncation is only performed once rather than once per iteration.
+        assert!(
+            optimized_opcodes < unoptimized_opcodes,
+            "expected fewer than {unoptimized_opcodes} opcodes, got {optimized_opcodes}"
+        );
+    }
+
+    #[test]
+    fn reduces_executed_brillig_opcodes() {
+        let arguments = vec![Value::from(3_usize), Value::from(5_usize)];
+
+        let ssa = loop_with_invariant_multiplication(RuntimeType::Brillig);
+        let unoptimized_opcodes = run_brillig_function(ssa.main(), arguments.clone());
+
+        let ssa = loop_with_invariant_multiplication(RuntimeType::Brillig);
+        let ssa = ssa.loop_invariant_code_motion();
+        let optimized_opcodes = run_brillig_function(ssa.main(), arguments);
+
+        assert!(
+            optimized_opcodes < unoptimized_opcodes,
+            "expected fewer than {unoptimized_opcodes} executed opcodes, got {optimized_opcodes}"
+        );
+    }
+}
@@ -5,12 +5,44 @@
 //! Generally, these passes are also expected to minimize the final amount of instructions.
 mod array_use;
 mod assert_constant;
+mod common_subexpression;
 mod constant_folding;
 mod defunctionalize;
 mod die;
 mod fill_internal_slices;
 pub(crate) mod flatten_cfg;
 mod inlining;
+mod loop_invariant;
 mod mem2reg;
 mod simplify_cfg;
+mod underconstrained_values;
 mod unrolling;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use noirc_frontend::Distinctness;
+
+    use crate::{brillig::Brillig, ssa::ssa_gen::Ssa};
+
+    /// Runs the remainder of the ACIR pipeline over `ssa` (starting from loop unrolling)
+    /// and returns the number of ACIR opcodes generated for its main function.
+    ///
+    /// This is used to compare the effect of individual passes on the final circuit size.
+    pub(crate) fn acir_opcode_count(ssa: Ssa) -> usize {
+        let ssa = ssa
+            .unroll_loops()
+            .expect("All loops should be unrolled")
+            .simplify_cfg()
+            .mem2reg()
+            .flatten_cfg()
+            .mem2reg()
+            .fold_constants()
+            .dead_instruction_elimination();
+
+        let last_array_uses = ssa.find_last_array_uses();
+        let mut generated_acir = ssa
+            .into_acir(Brillig::default(), Distinctness::DuplicationAllowed, &last_array_uses)
+            .expect("Should compile to ACIR");
+        generated_acir.take_opcodes().len()
+    }
+}
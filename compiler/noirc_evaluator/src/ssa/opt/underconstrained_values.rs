@@ -0,0 +1,285 @@
+//! This module defines an analysis pass which looks for the results of calls to unconstrained
+//! functions which are never constrained against the arguments of the call.
+//!
+//! The results of unconstrained functions, including those of oracles which can only be called from
+//! them, are returned to the circuit as witnesses which the prover is free to choose. Unless the
+//! circuit constrains these witnesses against the values they were computed from, a malicious
+//! prover can replace them with any other value.
+//!
+//! For each call to an unconstrained function from a constrained one, this pass checks that the
+//! results of the call are related to its arguments by at least one `Constrain` instruction.
+//! Relations are followed through any other instructions which feed into a `Constrain` instruction,
+//! so `assert(y * y == x)` constrains `y = sqrt(x)` while returning `y + x`, or passing it to
+//! another unconstrained function, does not.
+//!
+//! This pass is expected to run on the flattened SSA, as it follows each function's instructions
+//! in a single pass without regard for the control flow between its blocks.
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::{
+    errors::{InternalWarning, SsaReport},
+    ssa::{
+        ir::{
+            dfg::DataFlowGraph,
+            function::{Function, RuntimeType},
+            instruction::{Instruction, InstructionId},
+            post_order::PostOrder,
+            value::{Value, ValueId},
+        },
+        ssa_gen::Ssa,
+    },
+};
+
+impl Ssa {
+    /// Returns a warning for each call to an unconstrained function, from a constrained function,
+    /// whose results are used without ever being constrained against the arguments of the call.
+    pub(crate) fn check_for_underconstrained_values(&self) -> Vec<SsaReport> {
+        let mut warnings = Vec::new();
+        for function in self.functions.values() {
+            if function.runtime() == RuntimeType::Acir {
+                warnings.extend(self.check_function(function));
+            }
+        }
+        warnings
+    }
+
+    fn check_function(&self, function: &Function) -> Vec<SsaReport> {
+        let dfg = &function.dfg;
+        let mut instructions = Vec::new();
+        let mut reverse_post_order = PostOrder::with_function(function).into_vec();
+        reverse_post_order.reverse();
+        for block in reverse_post_order {
+            instructions.extend(dfg[block].instructions().iter().copied());
+        }
+
+        let is_unconstrained_call = |instruction: InstructionId| match &dfg[instruction] {
+            Instruction::Call { func, .. } => match &dfg[dfg.resolve(*func)] {
+                Value::Function(id) => self.functions[id].runtime() == RuntimeType::Brillig,
+                _ => false,
+            },
+            _ => false,
+        };
+
+        // The values which are constrained, i.e. those which flow into a `Constrain` instruction,
+        // either directly or through memory.
+        let mut relevant = HashSet::default();
+        // The values which are read by any instruction or terminator.
+        let mut used = HashSet::default();
+        for block in function.reachable_blocks() {
+            if let Some(terminator) = dfg[block].terminator() {
+                terminator.for_each_value(|value| insert_value(dfg, value, &mut used));
+            }
+        }
+
+        // The instructions, other than unconstrained calls, which compute relevant values.
+        let mut relevant_instructions = Vec::new();
+        for instruction_id in instructions.iter().rev() {
+            let instruction = &dfg[*instruction_id];
+            instruction.for_each_value(|value| insert_value(dfg, value, &mut used));
+
+            // Passing values to an unconstrained function doesn't constrain them, nor does it
+            // relate them to the results of the call.
+            if is_unconstrained_call(*instruction_id) {
+                continue;
+            }
+            let is_relevant = match instruction {
+                Instruction::Constrain(..) => true,
+                Instruction::Store { address, .. } => relevant.contains(&dfg.resolve(*address)),
+                _ => dfg
+                    .instruction_results(*instruction_id)
+                    .iter()
+                    .any(|result| relevant.contains(result)),
+            };
+            if is_relevant {
+                instruction.for_each_value(|value| insert_value(dfg, value, &mut relevant));
+                relevant_instructions.push(*instruction_id);
+            }
+        }
+
+        // Group the values which are related through the instructions computing relevant values.
+        let mut sets = DisjointSets::default();
+        for instruction_id in relevant_instructions {
+            let mut values = HashSet::default();
+            dfg[instruction_id].for_each_value(|value| insert_value(dfg, value, &mut values));
+            for result in dfg.instruction_results(instruction_id) {
+                insert_value(dfg, *result, &mut values);
+            }
+            let mut values = values.into_iter();
+            if let Some(first) = values.next() {
+                for value in values {
+                    sets.union(first, value);
+                }
+            }
+        }
+
+        let mut warnings = Vec::new();
+        for instruction_id in instructions {
+            let Instruction::Call { arguments, .. } = &dfg[instruction_id] else {
+                continue;
+            };
+            if !is_unconstrained_call(instruction_id) {
+                continue;
+            }
+
+            let mut argument_values = HashSet::default();
+            for argument in arguments {
+                insert_value(dfg, *argument, &mut argument_values);
+            }
+            let mut result_values = HashSet::default();
+            for result in dfg.instruction_results(instruction_id) {
+                insert_value(dfg, *result, &mut result_values);
+            }
+
+            let is_constrained =
+                result_values.iter().filter(|value| used.contains(*value)).all(|result| {
+                    if argument_values.is_empty() {
+                        // There is nothing to relate the result to, but it must still be constrained.
+                        relevant.contains(result)
+                    } else {
+                        let result_set = sets.find(*result);
+                        argument_values.iter().any(|argument| sets.find(*argument) == result_set)
+                    }
+                });
+            if !is_constrained {
+                let call_stack = dfg.get_call_stack(instruction_id);
+                warnings.push(SsaReport::Warning(InternalWarning::UnderconstrainedValue {
+                    call_stack,
+                }));
+            }
+        }
+        warnings
+    }
+}
+
+/// Inserts `value` into `values` unless it is a constant. The elements of arrays are inserted
+/// in place of the arrays themselves.
+fn insert_value(dfg: &DataFlowGraph, value: ValueId, values: &mut HashSet<ValueId>) {
+    let value = dfg.resolve(value);
+    match &dfg[value] {
+        Value::Instruction { .. } | Value::Param { .. } => {
+            values.insert(value);
+        }
+        Value::Array { array, .. } => {
+            for element in array {
+                insert_value(dfg, *element, values);
+            }
+        }
+        Value::NumericConstant { .. }
+        | Value::Function(_)
+        | Value::Intrinsic(_)
+        | Value::ForeignFunction(_) => (),
+    }
+}
+
+/// A union-find structure grouping values which are related to each other.
+#[derive(Default)]
+struct DisjointSets {
+    parents: HashMap<ValueId, ValueId>,
+}
+
+impl DisjointSets {
+    fn find(&mut self, value: ValueId) -> ValueId {
+        let parent = *self.parents.get(&value).unwrap_or(&value);
+        if parent == value {
+            return value;
+        }
+        let root = self.find(parent);
+        self.parents.insert(value, root);
+        root
+    }
+
+    fn union(&mut self, lhs: ValueId, rhs: ValueId) {
+        let lhs = self.find(lhs);
+        let rhs = self.find(rhs);
+        if lhs != rhs {
+            self.parents.insert(lhs, rhs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{
+        function_builder::FunctionBuilder,
+        ir::{function::RuntimeType, instruction::BinaryOp, map::Id, types::Type, value::ValueId},
+        ssa_gen::Ssa,
+    };
+
+    /// Builds the following program, where `constrain` adds the instructions of main after
+    /// the call to `sqrt`:
+    ///
+    /// ```text
+    /// acir fn main f0 {
+    ///   b0(v0: Field):
+    ///     v2 = call f1(v0)
+    ///     ...
+    /// }
+    /// brillig fn sqrt f1 {
+    ///   b0(v0: Field):
+    ///     return v0
+    /// }
+    /// ```
+    fn sqrt_program(constrain: impl FnOnce(&mut FunctionBuilder, ValueId, ValueId)) -> Ssa {
+        let main_id = Id::test_new(0);
+        let sqrt_id = Id::test_new(1);
+
+        let mut builder = FunctionBuilder::new("main".into(), main_id, RuntimeType::Acir);
+        let v0 = builder.add_parameter(Type::field());
+        let sqrt = builder.import_function(sqrt_id);
+        let v2 = builder.insert_call(sqrt, vec![v0], vec![Type::field()])[0];
+        constrain(&mut builder, v0, v2);
+
+        builder.new_brillig_function("sqrt".into(), sqrt_id);
+        let v0 = builder.add_parameter(Type::field());
+        builder.terminate_with_return(vec![v0]);
+
+        builder.finish()
+    }
+
+    #[test]
+    fn warns_when_result_is_returned_unconstrained() {
+        let ssa = sqrt_program(|builder, x, y| {
+            let sum = builder.insert_binary(y, BinaryOp::Add, x);
+            builder.terminate_with_return(vec![sum]);
+        });
+        assert_eq!(ssa.check_for_underconstrained_values().len(), 1);
+    }
+
+    #[test]
+    fn accepts_result_constrained_against_arguments() {
+        let ssa = sqrt_program(|builder, x, y| {
+            let square = builder.insert_binary(y, BinaryOp::Mul, y);
+            builder.insert_constrain(square, x, None);
+            builder.terminate_with_return(vec![y]);
+        });
+        assert!(ssa.check_for_underconstrained_values().is_empty());
+    }
+
+    #[test]
+    fn warns_when_result_is_only_constrained_against_constants() {
+        let ssa = sqrt_program(|builder, _, y| {
+            let two = builder.field_constant(2u128);
+            builder.insert_constrain(y, two, None);
+            builder.terminate_with_return(vec![y]);
+        });
+        assert_eq!(ssa.check_for_underconstrained_values().len(), 1);
+    }
+
+    #[test]
+    fn warns_when_result_is_only_passed_to_unconstrained_functions() {
+        // y = sqrt(x); w = y + x; sqrt(w)
+        let ssa = sqrt_program(|builder, x, y| {
+            let w = builder.insert_binary(y, BinaryOp::Add, x);
+            let sqrt = builder.import_function(Id::test_new(1));
+            builder.insert_call(sqrt, vec![w], vec![Type::field()]);
+            builder.terminate_with_return(Vec::new());
+        });
+        assert_eq!(ssa.check_for_underconstrained_values().len(), 1);
+    }
+
+    #[test]
+    fn ignores_unused_results() {
+        let ssa = sqrt_program(|builder, _, _| builder.terminate_with_return(Vec::new()));
+        assert!(ssa.check_for_underconstrained_values().is_empty());
+    }
+}
@@ -69,6 +69,9 @@ pub(super) struct SharedContext {
 
     /// The entire monomorphized source program
     pub(super) program: Program,
+
+    /// Whether left shifts which discard set bits are treated as overflows
+    strict_overflow: bool,
 }
 
 /// The queue of functions remaining to compile
@@ -272,8 +275,10 @@ impl<'a> FunctionContext<'a> {
     fn absolute_value_helper(&mut self, input: ValueId, sign: ValueId, bit_size: u32) -> ValueId {
         // We compute the absolute value of lhs
         let one = self.builder.numeric_constant(FieldElement::one(), Type::bool());
-        let bit_width =
-            self.builder.numeric_constant(FieldElement::from(2_i128.pow(bit_size)), Type::field());
+        let bit_width = self.builder.numeric_constant(
+            FieldElement::from(2_u128).pow(&FieldElement::from(bit_size as u128)),
+            Type::field(),
+        );
         let sign_not = self.builder.insert_binary(one, BinaryOp::Sub, sign);
         let as_field =
             self.builder.insert_instruction(Instruction::Cast(input, Type::field()), None).first();
@@ -332,9 +337,14 @@ impl<'a> FunctionContext<'a> {
                         self.builder.insert_cast(result, result_type)
                     }
                     BinaryOpKind::Multiply => {
-                        // Result is computed modulo the bit size
-                        let mut result =
-                            self.builder.insert_cast(result, Type::unsigned(2 * bit_size));
+                        // Result is computed modulo the bit size. Products of wide integers are
+                        // already reduced to the bit size, so they are not widened first.
+                        let product_type = if result_type.is_wide_integer() {
+                            Type::unsigned(bit_size)
+                        } else {
+                            Type::unsigned(2 * bit_size)
+                        };
+                        let mut result = self.builder.insert_cast(result, product_type);
                         result = self
                             .builder
                             .insert_instruction(
@@ -366,7 +376,7 @@ impl<'a> FunctionContext<'a> {
                 };
 
                 if operator == BinaryOpKind::ShiftLeft {
-                    self.check_left_shift_overflow(result, rhs, bit_size, location)
+                    self.check_left_shift_overflow(result, lhs, rhs, bit_size, location)
                 } else {
                     let message = format!("attempt to {} with overflow", op_name);
                     let range_constraint = Instruction::RangeCheck {
@@ -385,10 +395,12 @@ impl<'a> FunctionContext<'a> {
     /// Overflow checks for shift-left
     /// We use Rust behavior for shift left:
     /// If rhs is more or equal than the bit size, then we overflow
-    /// If not, we do not overflow and shift left with 0 when bits are falling out of the bit size
+    /// If not, we do not overflow and shift left with 0 when bits are falling out of the bit size,
+    /// unless overflows are strict, in which case set bits must not fall out of the bit size.
     fn check_left_shift_overflow(
         &mut self,
         result: ValueId,
+        lhs: ValueId,
         rhs: ValueId,
         bit_size: u32,
         location: Location,
@@ -403,7 +415,17 @@ impl<'a> FunctionContext<'a> {
             one,
             Some("attempt to left shift with overflow".to_owned()),
         );
-        self.builder.insert_truncate(result, bit_size, bit_size + 1)
+        let result = self.builder.insert_truncate(result, bit_size, bit_size + 1);
+        if self.shared_context.strict_overflow {
+            // No set bits fell out if shifting the result back gives the lhs.
+            let shifted_back = self.builder.insert_shift_right(result, rhs);
+            self.builder.set_location(location).insert_constrain(
+                shifted_back,
+                lhs,
+                Some("attempt to left shift with overflow".to_owned()),
+            );
+        }
+        result
     }
 
     /// Insert constraints ensuring that the operation does not overflow the bit size of the result
@@ -430,7 +452,7 @@ impl<'a> FunctionContext<'a> {
         let is_sub = operator == BinaryOpKind::Subtract;
         let one = self.builder.numeric_constant(FieldElement::one(), Type::bool());
         let half_width = self.builder.numeric_constant(
-            FieldElement::from(2_i128.pow(bit_size - 1)),
+            FieldElement::from(2_u128.pow(bit_size - 1)),
             Type::unsigned(bit_size),
         );
         // We compute the sign of the operands. The overflow checks for signed integers depends on these signs
@@ -461,8 +483,14 @@ impl<'a> FunctionContext<'a> {
             BinaryOpKind::Multiply => {
                 // Overflow check for the multiplication:
                 // First we compute the absolute value of operands, and their product
-                let lhs_abs = self.absolute_value_helper(lhs, lhs_sign, bit_size);
-                let rhs_abs = self.absolute_value_helper(rhs, rhs_sign, bit_size);
+                let mut lhs_abs = self.absolute_value_helper(lhs, lhs_sign, bit_size);
+                let mut rhs_abs = self.absolute_value_helper(rhs, rhs_sign, bit_size);
+                if Type::signed(bit_size).is_wide_integer() {
+                    // The product may not fit in a field element, so it has to be computed
+                    // as an integer for its overflow to be detected.
+                    lhs_abs = self.builder.insert_cast(lhs_abs, Type::unsigned(bit_size));
+                    rhs_abs = self.builder.insert_cast(rhs_abs, Type::unsigned(bit_size));
+                }
                 let product_field = self.builder.insert_binary(lhs_abs, BinaryOp::Mul, rhs_abs);
                 // It must not already overflow the bit_size
                 let message = "attempt to multiply with overflow".to_string();
@@ -1067,12 +1095,13 @@ fn convert_operator(op: noirc_frontend::BinaryOpKind) -> BinaryOp {
 
 impl SharedContext {
     /// Create a new SharedContext for the given monomorphized program.
-    pub(super) fn new(program: Program) -> Self {
+    pub(super) fn new(program: Program, strict_overflow: bool) -> Self {
         Self {
             functions: Default::default(),
             function_queue: Default::default(),
             function_counter: Default::default(),
             program,
+            strict_overflow,
         }
     }
 
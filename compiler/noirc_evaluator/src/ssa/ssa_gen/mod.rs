@@ -32,9 +32,10 @@ use super::ir::{
 /// Generates SSA for the given monomorphized program.
 ///
 /// This function will generate the SSA but does not perform any optimizations on it.
-pub(crate) fn generate_ssa(program: Program) -> Result<Ssa, RuntimeError> {
+/// When `strict_overflow` is set, left shifts which discard set bits fail like other overflows.
+pub(crate) fn generate_ssa(program: Program, strict_overflow: bool) -> Result<Ssa, RuntimeError> {
     let return_location = program.return_location;
-    let context = SharedContext::new(program);
+    let context = SharedContext::new(program, strict_overflow);
 
     let main_id = Program::main_id();
     let main = context.program.main();
@@ -3,10 +3,10 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 use crate::lexer::token::SpannedToken;
 use crate::parser::{ParserError, ParserErrorReason};
-use crate::token::Token;
+use crate::token::{SecondaryAttribute, Token};
 use crate::{
-    BlockExpression, Expression, ExpressionKind, IndexExpression, MemberAccessExpression,
-    MethodCallExpression, UnresolvedType,
+    BlockExpression, Expression, ExpressionKind, IndexExpression, ItemVisibility,
+    MemberAccessExpression, MethodCallExpression, UnresolvedType,
 };
 use acvm::FieldElement;
 use iter_extended::vecmap;
@@ -103,7 +103,7 @@ impl StatementKind {
     pub fn new_let(
         ((pattern, r#type), expression): ((Pattern, UnresolvedType), Expression),
     ) -> StatementKind {
-        StatementKind::Let(LetStatement { pattern, r#type, expression })
+        StatementKind::Let(LetStatement::new_let(((pattern, r#type), expression)))
     }
 
     /// Create a Statement::Assign value, desugaring any combined operators like += if needed.
@@ -242,8 +242,10 @@ pub trait Recoverable {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ImportStatement {
+    pub visibility: ItemVisibility,
     pub path: Path,
     pub alias: Option<Ident>,
+    pub attributes: Vec<SecondaryAttribute>,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
@@ -289,7 +291,14 @@ pub enum UseTreeKind {
 }
 
 impl UseTree {
-    pub fn desugar(self, root: Option<Path>) -> Vec<ImportStatement> {
+    /// Flattens this tree into one import per imported name, each with the given attributes and
+    /// visibility of the `use` statement it was declared in.
+    pub fn desugar(
+        self,
+        root: Option<Path>,
+        attributes: &[SecondaryAttribute],
+        visibility: ItemVisibility,
+    ) -> Vec<ImportStatement> {
         let prefix = if let Some(mut root) = root {
             root.segments.extend(self.prefix.segments);
             root
@@ -299,11 +308,13 @@ impl UseTree {
 
         match self.kind {
             UseTreeKind::Path(name, alias) => {
-                vec![ImportStatement { path: prefix.join(name), alias }]
-            }
-            UseTreeKind::List(trees) => {
-                trees.into_iter().flat_map(|tree| tree.desugar(Some(prefix.clone()))).collect()
+                let attributes = attributes.to_vec();
+                vec![ImportStatement { visibility, path: prefix.join(name), alias, attributes }]
             }
+            UseTreeKind::List(trees) => trees
+                .into_iter()
+                .flat_map(|tree| tree.desugar(Some(prefix.clone()), attributes, visibility))
+                .collect(),
         }
     }
 }
@@ -390,13 +401,15 @@ pub struct LetStatement {
     pub pattern: Pattern,
     pub r#type: UnresolvedType,
     pub expression: Expression,
+    /// The attributes of a global declaration. This is always empty for local `let` statements.
+    pub attributes: Vec<SecondaryAttribute>,
 }
 
 impl LetStatement {
     pub fn new_let(
         ((pattern, r#type), expression): ((Pattern, UnresolvedType), Expression),
     ) -> LetStatement {
-        LetStatement { pattern, r#type, expression }
+        LetStatement { pattern, r#type, expression, attributes: Vec::new() }
     }
 }
 
@@ -533,11 +546,10 @@ impl ForRange {
 
                 // let fresh1 = array;
                 let let_array = Statement {
-                    kind: StatementKind::Let(LetStatement {
-                        pattern: Pattern::Identifier(array_ident.clone()),
-                        r#type: UnresolvedType::unspecified(),
-                        expression: array,
-                    }),
+                    kind: StatementKind::new_let((
+                        (Pattern::Identifier(array_ident.clone()), UnresolvedType::unspecified()),
+                        array,
+                    )),
                     span: array_span,
                 };
 
@@ -575,11 +587,10 @@ impl ForRange {
 
                 // let elem = array[i];
                 let let_elem = Statement {
-                    kind: StatementKind::Let(LetStatement {
-                        pattern: Pattern::Identifier(identifier),
-                        r#type: UnresolvedType::unspecified(),
-                        expression: Expression::new(loop_element, array_span),
-                    }),
+                    kind: StatementKind::new_let((
+                        (Pattern::Identifier(identifier), UnresolvedType::unspecified()),
+                        Expression::new(loop_element, array_span),
+                    )),
                     span: array_span,
                 };
 
@@ -676,7 +687,7 @@ impl Display for PathKind {
 
 impl Display for ImportStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "use {}", self.path)?;
+        write!(f, "{}use {}", self.visibility, self.path)?;
         if let Some(alias) = &self.alias {
             write!(f, " as {alias}")?;
         }
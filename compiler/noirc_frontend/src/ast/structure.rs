@@ -1,28 +1,39 @@
 use std::fmt::Display;
 
-use crate::{token::SecondaryAttribute, Ident, UnresolvedGenerics, UnresolvedType};
+use crate::{token::SecondaryAttribute, Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
 use iter_extended::vecmap;
 use noirc_errors::Span;
 
 /// Ast node for a struct
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NoirStruct {
+    pub visibility: ItemVisibility,
     pub name: Ident,
     pub attributes: Vec<SecondaryAttribute>,
     pub generics: UnresolvedGenerics,
-    pub fields: Vec<(Ident, UnresolvedType)>,
+    pub fields: Vec<StructField>,
     pub span: Span,
 }
 
+/// Ast node for a field of a struct, which is only visible outside the struct's module if it is
+/// marked `pub` or `pub(crate)`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructField {
+    pub visibility: ItemVisibility,
+    pub name: Ident,
+    pub typ: UnresolvedType,
+}
+
 impl NoirStruct {
     pub fn new(
+        visibility: ItemVisibility,
         name: Ident,
         attributes: Vec<SecondaryAttribute>,
         generics: Vec<Ident>,
-        fields: Vec<(Ident, UnresolvedType)>,
+        fields: Vec<StructField>,
         span: Span,
     ) -> NoirStruct {
-        NoirStruct { name, attributes, generics, fields, span }
+        NoirStruct { visibility, name, attributes, generics, fields, span }
     }
 }
 
@@ -31,10 +42,10 @@ impl Display for NoirStruct {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
         let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
 
-        writeln!(f, "struct {}{} {{", self.name, generics)?;
+        writeln!(f, "{}struct {}{} {{", self.visibility, self.name, generics)?;
 
-        for (name, typ) in self.fields.iter() {
-            writeln!(f, "    {name}: {typ},")?;
+        for StructField { visibility, name, typ } in self.fields.iter() {
+            writeln!(f, "    {visibility}{name}: {typ},")?;
         }
 
         write!(f, "}}")
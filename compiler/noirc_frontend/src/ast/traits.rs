@@ -4,14 +4,16 @@ use iter_extended::vecmap;
 use noirc_errors::Span;
 
 use crate::{
-    node_interner::TraitId, BlockExpression, Expression, FunctionReturnType, Ident, NoirFunction,
-    Path, UnresolvedGenerics, UnresolvedType,
+    node_interner::TraitId, token::SecondaryAttribute, BlockExpression, Expression,
+    FunctionReturnType, Ident, ItemVisibility, NoirFunction, Path, UnresolvedGenerics,
+    UnresolvedType,
 };
 
 /// AST node for trait definitions:
 /// `trait name<generics> { ... items ... }`
 #[derive(Clone, Debug)]
 pub struct NoirTrait {
+    pub visibility: ItemVisibility,
     pub name: Ident,
     pub generics: Vec<Ident>,
     pub where_clause: Vec<UnresolvedTraitConstraint>,
@@ -55,6 +57,8 @@ pub struct TypeImpl {
 /// `impl trait_name<trait_generics> for object_type where where_clauses { ... items ... }`
 #[derive(Clone, Debug)]
 pub struct NoirTraitImpl {
+    pub attributes: Vec<SecondaryAttribute>,
+
     pub impl_generics: UnresolvedGenerics,
 
     pub trait_name: Path,
@@ -117,7 +121,7 @@ impl Display for NoirTrait {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
         let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
 
-        writeln!(f, "trait {}{} {{", self.name, generics)?;
+        writeln!(f, "{}trait {}{} {{", self.visibility, self.name, generics)?;
 
         for item in self.items.iter() {
             let item = item.to_string();
@@ -189,6 +193,10 @@ impl Display for NoirTraitImpl {
         let generics = vecmap(&self.trait_generics, |generic| generic.to_string());
         let generics = generics.join(", ");
 
+        for attribute in &self.attributes {
+            writeln!(f, "{attribute}")?;
+        }
+
         writeln!(f, "impl {}<{}> for {} {{", self.trait_name, generics, self.object_type)?;
 
         for item in self.items.iter() {
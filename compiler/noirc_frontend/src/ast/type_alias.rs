@@ -1,4 +1,4 @@
-use crate::{Ident, UnresolvedGenerics, UnresolvedType};
+use crate::{Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
 use iter_extended::vecmap;
 use noirc_errors::Span;
 use std::fmt::Display;
@@ -6,6 +6,7 @@ use std::fmt::Display;
 /// Ast node for type aliases
 #[derive(Clone, Debug)]
 pub struct NoirTypeAlias {
+    pub visibility: ItemVisibility,
     pub name: Ident,
     pub generics: UnresolvedGenerics,
     pub typ: UnresolvedType,
@@ -14,18 +15,19 @@ pub struct NoirTypeAlias {
 
 impl NoirTypeAlias {
     pub fn new(
+        visibility: ItemVisibility,
         name: Ident,
         generics: UnresolvedGenerics,
         typ: UnresolvedType,
         span: Span,
     ) -> NoirTypeAlias {
-        NoirTypeAlias { name, generics, typ, span }
+        NoirTypeAlias { visibility, name, generics, typ, span }
     }
 }
 
 impl Display for NoirTypeAlias {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
-        write!(f, "type {}<{}> = {}", self.name, generics.join(", "), self.typ)
+        write!(f, "{}type {}<{}> = {}", self.visibility, self.name, generics.join(", "), self.typ)
     }
 }
@@ -15,9 +15,12 @@ use crate::hir::type_check::{type_check_func, TypeCheckError, TypeChecker};
 use crate::hir::Context;
 
 use crate::macros_api::MacroProcessor;
-use crate::node_interner::{FuncId, NodeInterner, StmtId, StructId, TraitId, TypeAliasId};
+use crate::node_interner::{
+    FuncId, ImportInfo, NodeInterner, StmtId, StructId, TraitId, TypeAliasId, UsageTracker,
+};
 
 use crate::parser::{ParserError, SortedModule};
+use crate::token::SecondaryAttribute;
 use crate::{
     ExpressionKind, LetStatement, Literal, NoirFunction, NoirStruct, NoirTrait, NoirTypeAlias,
     Path, Type, UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType,
@@ -46,6 +49,7 @@ impl UnresolvedFunctions {
         &mut self,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         crate_id: CrateId,
+        usage_tracker: &mut UsageTracker,
     ) -> Vec<DefCollectorErrorKind> {
         let mut errors = Vec::new();
 
@@ -53,8 +57,8 @@ impl UnresolvedFunctions {
             let module = ModuleId { krate: crate_id, local_id: *local_id };
 
             for bound in &mut func.def.where_clause {
-                match resolve_trait_by_path(def_maps, module, bound.trait_bound.trait_path.clone())
-                {
+                let trait_path = bound.trait_bound.trait_path.clone();
+                match resolve_trait_by_path(def_maps, module, trait_path, usage_tracker) {
                     Ok(trait_id) => {
                         bound.trait_bound.trait_id = Some(trait_id);
                     }
@@ -86,6 +90,7 @@ pub struct UnresolvedTrait {
 
 pub struct UnresolvedTraitImpl {
     pub file_id: FileId,
+    pub attributes: Vec<SecondaryAttribute>,
     pub module_id: LocalModuleId,
     pub trait_id: Option<TraitId>,
     pub trait_path: Path,
@@ -247,8 +252,12 @@ impl DefCollector {
         context.def_maps.insert(crate_id, def_collector.def_map);
 
         // Resolve unresolved imports collected from the crate
-        let (resolved, unresolved_imports) =
-            resolve_imports(crate_id, def_collector.collected_imports, &context.def_maps);
+        let (resolved, unresolved_imports) = resolve_imports(
+            crate_id,
+            def_collector.collected_imports,
+            &context.def_maps,
+            context.def_interner.usage_tracker_mut(),
+        );
 
         {
             let current_def_map = context.def_maps.get(&crate_id).unwrap();
@@ -263,9 +272,19 @@ impl DefCollector {
         let current_def_map = context.def_maps.get_mut(&crate_id).unwrap();
         for resolved_import in resolved {
             let name = resolved_import.name;
+            let module = ModuleId { krate: crate_id, local_id: resolved_import.module_scope };
+            context.def_interner.usage_tracker_mut().add_import(ImportInfo {
+                module,
+                name: name.clone(),
+                visibility: resolved_import.visibility,
+                attributes: resolved_import.attributes,
+            });
             for ns in resolved_import.resolved_namespace.iter_defs() {
-                let result = current_def_map.modules[resolved_import.module_scope.0]
-                    .import(name.clone(), ns);
+                let result = current_def_map.modules[resolved_import.module_scope.0].import(
+                    name.clone(),
+                    resolved_import.visibility,
+                    ns,
+                );
 
                 if let Err((first_def, second_def)) = result {
                     let err = DefCollectorErrorKind::Duplicate {
@@ -348,12 +367,14 @@ impl DefCollector {
         for macro_processor in macro_processors {
             macro_processor.process_typed_ast(&crate_id, context);
         }
-        errors.extend(type_check_globals(&mut context.def_interner, resolved_globals.globals));
+        let interner = &mut context.def_interner;
+        let def_maps = &context.def_maps;
+        errors.extend(type_check_globals(interner, def_maps, resolved_globals.globals));
 
         // Type check all of the functions in the crate
-        errors.extend(type_check_functions(&mut context.def_interner, file_func_ids));
-        errors.extend(type_check_functions(&mut context.def_interner, file_method_ids));
-        errors.extend(type_check_functions(&mut context.def_interner, file_trait_impls_ids));
+        errors.extend(type_check_functions(interner, def_maps, file_func_ids));
+        errors.extend(type_check_functions(interner, def_maps, file_method_ids));
+        errors.extend(type_check_functions(interner, def_maps, file_trait_impls_ids));
         errors
     }
 }
@@ -372,12 +393,13 @@ fn filter_literal_globals(
 
 fn type_check_globals(
     interner: &mut NodeInterner,
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
     global_ids: Vec<(FileId, StmtId)>,
 ) -> Vec<(CompilationError, fm::FileId)> {
     global_ids
         .iter()
         .flat_map(|(file_id, stmt_id)| {
-            TypeChecker::check_global(stmt_id, interner)
+            TypeChecker::check_global(stmt_id, interner, def_maps)
                 .iter()
                 .cloned()
                 .map(|e| (e.into(), *file_id))
@@ -388,12 +410,13 @@ fn type_check_globals(
 
 fn type_check_functions(
     interner: &mut NodeInterner,
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
     file_func_ids: Vec<(FileId, FuncId)>,
 ) -> Vec<(CompilationError, fm::FileId)> {
     file_func_ids
         .iter()
         .flat_map(|(file, func)| {
-            type_check_func(interner, *func)
+            type_check_func(interner, def_maps, *func)
                 .iter()
                 .cloned()
                 .map(|e| (e.into(), *file))
@@ -8,9 +8,9 @@ use crate::{
     graph::CrateId,
     hir::def_collector::dc_crate::{UnresolvedStruct, UnresolvedTrait},
     node_interner::{FunctionModifiers, TraitId, TypeAliasId},
-    parser::{SortedModule, SortedSubModule},
-    FunctionDefinition, Ident, LetStatement, NoirFunction, NoirStruct, NoirTrait, NoirTraitImpl,
-    NoirTypeAlias, TraitImplItem, TraitItem, TypeImpl,
+    parser::{ModuleDeclaration, SortedModule, SortedSubModule},
+    FunctionDefinition, Ident, ItemVisibility, LetStatement, NoirFunction, NoirStruct, NoirTrait,
+    NoirTraitImpl, NoirTypeAlias, TraitImplItem, TraitItem, TypeImpl,
 };
 
 use super::{
@@ -55,8 +55,10 @@ pub fn collect_defs(
     for import in ast.imports {
         collector.def_collector.collected_imports.push(ImportDirective {
             module_id: collector.module_id,
+            visibility: import.visibility,
             path: import.path,
             alias: import.alias,
+            attributes: import.attributes,
         });
     }
 
@@ -81,10 +83,10 @@ impl<'a> ModCollector<'a> {
     fn collect_globals(
         &mut self,
         context: &mut Context,
-        globals: Vec<LetStatement>,
+        globals: Vec<(LetStatement, ItemVisibility)>,
     ) -> Vec<(CompilationError, fm::FileId)> {
         let mut errors = vec![];
-        for global in globals {
+        for (global, visibility) in globals {
             let name = global.pattern.name_ident().clone();
 
             // First create dummy function in the DefInterner
@@ -92,8 +94,8 @@ impl<'a> ModCollector<'a> {
             let stmt_id = context.def_interner.push_empty_global();
 
             // Add the statement to the scope so its path can be looked up later
-            let result =
-                self.def_collector.def_map.modules[self.module_id.0].declare_global(name, stmt_id);
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_global(name, visibility, stmt_id);
 
             if let Err((first_def, second_def)) = result {
                 let err = DefCollectorErrorKind::Duplicate {
@@ -157,6 +159,7 @@ impl<'a> ModCollector<'a> {
 
             let unresolved_trait_impl = UnresolvedTraitImpl {
                 file_id: self.file_id,
+                attributes: trait_impl.attributes,
                 module_id: self.module_id,
                 trait_path: trait_name,
                 methods: unresolved_functions,
@@ -214,6 +217,7 @@ impl<'a> ModCollector<'a> {
             }
 
             let name = function.name_ident().clone();
+            let visibility = function.def.visibility;
             let func_id = context.def_interner.push_empty_fn();
 
             // First create dummy function in the DefInterner
@@ -230,7 +234,7 @@ impl<'a> ModCollector<'a> {
 
             // Add function to scope/ns of the module
             let result = self.def_collector.def_map.modules[self.module_id.0]
-                .declare_function(name, func_id);
+                .declare_function(name, visibility, func_id);
 
             if let Err((first_def, second_def)) = result {
                 let error = DefCollectorErrorKind::Duplicate {
@@ -257,6 +261,7 @@ impl<'a> ModCollector<'a> {
         let mut definition_errors = vec![];
         for struct_definition in types {
             let name = struct_definition.name.clone();
+            let visibility = struct_definition.visibility;
 
             let unresolved = UnresolvedStruct {
                 file_id: self.file_id,
@@ -265,7 +270,7 @@ impl<'a> ModCollector<'a> {
             };
 
             // Create the corresponding module for the struct namespace
-            let id = match self.push_child_module(&name, self.file_id, false, false) {
+            let id = match self.push_child_module(&name, self.file_id, None, false) {
                 Ok(local_id) => context.def_interner.new_struct(&unresolved, krate, local_id),
                 Err(error) => {
                     definition_errors.push((error.into(), self.file_id));
@@ -274,8 +279,8 @@ impl<'a> ModCollector<'a> {
             };
 
             // Add the struct to scope so its path can be looked up later
-            let result =
-                self.def_collector.def_map.modules[self.module_id.0].declare_struct(name, id);
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_struct(name, visibility, id);
 
             if let Err((first_def, second_def)) = result {
                 let error = DefCollectorErrorKind::Duplicate {
@@ -302,6 +307,7 @@ impl<'a> ModCollector<'a> {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         for type_alias in type_aliases {
             let name = type_alias.name.clone();
+            let visibility = type_alias.visibility;
 
             // And store the TypeId -> TypeAlias mapping somewhere it is reachable
             let unresolved = UnresolvedTypeAlias {
@@ -313,8 +319,11 @@ impl<'a> ModCollector<'a> {
             let type_alias_id = context.def_interner.push_type_alias(&unresolved);
 
             // Add the type alias to scope so its path can be looked up later
-            let result = self.def_collector.def_map.modules[self.module_id.0]
-                .declare_type_alias(name, type_alias_id);
+            let result = self.def_collector.def_map.modules[self.module_id.0].declare_type_alias(
+                name,
+                visibility,
+                type_alias_id,
+            );
 
             if let Err((first_def, second_def)) = result {
                 let err = DefCollectorErrorKind::Duplicate {
@@ -341,9 +350,10 @@ impl<'a> ModCollector<'a> {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         for trait_definition in traits {
             let name = trait_definition.name.clone();
+            let visibility = trait_definition.visibility;
 
             // Create the corresponding module for the trait namespace
-            let id = match self.push_child_module(&name, self.file_id, false, false) {
+            let id = match self.push_child_module(&name, self.file_id, None, false) {
                 Ok(local_id) => TraitId(ModuleId { krate, local_id }),
                 Err(error) => {
                     errors.push((error.into(), self.file_id));
@@ -352,8 +362,8 @@ impl<'a> ModCollector<'a> {
             };
 
             // Add the trait to scope so its path can be looked up later
-            let result =
-                self.def_collector.def_map.modules[self.module_id.0].declare_trait(name, id);
+            let result = self.def_collector.def_map.modules[self.module_id.0]
+                .declare_trait(name, visibility, id);
 
             if let Err((first_def, second_def)) = result {
                 let error = DefCollectorErrorKind::Duplicate {
@@ -383,7 +393,7 @@ impl<'a> ModCollector<'a> {
                         let func_id = context.def_interner.push_empty_fn();
                         let modifiers = FunctionModifiers {
                             name: name.to_string(),
-                            visibility: crate::FunctionVisibility::Public,
+                            visibility: ItemVisibility::Public,
                             // TODO(Maddiaa): Investigate trait implementations with attributes see: https://github.com/noir-lang/noir/issues/2629
                             attributes: crate::token::Attributes::empty(),
                             is_unconstrained: false,
@@ -393,9 +403,11 @@ impl<'a> ModCollector<'a> {
 
                         context.def_interner.push_function_definition(func_id, modifiers, id.0);
 
-                        match self.def_collector.def_map.modules[id.0.local_id.0]
-                            .declare_function(name.clone(), func_id)
-                        {
+                        match self.def_collector.def_map.modules[id.0.local_id.0].declare_function(
+                            name.clone(),
+                            ItemVisibility::Public,
+                            func_id,
+                        ) {
                             Ok(()) => {
                                 if let Some(body) = body {
                                     let impl_method =
@@ -429,7 +441,7 @@ impl<'a> ModCollector<'a> {
 
                         if let Err((first_def, second_def)) = self.def_collector.def_map.modules
                             [id.0.local_id.0]
-                            .declare_global(name.clone(), stmt_id)
+                            .declare_global(name.clone(), ItemVisibility::Public, stmt_id)
                         {
                             let error = DefCollectorErrorKind::Duplicate {
                                 typ: DuplicateType::TraitAssociatedConst,
@@ -441,9 +453,12 @@ impl<'a> ModCollector<'a> {
                     }
                     TraitItem::Type { name } => {
                         // TODO(nickysn or alexvitkov): implement context.def_interner.push_empty_type_alias and get an id, instead of using TypeAliasId::dummy_id()
-                        if let Err((first_def, second_def)) = self.def_collector.def_map.modules
-                            [id.0.local_id.0]
-                            .declare_type_alias(name.clone(), TypeAliasId::dummy_id())
+                        if let Err((first_def, second_def)) =
+                            self.def_collector.def_map.modules[id.0.local_id.0].declare_type_alias(
+                                name.clone(),
+                                ItemVisibility::Public,
+                                TypeAliasId::dummy_id(),
+                            )
                         {
                             let error = DefCollectorErrorKind::Duplicate {
                                 typ: DuplicateType::TraitAssociatedType,
@@ -478,7 +493,13 @@ impl<'a> ModCollector<'a> {
     ) -> Vec<(CompilationError, FileId)> {
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         for submodule in submodules {
-            match self.push_child_module(&submodule.name, file_id, true, submodule.is_contract) {
+            let visibility = Some(submodule.visibility);
+            match self.push_child_module(
+                &submodule.name,
+                file_id,
+                visibility,
+                submodule.is_contract,
+            ) {
                 Ok(child) => {
                     errors.extend(collect_defs(
                         self.def_collector,
@@ -503,9 +524,10 @@ impl<'a> ModCollector<'a> {
     fn parse_module_declaration(
         &mut self,
         context: &mut Context,
-        mod_name: &Ident,
+        mod_decl: &ModuleDeclaration,
         crate_id: CrateId,
     ) -> Vec<(CompilationError, FileId)> {
+        let mod_name = &mod_decl.ident;
         let mut errors: Vec<(CompilationError, FileId)> = vec![];
         let child_file_id =
             match context.file_manager.find_module(self.file_id, &mod_name.0.contents) {
@@ -547,7 +569,7 @@ impl<'a> ModCollector<'a> {
         );
 
         // Add module into def collector and get a ModuleId
-        match self.push_child_module(mod_name, child_file_id, true, false) {
+        match self.push_child_module(mod_name, child_file_id, Some(mod_decl.visibility), false) {
             Ok(child_mod_id) => {
                 errors.extend(collect_defs(
                     self.def_collector,
@@ -566,12 +588,13 @@ impl<'a> ModCollector<'a> {
     }
 
     /// Add a child module to the current def_map.
+    /// If `visibility` is set, the module is also added to the scope of its parent.
     /// On error this returns None and pushes to `errors`
     fn push_child_module(
         &mut self,
         mod_name: &Ident,
         file_id: FileId,
-        add_to_parent_scope: bool,
+        visibility: Option<ItemVisibility>,
         is_contract: bool,
     ) -> Result<LocalModuleId, DefCollectorErrorKind> {
         let parent = Some(self.module_id);
@@ -591,15 +614,17 @@ impl<'a> ModCollector<'a> {
         // We do not want to do this in the case of struct modules (each struct type corresponds
         // to a child module containing its methods) since the module name should not shadow
         // the struct name.
-        if add_to_parent_scope {
+        if let Some(visibility) = visibility {
             let mod_id = ModuleId {
                 krate: self.def_collector.def_map.krate,
                 local_id: LocalModuleId(module_id),
             };
 
-            if let Err((first_def, second_def)) =
-                modules[self.module_id.0].declare_child_module(mod_name.to_owned(), mod_id)
-            {
+            if let Err((first_def, second_def)) = modules[self.module_id.0].declare_child_module(
+                mod_name.to_owned(),
+                visibility,
+                mod_id,
+            ) {
                 let err = DefCollectorErrorKind::Duplicate {
                     typ: DuplicateType::Module,
                     first_def,
@@ -2,7 +2,9 @@ use crate::hir::resolution::import::PathResolutionError;
 use crate::Ident;
 use crate::Path;
 
+use noirc_errors::error_codes::*;
 use noirc_errors::CustomDiagnostic as Diagnostic;
+use noirc_errors::ErrorCode;
 use noirc_errors::FileDiagnostic;
 use noirc_errors::Span;
 use thiserror::Error;
@@ -86,6 +88,29 @@ pub struct MacroError {
 }
 
 impl DefCollectorErrorKind {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            DefCollectorErrorKind::Duplicate { .. } => E0200,
+            DefCollectorErrorKind::UnresolvedModuleDecl { .. } => E0201,
+            DefCollectorErrorKind::PathResolutionError(error) => error.code(),
+            DefCollectorErrorKind::NonStructTypeInImpl { .. } => E0202,
+            DefCollectorErrorKind::MutableReferenceInTraitImpl { .. } => E0203,
+            DefCollectorErrorKind::OverlappingImpl { .. }
+            | DefCollectorErrorKind::OverlappingImplNote { .. } => E0204,
+            DefCollectorErrorKind::ForeignImpl { .. } => E0205,
+            DefCollectorErrorKind::MismatchTraitImplementationNumParameters { .. } => E0206,
+            DefCollectorErrorKind::MismatchTraitImplementationNumGenerics { .. } => E0207,
+            DefCollectorErrorKind::MethodNotInTrait { .. } => E0208,
+            DefCollectorErrorKind::NotATrait { .. } => E0209,
+            DefCollectorErrorKind::TraitNotFound { .. } => E0210,
+            DefCollectorErrorKind::TraitMissingMethod { .. } => E0211,
+            DefCollectorErrorKind::ModuleAlreadyPartOfCrate { .. }
+            | DefCollectorErrorKind::ModuleOriginallyDefined { .. } => E0212,
+            DefCollectorErrorKind::TraitImplOrphaned { .. } => E0213,
+            DefCollectorErrorKind::MacroError(_) => E0214,
+        }
+    }
+
     pub fn into_file_diagnostic(self, file: fm::FileId) -> FileDiagnostic {
         Diagnostic::from(self).in_file(file)
     }
@@ -110,7 +135,8 @@ impl fmt::Display for DuplicateType {
 
 impl From<DefCollectorErrorKind> for Diagnostic {
     fn from(error: DefCollectorErrorKind) -> Diagnostic {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             DefCollectorErrorKind::Duplicate { typ, first_def, second_def } => {
                 let primary_message = format!(
                     "Duplicate definitions of {} with name {} found",
@@ -249,6 +275,7 @@ impl From<DefCollectorErrorKind> for Diagnostic {
             DefCollectorErrorKind::MacroError(macro_error) => {
                 Diagnostic::simple_error(macro_error.primary_message, macro_error.secondary_message.unwrap_or_default(), macro_error.span.unwrap_or_default())
             },
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
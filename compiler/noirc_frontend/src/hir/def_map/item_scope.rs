@@ -1,19 +1,14 @@
 use super::{namespace::PerNs, ModuleDefId, ModuleId};
 use crate::{
     node_interner::{FuncId, TraitId},
-    Ident,
+    Ident, ItemVisibility,
 };
 use std::collections::{hash_map::Entry, HashMap};
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-pub enum Visibility {
-    Public,
-}
-
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct ItemScope {
-    types: HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, Visibility)>>,
-    values: HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, Visibility)>>,
+    types: HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, ItemVisibility)>>,
+    values: HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, ItemVisibility)>>,
 
     defs: Vec<ModuleDefId>,
 }
@@ -22,10 +17,11 @@ impl ItemScope {
     pub fn add_definition(
         &mut self,
         name: Ident,
+        visibility: ItemVisibility,
         mod_def: ModuleDefId,
         trait_id: Option<TraitId>,
     ) -> Result<(), (Ident, Ident)> {
-        self.add_item_to_namespace(name, mod_def, trait_id)?;
+        self.add_item_to_namespace(name, visibility, mod_def, trait_id)?;
         self.defs.push(mod_def);
         Ok(())
     }
@@ -36,23 +32,24 @@ impl ItemScope {
     pub fn add_item_to_namespace(
         &mut self,
         name: Ident,
+        visibility: ItemVisibility,
         mod_def: ModuleDefId,
         trait_id: Option<TraitId>,
     ) -> Result<(), (Ident, Ident)> {
         let add_item =
-            |map: &mut HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, Visibility)>>| {
+            |map: &mut HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, ItemVisibility)>>| {
                 if let Entry::Occupied(mut o) = map.entry(name.clone()) {
                     let trait_hashmap = o.get_mut();
                     if let Entry::Occupied(_) = trait_hashmap.entry(trait_id) {
                         let old_ident = o.key();
                         Err((old_ident.clone(), name))
                     } else {
-                        trait_hashmap.insert(trait_id, (mod_def, Visibility::Public));
+                        trait_hashmap.insert(trait_id, (mod_def, visibility));
                         Ok(())
                     }
                 } else {
                     let mut trait_hashmap = HashMap::new();
-                    trait_hashmap.insert(trait_id, (mod_def, Visibility::Public));
+                    trait_hashmap.insert(trait_id, (mod_def, visibility));
                     map.insert(name, trait_hashmap);
                     Ok(())
                 }
@@ -116,7 +113,7 @@ impl ItemScope {
         // Names, not associated with traits are searched first. If not found, we search for name, coming from a trait.
         // If we find only one name from trait, we return it. If there are multiple traits, providing the same name, we return None.
         let find_name_in =
-            |a: &HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, Visibility)>>| {
+            |a: &HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, ItemVisibility)>>| {
                 if let Some(t) = a.get(name) {
                     if let Some(tt) = t.get(&None) {
                         Some(*tt)
@@ -148,11 +145,15 @@ impl ItemScope {
         self.defs.clone()
     }
 
-    pub fn types(&self) -> &HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, Visibility)>> {
+    pub fn types(
+        &self,
+    ) -> &HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, ItemVisibility)>> {
         &self.types
     }
 
-    pub fn values(&self) -> &HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, Visibility)>> {
+    pub fn values(
+        &self,
+    ) -> &HashMap<Ident, HashMap<Option<TraitId>, (ModuleDefId, ItemVisibility)>> {
         &self.values
     }
 
@@ -313,15 +313,21 @@ impl TestFunction {
     pub fn should_fail(&self) -> bool {
         match self.scope {
             TestScope::ShouldFailWith { .. } => true,
-            TestScope::None => false,
+            TestScope::Snapshot | TestScope::None => false,
         }
     }
 
+    /// Returns true if the output of the test function should be checked against a snapshot
+    /// This is done by annotating the function with `#[test(snapshot)]`
+    pub fn is_snapshot(&self) -> bool {
+        matches!(self.scope, TestScope::Snapshot)
+    }
+
     /// Returns the reason for the test function to fail if specified
     /// by the user.
     pub fn failure_reason(&self) -> Option<&str> {
         match &self.scope {
-            TestScope::None => None,
+            TestScope::Snapshot | TestScope::None => None,
             TestScope::ShouldFailWith { reason } => reason.as_deref(),
         }
     }
@@ -4,7 +4,7 @@ use noirc_errors::Location;
 
 use crate::{
     node_interner::{FuncId, StmtId, StructId, TraitId, TypeAliasId},
-    Ident,
+    Ident, ItemVisibility,
 };
 
 use super::{ItemScope, LocalModuleId, ModuleDefId, ModuleId, PerNs};
@@ -44,18 +44,24 @@ impl ModuleData {
     fn declare(
         &mut self,
         name: Ident,
+        visibility: ItemVisibility,
         item_id: ModuleDefId,
         trait_id: Option<TraitId>,
     ) -> Result<(), (Ident, Ident)> {
-        self.scope.add_definition(name.clone(), item_id, trait_id)?;
+        self.scope.add_definition(name.clone(), visibility, item_id, trait_id)?;
 
         // definitions is a subset of self.scope so it is expected if self.scope.define_func_def
         // returns without error, so will self.definitions.define_func_def.
-        self.definitions.add_definition(name, item_id, trait_id)
+        self.definitions.add_definition(name, visibility, item_id, trait_id)
     }
 
-    pub fn declare_function(&mut self, name: Ident, id: FuncId) -> Result<(), (Ident, Ident)> {
-        self.declare(name, id.into(), None)
+    pub fn declare_function(
+        &mut self,
+        name: Ident,
+        visibility: ItemVisibility,
+        id: FuncId,
+    ) -> Result<(), (Ident, Ident)> {
+        self.declare(name, visibility, id.into(), None)
     }
 
     pub fn declare_trait_function(
@@ -64,7 +70,7 @@ impl ModuleData {
         id: FuncId,
         trait_id: TraitId,
     ) -> Result<(), (Ident, Ident)> {
-        self.declare(name, id.into(), Some(trait_id))
+        self.declare(name, ItemVisibility::Public, id.into(), Some(trait_id))
     }
 
     pub fn remove_function(&mut self, name: &Ident) {
@@ -72,40 +78,62 @@ impl ModuleData {
         self.definitions.remove_definition(name);
     }
 
-    pub fn declare_global(&mut self, name: Ident, id: StmtId) -> Result<(), (Ident, Ident)> {
-        self.declare(name, id.into(), None)
+    pub fn declare_global(
+        &mut self,
+        name: Ident,
+        visibility: ItemVisibility,
+        id: StmtId,
+    ) -> Result<(), (Ident, Ident)> {
+        self.declare(name, visibility, id.into(), None)
     }
 
-    pub fn declare_struct(&mut self, name: Ident, id: StructId) -> Result<(), (Ident, Ident)> {
-        self.declare(name, ModuleDefId::TypeId(id), None)
+    pub fn declare_struct(
+        &mut self,
+        name: Ident,
+        visibility: ItemVisibility,
+        id: StructId,
+    ) -> Result<(), (Ident, Ident)> {
+        self.declare(name, visibility, ModuleDefId::TypeId(id), None)
     }
 
     pub fn declare_type_alias(
         &mut self,
         name: Ident,
+        visibility: ItemVisibility,
         id: TypeAliasId,
     ) -> Result<(), (Ident, Ident)> {
-        self.declare(name, id.into(), None)
+        self.declare(name, visibility, id.into(), None)
     }
 
-    pub fn declare_trait(&mut self, name: Ident, id: TraitId) -> Result<(), (Ident, Ident)> {
-        self.declare(name, ModuleDefId::TraitId(id), None)
+    pub fn declare_trait(
+        &mut self,
+        name: Ident,
+        visibility: ItemVisibility,
+        id: TraitId,
+    ) -> Result<(), (Ident, Ident)> {
+        self.declare(name, visibility, ModuleDefId::TraitId(id), None)
     }
 
     pub fn declare_child_module(
         &mut self,
         name: Ident,
+        visibility: ItemVisibility,
         child_id: ModuleId,
     ) -> Result<(), (Ident, Ident)> {
-        self.declare(name, child_id.into(), None)
+        self.declare(name, visibility, child_id.into(), None)
     }
 
     pub fn find_func_with_name(&self, name: &Ident) -> Option<FuncId> {
         self.scope.find_func_with_name(name)
     }
 
-    pub fn import(&mut self, name: Ident, id: ModuleDefId) -> Result<(), (Ident, Ident)> {
-        self.scope.add_item_to_namespace(name, id, None)
+    pub fn import(
+        &mut self,
+        name: Ident,
+        visibility: ItemVisibility,
+        id: ModuleDefId,
+    ) -> Result<(), (Ident, Ident)> {
+        self.scope.add_item_to_namespace(name, visibility, id, None)
     }
 
     pub fn find_name(&self, name: &Ident) -> PerNs {
@@ -116,6 +144,16 @@ impl ModuleData {
         self.definitions.types().values().flat_map(|a| a.values().map(|(id, _)| *id))
     }
 
+    /// Return an iterator over all definitions defined within this module, along with
+    /// their visibility.
+    pub fn definitions_with_visibility(
+        &self,
+    ) -> impl Iterator<Item = (ModuleDefId, ItemVisibility)> + '_ {
+        let types = self.definitions.types().values();
+        let values = self.definitions.values().values();
+        types.chain(values).flat_map(|items| items.values().copied())
+    }
+
     /// Return an iterator over all definitions defined within this module,
     /// excluding any type definitions.
     pub fn value_definitions(&self) -> impl Iterator<Item = ModuleDefId> + '_ {
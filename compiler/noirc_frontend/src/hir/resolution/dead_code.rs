@@ -0,0 +1,459 @@
+//! Reports the items of a crate which are never used.
+//!
+//! Items are used if they can be reached from an entry point of the crate: `main`, `#[test]`
+//! functions and contract entry points. Items which are not private may also be used by other
+//! crates so they are treated as entry points as well, which means only private items are ever
+//! reported. Imports are tracked separately by the
+//! [`UsageTracker`](crate::node_interner::UsageTracker), as an import is used
+//! whenever any path is resolved through it. Like other items, only private imports are reported,
+//! as `pub use` and `pub(crate) use` re-export the item to other modules.
+//!
+//! Each warning can be silenced on the item it is reported on with `#[allow(<code>)]`.
+use std::collections::{HashMap, HashSet};
+
+use fm::FileId;
+use iter_extended::vecmap;
+use noirc_errors::{ErrorCode, Span};
+
+use crate::{
+    graph::CrateId,
+    hir::{def_collector::dc_crate::CompilationError, def_map::ModuleDefId, Context},
+    hir_def::{
+        expr::{HirArrayLiteral, HirExpression, HirLiteral},
+        stmt::{HirLValue, HirPattern, HirStatement},
+    },
+    node_interner::{
+        DefinitionId, DefinitionKind, ExprId, FuncId, NodeInterner, StmtId, StructId, TraitId,
+        TraitImplId, TraitImplKind,
+    },
+    token::SecondaryAttribute,
+    ItemVisibility, Type, TypeVariableKind,
+};
+
+use super::errors::ResolverError;
+
+/// Returns a warning for each unused import and each unused private function, struct, global
+/// and trait implementation of the given crate.
+///
+/// This expects the crate to be resolved and type checked without errors.
+pub fn find_dead_code(context: &Context, crate_id: CrateId) -> Vec<(CompilationError, FileId)> {
+    let interner = &context.def_interner;
+    let def_map = &context.def_maps[&crate_id];
+
+    let mut private_structs = HashSet::new();
+    let mut private_traits = HashSet::new();
+    let mut globals = Vec::new();
+    for (_, module) in def_map.modules().iter() {
+        for (item, visibility) in module.definitions_with_visibility() {
+            let is_private = visibility == ItemVisibility::Private;
+            match item {
+                ModuleDefId::TypeId(id) if is_private => {
+                    private_structs.insert(id);
+                }
+                ModuleDefId::TraitId(id) if is_private => {
+                    private_traits.insert(id);
+                }
+                ModuleDefId::GlobalId(id) => globals.push((id, is_private)),
+                _ => (),
+            }
+        }
+    }
+
+    let mut reachability = Reachability::new(interner, crate_id);
+
+    // Trait implementations can only be unused if they cannot be named by any other crate.
+    let mut private_trait_impls = Vec::new();
+    for impl_id in interner.trait_impl_ids() {
+        let trait_impl = interner.get_trait_implementation(impl_id);
+        let trait_impl = trait_impl.borrow();
+        let is_private = private_traits.contains(&trait_impl.trait_id)
+            || matches!(&trait_impl.typ, Type::Struct(struct_type, _)
+                if private_structs.contains(&struct_type.borrow().id));
+
+        if is_private {
+            private_trait_impls.push(impl_id);
+        } else {
+            trait_impl.methods.iter().for_each(|method| reachability.mark_function(*method));
+        }
+        reachability.impls_of_trait.entry(trait_impl.trait_id).or_default().push(impl_id);
+    }
+
+    let mut private_functions = Vec::new();
+    for func_id in interner.function_ids() {
+        if interner.function_module(func_id).krate != crate_id
+            || interner.get_function_trait(&func_id).is_some()
+        {
+            continue;
+        }
+        if interner.function_visibility(func_id) == ItemVisibility::Private {
+            private_functions.push(func_id);
+        } else {
+            reachability.mark_function(func_id);
+        }
+    }
+
+    for (global, is_private) in &globals {
+        if !is_private {
+            reachability.mark_global(*global);
+        }
+    }
+
+    let main_function = context.get_main_function(&crate_id);
+    let contracts = def_map.get_all_contracts(interner);
+
+    let mut entry_points: Vec<FuncId> = main_function.into_iter().collect();
+    entry_points.extend(def_map.get_all_test_functions(interner).map(|test| test.get_id()));
+    for contract in contracts {
+        let functions = contract.functions.into_iter().filter(|function| function.is_entry_point);
+        entry_points.extend(functions.map(|function| function.function_id));
+
+        // Events are part of the contract's ABI.
+        contract.events.into_iter().for_each(|event| reachability.mark_struct(event));
+    }
+    entry_points.into_iter().for_each(|func_id| reachability.mark_function(func_id));
+
+    reachability.run();
+
+    let mut warnings = Vec::new();
+    let mut warn = |error: ResolverError, file: FileId, attributes: &[SecondaryAttribute]| {
+        if !is_allowed(attributes, error.code()) {
+            warnings.push((error, file));
+        }
+    };
+
+    let usage_tracker = interner.usage_tracker();
+    for import in usage_tracker.imports() {
+        if import.module.krate == crate_id
+            && import.visibility == ItemVisibility::Private
+            && !usage_tracker.is_used(import.module, &import.name)
+        {
+            let file = def_map.file_id(import.module.local_id);
+            warn(
+                ResolverError::UnusedImport { ident: import.name.clone() },
+                file,
+                &import.attributes,
+            );
+        }
+    }
+
+    for func_id in private_functions {
+        if !reachability.functions.contains(&func_id) {
+            let meta = interner.function_meta(&func_id);
+            let error =
+                unused_item("function", interner.function_name(&func_id), meta.name.location.span);
+            let attributes = &interner.function_attributes(&func_id).secondary;
+            warn(error, meta.name.location.file, attributes);
+        }
+    }
+
+    for struct_id in &private_structs {
+        if !reachability.structs.contains(struct_id) {
+            let struct_type = interner.get_struct(*struct_id);
+            let struct_type = struct_type.borrow();
+            let error =
+                unused_item("struct", &struct_type.name.0.contents, struct_type.name.span());
+            let file = def_map.file_id(struct_id.local_module_id());
+            warn(error, file, interner.struct_attributes(struct_id));
+        }
+    }
+
+    for (global, is_private) in globals {
+        let is_used =
+            reachability.globals.contains(&global) || usage_tracker.is_global_used_in_type(global);
+        if is_private && !is_used {
+            let Some(info) = interner.get_global(&global) else { continue };
+            let error = unused_item("global", &info.ident.0.contents, info.ident.span());
+            warn(error, def_map.file_id(info.local_id), &info.attributes);
+        }
+    }
+
+    for impl_id in private_trait_impls {
+        if !reachability.trait_impls.contains(&impl_id) {
+            let trait_impl = interner.get_trait_implementation(impl_id);
+            let trait_impl = trait_impl.borrow();
+            let name = format!("{} for {}", trait_impl.ident, trait_impl.typ);
+            let error = unused_item("implementation of", &name, trait_impl.ident.span());
+            warn(error, trait_impl.file, &trait_impl.attributes);
+        }
+    }
+
+    warnings.sort_by_key(|(error, file)| (*file, error_span(error).start()));
+    warnings.into_iter().map(|(error, file)| (error.into(), file)).collect()
+}
+
+fn unused_item(item: &'static str, name: &str, span: Span) -> ResolverError {
+    ResolverError::UnusedItem { item, name: name.to_string(), span }
+}
+
+fn error_span(error: &ResolverError) -> Span {
+    match error {
+        ResolverError::UnusedImport { ident } => ident.span(),
+        ResolverError::UnusedItem { span, .. } => *span,
+        _ => unreachable!("only unused items are reported by the dead code pass"),
+    }
+}
+
+/// True if any `#[allow(...)]` attribute within `attributes` lists `code`.
+fn is_allowed(attributes: &[SecondaryAttribute], code: ErrorCode) -> bool {
+    attributes.iter().any(|attribute| match attribute {
+        SecondaryAttribute::Allow(codes) => codes.iter().any(|allowed| allowed == code.as_str()),
+        _ => false,
+    })
+}
+
+/// Walks the Hir of each reached function and global, marking every item it references.
+struct Reachability<'interner> {
+    interner: &'interner NodeInterner,
+    crate_id: CrateId,
+
+    functions: HashSet<FuncId>,
+    globals: HashSet<StmtId>,
+    structs: HashSet<StructId>,
+    traits: HashSet<TraitId>,
+    trait_impls: HashSet<TraitImplId>,
+
+    impls_of_trait: HashMap<TraitId, Vec<TraitImplId>>,
+    global_definitions: HashMap<DefinitionId, StmtId>,
+
+    /// Functions which are reached but whose bodies are yet to be walked.
+    queue: Vec<FuncId>,
+}
+
+impl<'interner> Reachability<'interner> {
+    fn new(interner: &'interner NodeInterner, crate_id: CrateId) -> Self {
+        let global_definitions = interner
+            .get_all_globals()
+            .into_keys()
+            .map(|global| (interner.let_statement(&global).ident().id, global))
+            .collect();
+
+        Self {
+            interner,
+            crate_id,
+            functions: HashSet::new(),
+            globals: HashSet::new(),
+            structs: HashSet::new(),
+            traits: HashSet::new(),
+            trait_impls: HashSet::new(),
+            impls_of_trait: HashMap::new(),
+            global_definitions,
+            queue: Vec::new(),
+        }
+    }
+
+    fn run(&mut self) {
+        while let Some(func_id) = self.queue.pop() {
+            let meta = self.interner.function_meta(&func_id);
+            self.mark_structs_in_type(&meta.typ);
+            for constraint in &meta.trait_constraints {
+                self.mark_trait(constraint.trait_id);
+            }
+            if let Some(impl_id) = meta.trait_impl {
+                self.mark_trait_impl(impl_id);
+            }
+            if meta.has_body {
+                self.walk_expression(*self.interner.function(&func_id).as_expr());
+            }
+        }
+    }
+
+    /// Marks a function as reached. Functions of other crates are never walked, as none of
+    /// their items are reported.
+    fn mark_function(&mut self, func_id: FuncId) {
+        if self.interner.try_function_meta(&func_id).is_some()
+            && self.interner.function_module(func_id).krate == self.crate_id
+            && self.functions.insert(func_id)
+        {
+            self.queue.push(func_id);
+        }
+    }
+
+    fn mark_global(&mut self, global: StmtId) {
+        if self.globals.insert(global) {
+            let let_statement = self.interner.let_statement(&global);
+            self.mark_structs_in_type(&let_statement.r#type);
+            self.walk_expression(let_statement.expression);
+        }
+    }
+
+    /// Marks a struct and the structs within its fields as used.
+    fn mark_struct(&mut self, struct_id: StructId) {
+        if self.structs.insert(struct_id) {
+            let struct_type = self.interner.get_struct(struct_id);
+            let struct_type = struct_type.borrow();
+            let generics = vecmap(&struct_type.generics, |(_, var)| {
+                Type::TypeVariable(var.clone(), TypeVariableKind::Normal)
+            });
+            for (_, field_type) in struct_type.get_fields(&generics) {
+                self.mark_structs_in_type(&field_type);
+            }
+        }
+    }
+
+    /// Marks a trait as used from generic code, where any of its implementations may be called.
+    fn mark_trait(&mut self, trait_id: TraitId) {
+        if self.traits.insert(trait_id) {
+            let impls = self.impls_of_trait.get(&trait_id).cloned().unwrap_or_default();
+            impls.into_iter().for_each(|impl_id| self.mark_trait_impl(impl_id));
+        }
+    }
+
+    fn mark_trait_impl(&mut self, impl_id: TraitImplId) {
+        if self.trait_impls.insert(impl_id) {
+            let trait_impl = self.interner.get_trait_implementation(impl_id);
+            let methods = trait_impl.borrow().methods.clone();
+            methods.into_iter().for_each(|method| self.mark_function(method));
+        }
+    }
+
+    fn mark_structs_in_type(&mut self, typ: &Type) {
+        match typ.follow_bindings() {
+            Type::Struct(struct_type, generic_args) => {
+                self.mark_struct(struct_type.borrow().id);
+                generic_args.iter().for_each(|arg| self.mark_structs_in_type(arg));
+            }
+            Type::Array(length, element) => {
+                self.mark_structs_in_type(&length);
+                self.mark_structs_in_type(&element);
+            }
+            Type::FmtString(_, elements) => self.mark_structs_in_type(&elements),
+            Type::Tuple(elements) => elements.iter().for_each(|typ| self.mark_structs_in_type(typ)),
+            Type::Function(arguments, ret, env) => {
+                arguments.iter().for_each(|typ| self.mark_structs_in_type(typ));
+                self.mark_structs_in_type(&ret);
+                self.mark_structs_in_type(&env);
+            }
+            Type::MutableReference(element) | Type::Forall(_, element) => {
+                self.mark_structs_in_type(&element);
+            }
+            _ => (),
+        }
+    }
+
+    fn walk_expression(&mut self, expr_id: ExprId) {
+        self.mark_structs_in_type(&self.interner.id_type(expr_id));
+        match self.interner.get_selected_impl_for_ident(expr_id) {
+            Some(TraitImplKind::Normal(impl_id)) => self.mark_trait_impl(impl_id),
+            Some(TraitImplKind::Assumed { .. }) | None => (),
+        }
+
+        match self.interner.expression(&expr_id) {
+            HirExpression::Ident(ident) => match &self.interner.definition(ident.id).kind {
+                DefinitionKind::Function(func_id) => self.mark_function(*func_id),
+                DefinitionKind::Global(_) => {
+                    if let Some(global) = self.global_definitions.get(&ident.id).copied() {
+                        self.mark_global(global);
+                    }
+                }
+                DefinitionKind::Local(_) | DefinitionKind::GenericType(_) => (),
+            },
+            HirExpression::Literal(literal) => match literal {
+                HirLiteral::Array(HirArrayLiteral::Standard(elements)) => {
+                    elements.into_iter().for_each(|element| self.walk_expression(element));
+                }
+                HirLiteral::Array(HirArrayLiteral::Repeated { repeated_element, .. }) => {
+                    self.walk_expression(repeated_element);
+                }
+                HirLiteral::FmtStr(_, arguments) => {
+                    arguments.into_iter().for_each(|argument| self.walk_expression(argument));
+                }
+                HirLiteral::Bool(_) | HirLiteral::Integer(_) | HirLiteral::Str(_) => (),
+                HirLiteral::Unit => (),
+            },
+            HirExpression::Block(block) => {
+                block.0.into_iter().for_each(|statement| self.walk_statement(statement));
+            }
+            HirExpression::Prefix(prefix) => self.walk_expression(prefix.rhs),
+            HirExpression::Infix(infix) => {
+                self.walk_expression(infix.lhs);
+                self.walk_expression(infix.rhs);
+            }
+            HirExpression::Index(index) => {
+                self.walk_expression(index.collection);
+                self.walk_expression(index.index);
+            }
+            HirExpression::Constructor(constructor) => {
+                self.mark_struct(constructor.r#type.borrow().id);
+                for (_, field) in constructor.fields {
+                    self.walk_expression(field);
+                }
+            }
+            HirExpression::MemberAccess(access) => self.walk_expression(access.lhs),
+            HirExpression::Call(call) => {
+                self.walk_expression(call.func);
+                call.arguments.into_iter().for_each(|argument| self.walk_expression(argument));
+            }
+            HirExpression::MethodCall(call) => {
+                self.walk_expression(call.object);
+                call.arguments.into_iter().for_each(|argument| self.walk_expression(argument));
+            }
+            HirExpression::Cast(cast) => {
+                self.mark_structs_in_type(&cast.r#type);
+                self.walk_expression(cast.lhs);
+            }
+            HirExpression::If(if_expr) => {
+                self.walk_expression(if_expr.condition);
+                self.walk_expression(if_expr.consequence);
+                if let Some(alternative) = if_expr.alternative {
+                    self.walk_expression(alternative);
+                }
+            }
+            HirExpression::Tuple(elements) => {
+                elements.into_iter().for_each(|element| self.walk_expression(element));
+            }
+            HirExpression::Lambda(lambda) => self.walk_expression(lambda.body),
+            HirExpression::TraitMethodReference(method) => self.mark_trait(method.trait_id),
+            HirExpression::Error => (),
+        }
+    }
+
+    fn walk_statement(&mut self, stmt_id: StmtId) {
+        match self.interner.statement(&stmt_id) {
+            HirStatement::Let(let_statement) => {
+                self.mark_structs_in_type(&let_statement.r#type);
+                self.walk_pattern(&let_statement.pattern);
+                self.walk_expression(let_statement.expression);
+            }
+            HirStatement::Constrain(constrain) => self.walk_expression(constrain.0),
+            HirStatement::Assign(assign) => {
+                self.walk_lvalue(&assign.lvalue);
+                self.walk_expression(assign.expression);
+            }
+            HirStatement::For(for_loop) => {
+                self.walk_expression(for_loop.start_range);
+                self.walk_expression(for_loop.end_range);
+                self.walk_expression(for_loop.block);
+            }
+            HirStatement::Expression(expr_id) | HirStatement::Semi(expr_id) => {
+                self.walk_expression(expr_id);
+            }
+            HirStatement::Error => (),
+        }
+    }
+
+    fn walk_pattern(&mut self, pattern: &HirPattern) {
+        match pattern {
+            HirPattern::Identifier(_) => (),
+            HirPattern::Mutable(pattern, _) => self.walk_pattern(pattern),
+            HirPattern::Tuple(patterns, _) => {
+                patterns.iter().for_each(|pattern| self.walk_pattern(pattern));
+            }
+            HirPattern::Struct(typ, fields, _) => {
+                self.mark_structs_in_type(typ);
+                fields.iter().for_each(|(_, pattern)| self.walk_pattern(pattern));
+            }
+        }
+    }
+
+    fn walk_lvalue(&mut self, lvalue: &HirLValue) {
+        match lvalue {
+            HirLValue::Ident(..) => (),
+            HirLValue::MemberAccess { object, .. } => self.walk_lvalue(object),
+            HirLValue::Index { array, index, .. } => {
+                self.walk_lvalue(array);
+                self.walk_expression(*index);
+            }
+            HirLValue::Dereference { lvalue, .. } => self.walk_lvalue(lvalue),
+        }
+    }
+}
@@ -1,5 +1,8 @@
+use noirc_errors::error_codes::*;
 pub use noirc_errors::Span;
-use noirc_errors::{CustomDiagnostic as Diagnostic, FileDiagnostic};
+use noirc_errors::{
+    Applicability, CustomDiagnostic as Diagnostic, ErrorCode, FileDiagnostic, Suggestion,
+};
 use thiserror::Error;
 
 use crate::{parser::ParserError, Ident, Type};
@@ -19,7 +22,7 @@ pub enum ResolverError {
     #[error("Duplicate definition")]
     DuplicateDefinition { name: String, first_span: Span, second_span: Span },
     #[error("Unused variable")]
-    UnusedVariable { ident: Ident },
+    UnusedVariable { ident: Ident, is_struct_shorthand: bool },
     #[error("Could not find variable in this scope")]
     VariableNotDeclared { name: String, span: Span },
     #[error("path is not an identifier")]
@@ -39,7 +42,7 @@ pub enum ResolverError {
     #[error("Unneeded 'pub', function is not the main method")]
     UnnecessaryPub { ident: Ident, position: PubPosition },
     #[error("Required 'pub', main function must return public value")]
-    NecessaryPub { ident: Ident },
+    NecessaryPub { ident: Ident, return_type_span: Option<Span> },
     #[error("'distinct' keyword can only be used with main method")]
     DistinctNotAllowed { ident: Ident },
     #[error("Missing expression for declared constant")]
@@ -82,9 +85,56 @@ pub enum ResolverError {
     NonCrateFunctionCalled { name: String, span: Span },
     #[error("Only sized types may be used in the entry point to a program")]
     InvalidTypeForEntryPoint { span: Span },
+    #[error("Unused import")]
+    UnusedImport { ident: Ident },
+    #[error("Unused item")]
+    UnusedItem { item: &'static str, name: String, span: Span },
+    #[error("Unreachable code")]
+    UnreachableCode { span: Span },
 }
 
 impl ResolverError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ResolverError::DuplicateDefinition { .. } => E0300,
+            ResolverError::UnusedVariable { .. } => E0301,
+            ResolverError::VariableNotDeclared { .. } => E0302,
+            ResolverError::PathIsNotIdent { .. } => E0303,
+            ResolverError::PathResolutionError(error) => error.code(),
+            ResolverError::Expected { .. } => E0304,
+            ResolverError::DuplicateField { .. } => E0305,
+            ResolverError::NoSuchField { .. } => E0306,
+            ResolverError::MissingFields { .. } => E0307,
+            ResolverError::UnnecessaryMut { .. } => E0308,
+            ResolverError::UnnecessaryPub { .. } => E0309,
+            ResolverError::NecessaryPub { .. } => E0310,
+            ResolverError::DistinctNotAllowed { .. } => E0311,
+            ResolverError::MissingRhsExpr { .. } => E0312,
+            ResolverError::InvalidArrayLengthExpr { .. } => E0313,
+            ResolverError::IntegerTooLarge { .. } => E0314,
+            ResolverError::NoSuchNumericTypeVariable { .. } => E0315,
+            ResolverError::CapturedMutableVariable { .. } => E0316,
+            ResolverError::TestFunctionHasParameters { .. } => E0317,
+            ResolverError::NonStructUsedInConstructor { .. } => E0318,
+            ResolverError::NonStructWithGenerics { .. } => E0319,
+            ResolverError::GenericsOnSelfType { .. } => E0320,
+            ResolverError::IncorrectGenericCount { .. } => E0321,
+            ResolverError::ParserError(error) => error.code(),
+            ResolverError::ContractFunctionTypeInNormalFunction { .. } => E0322,
+            ResolverError::MutableReferenceToImmutableVariable { .. } => E0323,
+            ResolverError::MutableReferenceToArrayElement { .. } => E0324,
+            ResolverError::ContractFunctionInternalInNormalFunction { .. } => E0325,
+            ResolverError::NumericConstantInFormatString { .. } => E0326,
+            ResolverError::InvalidClosureEnvironment { .. } => E0327,
+            ResolverError::PrivateFunctionCalled { .. } => E0328,
+            ResolverError::NonCrateFunctionCalled { .. } => E0329,
+            ResolverError::InvalidTypeForEntryPoint { .. } => E0330,
+            ResolverError::UnusedImport { .. } => E0331,
+            ResolverError::UnusedItem { .. } => E0332,
+            ResolverError::UnreachableCode { .. } => E0333,
+        }
+    }
+
     pub fn into_file_diagnostic(self, file: fm::FileId) -> FileDiagnostic {
         Diagnostic::from(self).in_file(file)
     }
@@ -95,7 +145,8 @@ impl From<ResolverError> for Diagnostic {
     /// ICEs will make the compiler panic, as they could affect the
     /// soundness of the generated program
     fn from(error: ResolverError) -> Diagnostic {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             ResolverError::DuplicateDefinition { name, first_span, second_span } => {
                 let mut diag = Diagnostic::simple_error(
                     format!("duplicate definitions of {name} found"),
@@ -105,14 +156,23 @@ impl From<ResolverError> for Diagnostic {
                 diag.add_secondary("second definition found here".to_string(), second_span);
                 diag
             }
-            ResolverError::UnusedVariable { ident } => {
+            ResolverError::UnusedVariable { ident, is_struct_shorthand } => {
                 let name = &ident.0.contents;
 
-                Diagnostic::simple_warning(
+                let mut diagnostic = Diagnostic::simple_warning(
                     format!("unused variable {name}"),
                     "unused variable ".to_string(),
                     ident.span(),
-                )
+                );
+                let replacement =
+                    if is_struct_shorthand { format!("{name}: _{name}") } else { format!("_{name}") };
+                diagnostic.add_suggestion(Suggestion::new(
+                    format!("if this is intentional, prefix it with an underscore: `_{name}`"),
+                    ident.span(),
+                    replacement,
+                    Applicability::MachineApplicable,
+                ));
+                diagnostic
             }
             ResolverError::VariableNotDeclared { name, span } => Diagnostic::simple_error(
                 format!("cannot find `{name}` in this scope "),
@@ -188,7 +248,7 @@ impl From<ResolverError> for Diagnostic {
                 diag.add_note("The `pub` keyword only has effects on arguments to the entry-point function of a program. Thus, adding it to other function parameters can be deceiving and should be removed".to_owned());
                 diag
             }
-            ResolverError::NecessaryPub { ident } => {
+            ResolverError::NecessaryPub { ident, return_type_span } => {
                 let name = &ident.0.contents;
 
                 let mut diag = Diagnostic::simple_error(
@@ -198,6 +258,14 @@ impl From<ResolverError> for Diagnostic {
                 );
 
                 diag.add_note("The `pub` keyword is mandatory for the entry-point function return type because the verifier cannot retrieve private witness and thus the function will not be able to return a 'priv' value".to_owned());
+                if let Some(span) = return_type_span {
+                    diag.add_suggestion(Suggestion::new(
+                        "make the return value public".to_string(),
+                        Span::empty(span.start()),
+                        "pub ".to_string(),
+                        Applicability::MachineApplicable,
+                    ));
+                }
                 diag
             }
             ResolverError::DistinctNotAllowed { ident } => {
@@ -304,6 +372,22 @@ impl From<ResolverError> for Diagnostic {
             ResolverError::InvalidTypeForEntryPoint { span } => Diagnostic::simple_error(
                 "Only sized types may be used in the entry point to a program".to_string(),
                 "Slices, references, or any type containing them may not be used in main or a contract function".to_string(), span),
-        }
+            ResolverError::UnusedImport { ident } => Diagnostic::simple_warning(
+                format!("unused import {ident}"),
+                "unused import".to_string(),
+                ident.span(),
+            ),
+            ResolverError::UnusedItem { item, name, span } => Diagnostic::simple_warning(
+                format!("{item} {name} is never used"),
+                "not reachable from any entry point or public item".to_string(),
+                span,
+            ),
+            ResolverError::UnreachableCode { span } => Diagnostic::simple_warning(
+                "unreachable code".to_string(),
+                "this code comes after an `assert(false)` and is never executed".to_string(),
+                span,
+            ),
+        };
+        diagnostic.with_code(code)
     }
 }
@@ -29,8 +29,11 @@ pub(crate) fn resolve_function_set(
 ) -> Vec<(FileId, FuncId)> {
     let file_id = unresolved_functions.file_id;
 
-    let where_clause_errors =
-        unresolved_functions.resolve_trait_bounds_trait_ids(def_maps, crate_id);
+    let where_clause_errors = unresolved_functions.resolve_trait_bounds_trait_ids(
+        def_maps,
+        crate_id,
+        interner.usage_tracker_mut(),
+    );
     errors.extend(where_clause_errors.iter().cloned().map(|e| (e.into(), file_id)));
 
     vecmap(unresolved_functions.functions, |(mod_id, func_id, func)| {
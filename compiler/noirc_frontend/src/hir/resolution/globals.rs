@@ -41,13 +41,14 @@ pub(crate) fn resolve_globals(
         );
 
         let name = global.stmt_def.pattern.name_ident().clone();
+        let attributes = global.stmt_def.attributes.clone();
 
         let hir_stmt = resolver.resolve_global_let(global.stmt_def);
         errors.extend(take_errors(global.file_id, resolver));
 
         context.def_interner.update_global(global.stmt_id, hir_stmt);
 
-        context.def_interner.push_global(global.stmt_id, name, global.module_id);
+        context.def_interner.push_global(global.stmt_id, name, global.module_id, attributes);
 
         (global.file_id, global.stmt_id)
     });
@@ -67,7 +67,8 @@ pub(crate) fn collect_impls(
                     // be accessed with the `TypeName::method` syntax. We'll check later whether the
                     // object types in each method overlap or not. If they do, we issue an error.
                     // If not, that is specialization which is allowed.
-                    if module.declare_function(method.name_ident().clone(), *method_id).is_err() {
+                    let name = method.name_ident().clone();
+                    if module.declare_function(name, method.def.visibility, *method_id).is_err() {
                         module.remove_function(method.name_ident());
                     }
                 }
@@ -1,17 +1,24 @@
 use iter_extended::partition_results;
-use noirc_errors::{CustomDiagnostic, Span};
+use noirc_errors::error_codes::{E0220, E0221, E0222, E0223, E0224};
+use noirc_errors::{CustomDiagnostic, ErrorCode, Span};
 
 use crate::graph::CrateId;
 use std::collections::BTreeMap;
 
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleDefId, ModuleId, PerNs};
-use crate::{Ident, Path, PathKind};
+use crate::node_interner::UsageTracker;
+use crate::token::SecondaryAttribute;
+use crate::{Ident, ItemVisibility, Path, PathKind};
+
+use super::{can_reexport, can_reference_item};
 
 #[derive(Debug, Clone)]
 pub struct ImportDirective {
     pub module_id: LocalModuleId,
+    pub visibility: ItemVisibility,
     pub path: Path,
     pub alias: Option<Ident>,
+    pub attributes: Vec<SecondaryAttribute>,
 }
 
 pub type PathResolution = Result<PerNs, PathResolutionError>;
@@ -20,6 +27,21 @@ pub type PathResolution = Result<PerNs, PathResolutionError>;
 pub enum PathResolutionError {
     Unresolved(Ident),
     ExternalContractUsed(Ident),
+    Private(Ident),
+    PrivateToCrate(Ident),
+    ReexportTooVisible { ident: Ident, visibility: ItemVisibility },
+}
+
+impl PathResolutionError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            PathResolutionError::Unresolved(_) => E0220,
+            PathResolutionError::ExternalContractUsed(_) => E0221,
+            PathResolutionError::Private(_) => E0222,
+            PathResolutionError::PrivateToCrate(_) => E0223,
+            PathResolutionError::ReexportTooVisible { .. } => E0224,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -30,11 +52,16 @@ pub struct ResolvedImport {
     pub resolved_namespace: PerNs,
     // The module which we must add the resolved namespace to
     pub module_scope: LocalModuleId,
+    // The visibility of the `use` statement this import was declared in
+    pub visibility: ItemVisibility,
+    // The attributes of the `use` statement this import was declared in
+    pub attributes: Vec<SecondaryAttribute>,
 }
 
 impl From<PathResolutionError> for CustomDiagnostic {
     fn from(error: PathResolutionError) -> Self {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             PathResolutionError::Unresolved(ident) => CustomDiagnostic::simple_error(
                 format!("Could not resolve '{ident}' in path"),
                 String::new(),
@@ -45,7 +72,29 @@ impl From<PathResolutionError> for CustomDiagnostic {
                 "Contracts may only be referenced from within a contract".to_string(),
                 ident.span(),
             ),
-        }
+            PathResolutionError::Private(ident) => CustomDiagnostic::simple_error(
+                format!("'{ident}' is private and not visible from the current module"),
+                format!("'{ident}' is private"),
+                ident.span(),
+            ),
+            PathResolutionError::PrivateToCrate(ident) => CustomDiagnostic::simple_error(
+                format!("'{ident}' is only visible within its own crate"),
+                format!("'{ident}' is pub(crate)"),
+                ident.span(),
+            ),
+            PathResolutionError::ReexportTooVisible { ident, visibility } => {
+                let item_visibility = match visibility {
+                    ItemVisibility::PublicCrate => "pub(crate)",
+                    _ => "private",
+                };
+                CustomDiagnostic::simple_error(
+                    format!("'{ident}' is {item_visibility} and cannot be re-exported with a more visible `use`"),
+                    format!("'{ident}' is {item_visibility}"),
+                    ident.span(),
+                )
+            }
+        };
+        diagnostic.with_code(code)
     }
 }
 
@@ -53,6 +102,7 @@ pub fn resolve_imports(
     crate_id: CrateId,
     imports_to_resolve: Vec<ImportDirective>,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    usage_tracker: &mut UsageTracker,
 ) -> (Vec<ResolvedImport>, Vec<(PathResolutionError, LocalModuleId)>) {
     let def_map = &def_maps[&crate_id];
 
@@ -61,12 +111,30 @@ pub fn resolve_imports(
             allow_referencing_contracts(def_maps, crate_id, import_directive.module_id);
 
         let module_scope = import_directive.module_id;
-        let resolved_namespace =
-            resolve_path_to_ns(&import_directive, def_map, def_maps, allow_contracts)
-                .map_err(|error| (error, module_scope))?;
+        let importing_module = ModuleId { krate: crate_id, local_id: module_scope };
+        let resolved_namespace = resolve_path_to_ns(
+            &import_directive,
+            def_map,
+            def_maps,
+            importing_module,
+            allow_contracts,
+            usage_tracker,
+        )
+        .map_err(|error| (error, module_scope))?;
 
         let name = resolve_path_name(&import_directive);
-        Ok(ResolvedImport { name, resolved_namespace, module_scope })
+        let visibility = import_directive.visibility;
+        for (_, item_visibility) in resolved_namespace.iter_items() {
+            if !can_reexport(item_visibility, visibility) {
+                let ident = import_directive.path.segments.last().unwrap().clone();
+                let error =
+                    PathResolutionError::ReexportTooVisible { ident, visibility: item_visibility };
+                return Err((error, module_scope));
+            }
+        }
+
+        let attributes = import_directive.attributes;
+        Ok(ResolvedImport { name, resolved_namespace, module_scope, visibility, attributes })
     })
 }
 
@@ -78,22 +146,38 @@ pub(super) fn allow_referencing_contracts(
     ModuleId { krate, local_id }.module(def_maps).is_contract
 }
 
+/// Resolves the path of `import_directive`, checking that each item along it is visible
+/// from `importing_module`, the module the path was written in.
 pub fn resolve_path_to_ns(
     import_directive: &ImportDirective,
     def_map: &CrateDefMap,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    importing_module: ModuleId,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> PathResolution {
     let import_path = &import_directive.path.segments;
 
     match import_directive.path.kind {
         crate::ast::PathKind::Crate => {
             // Resolve from the root of the crate
-            resolve_path_from_crate_root(def_map, import_path, def_maps, allow_contracts)
-        }
-        crate::ast::PathKind::Dep => {
-            resolve_external_dep(def_map, import_directive, def_maps, allow_contracts)
+            resolve_path_from_crate_root(
+                def_map,
+                import_path,
+                def_maps,
+                importing_module,
+                allow_contracts,
+                usage_tracker,
+            )
         }
+        crate::ast::PathKind::Dep => resolve_external_dep(
+            def_map,
+            import_directive,
+            def_maps,
+            importing_module,
+            allow_contracts,
+            usage_tracker,
+        ),
         crate::ast::PathKind::Plain => {
             // Plain paths are only used to import children modules. It's possible to allow import of external deps, but maybe this distinction is better?
             // In Rust they can also point to external Dependencies, if no children can be found with the specified name
@@ -102,7 +186,9 @@ pub fn resolve_path_to_ns(
                 import_path,
                 import_directive.module_id,
                 def_maps,
+                importing_module,
                 allow_contracts,
+                usage_tracker,
             )
         }
     }
@@ -112,9 +198,19 @@ fn resolve_path_from_crate_root(
     def_map: &CrateDefMap,
     import_path: &[Ident],
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    importing_module: ModuleId,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> PathResolution {
-    resolve_name_in_module(def_map, import_path, def_map.root, def_maps, allow_contracts)
+    resolve_name_in_module(
+        def_map,
+        import_path,
+        def_map.root,
+        def_maps,
+        importing_module,
+        allow_contracts,
+        usage_tracker,
+    )
 }
 
 fn resolve_name_in_module(
@@ -122,8 +218,11 @@ fn resolve_name_in_module(
     import_path: &[Ident],
     starting_mod: LocalModuleId,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    importing_module: ModuleId,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> PathResolution {
+    let mut current_mod_id = ModuleId { krate: def_map.krate, local_id: starting_mod };
     let mut current_mod = &def_map.modules[starting_mod.0];
 
     // There is a possibility that the import path is empty
@@ -139,6 +238,8 @@ fn resolve_name_in_module(
     if current_ns.is_none() {
         return Err(PathResolutionError::Unresolved(first_segment.clone()));
     }
+    usage_tracker.mark_as_used(current_mod_id, first_segment);
+    check_visibility(def_maps, importing_module, current_mod_id, first_segment, current_ns)?;
 
     for segment in import_path {
         let typ = match current_ns.take_types() {
@@ -157,6 +258,7 @@ fn resolve_name_in_module(
             ModuleDefId::GlobalId(_) => panic!("globals cannot be in the type namespace"),
         };
 
+        current_mod_id = new_module_id;
         current_mod = &def_maps[&new_module_id.krate].modules[new_module_id.local_id.0];
 
         // Check if namespace
@@ -169,12 +271,39 @@ fn resolve_name_in_module(
         if current_mod.is_contract && !allow_contracts {
             return Err(PathResolutionError::ExternalContractUsed(segment.clone()));
         }
+        usage_tracker.mark_as_used(current_mod_id, segment);
+        check_visibility(def_maps, importing_module, current_mod_id, segment, found_ns)?;
         current_ns = found_ns;
     }
 
     Ok(current_ns)
 }
 
+/// Returns an error if an item of `ns`, found in `module`, is not visible from `importing_module`.
+/// Functions are skipped here as the resolver already warns when they are referenced.
+fn check_visibility(
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    importing_module: ModuleId,
+    module: ModuleId,
+    segment: &Ident,
+    ns: PerNs,
+) -> Result<(), PathResolutionError> {
+    for (item, visibility) in ns.iter_items() {
+        if matches!(item, ModuleDefId::FunctionId(_)) {
+            continue;
+        }
+
+        let ModuleId { krate, local_id } = importing_module;
+        if !can_reference_item(def_maps, krate, local_id, module, visibility) {
+            return Err(match visibility {
+                ItemVisibility::PublicCrate => PathResolutionError::PrivateToCrate(segment.clone()),
+                _ => PathResolutionError::Private(segment.clone()),
+            });
+        }
+    }
+    Ok(())
+}
+
 fn resolve_path_name(import_directive: &ImportDirective) -> Ident {
     match &import_directive.alias {
         None => import_directive.path.segments.last().unwrap().clone(),
@@ -186,7 +315,9 @@ fn resolve_external_dep(
     current_def_map: &CrateDefMap,
     directive: &ImportDirective,
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    importing_module: ModuleId,
     allow_contracts: bool,
+    usage_tracker: &mut UsageTracker,
 ) -> PathResolution {
     // Use extern_prelude to get the dep
     //
@@ -207,10 +338,22 @@ fn resolve_external_dep(
         kind: PathKind::Plain,
         span: Span::default(),
     };
-    let dep_directive =
-        ImportDirective { module_id: dep_module.local_id, path, alias: directive.alias.clone() };
+    let dep_directive = ImportDirective {
+        module_id: dep_module.local_id,
+        visibility: directive.visibility,
+        path,
+        alias: directive.alias.clone(),
+        attributes: Vec::new(),
+    };
 
     let dep_def_map = def_maps.get(&dep_module.krate).unwrap();
 
-    resolve_path_to_ns(&dep_directive, dep_def_map, def_maps, allow_contracts)
+    resolve_path_to_ns(
+        &dep_directive,
+        dep_def_map,
+        def_maps,
+        importing_module,
+        allow_contracts,
+        usage_tracker,
+    )
 }
@@ -10,13 +10,16 @@ pub mod import;
 pub mod path_resolver;
 pub mod resolver;
 
+mod dead_code;
 mod functions;
 mod globals;
 mod impls;
 mod structs;
 mod traits;
 mod type_aliases;
+mod visibility;
 
+pub use dead_code::find_dead_code;
 pub(crate) use functions::resolve_free_functions;
 pub(crate) use globals::resolve_globals;
 pub(crate) use impls::{collect_impls, resolve_impls};
@@ -25,6 +28,7 @@ pub(crate) use traits::{
     collect_trait_impls, resolve_trait_by_path, resolve_trait_impls, resolve_traits,
 };
 pub(crate) use type_aliases::resolve_type_aliases;
+pub(crate) use visibility::{can_reexport, can_reference_item, module_descendent_of_target};
 
 use crate::{
     graph::CrateId,
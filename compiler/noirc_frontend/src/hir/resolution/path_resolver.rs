@@ -1,18 +1,21 @@
 use super::import::{
     allow_referencing_contracts, resolve_path_to_ns, ImportDirective, PathResolutionError,
 };
-use crate::Path;
+use crate::{ItemVisibility, Path};
 use std::collections::BTreeMap;
 
 use crate::graph::CrateId;
 use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleDefId, ModuleId};
+use crate::node_interner::UsageTracker;
 
 pub trait PathResolver {
     /// Resolve the given path returning the resolved ModuleDefId.
+    /// Each name the path is resolved through is marked as used in `usage_tracker`.
     fn resolve(
         &self,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         path: Path,
+        usage_tracker: &mut UsageTracker,
     ) -> Result<ModuleDefId, PathResolutionError>;
 
     fn local_module_id(&self) -> LocalModuleId;
@@ -36,8 +39,9 @@ impl PathResolver for StandardPathResolver {
         &self,
         def_maps: &BTreeMap<CrateId, CrateDefMap>,
         path: Path,
+        usage_tracker: &mut UsageTracker,
     ) -> Result<ModuleDefId, PathResolutionError> {
-        resolve_path(def_maps, self.module_id, path)
+        resolve_path(def_maps, self.module_id, path, usage_tracker)
     }
 
     fn local_module_id(&self) -> LocalModuleId {
@@ -55,14 +59,28 @@ pub fn resolve_path(
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     module_id: ModuleId,
     path: Path,
+    usage_tracker: &mut UsageTracker,
 ) -> Result<ModuleDefId, PathResolutionError> {
     // lets package up the path into an ImportDirective and resolve it using that
-    let import = ImportDirective { module_id: module_id.local_id, path, alias: None };
+    let import = ImportDirective {
+        module_id: module_id.local_id,
+        visibility: ItemVisibility::Private,
+        path,
+        alias: None,
+        attributes: Vec::new(),
+    };
     let allow_referencing_contracts =
         allow_referencing_contracts(def_maps, module_id.krate, module_id.local_id);
 
     let def_map = &def_maps[&module_id.krate];
-    let ns = resolve_path_to_ns(&import, def_map, def_maps, allow_referencing_contracts)?;
+    let ns = resolve_path_to_ns(
+        &import,
+        def_map,
+        def_maps,
+        module_id,
+        allow_referencing_contracts,
+        usage_tracker,
+    )?;
 
     let function = ns.values.map(|(id, _)| id);
     let id = function.or_else(|| ns.types.map(|(id, _)| id));
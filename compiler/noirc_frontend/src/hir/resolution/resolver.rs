@@ -10,7 +10,8 @@
 //
 // XXX: Change mentions of intern to resolve. In regards to the above comment
 //
-// XXX: Resolver does not check for unused functions
+// Unused functions and other items are reported separately by the `dead_code` pass once the
+// whole crate is resolved and type checked.
 use crate::hir_def::expr::{
     HirArrayLiteral, HirBinaryOp, HirBlockExpression, HirCallExpression, HirCapturedVar,
     HirCastExpression, HirConstructorExpression, HirExpression, HirIdent, HirIfExpression,
@@ -25,7 +26,7 @@ use std::collections::{BTreeMap, HashSet};
 use std::rc::Rc;
 
 use crate::graph::CrateId;
-use crate::hir::def_map::{LocalModuleId, ModuleDefId, TryFromModuleDefId, MAIN_FUNCTION};
+use crate::hir::def_map::{ModuleDefId, TryFromModuleDefId, MAIN_FUNCTION};
 use crate::hir_def::stmt::{HirAssignStatement, HirForStatement, HirLValue, HirPattern};
 use crate::node_interner::{
     DefinitionId, DefinitionKind, ExprId, FuncId, NodeInterner, StmtId, StructId, TraitId,
@@ -33,12 +34,12 @@ use crate::node_interner::{
 };
 use crate::{
     hir::{def_map::CrateDefMap, resolution::path_resolver::PathResolver},
-    BlockExpression, Expression, ExpressionKind, FunctionKind, Ident, Literal, NoirFunction,
-    StatementKind,
+    BlockExpression, ConstrainKind, ConstrainStatement, Expression, ExpressionKind, FunctionKind,
+    Ident, Literal, NoirFunction, Statement, StatementKind,
 };
 use crate::{
-    ArrayLiteral, ContractFunctionType, Distinctness, ForRange, FunctionVisibility, Generics,
-    LValue, NoirStruct, NoirTypeAlias, Param, Path, PathKind, Pattern, Shared, StructType, Type,
+    ArrayLiteral, ContractFunctionType, Distinctness, ForRange, Generics, ItemVisibility, LValue,
+    NoirStruct, NoirTypeAlias, Param, Path, PathKind, Pattern, Shared, StructType, Type,
     TypeAliasType, TypeBinding, TypeVariable, UnaryOp, UnresolvedGenerics,
     UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression,
     Visibility, ERROR_IDENT,
@@ -55,6 +56,7 @@ use crate::hir_def::{
     stmt::{HirConstrainStatement, HirLetStatement, HirStatement},
 };
 
+use super::can_reference_item;
 use super::errors::{PubPosition, ResolverError};
 
 const SELF_TYPE_NAME: &str = "Self";
@@ -111,6 +113,10 @@ pub struct Resolver<'a> {
     /// that are captured. We do this in order to create the hidden environment
     /// parameter for the lambda function.
     lambda_stack: Vec<LambdaContext>,
+
+    /// Spans of the variables bound by shorthand struct pattern fields, e.g. `x` in `Foo { x }`,
+    /// which can't simply be renamed when suggesting a fix for them being unused.
+    struct_pattern_shorthands: HashSet<Span>,
 }
 
 /// ResolverMetas are tagged onto each definition to track how many times they are used
@@ -150,6 +156,7 @@ impl<'a> Resolver<'a> {
             current_trait_impl: None,
             file,
             in_contract,
+            struct_pattern_shorthands: HashSet::new(),
         }
     }
 
@@ -210,8 +217,10 @@ impl<'a> Resolver<'a> {
             if let Some(definition_info) = self.interner.try_definition(unused_var.id) {
                 let name = &definition_info.name;
                 if name != ERROR_IDENT && !definition_info.is_global() {
-                    let ident = Ident(Spanned::from(unused_var.location.span, name.to_owned()));
-                    self.push_err(ResolverError::UnusedVariable { ident });
+                    let span = unused_var.location.span;
+                    let ident = Ident(Spanned::from(span, name.to_owned()));
+                    let is_struct_shorthand = self.struct_pattern_shorthands.contains(&span);
+                    self.push_err(ResolverError::UnusedVariable { ident, is_struct_shorthand });
                 }
             }
         }
@@ -556,8 +565,10 @@ impl<'a> Resolver<'a> {
         }
 
         // If we cannot find a local generic of the same name, try to look up a global
-        match self.path_resolver.resolve(self.def_maps, path.clone()) {
+        let usage_tracker = self.interner.usage_tracker_mut();
+        match self.path_resolver.resolve(self.def_maps, path.clone(), usage_tracker) {
             Ok(ModuleDefId::GlobalId(id)) => {
+                self.interner.usage_tracker_mut().mark_global_used_in_type(id);
                 Some(Type::Constant(self.eval_global_as_array_length(id)))
             }
             _ => None,
@@ -706,13 +717,15 @@ impl<'a> Resolver<'a> {
     pub fn resolve_struct_fields(
         mut self,
         unresolved: NoirStruct,
-    ) -> (Generics, Vec<(Ident, Type)>, Vec<ResolverError>) {
+    ) -> (Generics, Vec<(ItemVisibility, Ident, Type)>, Vec<ResolverError>) {
         let generics = self.add_generics(&unresolved.generics);
 
         // Check whether the struct definition has globals in the local module and add them to the scope
         self.resolve_local_globals();
 
-        let fields = vecmap(unresolved.fields, |(ident, typ)| (ident, self.resolve_type(typ)));
+        let fields = vecmap(unresolved.fields, |field| {
+            (field.visibility, field.name, self.resolve_type(field.typ))
+        });
 
         (generics, fields, self.errors)
     }
@@ -796,7 +809,10 @@ impl<'a> Resolver<'a> {
             && return_type.as_ref() != &Type::Unit
             && func.def.return_visibility != Visibility::Public
         {
-            self.push_err(ResolverError::NecessaryPub { ident: func.name_ident().clone() });
+            self.push_err(ResolverError::NecessaryPub {
+                ident: func.name_ident().clone(),
+                return_type_span: func.return_type().span,
+            });
         }
 
         if !self.distinct_allowed(func)
@@ -1100,51 +1116,28 @@ impl<'a> Resolver<'a> {
         &mut self,
         func: FuncId,
         span: Span,
-        visibility: FunctionVisibility,
+        visibility: ItemVisibility,
     ) {
         let function_module = self.interner.function_module(func);
         let current_module = self.path_resolver.module_id();
 
-        let same_crate = function_module.krate == current_module.krate;
-        let krate = function_module.krate;
-        let current_module = current_module.local_id;
+        if can_reference_item(
+            self.def_maps,
+            current_module.krate,
+            current_module.local_id,
+            function_module,
+            visibility,
+        ) {
+            return;
+        }
+
         let name = self.interner.function_name(&func).to_string();
         match visibility {
-            FunctionVisibility::Public => (),
-            FunctionVisibility::Private => {
-                if !same_crate
-                    || !self.module_descendent_of_target(
-                        krate,
-                        function_module.local_id,
-                        current_module,
-                    )
-                {
-                    self.errors.push(ResolverError::PrivateFunctionCalled { span, name });
-                }
+            ItemVisibility::PublicCrate => {
+                self.errors.push(ResolverError::NonCrateFunctionCalled { span, name });
             }
-            FunctionVisibility::PublicCrate => {
-                if !same_crate {
-                    self.errors.push(ResolverError::NonCrateFunctionCalled { span, name });
-                }
-            }
-        }
-    }
-
-    // Returns true if `current` is a (potentially nested) child module of `target`.
-    // This is also true if `current == target`.
-    fn module_descendent_of_target(
-        &self,
-        krate: CrateId,
-        target: LocalModuleId,
-        current: LocalModuleId,
-    ) -> bool {
-        if current == target {
-            return true;
+            _ => self.errors.push(ResolverError::PrivateFunctionCalled { span, name }),
         }
-
-        self.def_maps[&krate].modules[current.0]
-            .parent
-            .map_or(false, |parent| self.module_descendent_of_target(krate, target, parent))
     }
 
     fn resolve_local_variable(&mut self, hir_ident: HirIdent, var_scope_index: usize) {
@@ -1225,9 +1218,7 @@ impl<'a> Resolver<'a> {
                     if hir_ident.id != DefinitionId::dummy_id() {
                         match self.interner.definition(hir_ident.id).kind {
                             DefinitionKind::Function(id) => {
-                                if self.interner.function_visibility(id)
-                                    != FunctionVisibility::Public
-                                {
+                                if self.interner.function_visibility(id) != ItemVisibility::Public {
                                     let span = hir_ident.location.span;
                                     self.check_can_reference_function(
                                         id,
@@ -1437,6 +1428,12 @@ impl<'a> Resolver<'a> {
                     }
                 };
 
+                for (field, pattern) in &fields {
+                    if matches!(pattern, Pattern::Identifier(name) if name.span() == field.span()) {
+                        self.struct_pattern_shorthands.insert(field.span());
+                    }
+                }
+
                 let resolve_field = |this: &mut Self, pattern| {
                     this.resolve_pattern_mutable(pattern, mutable, definition.clone())
                 };
@@ -1627,8 +1624,10 @@ impl<'a> Resolver<'a> {
                     continue;
                 }
 
+                let usage_tracker = self.interner.usage_tracker_mut();
+                let trait_path = trait_bound.trait_path.clone();
                 if let Ok(ModuleDefId::TraitId(trait_id)) =
-                    self.path_resolver.resolve(self.def_maps, trait_bound.trait_path.clone())
+                    self.path_resolver.resolve(self.def_maps, trait_path, usage_tracker)
                 {
                     let the_trait = self.interner.get_trait(trait_id);
                     if let Some(method) =
@@ -1649,15 +1648,39 @@ impl<'a> Resolver<'a> {
     }
 
     fn resolve_path(&mut self, path: Path) -> Result<ModuleDefId, ResolverError> {
-        self.path_resolver.resolve(self.def_maps, path).map_err(ResolverError::PathResolutionError)
+        let usage_tracker = self.interner.usage_tracker_mut();
+        self.path_resolver
+            .resolve(self.def_maps, path, usage_tracker)
+            .map_err(ResolverError::PathResolutionError)
     }
 
     fn resolve_block(&mut self, block_expr: BlockExpression) -> HirExpression {
+        self.check_for_unreachable_code(&block_expr);
         let statements =
             self.in_new_scope(|this| vecmap(block_expr.0, |stmt| this.intern_stmt(stmt.kind)));
         HirExpression::Block(HirBlockExpression(statements))
     }
 
+    /// Warns about any statements following an `assert(false)` in the given block.
+    fn check_for_unreachable_code(&mut self, block_expr: &BlockExpression) {
+        let is_assert_false = |statement: &Statement| {
+            matches!(
+                &statement.kind,
+                StatementKind::Constrain(ConstrainStatement(
+                    Expression { kind: ExpressionKind::Literal(Literal::Bool(false)), .. },
+                    _,
+                    ConstrainKind::Assert,
+                ))
+            )
+        };
+
+        let Some(index) = block_expr.0.iter().position(is_assert_false) else { return };
+        if let (Some(first), Some(last)) = (block_expr.0.get(index + 1), block_expr.0.last()) {
+            let span = first.span.merge(last.span);
+            self.push_err(ResolverError::UnreachableCode { span });
+        }
+    }
+
     pub fn intern_block(&mut self, block: BlockExpression) -> ExprId {
         let hir_block = self.resolve_block(block);
         self.interner.push_expr(hir_block)
@@ -11,7 +11,7 @@ use crate::{
         Context,
     },
     node_interner::StructId,
-    Generics, Ident, Type,
+    Generics, Ident, ItemVisibility, Type,
 };
 
 use super::{errors::ResolverError, path_resolver::StandardPathResolver, resolver::Resolver};
@@ -42,7 +42,7 @@ fn resolve_struct_fields(
     context: &mut Context,
     krate: CrateId,
     unresolved: UnresolvedStruct,
-) -> (Generics, Vec<(Ident, Type)>, Vec<ResolverError>) {
+) -> (Generics, Vec<(ItemVisibility, Ident, Type)>, Vec<ResolverError>) {
     let path_resolver =
         StandardPathResolver::new(ModuleId { local_id: unresolved.module_id, krate });
     let file_id = unresolved.file_id;
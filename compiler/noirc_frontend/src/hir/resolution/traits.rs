@@ -17,8 +17,8 @@ use crate::{
         Context,
     },
     hir_def::traits::{Trait, TraitConstant, TraitFunction, TraitImpl, TraitType},
-    node_interner::{FuncId, NodeInterner, TraitId},
-    Path, Shared, TraitItem, Type, TypeVariableKind,
+    node_interner::{FuncId, NodeInterner, TraitId, UsageTracker},
+    ItemVisibility, Path, Shared, TraitItem, Type, TypeVariableKind,
 };
 
 use super::{
@@ -236,14 +236,18 @@ fn collect_trait_impl(
     let mut errors: Vec<(CompilationError, FileId)> = vec![];
     let unresolved_type = trait_impl.object_type.clone();
     let module = ModuleId { local_id: trait_impl.module_id, krate: crate_id };
-    trait_impl.trait_id =
-        match resolve_trait_by_path(def_maps, module, trait_impl.trait_path.clone()) {
-            Ok(trait_id) => Some(trait_id),
-            Err(error) => {
-                errors.push((error.into(), trait_impl.file_id));
-                None
-            }
-        };
+    trait_impl.trait_id = match resolve_trait_by_path(
+        def_maps,
+        module,
+        trait_impl.trait_path.clone(),
+        interner.usage_tracker_mut(),
+    ) {
+        Ok(trait_id) => Some(trait_id),
+        Err(error) => {
+            errors.push((error.into(), trait_impl.file_id));
+            None
+        }
+    };
 
     if let Some(trait_id) = trait_impl.trait_id {
         errors
@@ -265,7 +269,8 @@ fn collect_trait_impl(
                 // be accessed with the `TypeName::method` syntax. We'll check later whether the
                 // object types in each method overlap or not. If they do, we issue an error.
                 // If not, that is specialization which is allowed.
-                if module.declare_function(method.name_ident().clone(), *method_id).is_err() {
+                let name = method.name_ident().clone();
+                if module.declare_function(name, ItemVisibility::Public, *method_id).is_err() {
                     module.remove_function(method.name_ident());
                 }
             }
@@ -318,10 +323,11 @@ pub(crate) fn resolve_trait_by_path(
     def_maps: &BTreeMap<CrateId, CrateDefMap>,
     module: ModuleId,
     path: Path,
+    usage_tracker: &mut UsageTracker,
 ) -> Result<TraitId, DefCollectorErrorKind> {
     let path_resolver = StandardPathResolver::new(module);
 
-    match path_resolver.resolve(def_maps, path.clone()) {
+    match path_resolver.resolve(def_maps, path.clone(), usage_tracker) {
         Ok(ModuleDefId::TraitId(trait_id)) => Ok(trait_id),
         Ok(_) => Err(DefCollectorErrorKind::NotATrait { not_a_trait_name: path }),
         Err(_) => Err(DefCollectorErrorKind::TraitNotFound { trait_path: path }),
@@ -399,6 +405,7 @@ pub(crate) fn resolve_trait_impls(
 
             let resolved_trait_impl = Shared::new(TraitImpl {
                 ident: trait_impl.trait_path.last_segment().clone(),
+                attributes: trait_impl.attributes,
                 typ: self_type.clone(),
                 trait_id,
                 file: trait_impl.file_id,
@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use crate::graph::CrateId;
+use crate::hir::def_map::{CrateDefMap, LocalModuleId, ModuleId};
+use crate::ItemVisibility;
+
+/// Returns true if an item with the given visibility, declared in `target_module`, can be
+/// referenced from `current_module` of `current_crate`.
+pub(crate) fn can_reference_item(
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    current_crate: CrateId,
+    current_module: LocalModuleId,
+    target_module: ModuleId,
+    visibility: ItemVisibility,
+) -> bool {
+    let same_crate = target_module.krate == current_crate;
+    match visibility {
+        ItemVisibility::Public => true,
+        ItemVisibility::PublicCrate => same_crate,
+        ItemVisibility::Private => {
+            same_crate
+                && module_descendent_of_target(
+                    &def_maps[&current_crate],
+                    target_module.local_id,
+                    current_module,
+                )
+        }
+    }
+}
+
+/// Returns true if an item with the given visibility can be re-exported by a `use` statement
+/// with `import_visibility`, i.e. the import doesn't make the item visible to more modules.
+pub(crate) fn can_reexport(item: ItemVisibility, import_visibility: ItemVisibility) -> bool {
+    match import_visibility {
+        ItemVisibility::Private => true,
+        ItemVisibility::PublicCrate => item != ItemVisibility::Private,
+        ItemVisibility::Public => item == ItemVisibility::Public,
+    }
+}
+
+// Returns true if `current` is a (potentially nested) child module of `target`.
+// This is also true if `current == target`.
+pub(crate) fn module_descendent_of_target(
+    def_map: &CrateDefMap,
+    target: LocalModuleId,
+    current: LocalModuleId,
+) -> bool {
+    if current == target {
+        return true;
+    }
+
+    def_map.modules[current.0]
+        .parent
+        .map_or(false, |parent| module_descendent_of_target(def_map, target, parent))
+}
@@ -1,6 +1,7 @@
 use acvm::FieldElement;
+use noirc_errors::error_codes::*;
 use noirc_errors::CustomDiagnostic as Diagnostic;
-use noirc_errors::Span;
+use noirc_errors::{ErrorCode, Span};
 use thiserror::Error;
 
 use crate::hir::resolution::errors::ResolverError;
@@ -115,9 +116,52 @@ pub enum TypeCheckError {
     NoMatchingImplFound { constraints: Vec<(Type, String)>, span: Span },
     #[error("Constraint for `{typ}: {trait_name}` is not needed, another matching impl is already in scope")]
     UnneededTraitConstraint { trait_name: String, typ: Type, span: Span },
+    #[error("Field '{field_name}' of struct '{struct_name}' is private")]
+    PrivateStructField { field_name: String, struct_name: String, span: Span },
 }
 
 impl TypeCheckError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            TypeCheckError::OpCannotBeUsed { .. } => E0400,
+            TypeCheckError::OverflowingAssignment { .. } => E0401,
+            TypeCheckError::TypeCannotBeUsed { .. } => E0402,
+            TypeCheckError::TypeMismatch { .. } => E0403,
+            TypeCheckError::TypeMismatchWithSource { .. } => E0404,
+            TypeCheckError::ArityMisMatch { .. } => E0405,
+            TypeCheckError::PublicReturnType { .. } => E0406,
+            TypeCheckError::InvalidCast { .. } => E0407,
+            TypeCheckError::ExpectedFunction { .. } => E0408,
+            TypeCheckError::AccessUnknownMember { .. } => E0409,
+            TypeCheckError::ParameterCountMismatch { .. } => E0410,
+            TypeCheckError::UnsupportedCast { .. } => E0411,
+            TypeCheckError::TupleIndexOutOfBounds { .. } => E0412,
+            TypeCheckError::VariableMustBeMutable { .. } => E0413,
+            TypeCheckError::UnresolvedMethodCall { .. } => E0414,
+            TypeCheckError::InvalidComparisonOnField { .. } => E0415,
+            TypeCheckError::IntegerSignedness { .. } => E0416,
+            TypeCheckError::IntegerBitWidth { .. } => E0417,
+            TypeCheckError::InvalidInfixOp { .. } => E0418,
+            TypeCheckError::InvalidUnaryOp { .. } => E0419,
+            TypeCheckError::InvalidBitwiseOperationOnField { .. } => E0420,
+            TypeCheckError::IntegerTypeMismatch { .. } => E0421,
+            TypeCheckError::IntegerAndFieldBinaryOperation { .. } => E0422,
+            TypeCheckError::FieldModulo { .. } => E0423,
+            TypeCheckError::FieldComparison { .. } => E0424,
+            TypeCheckError::AmbiguousBitWidth { .. } => E0425,
+            TypeCheckError::NonHomogeneousArray { .. } => E0426,
+            TypeCheckError::TypeAnnotationsNeeded { .. } => E0427,
+            TypeCheckError::CallDeprecated { .. } => E0428,
+            TypeCheckError::UnusedResultError { .. } => E0429,
+            TypeCheckError::TraitMethodParameterTypeMismatch { .. } => E0430,
+            TypeCheckError::NoMatchingImplFound { .. } => E0431,
+            TypeCheckError::UnneededTraitConstraint { .. } => E0432,
+            TypeCheckError::PrivateStructField { .. } => E0433,
+            TypeCheckError::Context { err, .. } => err.code(),
+            TypeCheckError::ResolverError(error) => error.code(),
+        }
+    }
+
     pub fn add_context(self, ctx: &'static str) -> Self {
         TypeCheckError::Context { err: Box::new(self), ctx }
     }
@@ -125,7 +169,8 @@ impl TypeCheckError {
 
 impl From<TypeCheckError> for Diagnostic {
     fn from(error: TypeCheckError) -> Diagnostic {
-        match error {
+        let code = error.code();
+        let diagnostic = match error {
             TypeCheckError::TypeCannotBeUsed { typ, place, span } => Diagnostic::simple_error(
                 format!("The type {} cannot be used in a {}", &typ, place),
                 String::new(),
@@ -202,6 +247,7 @@ impl From<TypeCheckError> for Diagnostic {
             | TypeCheckError::AmbiguousBitWidth { span, .. }
             | TypeCheckError::IntegerAndFieldBinaryOperation { span }
             | TypeCheckError::OverflowingAssignment { span, .. }
+            | TypeCheckError::PrivateStructField { span, .. }
             | TypeCheckError::FieldModulo { span } => {
                 Diagnostic::simple_error(error.to_string(), String::new(), span)
             }
@@ -241,7 +287,7 @@ impl From<TypeCheckError> for Diagnostic {
 
                         diagnostic.add_secondary(format!("{actual} returned here"), expr_span);
 
-                        return diagnostic
+                        return diagnostic.with_code(code)
                     },
                 };
 
@@ -275,6 +321,7 @@ impl From<TypeCheckError> for Diagnostic {
                 let msg = format!("Constraint for `{typ}: {trait_name}` is not needed, another matching impl is already in scope");
                 Diagnostic::simple_warning(msg, "Unnecessary trait constraint in where clause".into(), span)
             }
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
@@ -631,6 +631,7 @@ impl<'interner> TypeChecker<'interner> {
             // mismatch here as long as we continue typechecking the rest of the program to the best
             // of our ability.
             if param_name == arg_ident.0.contents {
+                self.check_field_visibility(&typ.borrow(), &param_name, arg_ident.span());
                 let arg_type = self.check_expression(&arg);
 
                 let span = self.interner.expr_span(expr_id);
@@ -702,6 +703,7 @@ impl<'interner> TypeChecker<'interner> {
             Type::Struct(s, args) => {
                 let s = s.borrow();
                 if let Some((field, index)) = s.get_field(field_name, args) {
+                    self.check_field_visibility(&s, field_name, span);
                     return Some((field, index));
                 }
             }
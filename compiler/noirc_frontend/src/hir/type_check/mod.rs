@@ -13,10 +13,19 @@ mod stmt;
 
 pub use errors::TypeCheckError;
 
+use std::collections::BTreeMap;
+
+use noirc_errors::Span;
+
 use crate::{
+    graph::CrateId,
+    hir::{
+        def_map::{CrateDefMap, ModuleId},
+        resolution::can_reference_item,
+    },
     hir_def::{expr::HirExpression, stmt::HirStatement, traits::TraitConstraint},
     node_interner::{ExprId, FuncId, NodeInterner, StmtId},
-    Type,
+    StructType, Type,
 };
 
 use self::errors::Source;
@@ -26,6 +35,8 @@ type TypeCheckFn = Box<dyn FnOnce() -> Result<(), TypeCheckError>>;
 pub struct TypeChecker<'interner> {
     delayed_type_checks: Vec<TypeCheckFn>,
     interner: &'interner mut NodeInterner,
+    /// Used to check whether struct fields are visible from the current function's module
+    def_maps: &'interner BTreeMap<CrateId, CrateDefMap>,
     errors: Vec<TypeCheckError>,
     current_function: Option<FuncId>,
 
@@ -38,7 +49,11 @@ pub struct TypeChecker<'interner> {
 
 /// Type checks a function and assigns the
 /// appropriate types to expressions in a side table
-pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<TypeCheckError> {
+pub fn type_check_func(
+    interner: &mut NodeInterner,
+    def_maps: &BTreeMap<CrateId, CrateDefMap>,
+    func_id: FuncId,
+) -> Vec<TypeCheckError> {
     let meta = interner.function_meta(&func_id);
     let declared_return_type = meta.return_type().clone();
     let can_ignore_ret = meta.can_ignore_return_type();
@@ -46,7 +61,7 @@ pub fn type_check_func(interner: &mut NodeInterner, func_id: FuncId) -> Vec<Type
     let function_body = interner.function(&func_id);
     let function_body_id = function_body.as_expr();
 
-    let mut type_checker = TypeChecker::new(interner);
+    let mut type_checker = TypeChecker::new(interner, def_maps);
     type_checker.current_function = Some(func_id);
 
     let meta = type_checker.interner.function_meta(&func_id);
@@ -159,10 +174,14 @@ fn function_info(interner: &NodeInterner, function_body_id: &ExprId) -> (noirc_e
 }
 
 impl<'interner> TypeChecker<'interner> {
-    fn new(interner: &'interner mut NodeInterner) -> Self {
+    fn new(
+        interner: &'interner mut NodeInterner,
+        def_maps: &'interner BTreeMap<CrateId, CrateDefMap>,
+    ) -> Self {
         Self {
             delayed_type_checks: Vec::new(),
             interner,
+            def_maps,
             errors: Vec::new(),
             trait_constraints: Vec::new(),
             current_function: None,
@@ -178,10 +197,15 @@ impl<'interner> TypeChecker<'interner> {
         (body_type, std::mem::take(&mut self.delayed_type_checks))
     }
 
-    pub fn check_global(id: &StmtId, interner: &'interner mut NodeInterner) -> Vec<TypeCheckError> {
+    pub fn check_global(
+        id: &StmtId,
+        interner: &'interner mut NodeInterner,
+        def_maps: &'interner BTreeMap<CrateId, CrateDefMap>,
+    ) -> Vec<TypeCheckError> {
         let mut this = Self {
             delayed_type_checks: Vec::new(),
             interner,
+            def_maps,
             errors: Vec::new(),
             trait_constraints: Vec::new(),
             current_function: None,
@@ -190,6 +214,37 @@ impl<'interner> TypeChecker<'interner> {
         this.errors
     }
 
+    /// Pushes an error if the field `field_name` of `struct_type` is not visible from the module
+    /// of the function being type checked. Fields are visible within the module declaring the
+    /// struct and its children unless they are marked `pub` or `pub(crate)`.
+    fn check_field_visibility(&mut self, struct_type: &StructType, field_name: &str, span: Span) {
+        let (Some(visibility), Some(function)) =
+            (struct_type.field_visibility(field_name), self.current_function)
+        else {
+            return;
+        };
+
+        // Each struct has its own module for its methods, whose parent declares the struct.
+        let struct_module = struct_type.id.module_id();
+        let Some(def_map) = self.def_maps.get(&struct_module.krate) else {
+            return;
+        };
+        let Some(parent) = def_map.modules()[struct_module.local_id.0].parent else {
+            return;
+        };
+
+        let target_module = ModuleId { krate: struct_module.krate, local_id: parent };
+        let current_module = self.interner.function_module(function);
+        let ModuleId { krate, local_id } = current_module;
+        if !can_reference_item(self.def_maps, krate, local_id, target_module, visibility) {
+            self.errors.push(TypeCheckError::PrivateStructField {
+                field_name: field_name.to_string(),
+                struct_name: struct_type.name.to_string(),
+                span,
+            });
+        }
+    }
+
     /// Wrapper of Type::unify using self.errors
     fn unify(
         &mut self,
@@ -241,7 +296,7 @@ mod test {
         function::{FuncMeta, HirFunction},
         stmt::HirStatement,
     };
-    use crate::node_interner::{DefinitionKind, FuncId, NodeInterner};
+    use crate::node_interner::{DefinitionKind, FuncId, NodeInterner, UsageTracker};
     use crate::{
         hir::{
             def_map::{CrateDefMap, LocalModuleId, ModuleDefId},
@@ -331,7 +386,7 @@ mod test {
         };
         interner.push_fn_meta(func_meta, func_id);
 
-        let errors = super::type_check_func(&mut interner, func_id);
+        let errors = super::type_check_func(&mut interner, &BTreeMap::new(), func_id);
         assert!(errors.is_empty());
     }
 
@@ -424,6 +479,7 @@ mod test {
             &self,
             _def_maps: &BTreeMap<CrateId, CrateDefMap>,
             path: Path,
+            _usage_tracker: &mut UsageTracker,
         ) -> Result<ModuleDefId, PathResolutionError> {
             // Not here that foo::bar and hello::foo::bar would fetch the same thing
             let name = path.segments.last().unwrap();
@@ -511,7 +567,8 @@ mod test {
         }
 
         // Type check section
-        let errors = super::type_check_func(&mut interner, func_ids.first().cloned().unwrap());
+        let func_id = func_ids.first().cloned().unwrap();
+        let errors = super::type_check_func(&mut interner, &def_maps, func_id);
         assert_eq!(errors, vec![]);
     }
 }
@@ -129,6 +129,8 @@ impl<'interner> TypeChecker<'interner> {
                         if let Some((type_field, _)) =
                             struct_type.get_field(&field_name.0.contents, generics)
                         {
+                            let span = field_name.span();
+                            self.check_field_visibility(&struct_type, &field_name.0.contents, span);
                             self.bind_pattern(field_pattern, type_field);
                         }
                     }
@@ -351,14 +353,13 @@ impl<'interner> TypeChecker<'interner> {
         let span = self.interner.expr_span(rhs_expr);
         match expr {
             HirExpression::Literal(HirLiteral::Integer(value)) => {
-                let v = value.to_u128();
                 if let Type::Integer(_, bit_count) = annotated_type {
-                    let max = 1 << bit_count;
-                    if v >= max {
+                    if value.num_bits() > *bit_count {
+                        let max = u128::MAX.checked_shr(128 - bit_count).unwrap_or(0);
                         self.errors.push(TypeCheckError::OverflowingAssignment {
                             expr: value,
                             ty: annotated_type.clone(),
-                            range: format!("0..={}", max - 1),
+                            range: format!("0..={max}"),
                             span,
                         });
                     };
@@ -3,6 +3,7 @@ use std::rc::Rc;
 use crate::{
     graph::CrateId,
     node_interner::{FuncId, TraitId, TraitMethodId},
+    token::SecondaryAttribute,
     Generics, Ident, NoirFunction, Type, TypeVariable, TypeVariableId,
 };
 use fm::FileId;
@@ -63,6 +64,7 @@ pub struct Trait {
 
 pub struct TraitImpl {
     pub ident: Ident,
+    pub attributes: Vec<SecondaryAttribute>,
     pub typ: Type,
     pub trait_id: TraitId,
     pub file: FileId,
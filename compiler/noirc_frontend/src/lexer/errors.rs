@@ -3,8 +3,9 @@ use crate::parser::ParserErrorReason;
 use crate::token::SpannedToken;
 
 use super::token::Token;
+use noirc_errors::error_codes::*;
 use noirc_errors::CustomDiagnostic as Diagnostic;
-use noirc_errors::Span;
+use noirc_errors::{ErrorCode, Span};
 use thiserror::Error;
 
 #[derive(Error, Clone, Debug, PartialEq, Eq)]
@@ -17,7 +18,7 @@ pub enum LexerErrorKind {
     InvalidIntegerLiteral { span: Span, found: String },
     #[error("{:?} is not a valid attribute", found)]
     MalformedFuncAttribute { span: Span, found: String },
-    #[error("Integer type is larger than the maximum supported size of u127")]
+    #[error("Integer type is larger than the maximum supported size of u{max}")]
     TooManyBits { span: Span, max: u32, got: u32 },
     #[error("Logical and used instead of bitwise and")]
     LogicalAnd { span: Span },
@@ -53,6 +54,20 @@ impl LexerErrorKind {
         }
     }
 
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            LexerErrorKind::UnexpectedCharacter { .. } => E0001,
+            LexerErrorKind::NotADoubleChar { .. } => E0002,
+            LexerErrorKind::InvalidIntegerLiteral { .. } => E0003,
+            LexerErrorKind::MalformedFuncAttribute { .. } => E0004,
+            LexerErrorKind::TooManyBits { .. } => E0005,
+            LexerErrorKind::LogicalAnd { .. } => E0006,
+            LexerErrorKind::UnterminatedBlockComment { .. } => E0007,
+            LexerErrorKind::UnterminatedStringLiteral { .. } => E0008,
+            LexerErrorKind::InvalidEscape { .. } => E0009,
+        }
+    }
+
     fn parts(&self) -> (String, String, Span) {
         match self {
             LexerErrorKind::UnexpectedCharacter {
@@ -86,9 +101,9 @@ impl LexerErrorKind {
                 *span,
             ),
             LexerErrorKind::TooManyBits { span, max, got } => (
-                "Integer literal too large".to_string(),
+                "Integer type too large".to_string(),
                 format!(
-                    "The maximum number of bits needed to represent a field is {max}, This integer type needs {got} bits"
+                    "The maximum supported integer size is {max} bits, this integer type needs {got} bits"
                 ),
                 *span,
             ),
@@ -109,7 +124,7 @@ impl LexerErrorKind {
 impl From<LexerErrorKind> for Diagnostic {
     fn from(error: LexerErrorKind) -> Diagnostic {
         let (primary, secondary, span) = error.parts();
-        Diagnostic::simple_error(primary, secondary, span)
+        Diagnostic::simple_error(primary, secondary, span).with_code(error.code())
     }
 }
 
@@ -693,6 +693,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn allow_attribute() {
+        let input = r#"#[allow(E0301, e0429)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Secondary(SecondaryAttribute::Allow(vec![
+                "E0301".to_string(),
+                "E0429".to_string()
+            ])))
+        );
+    }
+
+    #[test]
+    fn allow_attribute_with_unknown_code() {
+        let input = r#"#[allow(unused_variables)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Secondary(SecondaryAttribute::Custom(
+                "allow(unused_variables)".to_string()
+            )))
+        );
+    }
+
     #[test]
     fn test_attribute() {
         let input = r#"#[test]"#;
@@ -745,6 +774,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_attribute_with_snapshot_scope() {
+        let input = r#"#[test(snapshot)]"#;
+        let mut lexer = Lexer::new(input);
+
+        let token = lexer.next_token().unwrap();
+        assert_eq!(
+            token.token(),
+            &Token::Attribute(Attribute::Function(FunctionAttribute::Test(TestScope::Snapshot)))
+        );
+    }
+
     #[test]
     fn test_attribute_with_invalid_scope() {
         let input = r#"#[test(invalid_scope)]"#;
@@ -785,6 +826,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn integer_types_are_limited_to_128_bits() {
+        let mut lexer = Lexer::new("u128 i128 u129 i256");
+
+        assert_eq!(lexer.next_token().unwrap(), Token::IntType(IntType::Unsigned(128)));
+        assert_eq!(lexer.next_token().unwrap(), Token::IntType(IntType::Signed(128)));
+        for got in [129, 256] {
+            match lexer.next_token() {
+                Err(LexerErrorKind::TooManyBits { max, got: bits, .. }) => {
+                    assert_eq!((max, bits), (128, got));
+                }
+                other => panic!("expected a too many bits error, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn test_arithmetic_sugar() {
         let input = "+= -= *= /= %=";
@@ -1,5 +1,5 @@
 use acvm::FieldElement;
-use noirc_errors::{Position, Span, Spanned};
+use noirc_errors::{ErrorCode, Position, Span, Spanned};
 use std::{fmt, iter::Map, vec::IntoIter};
 
 use crate::lexer::errors::LexerErrorKind;
@@ -302,6 +302,14 @@ impl fmt::Display for IntType {
     }
 }
 
+/// The widest integer type which can be declared.
+///
+/// ACIR generation splits integers which are too wide to be multiplied within the field into
+/// limbs, and could handle integers up to `FieldElement::max_num_bits() - 5` bits. Wider integers
+/// are not supported however, as constant folding, the SSA interpreter and the Brillig VM evaluate
+/// integers as `u128`/`i128`.
+pub const MAX_INTEGER_BIT_SIZE: u32 = 128;
+
 impl IntType {
     // XXX: Result<Option<Token, LexerErrorKind>
     // Is not the best API. We could split this into two functions. One that checks if the the
@@ -324,10 +332,9 @@ impl IntType {
             Err(_) => return Ok(None),
         };
 
-        let max_bits = FieldElement::max_num_bits() / 2;
-
-        if str_as_u32 > max_bits {
-            return Err(LexerErrorKind::TooManyBits { span, max: max_bits, got: str_as_u32 });
+        if str_as_u32 > MAX_INTEGER_BIT_SIZE {
+            let max = MAX_INTEGER_BIT_SIZE;
+            return Err(LexerErrorKind::TooManyBits { span, max, got: str_as_u32 });
         }
 
         if is_signed {
@@ -345,6 +352,9 @@ pub enum TestScope {
     /// if it fails with the specified reason. If the reason is None, then
     /// the test must unconditionally fail
     ShouldFailWith { reason: Option<String> },
+    /// The test must pass, and its `println` output and return value must match
+    /// the snapshot checked in alongside the package
+    Snapshot,
     /// No scope is applied and so the test must pass
     None,
 }
@@ -353,6 +363,7 @@ impl TestScope {
     fn lookup_str(string: &str) -> Option<TestScope> {
         match string.trim() {
             "should_fail" => Some(TestScope::ShouldFailWith { reason: None }),
+            "snapshot" => Some(TestScope::Snapshot),
             s if s.starts_with("should_fail_with") => {
                 let parts: Vec<&str> = s.splitn(2, '=').collect();
                 if parts.len() == 2 {
@@ -376,6 +387,7 @@ impl fmt::Display for TestScope {
                 Some(failure_reason) => write!(f, "(should_fail_with = ({failure_reason}))"),
                 None => write!(f, "should_fail"),
             },
+            TestScope::Snapshot => write!(f, "(snapshot)"),
         }
     }
 }
@@ -515,6 +527,22 @@ impl Attribute {
                 Attribute::Secondary(SecondaryAttribute::ContractLibraryMethod)
             }
             ["event"] => Attribute::Secondary(SecondaryAttribute::Event),
+            // `allow` attributes naming anything other than error codes, such as Rust lints, are
+            // left to whoever consumes them as custom attributes.
+            ["allow", codes]
+                if codes.split(',').all(|code| ErrorCode::find(code.trim()).is_some()) =>
+            {
+                let codes = codes
+                    .split(',')
+                    .filter_map(|code| ErrorCode::find(code.trim()))
+                    .map(|code| code.to_string())
+                    .collect();
+                Attribute::Secondary(SecondaryAttribute::Allow(codes))
+            }
+            ["allow", names] => {
+                names.split(',').try_for_each(|name| validate(name.trim()))?;
+                Attribute::Secondary(SecondaryAttribute::Custom(word.to_owned()))
+            }
             ["deprecated", name] => {
                 if !name.starts_with('"') && !name.ends_with('"') {
                     return Err(LexerErrorKind::MalformedFuncAttribute {
@@ -594,6 +622,8 @@ pub enum SecondaryAttribute {
     ContractLibraryMethod,
     Event,
     Field(String),
+    // The codes of the warnings to suppress within the function.
+    Allow(Vec<String>),
     Custom(String),
 }
 
@@ -608,6 +638,7 @@ impl fmt::Display for SecondaryAttribute {
             SecondaryAttribute::ContractLibraryMethod => write!(f, "#[contract_library_method]"),
             SecondaryAttribute::Event => write!(f, "#[event]"),
             SecondaryAttribute::Field(ref k) => write!(f, "#[field({k})]"),
+            SecondaryAttribute::Allow(ref codes) => write!(f, "#[allow({})]", codes.join(", ")),
         }
     }
 }
@@ -631,6 +662,7 @@ impl AsRef<str> for SecondaryAttribute {
             SecondaryAttribute::Custom(string) | SecondaryAttribute::Field(string) => string,
             SecondaryAttribute::ContractLibraryMethod => "",
             SecondaryAttribute::Event => "",
+            SecondaryAttribute::Allow(_) => "",
         }
     }
 }
@@ -58,7 +58,7 @@ pub mod macros_api {
         Pattern, Statement, UnresolvedType, UnresolvedTypeData, Visibility,
     };
     pub use crate::{
-        ForLoopStatement, ForRange, FunctionDefinition, FunctionVisibility, ImportStatement,
+        ForLoopStatement, ForRange, FunctionDefinition, ImportStatement, ItemVisibility,
         NoirStruct, Param, PrefixExpression, Signedness, StatementKind, StructType, Type, TypeImpl,
         UnaryOp,
     };
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use arena::{Arena, Index};
 use fm::FileId;
@@ -20,7 +20,7 @@ use crate::hir_def::{
 };
 use crate::token::{Attributes, SecondaryAttribute};
 use crate::{
-    ContractFunctionType, FunctionDefinition, FunctionVisibility, Generics, Shared, TypeAliasType,
+    ContractFunctionType, FunctionDefinition, Generics, ItemVisibility, Shared, TypeAliasType,
     TypeBinding, TypeBindings, TypeVariable, TypeVariableId, TypeVariableKind,
 };
 
@@ -134,6 +134,10 @@ pub struct NodeInterner {
 
     // For trait implementation functions, this is their self type and trait they belong to
     func_id_to_trait: HashMap<FuncId, (Type, TraitId)>,
+
+    /// Records which imports and globals are used during name resolution, so that unused
+    /// items can be reported once the crate is checked.
+    usage_tracker: UsageTracker,
 }
 
 /// A trait implementation is either a normal implementation that is present in the source
@@ -169,7 +173,7 @@ pub struct FunctionModifiers {
     pub name: String,
 
     /// Whether the function is `pub` or not.
-    pub visibility: FunctionVisibility,
+    pub visibility: ItemVisibility,
 
     pub attributes: Attributes,
 
@@ -192,7 +196,7 @@ impl FunctionModifiers {
     pub fn new() -> Self {
         Self {
             name: String::new(),
-            visibility: FunctionVisibility::Public,
+            visibility: ItemVisibility::Public,
             attributes: Attributes::empty(),
             is_unconstrained: false,
             is_internal: None,
@@ -398,6 +402,54 @@ impl DefinitionKind {
 pub struct GlobalInfo {
     pub ident: Ident,
     pub local_id: LocalModuleId,
+    pub attributes: Vec<SecondaryAttribute>,
+}
+
+/// An import of a single name into a module, as desugared from a `use` statement.
+#[derive(Debug, Clone)]
+pub struct ImportInfo {
+    pub module: ModuleId,
+    pub name: Ident,
+    pub visibility: ItemVisibility,
+    pub attributes: Vec<SecondaryAttribute>,
+}
+
+/// Tracks the names each module is asked for while resolving paths.
+///
+/// An import is used if any path was resolved through its name. Globals used as array lengths
+/// are also recorded here, as they are evaluated to constants while types are resolved and
+/// leave no reference behind in the Hir.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    imports: Vec<ImportInfo>,
+    used_names: HashSet<(ModuleId, String)>,
+    globals_used_in_types: HashSet<StmtId>,
+}
+
+impl UsageTracker {
+    pub fn add_import(&mut self, import: ImportInfo) {
+        self.imports.push(import);
+    }
+
+    pub fn mark_as_used(&mut self, module: ModuleId, name: &Ident) {
+        self.used_names.insert((module, name.0.contents.clone()));
+    }
+
+    pub fn mark_global_used_in_type(&mut self, global: StmtId) {
+        self.globals_used_in_types.insert(global);
+    }
+
+    pub fn imports(&self) -> &[ImportInfo] {
+        &self.imports
+    }
+
+    pub fn is_used(&self, module: ModuleId, name: &Ident) -> bool {
+        self.used_names.contains(&(module, name.0.contents.clone()))
+    }
+
+    pub fn is_global_used_in_type(&self, global: StmtId) -> bool {
+        self.globals_used_in_types.contains(&global)
+    }
 }
 
 impl Default for NodeInterner {
@@ -425,6 +477,7 @@ impl Default for NodeInterner {
             globals: HashMap::new(),
             struct_methods: HashMap::new(),
             primitive_methods: HashMap::new(),
+            usage_tracker: UsageTracker::default(),
         };
 
         // An empty block expression is used often, we add this into the `node` on startup
@@ -573,8 +626,14 @@ impl NodeInterner {
         self.id_to_type.insert(definition_id.into(), typ);
     }
 
-    pub fn push_global(&mut self, stmt_id: StmtId, ident: Ident, local_id: LocalModuleId) {
-        self.globals.insert(stmt_id, GlobalInfo { ident, local_id });
+    pub fn push_global(
+        &mut self,
+        stmt_id: StmtId,
+        ident: Ident,
+        local_id: LocalModuleId,
+        attributes: Vec<SecondaryAttribute>,
+    ) {
+        self.globals.insert(stmt_id, GlobalInfo { ident, local_id, attributes });
     }
 
     /// Intern an empty global stmt. Used for collecting globals
@@ -615,6 +674,11 @@ impl NodeInterner {
         *func = hir_func;
     }
 
+    /// Returns the ids of every function whose metadata has been interned.
+    pub fn function_ids(&self) -> impl Iterator<Item = FuncId> + '_ {
+        self.func_meta.keys().copied()
+    }
+
     pub fn find_function(&self, function_name: &str) -> Option<FuncId> {
         self.func_meta
             .iter()
@@ -701,7 +765,7 @@ impl NodeInterner {
     ///
     /// The underlying function_visibilities map is populated during def collection,
     /// so this function can be called anytime afterward.
-    pub fn function_visibility(&self, func: FuncId) -> FunctionVisibility {
+    pub fn function_visibility(&self, func: FuncId) -> ItemVisibility {
         self.function_modifiers[&func].visibility
     }
 
@@ -1177,6 +1241,19 @@ impl NodeInterner {
         entries.retain(|(_, kind)| matches!(kind, TraitImplKind::Normal(_)));
     }
 
+    /// Returns the id of every trait implementation, including those of dependencies.
+    pub fn trait_impl_ids(&self) -> impl Iterator<Item = TraitImplId> {
+        (0..self.trait_implementations.len()).map(TraitImplId)
+    }
+
+    pub fn usage_tracker(&self) -> &UsageTracker {
+        &self.usage_tracker
+    }
+
+    pub fn usage_tracker_mut(&mut self) -> &mut UsageTracker {
+        &mut self.usage_tracker
+    }
+
     /// Tags the given identifier with the selected trait_impl so that monomorphization
     /// can later recover which impl was selected, or alternatively see if it needs to
     /// decide which impl to select (because the impl was Assumed).
@@ -5,8 +5,9 @@ use small_ord_set::SmallOrdSet;
 use thiserror::Error;
 
 use iter_extended::vecmap;
+use noirc_errors::error_codes::*;
 use noirc_errors::CustomDiagnostic as Diagnostic;
-use noirc_errors::Span;
+use noirc_errors::{Applicability, ErrorCode, Replacement, Span, Suggestion};
 
 use super::labels::ParsingRuleLabel;
 
@@ -19,7 +20,7 @@ pub enum ParserErrorReason {
     #[error("Expected a ; separating these two statements")]
     MissingSeparatingSemi,
     #[error("constrain keyword is deprecated")]
-    ConstrainDeprecated,
+    ConstrainDeprecated { expression_span: Span },
     #[error("Expression is invalid in an array-length type: '{0}'. Only unsigned integer constants, globals, generics, +, -, *, /, and % may be used in this context.")]
     InvalidArrayLengthExpression(Expression),
     #[error("Early 'return' is unsupported")]
@@ -34,14 +35,34 @@ pub enum ParserErrorReason {
         "Multiple primary attributes found. Only one function attribute is allowed per function"
     )]
     MultipleFunctionAttributesFound,
-    #[error("A function attribute cannot be placed on a struct")]
-    NoFunctionAttributesAllowedOnStruct,
+    #[error("A function attribute cannot be placed on a {0}")]
+    NoFunctionAttributesAllowedOnItem(&'static str),
     #[error("Assert statements can only accept string literals")]
     AssertMessageNotString,
     #[error("{0}")]
     Lexer(LexerErrorKind),
 }
 
+impl ParserErrorReason {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ParserErrorReason::ExpectedFieldName(_) => E0101,
+            ParserErrorReason::ExpectedPatternButFoundType(_) => E0102,
+            ParserErrorReason::MissingSeparatingSemi => E0103,
+            ParserErrorReason::ConstrainDeprecated { .. } => E0104,
+            ParserErrorReason::InvalidArrayLengthExpression(_) => E0105,
+            ParserErrorReason::EarlyReturn => E0106,
+            ParserErrorReason::PatternInTraitFunctionParameter => E0107,
+            ParserErrorReason::ComptimeDeprecated => E0108,
+            ParserErrorReason::ExperimentalFeature(_) => E0109,
+            ParserErrorReason::MultipleFunctionAttributesFound => E0110,
+            ParserErrorReason::NoFunctionAttributesAllowedOnItem(_) => E0111,
+            ParserErrorReason::AssertMessageNotString => E0112,
+            ParserErrorReason::Lexer(error) => error.code(),
+        }
+    }
+}
+
 /// Represents a parsing error, or a parsing error in the making.
 ///
 /// `ParserError` is used extensively by the parser, as it not only used to report badly formed
@@ -101,6 +122,11 @@ impl ParserError {
     pub fn is_warning(&self) -> bool {
         matches!(self.reason(), Some(ParserErrorReason::ExperimentalFeature(_)))
     }
+
+    /// Returns the code of this error, or that of a generic syntax error if it has no reason.
+    pub fn code(&self) -> ErrorCode {
+        self.reason().map_or(E0100, ParserErrorReason::code)
+    }
 }
 
 impl std::fmt::Display for ParserError {
@@ -130,38 +156,58 @@ impl std::fmt::Display for ParserError {
 
 impl From<ParserError> for Diagnostic {
     fn from(error: ParserError) -> Diagnostic {
-        match error.reason {
-            Some(reason) => {
-                match reason {
-                    ParserErrorReason::ConstrainDeprecated => Diagnostic::simple_error(
-                        "Use of deprecated keyword 'constrain'".into(),
-                        "The 'constrain' keyword is deprecated. Please use the 'assert' function instead.".into(),
-                        error.span,
-                    ),
-                    ParserErrorReason::ComptimeDeprecated => Diagnostic::simple_warning(
-                        "Use of deprecated keyword 'comptime'".into(),
-                        "The 'comptime' keyword has been deprecated. It can be removed without affecting your program".into(),
-                        error.span,
-                    ),
-                    ParserErrorReason::ExperimentalFeature(_) => Diagnostic::simple_warning(
-                        reason.to_string(),
-                        "".into(),
+        let code = error.code();
+        let diagnostic = match error.reason {
+            Some(reason) => match reason {
+                ParserErrorReason::ConstrainDeprecated { expression_span } => {
+                    let mut diagnostic = Diagnostic::simple_error(
+                            "Use of deprecated keyword 'constrain'".into(),
+                            "The 'constrain' keyword is deprecated. Please use the 'assert' function instead.".into(),
+                            error.span,
+                        );
+                    let keyword_span = Span::from(error.span.start()..expression_span.start());
+                    let end = Span::empty(error.span.end());
+                    diagnostic.add_suggestion(Suggestion::multipart(
+                        "use `assert` instead".into(),
+                        vec![
+                            Replacement { span: keyword_span, text: "assert(".into() },
+                            Replacement { span: end, text: ")".into() },
+                        ],
+                        Applicability::MachineApplicable,
+                    ));
+                    diagnostic
+                }
+                ParserErrorReason::ComptimeDeprecated => {
+                    let mut diagnostic = Diagnostic::simple_warning(
+                            "Use of deprecated keyword 'comptime'".into(),
+                            "The 'comptime' keyword has been deprecated. It can be removed without affecting your program".into(),
+                            error.span,
+                        );
+                    diagnostic.add_suggestion(Suggestion::new(
+                        "remove the `comptime` keyword".into(),
                         error.span,
-                    ),
-                    ParserErrorReason::ExpectedPatternButFoundType(ty) => {
-                        Diagnostic::simple_error("Expected a ; separating these two statements".into(), format!("{ty} is a type and cannot be used as a variable name"), error.span)
-                    }
-                    ParserErrorReason::Lexer(error) => error.into(),
-                    other => {
-                        Diagnostic::simple_error(format!("{other}"), String::new(), error.span)
-                    }
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    ));
+                    diagnostic
                 }
-            }
+                ParserErrorReason::ExperimentalFeature(_) => {
+                    Diagnostic::simple_warning(reason.to_string(), "".into(), error.span)
+                }
+                ParserErrorReason::ExpectedPatternButFoundType(ty) => Diagnostic::simple_error(
+                    "Expected a ; separating these two statements".into(),
+                    format!("{ty} is a type and cannot be used as a variable name"),
+                    error.span,
+                ),
+                ParserErrorReason::Lexer(error) => error.into(),
+                other => Diagnostic::simple_error(format!("{other}"), String::new(), error.span),
+            },
             None => {
                 let primary = error.to_string();
                 Diagnostic::simple_error(primary, String::new(), error.span)
             }
-        }
+        };
+        diagnostic.with_code(code)
     }
 }
 
@@ -11,11 +11,11 @@ mod labels;
 #[allow(clippy::module_inception)]
 mod parser;
 
-use crate::token::{Keyword, Token};
+use crate::token::{Keyword, SecondaryAttribute, Token};
 use crate::{ast::ImportStatement, Expression, NoirStruct};
 use crate::{
-    Ident, LetStatement, NoirFunction, NoirTrait, NoirTraitImpl, NoirTypeAlias, Recoverable,
-    StatementKind, TypeImpl, UseTree,
+    Ident, ItemVisibility, LetStatement, NoirFunction, NoirTrait, NoirTraitImpl, NoirTypeAlias,
+    Recoverable, StatementKind, TypeImpl, UseTree,
 };
 
 use chumsky::prelude::*;
@@ -28,15 +28,15 @@ pub use parser::parse_program;
 #[derive(Debug, Clone)]
 pub(crate) enum TopLevelStatement {
     Function(NoirFunction),
-    Module(Ident),
-    Import(UseTree),
+    Module(ModuleDeclaration),
+    Import(UseTree, Vec<SecondaryAttribute>, ItemVisibility),
     Struct(NoirStruct),
     Trait(NoirTrait),
     TraitImpl(NoirTraitImpl),
     Impl(TypeImpl),
     TypeAlias(NoirTypeAlias),
     SubModule(ParsedSubModule),
-    Global(LetStatement),
+    Global(LetStatement, ItemVisibility),
     Error,
 }
 
@@ -217,10 +217,10 @@ pub struct SortedModule {
     pub trait_impls: Vec<NoirTraitImpl>,
     pub impls: Vec<TypeImpl>,
     pub type_aliases: Vec<NoirTypeAlias>,
-    pub globals: Vec<LetStatement>,
+    pub globals: Vec<(LetStatement, ItemVisibility)>,
 
     /// Module declarations like `mod foo;`
-    pub module_decls: Vec<Ident>,
+    pub module_decls: Vec<ModuleDeclaration>,
 
     /// Full submodules as in `mod foo { ... definitions ... }`
     pub submodules: Vec<SortedSubModule>,
@@ -229,15 +229,15 @@ pub struct SortedModule {
 impl std::fmt::Display for SortedModule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for decl in &self.module_decls {
-            writeln!(f, "mod {decl};")?;
+            writeln!(f, "{decl};")?;
         }
 
         for import in &self.imports {
             write!(f, "{import}")?;
         }
 
-        for global_const in &self.globals {
-            write!(f, "{global_const}")?;
+        for (global_const, visibility) in &self.globals {
+            write!(f, "{visibility}{global_const}")?;
         }
 
         for type_ in &self.types {
@@ -276,15 +276,17 @@ impl ParsedModule {
 
         for item in self.items {
             match item.kind {
-                ItemKind::Import(import) => module.push_import(import),
+                ItemKind::Import(import, attributes, visibility) => {
+                    module.push_import(import, &attributes, visibility);
+                }
                 ItemKind::Function(func) => module.push_function(func),
                 ItemKind::Struct(typ) => module.push_type(typ),
                 ItemKind::Trait(noir_trait) => module.push_trait(noir_trait),
                 ItemKind::TraitImpl(trait_impl) => module.push_trait_impl(trait_impl),
                 ItemKind::Impl(r#impl) => module.push_impl(r#impl),
                 ItemKind::TypeAlias(type_alias) => module.push_type_alias(type_alias),
-                ItemKind::Global(global) => module.push_global(global),
-                ItemKind::ModuleDecl(mod_name) => module.push_module_decl(mod_name),
+                ItemKind::Global(global, visibility) => module.push_global(global, visibility),
+                ItemKind::ModuleDecl(declaration) => module.push_module_decl(declaration),
                 ItemKind::Submodules(submodule) => module.push_submodule(submodule.into_sorted()),
             }
         }
@@ -301,22 +303,36 @@ pub struct Item {
 
 #[derive(Clone, Debug)]
 pub enum ItemKind {
-    Import(UseTree),
+    Import(UseTree, Vec<SecondaryAttribute>, ItemVisibility),
     Function(NoirFunction),
     Struct(NoirStruct),
     Trait(NoirTrait),
     TraitImpl(NoirTraitImpl),
     Impl(TypeImpl),
     TypeAlias(NoirTypeAlias),
-    Global(LetStatement),
-    ModuleDecl(Ident),
+    Global(LetStatement, ItemVisibility),
+    ModuleDecl(ModuleDeclaration),
     Submodules(ParsedSubModule),
 }
 
+/// A module declared via `mod name;`, whose contents live in a separate file.
+#[derive(Clone, Debug)]
+pub struct ModuleDeclaration {
+    pub visibility: ItemVisibility,
+    pub ident: Ident,
+}
+
+impl std::fmt::Display for ModuleDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}mod {}", self.visibility, self.ident)
+    }
+}
+
 /// A submodule defined via `mod name { contents }` in some larger file.
 /// These submodules always share the same file as some larger ParsedModule
 #[derive(Clone, Debug)]
 pub struct ParsedSubModule {
+    pub visibility: ItemVisibility,
     pub name: Ident,
     pub contents: ParsedModule,
     pub is_contract: bool,
@@ -325,6 +341,7 @@ pub struct ParsedSubModule {
 impl ParsedSubModule {
     pub fn into_sorted(self) -> SortedSubModule {
         SortedSubModule {
+            visibility: self.visibility,
             name: self.name,
             contents: self.contents.into_sorted(),
             is_contract: self.is_contract,
@@ -334,7 +351,8 @@ impl ParsedSubModule {
 
 impl std::fmt::Display for SortedSubModule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "mod {} {{", self.name)?;
+        let keyword = if self.is_contract { "contract" } else { "mod" };
+        write!(f, "{}{keyword} {} {{", self.visibility, self.name)?;
 
         for line in self.contents.to_string().lines() {
             write!(f, "\n    {line}")?;
@@ -345,6 +363,7 @@ impl std::fmt::Display for SortedSubModule {
 }
 
 pub struct SortedSubModule {
+    pub visibility: ItemVisibility,
     pub name: Ident,
     pub contents: SortedModule,
     pub is_contract: bool,
@@ -375,20 +394,25 @@ impl SortedModule {
         self.type_aliases.push(type_alias);
     }
 
-    fn push_import(&mut self, import_stmt: UseTree) {
-        self.imports.extend(import_stmt.desugar(None));
+    fn push_import(
+        &mut self,
+        import_stmt: UseTree,
+        attributes: &[SecondaryAttribute],
+        visibility: ItemVisibility,
+    ) {
+        self.imports.extend(import_stmt.desugar(None, attributes, visibility));
     }
 
-    fn push_module_decl(&mut self, mod_name: Ident) {
-        self.module_decls.push(mod_name);
+    fn push_module_decl(&mut self, declaration: ModuleDeclaration) {
+        self.module_decls.push(declaration);
     }
 
     fn push_submodule(&mut self, submodule: SortedSubModule) {
         self.submodules.push(submodule);
     }
 
-    fn push_global(&mut self, global: LetStatement) {
-        self.globals.push(global);
+    fn push_global(&mut self, global: LetStatement, visibility: ItemVisibility) {
+        self.globals.push((global, visibility));
     }
 }
 
@@ -473,15 +497,20 @@ impl std::fmt::Display for TopLevelStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TopLevelStatement::Function(fun) => fun.fmt(f),
-            TopLevelStatement::Module(m) => write!(f, "mod {m}"),
-            TopLevelStatement::Import(tree) => write!(f, "use {tree}"),
+            TopLevelStatement::Module(m) => m.fmt(f),
+            TopLevelStatement::Import(tree, attributes, visibility) => {
+                for attribute in attributes {
+                    writeln!(f, "{attribute}")?;
+                }
+                write!(f, "{visibility}use {tree}")
+            }
             TopLevelStatement::Trait(t) => t.fmt(f),
             TopLevelStatement::TraitImpl(i) => i.fmt(f),
             TopLevelStatement::Struct(s) => s.fmt(f),
             TopLevelStatement::Impl(i) => i.fmt(f),
             TopLevelStatement::TypeAlias(t) => t.fmt(f),
             TopLevelStatement::SubModule(s) => s.fmt(f),
-            TopLevelStatement::Global(c) => c.fmt(f),
+            TopLevelStatement::Global(c, visibility) => write!(f, "{visibility}{c}"),
             TopLevelStatement::Error => write!(f, "error"),
         }
     }
@@ -26,8 +26,8 @@
 use super::{
     foldl_with_span, labels::ParsingRuleLabel, parameter_name_recovery, parameter_recovery,
     parenthesized, then_commit, then_commit_ignore, top_level_statement_recovery, ExprParser,
-    NoirParser, ParsedModule, ParsedSubModule, ParserError, ParserErrorReason, Precedence,
-    TopLevelStatement,
+    ModuleDeclaration, NoirParser, ParsedModule, ParsedSubModule, ParserError, ParserErrorReason,
+    Precedence, TopLevelStatement,
 };
 use super::{spanned, Item, ItemKind};
 use crate::ast::{
@@ -38,11 +38,11 @@ use crate::parser::{force, ignore_then_commit, statement_recovery};
 use crate::token::{Attribute, Attributes, Keyword, SecondaryAttribute, Token, TokenKind};
 use crate::{
     BinaryOp, BinaryOpKind, BlockExpression, ConstrainKind, ConstrainStatement, Distinctness,
-    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, FunctionVisibility, Ident,
-    IfExpression, InfixExpression, LValue, Lambda, Literal, NoirFunction, NoirStruct, NoirTrait,
+    ForLoopStatement, ForRange, FunctionDefinition, FunctionReturnType, Ident, IfExpression,
+    InfixExpression, ItemVisibility, LValue, Lambda, Literal, NoirFunction, NoirStruct, NoirTrait,
     NoirTraitImpl, NoirTypeAlias, Param, Path, PathKind, Pattern, Recoverable, Statement,
-    TraitBound, TraitImplItem, TraitItem, TypeImpl, UnaryOp, UnresolvedTraitConstraint,
-    UnresolvedTypeExpression, UseTree, UseTreeKind, Visibility,
+    StructField, TraitBound, TraitImplItem, TraitItem, TypeImpl, UnaryOp,
+    UnresolvedTraitConstraint, UnresolvedTypeExpression, UseTree, UseTreeKind, Visibility,
 };
 
 use chumsky::prelude::*;
@@ -82,14 +82,18 @@ fn module() -> impl NoirParser<ParsedModule> {
                 match statement {
                     TopLevelStatement::Function(f) => push_item(ItemKind::Function(f)),
                     TopLevelStatement::Module(m) => push_item(ItemKind::ModuleDecl(m)),
-                    TopLevelStatement::Import(i) => push_item(ItemKind::Import(i)),
+                    TopLevelStatement::Import(i, attributes, visibility) => {
+                        push_item(ItemKind::Import(i, attributes, visibility))
+                    }
                     TopLevelStatement::Struct(s) => push_item(ItemKind::Struct(s)),
                     TopLevelStatement::Trait(t) => push_item(ItemKind::Trait(t)),
                     TopLevelStatement::TraitImpl(t) => push_item(ItemKind::TraitImpl(t)),
                     TopLevelStatement::Impl(i) => push_item(ItemKind::Impl(i)),
                     TopLevelStatement::TypeAlias(t) => push_item(ItemKind::TypeAlias(t)),
                     TopLevelStatement::SubModule(s) => push_item(ItemKind::Submodules(s)),
-                    TopLevelStatement::Global(c) => push_item(ItemKind::Global(c)),
+                    TopLevelStatement::Global(c, visibility) => {
+                        push_item(ItemKind::Global(c, visibility))
+                    }
                     TopLevelStatement::Error => (),
                 }
                 program
@@ -124,39 +128,55 @@ fn top_level_statement(
     .recover_via(top_level_statement_recovery())
 }
 
-/// global_declaration: 'global' ident global_type_annotation '=' literal
+/// global_declaration: attributes item_visibility 'global' ident global_type_annotation '=' literal
 fn global_declaration() -> impl NoirParser<TopLevelStatement> {
-    let p = ignore_then_commit(
+    let p = attributes().or_not().then(item_visibility()).then(ignore_then_commit(
         keyword(Keyword::Global).labelled(ParsingRuleLabel::Global),
         ident().map(Pattern::Identifier),
-    );
+    ));
     let p = then_commit(p, optional_type_annotation());
     let p = then_commit_ignore(p, just(Token::Assign));
     let p = then_commit(p, literal_or_collection(expression()).map_with_span(Expression::new));
-    p.map(LetStatement::new_let).map(TopLevelStatement::Global)
+    p.validate(|((((raw_attributes, visibility), pattern), typ), expression), span, emit| {
+        let mut global = LetStatement::new_let(((pattern, typ), expression));
+        global.attributes = validate_secondary_attributes(raw_attributes, "global", span, emit);
+        TopLevelStatement::Global(global, visibility)
+    })
 }
 
-/// submodule: 'mod' ident '{' module '}'
+/// submodule: item_visibility 'mod' ident '{' module '}'
 fn submodule(module_parser: impl NoirParser<ParsedModule>) -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Mod)
-        .ignore_then(ident())
+    item_visibility()
+        .then_ignore(keyword(Keyword::Mod))
+        .then(ident())
         .then_ignore(just(Token::LeftBrace))
         .then(module_parser)
         .then_ignore(just(Token::RightBrace))
-        .map(|(name, contents)| {
-            TopLevelStatement::SubModule(ParsedSubModule { name, contents, is_contract: false })
+        .map(|((visibility, name), contents)| {
+            TopLevelStatement::SubModule(ParsedSubModule {
+                visibility,
+                name,
+                contents,
+                is_contract: false,
+            })
         })
 }
 
-/// contract: 'contract' ident '{' module '}'
+/// contract: item_visibility 'contract' ident '{' module '}'
 fn contract(module_parser: impl NoirParser<ParsedModule>) -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Contract)
-        .ignore_then(ident())
+    item_visibility()
+        .then_ignore(keyword(Keyword::Contract))
+        .then(ident())
         .then_ignore(just(Token::LeftBrace))
         .then(module_parser)
         .then_ignore(just(Token::RightBrace))
-        .map(|(name, contents)| {
-            TopLevelStatement::SubModule(ParsedSubModule { name, contents, is_contract: true })
+        .map(|((visibility, name), contents)| {
+            TopLevelStatement::SubModule(ParsedSubModule {
+                visibility,
+                name,
+                contents,
+                is_contract: true,
+            })
         })
 }
 
@@ -186,11 +206,11 @@ fn function_definition(allow_self: bool) -> impl NoirParser<NoirFunction> {
                 is_open: modifiers.2,
                 is_internal: modifiers.3,
                 visibility: if modifiers.1 {
-                    FunctionVisibility::PublicCrate
+                    ItemVisibility::PublicCrate
                 } else if modifiers.4 {
-                    FunctionVisibility::Public
+                    ItemVisibility::Public
                 } else {
-                    FunctionVisibility::Private
+                    ItemVisibility::Private
                 },
                 generics,
                 parameters,
@@ -233,6 +253,21 @@ fn is_pub_crate() -> impl NoirParser<bool> {
     .map(|a| a.is_some())
 }
 
+/// item_visibility: 'pub(crate)'
+///                | 'pub'
+///                | %empty
+fn item_visibility() -> impl NoirParser<ItemVisibility> {
+    is_pub_crate().then(keyword(Keyword::Pub).or_not()).map(|(is_pub_crate, public)| {
+        if is_pub_crate {
+            ItemVisibility::PublicCrate
+        } else if public.is_some() {
+            ItemVisibility::Public
+        } else {
+            ItemVisibility::Private
+        }
+    })
+}
+
 /// non_empty_ident_list: ident ',' non_empty_ident_list
 ///                     | ident
 ///
@@ -264,26 +299,34 @@ fn struct_definition() -> impl NoirParser<TopLevelStatement> {
 
     attributes()
         .or_not()
+        .then(item_visibility())
         .then_ignore(keyword(Struct))
         .then(ident())
         .then(generics())
         .then(fields)
-        .validate(|(((raw_attributes, name), generics), fields), span, emit| {
-            let attributes = validate_struct_attributes(raw_attributes, span, emit);
-            TopLevelStatement::Struct(NoirStruct { name, attributes, generics, fields, span })
+        .validate(|((((raw_attributes, visibility), name), generics), fields), span, emit| {
+            let attributes = validate_secondary_attributes(raw_attributes, "struct", span, emit);
+            TopLevelStatement::Struct(NoirStruct {
+                visibility,
+                name,
+                attributes,
+                generics,
+                fields,
+                span,
+            })
         })
 }
 
 fn type_alias_definition() -> impl NoirParser<TopLevelStatement> {
     use self::Keyword::Type;
 
-    let p = ignore_then_commit(keyword(Type), ident());
+    let p = item_visibility().then(ignore_then_commit(keyword(Type), ident()));
     let p = then_commit(p, generics());
     let p = then_commit_ignore(p, just(Token::Assign));
     let p = then_commit(p, parse_type());
 
-    p.map_with_span(|((name, generics), typ), span| {
-        TopLevelStatement::TypeAlias(NoirTypeAlias { name, generics, typ, span })
+    p.map_with_span(|(((visibility, name), generics), typ), span| {
+        TopLevelStatement::TypeAlias(NoirTypeAlias { visibility, name, generics, typ, span })
     })
 }
 
@@ -320,10 +363,12 @@ fn attributes() -> impl NoirParser<Vec<Attribute>> {
     attribute().repeated()
 }
 
-fn struct_fields() -> impl NoirParser<Vec<(Ident, UnresolvedType)>> {
-    ident()
+fn struct_fields() -> impl NoirParser<Vec<StructField>> {
+    item_visibility()
+        .then(ident())
         .then_ignore(just(Token::Colon))
         .then(parse_type())
+        .map(|((visibility, name), typ)| StructField { visibility, name, typ })
         .separated_by(just(Token::Comma))
         .allow_trailing()
 }
@@ -384,16 +429,16 @@ fn self_parameter() -> impl NoirParser<Param> {
             Token::Ident(ref word) if word == "self" => Ok(span),
             _ => Err(ParserError::expected_label(ParsingRuleLabel::Parameter, found, span)),
         }))
-        .map(|(pattern_keyword, span)| {
-            let ident = Ident::new("self".to_string(), span);
-            let path = Path::from_single("Self".to_owned(), span);
-            let mut self_type = UnresolvedTypeData::Named(path, vec![]).with_span(span);
+        .map_with_span(|(pattern_keyword, self_span), span| {
+            let ident = Ident::new("self".to_string(), self_span);
+            let path = Path::from_single("Self".to_owned(), self_span);
+            let mut self_type = UnresolvedTypeData::Named(path, vec![]).with_span(self_span);
             let mut pattern = Pattern::Identifier(ident);
 
             match pattern_keyword {
                 Some((Token::Ampersand, _)) => {
-                    self_type =
-                        UnresolvedTypeData::MutableReference(Box::new(self_type)).with_span(span);
+                    self_type = UnresolvedTypeData::MutableReference(Box::new(self_type))
+                        .with_span(self_span);
                 }
                 Some((Token::Keyword(_), span)) => {
                     pattern = Pattern::Mutable(Box::new(pattern), span);
@@ -401,21 +446,30 @@ fn self_parameter() -> impl NoirParser<Param> {
                 _ => (),
             }
 
+            // The span of the parameter covers the `&mut` or `mut` preceding `self`.
             Param { pattern, typ: self_type, visibility: Visibility::Private, span }
         })
 }
 
 fn trait_definition() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Trait)
-        .ignore_then(ident())
+    item_visibility()
+        .then_ignore(keyword(Keyword::Trait))
+        .then(ident())
         .then(generics())
         .then(where_clause())
         .then_ignore(just(Token::LeftBrace))
         .then(trait_body())
         .then_ignore(just(Token::RightBrace))
-        .validate(|(((name, generics), where_clause), items), span, emit| {
+        .validate(|((((visibility, name), generics), where_clause), items), span, emit| {
             emit(ParserError::with_reason(ParserErrorReason::ExperimentalFeature("Traits"), span));
-            TopLevelStatement::Trait(NoirTrait { name, generics, where_clause, span, items })
+            TopLevelStatement::Trait(NoirTrait {
+                visibility,
+                name,
+                generics,
+                where_clause,
+                span,
+                items,
+            })
         })
 }
 
@@ -489,27 +543,30 @@ fn validate_attributes(
     Attributes { function: primary, secondary }
 }
 
-fn validate_struct_attributes(
+/// Returns the secondary attributes of an item which is not a function, described by `item`,
+/// reporting an error for each function attribute.
+fn validate_secondary_attributes(
     attributes: Option<Vec<Attribute>>,
+    item: &'static str,
     span: Span,
     emit: &mut dyn FnMut(ParserError),
 ) -> Vec<SecondaryAttribute> {
     let attrs = attributes.unwrap_or_default();
-    let mut struct_attributes = vec![];
+    let mut secondary_attributes = vec![];
 
     for attribute in attrs {
         match attribute {
             Attribute::Function(..) => {
                 emit(ParserError::with_reason(
-                    ParserErrorReason::NoFunctionAttributesAllowedOnStruct,
+                    ParserErrorReason::NoFunctionAttributesAllowedOnItem(item),
                     span,
                 ));
             }
-            Attribute::Secondary(attr) => struct_attributes.push(attr),
+            Attribute::Secondary(attr) => secondary_attributes.push(attr),
         }
     }
 
-    struct_attributes
+    secondary_attributes
 }
 
 /// Function declaration parameters differ from other parameters in that parameter
@@ -569,10 +626,12 @@ fn implementation() -> impl NoirParser<TopLevelStatement> {
 /// This has a similar syntax to `implementation`, but the `for type` clause is required,
 /// and an optional `where` clause is also useable.
 ///
-/// trait_implementation: 'impl' generics ident generic_args for type '{' trait_implementation_body '}'
+/// trait_implementation: attributes 'impl' generics ident generic_args for type '{' trait_implementation_body '}'
 fn trait_implementation() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Impl)
-        .ignore_then(generics())
+    attributes()
+        .or_not()
+        .then_ignore(keyword(Keyword::Impl))
+        .then(generics())
         .then(path())
         .then(generic_type_args(parse_type()))
         .then_ignore(keyword(Keyword::For))
@@ -583,10 +642,14 @@ fn trait_implementation() -> impl NoirParser<TopLevelStatement> {
         .then_ignore(just(Token::RightBrace))
         .validate(|args, span, emit| {
             let ((other_args, where_clause), items) = args;
-            let (((impl_generics, trait_name), trait_generics), object_type) = other_args;
+            let ((((raw_attributes, impl_generics), trait_name), trait_generics), object_type) =
+                other_args;
 
             emit(ParserError::with_reason(ParserErrorReason::ExperimentalFeature("Traits"), span));
+            let attributes =
+                validate_secondary_attributes(raw_attributes, "trait implementation", span, emit);
             TopLevelStatement::TraitImpl(NoirTraitImpl {
+                attributes,
                 impl_generics,
                 trait_name,
                 trait_generics,
@@ -624,7 +687,7 @@ fn where_clause() -> impl NoirParser<Vec<UnresolvedTraitConstraint>> {
     );
 
     keyword(Keyword::Where)
-        .ignore_then(constraints.separated_by(just(Token::Comma)))
+        .ignore_then(constraints.separated_by(just(Token::Comma)).allow_trailing())
         .or_not()
         .map(|option| option.unwrap_or_default())
         .map(|x: Vec<MultiTraitConstraint>| {
@@ -699,11 +762,23 @@ fn optional_type_annotation<'a>() -> impl NoirParser<UnresolvedType> + 'a {
 }
 
 fn module_declaration() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Mod).ignore_then(ident()).map(TopLevelStatement::Module)
+    item_visibility().then_ignore(keyword(Keyword::Mod)).then(ident()).map(|(visibility, ident)| {
+        TopLevelStatement::Module(ModuleDeclaration { visibility, ident })
+    })
 }
 
+/// use_statement: attributes item_visibility 'use' use_tree
 fn use_statement() -> impl NoirParser<TopLevelStatement> {
-    keyword(Keyword::Use).ignore_then(use_tree()).map(TopLevelStatement::Import)
+    attributes()
+        .or_not()
+        .then(item_visibility())
+        .then_ignore(keyword(Keyword::Use))
+        .then(use_tree())
+        .validate(|((raw_attributes, visibility), use_tree), span, emit| {
+            let attributes =
+                validate_secondary_attributes(raw_attributes, "use statement", span, emit);
+            TopLevelStatement::Import(use_tree, attributes, visibility)
+        })
 }
 
 fn keyword(keyword: Keyword) -> impl NoirParser<Token> {
@@ -809,11 +884,12 @@ where
         keyword(Keyword::Constrain).labelled(ParsingRuleLabel::Statement),
         expr_parser,
     )
-    .map(|expr| StatementKind::Constrain(ConstrainStatement(expr, None, ConstrainKind::Constrain)))
     .validate(|expr, span, emit| {
-        emit(ParserError::with_reason(ParserErrorReason::ConstrainDeprecated, span));
+        let reason = ParserErrorReason::ConstrainDeprecated { expression_span: expr.span };
+        emit(ParserError::with_reason(reason, span));
         expr
     })
+    .map(|expr| StatementKind::Constrain(ConstrainStatement(expr, None, ConstrainKind::Constrain)))
 }
 
 fn assertion<'a, P>(expr_parser: P) -> impl NoirParser<StatementKind> + 'a
@@ -2164,6 +2240,8 @@ mod test {
                 "fn func_name<T>(f: Field, y : T) where u32: SomeTrait {}",
                 // A trailing plus is allowed by Rust, so we support it as well.
                 "fn func_name<T>(f: Field, y : T) where T: SomeTrait + {}",
+                // As is a trailing comma, which `nargo fmt` emits when the clause spans several lines.
+                "fn func_name<T>(f: Field, y : T) where T: SomeTrait, {}",
                 // The following should produce compile error on later stage. From the parser's perspective it's fine
                 "fn func_name<A>(f: Field, y : Field, z : Field) where T: SomeTrait {}",
             ],
@@ -2288,6 +2366,8 @@ mod test {
     fn parse_module_declaration() {
         parse_with(module_declaration(), "mod foo").unwrap();
         parse_with(module_declaration(), "mod 1").unwrap_err();
+        parse_with(module_declaration(), "pub(crate) mod foo").unwrap();
+        parse_with(module_declaration(), "pub mod pub foo").unwrap_err();
     }
 
     #[test]
@@ -2356,6 +2436,8 @@ mod test {
                 "use foo::{bar as bar2, hello}",
                 "use foo::{bar as bar2, hello::{foo}, nested::{foo, bar}}",
                 "use dep::{std::println, bar::baz}",
+                "pub use foo::bar",
+                "pub(crate) use foo::{bar, baz}",
             ],
         );
 
@@ -2363,6 +2445,8 @@ mod test {
             use_statement(),
             vec![
                 "use std as ;",
+                "pub(foo) use bar",
+                "use pub bar",
                 "use foobar as as;",
                 "use hello:: as foo;",
                 "use foo bar::baz",
@@ -2380,6 +2464,8 @@ mod test {
             "struct Bar { ident: Field, }",
             "struct Baz { ident: Field, other: Field }",
             "#[attribute] struct Baz { ident: Field, other: Field }",
+            "pub struct Baz { pub ident: Field, pub(crate) other: Field, private: Field }",
+            "#[attribute] pub(crate) struct Baz { ident: Field }",
         ];
         parse_all(struct_definition(), cases);
 
@@ -2388,13 +2474,21 @@ mod test {
             "struct Foo { bar: pub Field }",
             "struct Foo { bar: pub Field }",
             "#[oracle(some)] struct Foo { bar: Field }",
+            "pub #[attribute] struct Foo { bar: Field }",
+            "struct Foo { pub pub bar: Field }",
         ];
         parse_all_failing(struct_definition(), failing);
     }
 
     #[test]
     fn parse_type_aliases() {
-        let cases = vec!["type foo = u8", "type bar = String", "type baz<T> = Vec<T>"];
+        let cases = vec![
+            "type foo = u8",
+            "type bar = String",
+            "type baz<T> = Vec<T>",
+            "pub type foo = u8",
+            "pub(crate) type foo = u8",
+        ];
         parse_all(type_alias_definition(), cases);
 
         let failing = vec!["type = u8", "type foo", "type foo = 1"];
@@ -9,12 +9,13 @@ mod test {
 
     use fm::FileId;
 
-    use noirc_errors::Location;
+    use noirc_errors::{CustomDiagnostic, Location, Replacement, Span};
 
     use crate::hir::def_collector::dc_crate::CompilationError;
     use crate::hir::def_collector::errors::{DefCollectorErrorKind, DuplicateType};
     use crate::hir::def_map::ModuleData;
     use crate::hir::resolution::errors::ResolverError;
+    use crate::hir::resolution::find_dead_code;
     use crate::hir::resolution::import::PathResolutionError;
     use crate::hir::type_check::TypeCheckError;
     use crate::hir::Context;
@@ -30,10 +31,11 @@ mod test {
     use crate::ParsedModule;
     use crate::{
         hir::def_map::{CrateDefMap, LocalModuleId},
-        parse_program,
+        parse_program, ItemVisibility,
     };
     use arena::Arena;
     use fm::FileManager;
+    use iter_extended::vecmap;
 
     pub(crate) fn has_parser_error(errors: &[(CompilationError, FileId)]) -> bool {
         errors.iter().any(|(e, _f)| matches!(e, CompilationError::ParseError(_)))
@@ -809,7 +811,7 @@ mod test {
         assert!(errors.len() == 1, "Expected 1 error, got: {:?}", errors);
         // It should be regarding the unused variable
         match &errors[0].0 {
-            CompilationError::ResolverError(ResolverError::UnusedVariable { ident }) => {
+            CompilationError::ResolverError(ResolverError::UnusedVariable { ident, .. }) => {
                 assert_eq!(&ident.0.contents, "y");
             }
             _ => unreachable!("we should only have an unused var error"),
@@ -893,7 +895,7 @@ mod test {
             match compilation_error {
                 CompilationError::ResolverError(err) => {
                     match err {
-                        ResolverError::UnusedVariable { ident } => {
+                        ResolverError::UnusedVariable { ident, .. } => {
                             assert_eq!(&ident.0.contents, "z");
                         }
                         ResolverError::VariableNotDeclared { name, .. } => {
@@ -1133,4 +1135,326 @@ fn lambda$f1(mut env$l1: (Field)) -> Field {
 "#;
         check_rewrite(src, expected_rewrite);
     }
+
+    #[test]
+    fn private_items_are_not_visible_outside_their_module() {
+        let src = r#"
+        mod shapes {
+            struct Square { side: Field }
+            pub struct Circle { radius: Field }
+            global SIDES: Field = 4;
+
+            mod inner {
+                fn sides(_square: crate::shapes::Square) -> Field {
+                    crate::shapes::SIDES
+                }
+            }
+        }
+
+        fn main(_square: shapes::Square, _circle: shapes::Circle) -> pub Field {
+            shapes::SIDES
+        }
+        "#;
+
+        let errors = get_program_errors(src);
+        let names = vecmap(&errors, |(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::PathResolutionError(
+                PathResolutionError::Private(ident),
+            )) => ident.0.contents.clone(),
+            other => panic!("Expected a private item error, got: {other:?}"),
+        });
+        assert!(names.contains(&"Square".to_string()), "Expected an error for Square: {names:?}");
+        assert!(names.contains(&"SIDES".to_string()), "Expected an error for SIDES: {names:?}");
+    }
+
+    #[test]
+    fn private_struct_fields_are_not_visible_outside_their_module() {
+        let src = r#"
+        mod stack {
+            pub struct Stack {
+                pub items: [Field; 2],
+                len: u64,
+            }
+
+            pub fn new() -> Stack {
+                Stack { items: [0; 2], len: 0 }
+            }
+        }
+
+        use stack::Stack;
+
+        fn main() -> pub u64 {
+            let mut s = stack::new();
+            s.len = 2;
+            let _items = s.items;
+            let Stack { items: _, len } = s;
+            let _other = Stack { items: [1, 2], len };
+            len
+        }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 3, "Expected 3 errors, got: {errors:?}");
+        for (error, _) in errors {
+            match error {
+                CompilationError::TypeError(TypeCheckError::PrivateStructField {
+                    field_name,
+                    struct_name,
+                    ..
+                }) => {
+                    assert_eq!(field_name, "len");
+                    assert_eq!(struct_name, "Stack");
+                }
+                other => panic!("Expected a private field error, got: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn plain_imports_are_private_to_their_module() {
+        let src = r#"
+        mod shapes {
+            pub struct Square { pub side: Field }
+        }
+
+        mod geometry {
+            use crate::shapes::Square;
+            pub use crate::shapes::Square as PublicSquare;
+
+            pub fn area(square: Square) -> Field {
+                square.side * square.side
+            }
+        }
+
+        fn main(_square: geometry::PublicSquare) -> pub Field {
+            geometry::area(geometry::Square { side: 2 })
+        }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {errors:?}");
+        match &errors[0].0 {
+            CompilationError::ResolverError(ResolverError::PathResolutionError(
+                PathResolutionError::Private(ident),
+            )) => assert_eq!(ident.0.contents, "Square"),
+            other => panic!("Expected a private item error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn private_items_cannot_be_reexported_through_a_public_module() {
+        let src = r#"
+        mod shapes {
+            struct Square { side: Field }
+            pub(crate) struct Circle { radius: Field }
+
+            pub mod reexports {
+                pub use super::Square;
+                pub(crate) use super::Circle;
+                pub use super::Circle as PublicCircle;
+            }
+        }
+
+        fn main() {}
+        "#;
+
+        let errors = get_program_errors(src);
+        let reexports = vecmap(&errors, |(error, _)| match error {
+            CompilationError::DefinitionError(DefCollectorErrorKind::PathResolutionError(
+                PathResolutionError::ReexportTooVisible { ident, visibility },
+            )) => (ident.0.contents.clone(), *visibility),
+            other => panic!("Expected a re-export error, got: {other:?}"),
+        });
+        assert_eq!(
+            reexports,
+            vec![
+                ("Square".to_string(), ItemVisibility::Private),
+                ("Circle".to_string(), ItemVisibility::PublicCrate),
+            ]
+        );
+    }
+
+    fn get_dead_code_warnings(src: &str) -> Vec<String> {
+        let (_program, context, errors) = get_program(src);
+        assert!(errors.is_empty(), "Expected no errors, got: {errors:?}");
+        let crate_id = *context.root_crate_id();
+        vecmap(find_dead_code(&context, crate_id), |(error, _)| match error {
+            CompilationError::ResolverError(ResolverError::UnusedImport { ident }) => {
+                format!("import {ident}")
+            }
+            CompilationError::ResolverError(ResolverError::UnusedItem { item, name, .. }) => {
+                format!("{item} {name}")
+            }
+            other => panic!("Expected an unused item warning, got: {other:?}"),
+        })
+    }
+
+    #[test]
+    fn unused_private_items_are_reported() {
+        let src = r#"
+        mod shapes {
+            pub struct Square { pub side: Field }
+            struct Circle { radius: Field }
+            global SIDES: Field = 4;
+            global CORNERS: Field = 4;
+
+            pub fn sides(_square: Square) -> Field {
+                helper()
+            }
+
+            fn helper() -> Field {
+                SIDES
+            }
+
+            fn unused() -> Field {
+                CORNERS
+            }
+        }
+
+        use shapes::Square;
+        use shapes::sides;
+
+        fn main(side: Field) -> pub Field {
+            shapes::sides(Square { side })
+        }
+        "#;
+
+        let warnings = get_dead_code_warnings(src);
+        assert_eq!(
+            warnings,
+            vec!["struct Circle", "global CORNERS", "function unused", "import sides"],
+        );
+    }
+
+    #[test]
+    fn only_private_imports_of_libraries_are_reported() {
+        // Without a `main` function this may be a library whose dependents use `ReExportMe`
+        // through it, but they can't reach the private `Unused` import.
+        let src = r#"
+        mod inner {
+            pub struct ReExportMe { pub value: Field }
+            pub struct Unused { pub value: Field }
+        }
+
+        pub use inner::ReExportMe;
+        use inner::Unused;
+        "#;
+
+        assert_eq!(get_dead_code_warnings(src), vec!["import Unused"]);
+    }
+
+    #[test]
+    fn unused_items_can_be_allowed() {
+        let src = r#"
+        #[allow(E0332)]
+        global UNUSED: Field = 1;
+
+        mod hash {
+            pub fn hash(x: Field) -> Field {
+                x
+            }
+        }
+
+        #[allow(E0331)]
+        use hash::hash;
+
+        fn main() {}
+
+        #[allow(E0332)]
+        fn helper() -> Field {
+            UNUSED
+        }
+        "#;
+
+        assert_eq!(get_dead_code_warnings(src), Vec::<String>::new());
+    }
+
+    #[test]
+    fn code_after_assert_false_is_unreachable() {
+        let src = r#"
+        fn main(x: Field) {
+            assert(false);
+            let _y = x + 1;
+            assert(x == 2);
+        }
+        "#;
+
+        let errors = get_program_errors(src);
+        assert_eq!(errors.len(), 1, "Expected 1 error, got: {errors:?}");
+        assert!(matches!(
+            errors[0].0,
+            CompilationError::ResolverError(ResolverError::UnreachableCode { .. })
+        ));
+    }
+
+    /// Returns the replacements of every fix suggested for the errors in `src`.
+    fn get_suggested_replacements(src: &str) -> Vec<Vec<Replacement>> {
+        get_program_errors(src)
+            .into_iter()
+            .flat_map(|(error, _)| CustomDiagnostic::from(error).suggestions)
+            .map(|suggestion| suggestion.replacements)
+            .collect()
+    }
+
+    /// Returns the span of the only occurrence of `text` in `src`.
+    fn span_of(src: &str, text: &str) -> Span {
+        assert_eq!(src.matches(text).count(), 1, "Expected `{text}` to occur once");
+        let start = src.find(text).unwrap() as u32;
+        Span::from(start..start + text.len() as u32)
+    }
+
+    fn replacement(span: Span, text: &str) -> Replacement {
+        Replacement { span, text: text.to_string() }
+    }
+
+    #[test]
+    fn suggests_renaming_unused_struct_shorthand_fields() {
+        let src = r#"
+        struct Foo { x: Field }
+
+        fn main() {
+            let Foo { x } = Foo { x: 1 };
+        }
+        "#;
+
+        // The field name must be kept, so the shorthand is expanded.
+        let x = span_of(src, "{ x }");
+        let x = Span::from(x.start() + 2..x.start() + 3);
+        assert_eq!(get_suggested_replacements(src), vec![vec![replacement(x, "x: _x")]]);
+    }
+
+    #[test]
+    fn suggests_inserting_pub_before_the_return_type_of_main() {
+        let src = "fn main() -> Field { 1 }";
+
+        let return_type = span_of(src, "Field");
+        assert_eq!(
+            get_suggested_replacements(src),
+            vec![vec![replacement(Span::empty(return_type.start()), "pub ")]]
+        );
+    }
+
+    #[test]
+    fn suggests_replacing_constrain_with_assert() {
+        let src = "fn main(x: Field) { constrain x == 1; }";
+
+        let expression = span_of(src, "x == 1");
+        assert_eq!(
+            get_suggested_replacements(src),
+            vec![vec![
+                replacement(span_of(src, "constrain "), "assert("),
+                replacement(Span::empty(expression.end()), ")"),
+            ]]
+        );
+    }
+
+    #[test]
+    fn suggests_removing_comptime() {
+        let src = "fn main(x: comptime Field) { assert(x == 1); }";
+
+        assert_eq!(
+            get_suggested_replacements(src),
+            vec![vec![replacement(span_of(src, "comptime"), "")]]
+        );
+    }
 }
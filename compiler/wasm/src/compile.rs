@@ -261,10 +261,12 @@ fn preprocess_contract(contract: CompiledContract) -> CompileResult {
         .into_iter()
         .map(|func| PreprocessedContractFunction {
             name: func.name,
+            selector: func.selector,
             function_type: func.function_type,
             is_internal: func.is_internal,
             abi: func.abi,
             bytecode: func.bytecode,
+            verification_key: None,
         })
         .collect();
 
@@ -145,9 +145,10 @@ impl BackendOpcodeSupport {
         match opcode {
             Opcode::Arithmetic(_) => self.opcodes.contains("arithmetic"),
             Opcode::Directive(_) => self.opcodes.contains("directive"),
-            Opcode::Brillig(_) => self.opcodes.contains("brillig"),
+            Opcode::Brillig(_) | Opcode::BrilligCall { .. } => self.opcodes.contains("brillig"),
             Opcode::MemoryInit { .. } => self.opcodes.contains("memory_init"),
             Opcode::MemoryOp { .. } => self.opcodes.contains("memory_op"),
+            Opcode::Lookup { .. } => self.opcodes.contains("lookup"),
             Opcode::BlackBoxFuncCall(func) => {
                 self.black_box_functions.contains(func.get_black_box_func().name())
             }
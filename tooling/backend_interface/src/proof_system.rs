@@ -127,6 +127,32 @@ impl Backend {
             .run(binary_path)
     }
 
+    /// Generates the verification key for `circuit`.
+    pub fn write_vk(&self, circuit: &Circuit) -> Result<Vec<u8>, BackendError> {
+        let binary_path = self.assert_binary_exists()?;
+        self.assert_correct_version()?;
+
+        let temp_directory = tempdir().expect("could not create a temporary directory");
+        let temp_directory = temp_directory.path().to_path_buf();
+
+        // Create a temporary file for the circuit
+        let bytecode_path = temp_directory.join("circuit").with_extension("bytecode");
+        let serialized_circuit = Circuit::serialize_circuit(circuit);
+        write_to_file(&serialized_circuit, &bytecode_path);
+
+        // Create the verification key and read it back from the specified path
+        let vk_path = temp_directory.join("vk");
+
+        WriteVkCommand {
+            crs_path: self.crs_directory(),
+            bytecode_path,
+            vk_path_output: vk_path.clone(),
+        }
+        .run(binary_path)?;
+
+        Ok(std::fs::read(vk_path)?)
+    }
+
     pub fn get_intermediate_proof_artifacts(
         &self,
         circuit: &Circuit,
@@ -186,3 +212,55 @@ pub(super) fn write_to_file(bytes: &[u8], path: &Path) -> String {
         Ok(_) => display.to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use acvm::acir::{
+        circuit::{Circuit, Opcode, PublicInputs},
+        native_types::{Expression, Witness, WitnessMap},
+    };
+
+    use crate::{get_mock_backend, BackendError};
+
+    fn circuit() -> Circuit {
+        let expression = &(Witness(1) + Witness(2)) - &Expression::from(Witness(3));
+        let constraint = Opcode::Arithmetic(expression);
+
+        Circuit {
+            current_witness_index: 4,
+            opcodes: vec![constraint],
+            private_parameters: BTreeSet::from([Witness(1), Witness(2)]),
+            public_parameters: PublicInputs::default(),
+            return_values: PublicInputs::default(),
+            assert_messages: Default::default(),
+            unconstrained_functions: Vec::new(),
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_write_vk() -> Result<(), BackendError> {
+        let verification_key = get_mock_backend()?.write_vk(&circuit())?;
+
+        assert_eq!(verification_key, b"vk");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intermediate_proof_artifacts() -> Result<(), BackendError> {
+        let backend = get_mock_backend()?;
+        let proof = backend.prove(&circuit(), WitnessMap::new(), true)?;
+
+        let (proof_as_fields, vk_hash, vk_as_fields) =
+            backend.get_intermediate_proof_artifacts(&circuit(), &proof, WitnessMap::new())?;
+
+        assert_eq!(proof_as_fields.len(), 4);
+        assert_eq!(vk_hash, 10_u128.into());
+        assert_eq!(vk_as_fields.len(), 3);
+
+        Ok(())
+    }
+}
@@ -8,8 +8,10 @@ use clap::{Parser, Subcommand};
 mod contract_cmd;
 mod gates_cmd;
 mod info_cmd;
+mod proof_as_fields_cmd;
 mod prove_cmd;
 mod verify_cmd;
+mod vk_as_fields_cmd;
 mod write_vk_cmd;
 
 #[derive(Parser, Debug)]
@@ -28,6 +30,10 @@ enum BackendCommand {
     Verify(verify_cmd::VerifyCommand),
     #[command(name = "write_vk")]
     WriteVk(write_vk_cmd::WriteVkCommand),
+    #[command(name = "proof_as_fields")]
+    ProofAsFields(proof_as_fields_cmd::ProofAsFieldsCommand),
+    #[command(name = "vk_as_fields")]
+    VkAsFields(vk_as_fields_cmd::VkAsFieldsCommand),
 }
 
 fn main() {
@@ -40,5 +46,7 @@ fn main() {
         BackendCommand::Prove(args) => prove_cmd::run(args),
         BackendCommand::Verify(args) => verify_cmd::run(args),
         BackendCommand::WriteVk(args) => write_vk_cmd::run(args),
+        BackendCommand::ProofAsFields(args) => proof_as_fields_cmd::run(args),
+        BackendCommand::VkAsFields(args) => vk_as_fields_cmd::run(args),
     };
 }
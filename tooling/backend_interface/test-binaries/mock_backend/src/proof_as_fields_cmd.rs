@@ -0,0 +1,22 @@
+use clap::Args;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ProofAsFieldsCommand {
+    #[clap(short = 'p')]
+    pub(crate) proof_path: PathBuf,
+
+    #[clap(short = 'k')]
+    pub(crate) vk_path: PathBuf,
+
+    #[clap(short = 'o')]
+    pub(crate) output_path: PathBuf,
+}
+
+pub(crate) fn run(args: ProofAsFieldsCommand) {
+    assert!(args.proof_path.is_file(), "Could not find proof file at provided path");
+    assert!(args.vk_path.is_file(), "Could not find verification key file at provided path");
+
+    std::io::stdout().write_all(br#"["0x01","0x02","0x03","0x04"]"#).unwrap();
+}
@@ -15,6 +15,9 @@ pub(crate) struct ProveCommand {
 
     #[clap(short = 'o')]
     pub(crate) proof_path: PathBuf,
+
+    #[clap(short = 'r')]
+    pub(crate) is_recursive: bool,
 }
 
 pub(crate) fn run(args: ProveCommand) {
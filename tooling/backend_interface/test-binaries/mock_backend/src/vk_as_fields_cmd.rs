@@ -0,0 +1,19 @@
+use clap::Args;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct VkAsFieldsCommand {
+    #[clap(short = 'k')]
+    pub(crate) vk_path: PathBuf,
+
+    #[clap(short = 'o')]
+    pub(crate) output_path: PathBuf,
+}
+
+pub(crate) fn run(args: VkAsFieldsCommand) {
+    assert!(args.vk_path.is_file(), "Could not find verification key file at provided path");
+
+    // The hash of the verification key comes first.
+    std::io::stdout().write_all(br#"["0x0a","0x0b","0x0c","0x0d"]"#).unwrap();
+}
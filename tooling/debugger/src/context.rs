@@ -1,6 +1,9 @@
 use acvm::acir::circuit::{Circuit, Opcode, OpcodeLocation};
 use acvm::acir::native_types::{Witness, WitnessMap};
-use acvm::brillig_vm::{brillig::Value, Registers};
+use acvm::brillig_vm::{
+    brillig::{Opcode as BrilligOpcode, Value},
+    Registers,
+};
 use acvm::pwg::{
     ACVMStatus, BrilligSolver, BrilligSolverStatus, ForeignCallWaitInfo, StepResult, ACVM,
 };
@@ -38,7 +41,13 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
         foreign_call_executor: Box<dyn ForeignCallExecutor + 'a>,
     ) -> Self {
         Self {
-            acvm: ACVM::new(blackbox_solver, &circuit.opcodes, initial_witness),
+            acvm: ACVM::new(
+                blackbox_solver,
+                &circuit.opcodes,
+                initial_witness,
+                &circuit.unconstrained_functions,
+                &circuit.lookup_tables,
+            ),
             brillig_solver: None,
             foreign_call_executor,
             debug_artifact,
@@ -50,6 +59,20 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
         self.acvm.opcodes()
     }
 
+    /// Returns the Brillig bytecode executed by the opcode at `acir_index`,
+    /// or `None` if that opcode is not executed by the Brillig VM.
+    pub(super) fn get_brillig_bytecode(&self, acir_index: usize) -> Option<&[BrilligOpcode]> {
+        match self.get_opcodes().get(acir_index)? {
+            Opcode::Brillig(brillig) => Some(&brillig.bytecode),
+            Opcode::BrilligCall { id, .. } => self
+                .acvm
+                .unconstrained_functions()
+                .get(*id as usize)
+                .map(|function| function.bytecode.as_slice()),
+            _ => None,
+        }
+    }
+
     pub(super) fn get_witness_map(&self) -> &WitnessMap {
         self.acvm.witness_map()
     }
@@ -182,7 +205,7 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
         match self.get_current_opcode_location() {
             Some(OpcodeLocation::Brillig { .. }) => true,
             Some(OpcodeLocation::Acir(acir_index)) => {
-                matches!(self.get_opcodes()[acir_index], Opcode::Brillig(_))
+                self.get_brillig_bytecode(acir_index).is_some()
             }
             _ => false,
         }
@@ -244,9 +267,7 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
     }
 
     pub(super) fn is_executing_brillig(&self) -> bool {
-        let opcodes = self.get_opcodes();
-        let acir_index = self.acvm.instruction_pointer();
-        acir_index < opcodes.len() && matches!(opcodes[acir_index], Opcode::Brillig(..))
+        self.get_brillig_bytecode(self.acvm.instruction_pointer()).is_some()
     }
 
     pub(super) fn get_brillig_registers(&self) -> Option<&Registers> {
@@ -281,17 +302,9 @@ impl<'a, B: BlackBoxFunctionSolver> DebugContext<'a, B> {
         let opcodes = self.get_opcodes();
         match *location {
             OpcodeLocation::Acir(acir_index) => acir_index < opcodes.len(),
-            OpcodeLocation::Brillig { acir_index, brillig_index } => {
-                acir_index < opcodes.len()
-                    && matches!(opcodes[acir_index], Opcode::Brillig(..))
-                    && {
-                        if let Opcode::Brillig(ref brillig) = opcodes[acir_index] {
-                            brillig_index < brillig.bytecode.len()
-                        } else {
-                            false
-                        }
-                    }
-            }
+            OpcodeLocation::Brillig { acir_index, brillig_index } => self
+                .get_brillig_bytecode(acir_index)
+                .map_or(false, |bytecode| brillig_index < bytecode.len()),
         }
     }
 
@@ -61,12 +61,12 @@ impl<'a, B: BlackBoxFunctionSolver> ReplDebugger<'a, B> {
                         println!("At opcode {}: {}", ip, opcodes[ip])
                     }
                     OpcodeLocation::Brillig { acir_index, brillig_index } => {
-                        let Opcode::Brillig(ref brillig) = opcodes[acir_index] else {
+                        let Some(bytecode) = self.context.get_brillig_bytecode(acir_index) else {
                             unreachable!("Brillig location does not contain a Brillig block");
                         };
                         println!(
                             "At opcode {}.{}: {:?}",
-                            acir_index, brillig_index, brillig.bytecode[brillig_index]
+                            acir_index, brillig_index, bytecode[brillig_index]
                         );
                     }
                 }
@@ -176,10 +176,16 @@ impl<'a, B: BlackBoxFunctionSolver> ReplDebugger<'a, B> {
         };
         for (acir_index, opcode) in opcodes.iter().enumerate() {
             let marker = outer_marker(acir_index);
-            if let Opcode::Brillig(brillig) = opcode {
-                println!("{:>3} {:2} BRILLIG inputs={:?}", acir_index, marker, brillig.inputs);
-                println!("       |       outputs={:?}", brillig.outputs);
-                for (brillig_index, brillig_opcode) in brillig.bytecode.iter().enumerate() {
+            let brillig_call = match opcode {
+                Opcode::Brillig(brillig) => Some((&brillig.inputs, &brillig.outputs)),
+                Opcode::BrilligCall { inputs, outputs, .. } => Some((inputs, outputs)),
+                _ => None,
+            };
+            if let Some((inputs, outputs)) = brillig_call {
+                let bytecode = self.context.get_brillig_bytecode(acir_index).unwrap_or_default();
+                println!("{:>3} {:2} BRILLIG inputs={:?}", acir_index, marker, inputs);
+                println!("       |       outputs={:?}", outputs);
+                for (brillig_index, brillig_opcode) in bytecode.iter().enumerate() {
                     println!(
                         "{:>3}.{:<2} |{:2} {:?}",
                         acir_index,
@@ -4,7 +4,7 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies, unused_extern_crates))]
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     future::Future,
     ops::{self, ControlFlow},
     path::{Path, PathBuf},
@@ -23,12 +23,13 @@ use noirc_frontend::{
     hir::{Context, FunctionNameMatch},
 };
 use notifications::{
-    on_did_change_configuration, on_did_change_text_document, on_did_close_text_document,
-    on_did_open_text_document, on_did_save_text_document, on_exit, on_initialized,
+    on_check_workspace, on_did_change_configuration, on_did_change_text_document,
+    on_did_close_text_document, on_did_open_text_document, on_did_save_text_document, on_exit,
+    on_initialized, CheckWorkspace,
 };
 use requests::{
-    on_code_lens_request, on_formatting, on_initialize, on_profile_run_request, on_shutdown,
-    on_test_run_request, on_tests_request,
+    on_code_action_request, on_code_lens_request, on_formatting, on_initialize,
+    on_profile_run_request, on_shutdown, on_test_run_request, on_tests_request,
 };
 use serde_json::Value as JsonValue;
 use tower::Service;
@@ -47,6 +48,17 @@ pub struct LspState {
     client: ClientSocket,
     solver: WrapperSolver,
     input_files: HashMap<String, String>,
+    /// Files changed since the workspaces containing them were last checked.
+    files_to_check: HashSet<PathBuf>,
+    /// The number of changes made to the open files, used to tell whether a pending check
+    /// has been superseded by a later one.
+    changes: u64,
+    /// The files for which diagnostics were last published, by workspace manifest, so that
+    /// they can be cleared once they are fixed.
+    published_diagnostics: HashMap<PathBuf, HashSet<Url>>,
+    /// Sends the checks scheduled by changes to the thread which debounces them, once spawned.
+    #[cfg(not(target_arch = "wasm32"))]
+    check_debouncer: Option<std::sync::mpsc::Sender<CheckWorkspace>>,
 }
 
 impl LspState {
@@ -56,6 +68,11 @@ impl LspState {
             root_path: None,
             solver: WrapperSolver(Box::new(solver)),
             input_files: HashMap::new(),
+            files_to_check: HashSet::new(),
+            changes: 0,
+            published_diagnostics: HashMap::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            check_debouncer: None,
         }
     }
 }
@@ -73,6 +90,7 @@ impl NargoLspService {
             .request::<request::Formatting, _>(on_formatting)
             .request::<request::Shutdown, _>(on_shutdown)
             .request::<request::CodeLens, _>(on_code_lens_request)
+            .request::<request::CodeAction, _>(on_code_action_request)
             .request::<request::NargoTests, _>(on_tests_request)
             .request::<request::NargoTestRun, _>(on_test_run_request)
             .request::<request::NargoProfileRun, _>(on_profile_run_request)
@@ -82,7 +100,8 @@ impl NargoLspService {
             .notification::<notification::DidChangeTextDocument>(on_did_change_text_document)
             .notification::<notification::DidCloseTextDocument>(on_did_close_text_document)
             .notification::<notification::DidSaveTextDocument>(on_did_save_text_document)
-            .notification::<notification::Exit>(on_exit);
+            .notification::<notification::Exit>(on_exit)
+            .event::<CheckWorkspace>(on_check_workspace);
         Self { router }
     }
 }
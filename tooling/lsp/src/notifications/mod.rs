@@ -1,20 +1,41 @@
-use std::ops::ControlFlow;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
 
+#[cfg(not(target_arch = "wasm32"))]
+use async_lsp::ClientSocket;
 use async_lsp::{ErrorCode, LanguageClient, ResponseError};
-use nargo::prepare_package;
+use nargo::prepare_package_with_overlays;
 use nargo_toml::{find_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::{check_crate, NOIR_ARTIFACT_VERSION_STRING};
 use noirc_errors::{DiagnosticKind, FileDiagnostic};
 
 use crate::types::{
-    notification, Diagnostic, DiagnosticSeverity, DidChangeConfigurationParams,
+    notification, Diagnostic, DiagnosticFix, DiagnosticSeverity, DidChangeConfigurationParams,
     DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
     DidSaveTextDocumentParams, InitializedParams, LogMessageParams, MessageType, NargoPackageTests,
-    PublishDiagnosticsParams,
+    NumberOrString, PublishDiagnosticsParams, TextEdit, Url,
 };
 
 use crate::{byte_span_to_range, get_non_stdlib_asset, get_package_tests_in_crate, LspState};
 
+/// How long to wait after a change for further ones before checking the workspace,
+/// so that we don't check it on every keystroke while the user is typing.
+#[cfg(not(target_arch = "wasm32"))]
+const CHECK_DEBOUNCE_DELAY: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Requests a check of the workspaces containing the files which changed since the last check.
+///
+/// The check only runs if no other change was made after the one which scheduled it.
+pub(super) struct CheckWorkspace {
+    change: u64,
+}
+
 pub(super) fn on_initialized(
     _state: &mut LspState,
     _params: InitializedParams,
@@ -33,7 +54,9 @@ pub(super) fn on_did_open_text_document(
     state: &mut LspState,
     params: DidOpenTextDocumentParams,
 ) -> ControlFlow<Result<(), async_lsp::Error>> {
-    state.input_files.insert(params.text_document.uri.to_string(), params.text_document.text);
+    let uri = params.text_document.uri;
+    state.input_files.insert(uri.to_string(), params.text_document.text);
+    schedule_check(state, &uri);
     ControlFlow::Continue(())
 }
 
@@ -41,8 +64,10 @@ pub(super) fn on_did_change_text_document(
     state: &mut LspState,
     params: DidChangeTextDocumentParams,
 ) -> ControlFlow<Result<(), async_lsp::Error>> {
+    let uri = params.text_document.uri;
     let text = params.content_changes.into_iter().next().unwrap().text;
-    state.input_files.insert(params.text_document.uri.to_string(), text);
+    state.input_files.insert(uri.to_string(), text);
+    schedule_check(state, &uri);
     ControlFlow::Continue(())
 }
 
@@ -50,7 +75,10 @@ pub(super) fn on_did_close_text_document(
     state: &mut LspState,
     params: DidCloseTextDocumentParams,
 ) -> ControlFlow<Result<(), async_lsp::Error>> {
-    state.input_files.remove(&params.text_document.uri.to_string());
+    let uri = params.text_document.uri;
+    state.input_files.remove(&uri.to_string());
+    // Unsaved changes are discarded when closing a file, so its diagnostics must reflect the file on disk.
+    schedule_check(state, &uri);
     ControlFlow::Continue(())
 }
 
@@ -69,19 +97,138 @@ pub(super) fn on_did_save_text_document(
         }
     };
 
+    let result = find_workspace_manifest(state, &file_path).and_then(|toml_path| match toml_path {
+        Some(toml_path) => check_workspace(state, &toml_path),
+        None => Ok(()),
+    });
+
+    match result {
+        Ok(()) => ControlFlow::Continue(()),
+        Err(err) => ControlFlow::Break(Err(err)),
+    }
+}
+
+pub(super) fn on_check_workspace(
+    state: &mut LspState,
+    event: CheckWorkspace,
+) -> ControlFlow<Result<(), async_lsp::Error>> {
+    // A later change scheduled a check of its own, which will also cover the files changed so far.
+    if event.change != state.changes {
+        return ControlFlow::Continue(());
+    }
+
+    let mut checked_workspaces = HashSet::new();
+    for file_path in std::mem::take(&mut state.files_to_check) {
+        let result =
+            find_workspace_manifest(state, &file_path).and_then(|toml_path| match toml_path {
+                Some(toml_path) if checked_workspaces.insert(toml_path.clone()) => {
+                    check_workspace(state, &toml_path)
+                }
+                _ => Ok(()),
+            });
+
+        // Unlike on save, the workspace may well be invalid while it is being edited,
+        // so we report the error without shutting down the server.
+        if let Err(err) = result {
+            let _ = state.client.log_message(LogMessageParams {
+                typ: MessageType::ERROR,
+                message: err.to_string(),
+            });
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub(super) fn on_exit(
+    _state: &mut LspState,
+    _params: (),
+) -> ControlFlow<Result<(), async_lsp::Error>> {
+    ControlFlow::Continue(())
+}
+
+/// Schedules a check of the workspace containing the file at `uri` once the user stops typing.
+fn schedule_check(state: &mut LspState, uri: &Url) {
+    let Ok(file_path) = uri.to_file_path() else {
+        return;
+    };
+    state.files_to_check.insert(file_path);
+    state.changes += 1;
+
+    let event = CheckWorkspace { change: state.changes };
+
+    cfg_if::cfg_if! {
+        if #[cfg(target_arch = "wasm32")] {
+            // There are no threads to wait on, so changes are only debounced when they
+            // are already queued by the time the check is handled.
+            let _ = state.client.emit(event);
+        } else {
+            let client = &state.client;
+            let debouncer = state
+                .check_debouncer
+                .get_or_insert_with(|| spawn_check_debouncer(client.clone()));
+            let _ = debouncer.send(event);
+        }
+    }
+}
+
+/// Spawns the thread which emits the checks scheduled through the returned sender, once no
+/// further check has been scheduled for [`CHECK_DEBOUNCE_DELAY`].
+///
+/// Only the latest of the checks scheduled in quick succession is emitted. The thread stops
+/// once the sender is dropped along with the [`LspState`].
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_check_debouncer(client: ClientSocket) -> Sender<CheckWorkspace> {
+    let (sender, receiver) = channel();
+    std::thread::spawn(move || {
+        while let Ok(mut event) = receiver.recv() {
+            loop {
+                match receiver.recv_timeout(CHECK_DEBOUNCE_DELAY) {
+                    Ok(later_event) => event = later_event,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            if client.emit(event).is_err() {
+                return;
+            }
+        }
+    });
+    sender
+}
+
+/// Returns the unsaved contents of the open files, by path.
+fn overlays(state: &LspState) -> HashMap<PathBuf, String> {
+    state
+        .input_files
+        .iter()
+        .filter_map(|(uri, source)| {
+            let file_path = Url::parse(uri).ok()?.to_file_path().ok()?;
+            Some((file_path, source.clone()))
+        })
+        .collect()
+}
+
+/// Finds the manifest of the workspace containing the file at `file_path`.
+///
+/// If there is none, we log a warning but return no error.
+fn find_workspace_manifest(
+    state: &LspState,
+    file_path: &Path,
+) -> Result<Option<PathBuf>, async_lsp::Error> {
     let root_path = match &state.root_path {
         Some(root) => root,
         None => {
-            return ControlFlow::Break(Err(ResponseError::new(
+            return Err(ResponseError::new(
                 ErrorCode::REQUEST_FAILED,
                 "Could not find project root",
             )
-            .into()));
+            .into());
         }
     };
 
-    let toml_path = match find_package_manifest(root_path, &file_path) {
-        Ok(toml_path) => toml_path,
+    match find_package_manifest(root_path, file_path) {
+        Ok(toml_path) => Ok(Some(toml_path)),
         Err(err) => {
             // If we cannot find a manifest, we log a warning but return no diagnostics
             // We can reconsider this when we can build a file without the need for a Nargo.toml file to resolve deps
@@ -89,93 +236,226 @@ pub(super) fn on_did_save_text_document(
                 typ: MessageType::WARNING,
                 message: format!("{err}"),
             });
-            return ControlFlow::Continue(());
+            Ok(None)
         }
-    };
+    }
+}
+
+/// Checks every package of the workspace at `toml_path`, reading the open files from their unsaved
+/// contents, and publishes the diagnostics of every file in it.
+fn check_workspace(state: &mut LspState, toml_path: &Path) -> Result<(), async_lsp::Error> {
     let workspace = match resolve_workspace_from_toml(
-        &toml_path,
+        toml_path,
         PackageSelection::All,
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
     ) {
         Ok(workspace) => workspace,
         Err(err) => {
             // If we found a manifest, but the workspace is invalid, we raise an error about it
-            return ControlFlow::Break(Err(ResponseError::new(
-                ErrorCode::REQUEST_FAILED,
-                format!("{err}"),
-            )
-            .into()));
+            return Err(ResponseError::new(ErrorCode::REQUEST_FAILED, format!("{err}")).into());
         }
     };
 
-    let diagnostics: Vec<_> = workspace
-        .into_iter()
-        .flat_map(|package| -> Vec<Diagnostic> {
-            let (mut context, crate_id) = prepare_package(package, Box::new(get_non_stdlib_asset));
+    let overlays = overlays(state);
+    let mut diagnostics_by_file: HashMap<Url, Vec<Diagnostic>> = HashMap::new();
 
-            let file_diagnostics = match check_crate(&mut context, crate_id, false) {
-                Ok(((), warnings)) => warnings,
-                Err(errors_and_warnings) => errors_and_warnings,
-            };
+    for package in &workspace {
+        let (mut context, crate_id) =
+            prepare_package_with_overlays(package, Box::new(get_non_stdlib_asset), &overlays);
 
-            // We don't add test headings for a package if it contains no `#[test]` functions
-            if let Some(tests) = get_package_tests_in_crate(&context, &crate_id, &package.name) {
-                let _ = state.client.notify::<notification::NargoUpdateTests>(NargoPackageTests {
-                    package: package.name.to_string(),
-                    tests,
-                });
-            }
+        let file_diagnostics = match check_crate(&mut context, crate_id, false) {
+            Ok(((), warnings)) => warnings,
+            Err(errors_and_warnings) => errors_and_warnings,
+        };
+
+        // We don't add test headings for a package if it contains no `#[test]` functions
+        if let Some(tests) = get_package_tests_in_crate(&context, &crate_id, &package.name) {
+            let _ = state.client.notify::<notification::NargoUpdateTests>(NargoPackageTests {
+                package: package.name.to_string(),
+                tests,
+            });
+        }
 
-            let fm = &context.file_manager;
-            let files = fm.as_file_map();
+        let fm = &context.file_manager;
+        let files = fm.as_file_map();
 
-            file_diagnostics
+        for FileDiagnostic { file_id, diagnostic, call_stack: _ } in file_diagnostics {
+            // The standard library isn't on disk, so its files can't be shown to the user
+            let Ok(uri) = Url::from_file_path(fm.path(file_id)) else {
+                continue;
+            };
+
+            // TODO: Should this be the first item in secondaries? Should we bail when we find a range?
+            let range = diagnostic
+                .secondaries
                 .into_iter()
-                .filter_map(|FileDiagnostic { file_id, diagnostic, call_stack: _ }| {
-                    // Ignore diagnostics for any file that wasn't the file we saved
-                    // TODO: In the future, we could create "related" diagnostics for these files
-                    if fm.path(file_id) != file_path {
-                        return None;
-                    }
-
-                    // TODO: Should this be the first item in secondaries? Should we bail when we find a range?
-                    let range = diagnostic
-                        .secondaries
-                        .into_iter()
-                        .filter_map(|sec| byte_span_to_range(files, file_id, sec.span.into()))
-                        .last()
-                        .unwrap_or_default();
-
-                    let severity = match diagnostic.kind {
-                        DiagnosticKind::Error => DiagnosticSeverity::ERROR,
-                        DiagnosticKind::Warning => DiagnosticSeverity::WARNING,
-                    };
-                    Some(Diagnostic {
-                        range,
-                        severity: Some(severity),
-                        message: diagnostic.message,
-                        ..Default::default()
+                .filter_map(|sec| byte_span_to_range(files, file_id, sec.span.into()))
+                .last()
+                .unwrap_or_default();
+
+            let severity = match diagnostic.kind {
+                DiagnosticKind::Error => DiagnosticSeverity::ERROR,
+                DiagnosticKind::Warning => DiagnosticSeverity::WARNING,
+            };
+
+            // The fixes are sent along with the diagnostic so that code actions can be offered for it.
+            let fixes: Vec<DiagnosticFix> = diagnostic
+                .suggestions
+                .iter()
+                .filter_map(|suggestion| {
+                    let edits = suggestion
+                        .replacements
+                        .iter()
+                        .map(|replacement| {
+                            let range =
+                                byte_span_to_range(files, file_id, replacement.span.into())?;
+                            Some(TextEdit { range, new_text: replacement.text.clone() })
+                        })
+                        .collect::<Option<_>>()?;
+                    Some(DiagnosticFix {
+                        title: suggestion.message.clone(),
+                        edits,
+                        is_preferred: suggestion.is_machine_applicable(),
                     })
                 })
-                .collect()
-        })
-        .collect();
+                .collect();
+            let data = (!fixes.is_empty())
+                .then(|| serde_json::to_value(fixes).expect("fixes should serialize to JSON"));
+
+            diagnostics_by_file.entry(uri).or_default().push(Diagnostic {
+                range,
+                severity: Some(severity),
+                code: diagnostic.code.map(|code| NumberOrString::String(code.to_string())),
+                message: diagnostic.message,
+                data,
+                ..Default::default()
+            });
+        }
+    }
 
     // We need to refresh lenses when we compile since that's the only time they can be accurately reflected
     std::mem::drop(state.client.code_lens_refresh(()));
 
-    let _ = state.client.publish_diagnostics(PublishDiagnosticsParams {
-        uri: params.text_document.uri,
-        version: None,
-        diagnostics,
-    });
+    // Files which had diagnostics the last time around need an empty list to clear them.
+    let files_with_diagnostics: HashSet<Url> = diagnostics_by_file.keys().cloned().collect();
+    let previous_files = state
+        .published_diagnostics
+        .insert(toml_path.to_path_buf(), files_with_diagnostics)
+        .unwrap_or_default();
+    for uri in previous_files {
+        diagnostics_by_file.entry(uri).or_default();
+    }
 
-    ControlFlow::Continue(())
+    for (uri, diagnostics) in diagnostics_by_file {
+        let _ = state.client.publish_diagnostics(PublishDiagnosticsParams {
+            uri,
+            version: None,
+            diagnostics,
+        });
+    }
+
+    Ok(())
 }
 
-pub(super) fn on_exit(
-    _state: &mut LspState,
-    _params: (),
-) -> ControlFlow<Result<(), async_lsp::Error>> {
-    ControlFlow::Continue(())
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use async_lsp::ClientSocket;
+    use nargo::prepare_package_with_overlays;
+    use nargo_toml::{resolve_workspace_from_toml, PackageSelection};
+    use noirc_driver::check_crate;
+    use tokio::test;
+
+    use super::{on_check_workspace, overlays, CheckWorkspace};
+    use crate::{get_non_stdlib_asset, solver::MockBackend, types::Url, LspState};
+
+    const VALID_MAIN: &str = "fn main(x: Field) { assert(x == 1); }";
+    const INVALID_MAIN: &str = "fn main(x: Field) { assert(x == true); }";
+
+    /// Checks the package at `project_dir` with the open files of `state`, returning whether
+    /// it has any errors.
+    fn package_has_errors(state: &LspState, project_dir: &Path) -> bool {
+        let workspace = resolve_workspace_from_toml(
+            &project_dir.join("Nargo.toml"),
+            PackageSelection::All,
+            None,
+        )
+        .unwrap();
+        let package = workspace.into_iter().next().unwrap();
+        let (mut context, crate_id) = prepare_package_with_overlays(
+            package,
+            Box::new(get_non_stdlib_asset),
+            &overlays(state),
+        );
+        check_crate(&mut context, crate_id, false).is_err()
+    }
+
+    /// Creates a binary package whose `main.nr` contains `main_source` on disk.
+    fn create_package(main_source: &str) -> tempfile::TempDir {
+        let project_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            project_dir.path().join("Nargo.toml"),
+            "[package]\nname = \"foo\"\ntype = \"bin\"\nauthors = [\"\"]\n[dependencies]\n",
+        )
+        .unwrap();
+        std::fs::create_dir(project_dir.path().join("src")).unwrap();
+        std::fs::write(project_dir.path().join("src/main.nr"), main_source).unwrap();
+        project_dir
+    }
+
+    #[test]
+    async fn open_file_contents_take_precedence_over_disk() {
+        let client = ClientSocket::new_closed();
+        let mut state = LspState::new(&client, MockBackend);
+        let project_dir = create_package(INVALID_MAIN);
+        assert!(package_has_errors(&state, project_dir.path()));
+
+        let main_uri = Url::from_file_path(project_dir.path().join("src/main.nr")).unwrap();
+        state.input_files.insert(main_uri.to_string(), VALID_MAIN.to_string());
+        assert!(!package_has_errors(&state, project_dir.path()));
+
+        state.input_files.insert(main_uri.to_string(), INVALID_MAIN.to_string());
+        std::fs::write(project_dir.path().join("src/main.nr"), VALID_MAIN).unwrap();
+        assert!(package_has_errors(&state, project_dir.path()));
+    }
+
+    #[test]
+    async fn closed_files_are_read_from_disk() {
+        let client = ClientSocket::new_closed();
+        let mut state = LspState::new(&client, MockBackend);
+        let project_dir = create_package(VALID_MAIN);
+        let main_uri = Url::from_file_path(project_dir.path().join("src/main.nr")).unwrap();
+        state.input_files.insert(main_uri.to_string(), INVALID_MAIN.to_string());
+        assert!(package_has_errors(&state, project_dir.path()));
+
+        state.input_files.remove(&main_uri.to_string());
+        assert!(!package_has_errors(&state, project_dir.path()));
+    }
+
+    #[test]
+    async fn superseded_check_is_skipped() {
+        let client = ClientSocket::new_closed();
+        let mut state = LspState::new(&client, MockBackend);
+        state.files_to_check.insert(PathBuf::from("/project/src/main.nr"));
+        state.changes = 2;
+
+        let _ = on_check_workspace(&mut state, CheckWorkspace { change: 1 });
+
+        // The files are left for the check scheduled by the later change.
+        assert_eq!(state.files_to_check.len(), 1);
+    }
+
+    #[test]
+    async fn overlays_are_keyed_by_file_path() {
+        let client = ClientSocket::new_closed();
+        let mut state = LspState::new(&client, MockBackend);
+        state.input_files.insert("file:///project/src/main.nr".to_string(), "fn main() {}".into());
+        state.input_files.insert("untitled:Untitled-1".to_string(), "fn foo() {}".into());
+
+        let overlays = overlays(&state);
+
+        assert_eq!(overlays.len(), 1);
+        assert_eq!(overlays[&PathBuf::from("/project/src/main.nr")], "fn main() {}");
+    }
 }
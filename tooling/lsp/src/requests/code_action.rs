@@ -0,0 +1,115 @@
+use std::{
+    collections::HashMap,
+    future::{self, Future},
+};
+
+use async_lsp::ResponseError;
+
+use crate::{
+    types::{
+        CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
+        DiagnosticFix, WorkspaceEdit,
+    },
+    LspState,
+};
+
+pub(crate) fn on_code_action_request(
+    _state: &mut LspState,
+    params: CodeActionParams,
+) -> impl Future<Output = Result<Option<CodeActionResponse>, ResponseError>> {
+    future::ready(Ok(on_code_action_request_inner(params)))
+}
+
+/// Offers the fixes which were published along with the diagnostics in the requested range.
+fn on_code_action_request_inner(params: CodeActionParams) -> Option<CodeActionResponse> {
+    let uri = params.text_document.uri;
+
+    let mut actions = Vec::new();
+    for diagnostic in params.context.diagnostics {
+        let Some(fixes) = diagnostic
+            .data
+            .clone()
+            .and_then(|data| serde_json::from_value::<Vec<DiagnosticFix>>(data).ok())
+        else {
+            continue;
+        };
+
+        for DiagnosticFix { title, edits, is_preferred } in fixes {
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(uri.clone(), edits)])),
+                    ..Default::default()
+                }),
+                is_preferred: Some(is_preferred),
+                ..Default::default()
+            }));
+        }
+    }
+
+    if actions.is_empty() {
+        None
+    } else {
+        Some(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{
+        CodeActionContext, Diagnostic, PartialResultParams, Position, Range,
+        TextDocumentIdentifier, TextEdit, Url, WorkDoneProgressParams,
+    };
+
+    use super::on_code_action_request_inner;
+    use crate::types::{CodeActionOrCommand, CodeActionParams, DiagnosticFix};
+
+    fn code_action_params(diagnostics: Vec<Diagnostic>) -> CodeActionParams {
+        CodeActionParams {
+            text_document: TextDocumentIdentifier {
+                uri: Url::parse("file:///project/src/main.nr").unwrap(),
+            },
+            range: Range::default(),
+            context: CodeActionContext { diagnostics, only: None, trigger_kind: None },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        }
+    }
+
+    #[test]
+    fn offers_fixes_of_diagnostics() {
+        let range = Range::new(Position::new(1, 8), Position::new(1, 9));
+        let fix = DiagnosticFix {
+            title: "prefix it with an underscore".to_string(),
+            edits: vec![TextEdit { range, new_text: "_x".to_string() }],
+            is_preferred: true,
+        };
+        let diagnostic = Diagnostic {
+            range,
+            message: "unused variable x".to_string(),
+            data: Some(serde_json::to_value(vec![fix]).unwrap()),
+            ..Default::default()
+        };
+        let without_fixes = Diagnostic { data: None, ..diagnostic.clone() };
+
+        let actions =
+            on_code_action_request_inner(code_action_params(vec![diagnostic, without_fixes]))
+                .unwrap();
+
+        assert_eq!(actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+            panic!("expected a code action");
+        };
+        assert_eq!(action.title, "prefix it with an underscore");
+        assert_eq!(action.is_preferred, Some(true));
+        let changes = action.edit.as_ref().and_then(|edit| edit.changes.as_ref()).unwrap();
+        assert_eq!(changes.values().next().unwrap()[0].new_text, "_x");
+    }
+
+    #[test]
+    fn no_actions_without_fixes() {
+        assert!(on_code_action_request_inner(code_action_params(Vec::new())).is_none());
+    }
+}
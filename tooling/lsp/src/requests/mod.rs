@@ -1,6 +1,6 @@
 use std::future::Future;
 
-use crate::types::{CodeLensOptions, InitializeParams};
+use crate::types::{CodeActionProviderCapability, CodeLensOptions, InitializeParams};
 use async_lsp::ResponseError;
 use lsp_types::{Position, TextDocumentSyncCapability, TextDocumentSyncKind};
 use nargo_fmt::Config;
@@ -20,14 +20,15 @@ use crate::{
 // They are not attached to the `NargoLspService` struct so they can be unit tested with only `LspState`
 // and params passed in.
 
+mod code_action;
 mod code_lens_request;
 mod profile_run;
 mod test_run;
 mod tests;
 
 pub(crate) use {
-    code_lens_request::on_code_lens_request, profile_run::on_profile_run_request,
-    test_run::on_test_run_request, tests::on_tests_request,
+    code_action::on_code_action_request, code_lens_request::on_code_lens_request,
+    profile_run::on_profile_run_request, test_run::on_test_run_request, tests::on_tests_request,
 };
 
 pub(crate) fn on_initialize(
@@ -54,6 +55,7 @@ pub(crate) fn on_initialize(
                 text_document_sync: Some(text_document_sync),
                 code_lens_provider: Some(code_lens),
                 document_formatting_provider: true,
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
                 nargo: Some(nargo),
             },
             server_info: None,
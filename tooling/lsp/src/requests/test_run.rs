@@ -2,7 +2,7 @@ use std::future::{self, Future};
 
 use async_lsp::{ErrorCode, ResponseError};
 use nargo::{
-    ops::{run_test, TestStatus},
+    ops::{run_test, TestOptions, TestSnapshots, TestStatus},
     prepare_package,
 };
 use nargo_toml::{find_package_manifest, resolve_workspace_from_toml, PackageSelection};
@@ -66,15 +66,27 @@ fn on_test_run_request_inner(
                 FunctionNameMatch::Exact(function_name),
             );
 
-            let (_, test_function) = test_functions.into_iter().next().ok_or_else(|| {
-                ResponseError::new(
-                    ErrorCode::REQUEST_FAILED,
-                    format!("Could not locate test named: {function_name} in {crate_name}"),
-                )
-            })?;
+            let (test_name, test_function) =
+                test_functions.into_iter().next().ok_or_else(|| {
+                    ResponseError::new(
+                        ErrorCode::REQUEST_FAILED,
+                        format!("Could not locate test named: {function_name} in {crate_name}"),
+                    )
+                })?;
 
-            let test_result =
-                run_test(&state.solver, &context, test_function, false, &CompileOptions::default());
+            let mut options = TestOptions {
+                show_output: false,
+                snapshots: TestSnapshots { dir: package.snapshots_dir(), update: false },
+                coverage: None,
+            };
+            let test_result = run_test(
+                &state.solver,
+                &context,
+                &test_name,
+                test_function,
+                &mut options,
+                &CompileOptions::default(),
+            );
             let result = match test_result {
                 TestStatus::Pass => NargoTestRunResult {
                     id: params.id.clone(),
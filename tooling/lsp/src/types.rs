@@ -8,11 +8,13 @@ use std::collections::{BTreeMap, HashMap};
 
 // Re-providing lsp_types that we don't need to override
 pub(crate) use lsp_types::{
-    CodeLens, CodeLensOptions, CodeLensParams, Command, Diagnostic, DiagnosticSeverity,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializedParams,
-    LogMessageParams, MessageType, Position, PublishDiagnosticsParams, Range, ServerInfo,
-    TextDocumentSyncCapability, Url,
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, CodeActionResponse, CodeLens, CodeLensOptions, CodeLensParams,
+    Command, Diagnostic, DiagnosticSeverity, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams, InitializeParams, InitializedParams, LogMessageParams, MessageType,
+    NumberOrString, Position, PublishDiagnosticsParams, Range, ServerInfo,
+    TextDocumentSyncCapability, TextEdit, Url, WorkspaceEdit,
 };
 
 pub(crate) mod request {
@@ -24,7 +26,9 @@ pub(crate) mod request {
     };
 
     // Re-providing lsp_types that we don't need to override
-    pub(crate) use lsp_types::request::{CodeLensRequest as CodeLens, Formatting, Shutdown};
+    pub(crate) use lsp_types::request::{
+        CodeActionRequest as CodeAction, CodeLensRequest as CodeLens, Formatting, Shutdown,
+    };
 
     #[derive(Debug)]
     pub(crate) struct Initialize;
@@ -115,6 +119,10 @@ pub(crate) struct ServerCapabilities {
     /// The server provides document formatting.
     pub(crate) document_formatting_provider: bool,
 
+    /// The server provides code actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) code_action_provider: Option<CodeActionProviderCapability>,
+
     /// The server handles and provides custom nargo messages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) nargo: Option<NargoCapability>,
@@ -215,3 +223,14 @@ pub(crate) struct NargoProfileRunResult {
 }
 
 pub(crate) type CodeLensResult = Option<Vec<CodeLens>>;
+
+/// A fix suggested for a diagnostic, which is sent along with it in its `data` so that
+/// it can be offered as a code action without checking the workspace again.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DiagnosticFix {
+    pub(crate) title: String,
+    pub(crate) edits: Vec<TextEdit>,
+    /// Whether the fix is definitely what the user wants
+    pub(crate) is_preferred: bool,
+}
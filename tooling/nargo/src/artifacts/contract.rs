@@ -1,13 +1,13 @@
 use acvm::acir::circuit::Circuit;
 use noirc_abi::{Abi, ContractEvent};
-use noirc_driver::ContractFunctionType;
-use serde::{Deserialize, Serialize};
+use noirc_driver::{ContractFunctionType, FunctionSelector};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// `PreprocessedContract` represents a Noir contract which has been preprocessed by a particular backend proving system.
 ///
 /// This differs from a generic Noir contract artifact in that:
 /// - The ACIR bytecode has had an optimization pass applied to tailor it for the backend.
-/// - Proving and verification keys have been pregenerated based on this ACIR.
+/// - Verification keys may have been pregenerated based on this ACIR.
 #[derive(Serialize, Deserialize)]
 pub struct PreprocessedContract {
     /// Version of noir used to compile this contract
@@ -30,6 +30,9 @@ pub struct PreprocessedContract {
 pub struct PreprocessedContractFunction {
     pub name: String,
 
+    /// Identifies the function by its signature, see [`FunctionSelector`].
+    pub selector: FunctionSelector,
+
     pub function_type: ContractFunctionType,
 
     pub is_internal: bool,
@@ -41,4 +44,34 @@ pub struct PreprocessedContractFunction {
         deserialize_with = "Circuit::deserialize_circuit_base64"
     )]
     pub bytecode: Circuit,
+
+    /// The hex encoded verification key of the function, if it was generated during compilation.
+    ///
+    /// Only secret and open functions have a verification key.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_verification_key",
+        deserialize_with = "deserialize_verification_key"
+    )]
+    pub verification_key: Option<Vec<u8>>,
+}
+
+fn serialize_verification_key<S>(
+    verification_key: &Option<Vec<u8>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    verification_key.as_ref().map(hex::encode).serialize(serializer)
+}
+
+fn deserialize_verification_key<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|verification_key| hex::decode(verification_key).map_err(serde::de::Error::custom))
+        .transpose()
 }
@@ -0,0 +1,279 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
+
+use acvm::Language;
+use fm::FileManager;
+use noirc_driver::{CompileOptions, Warnings};
+use noirc_frontend::hir::Context;
+use serde::{Deserialize, Serialize};
+
+/// `PackageFingerprint` records what the program artifact of a binary package was compiled from, so
+/// that the artifact can be reused as a whole, without running the frontend again, while neither the
+/// sources of the package and its dependencies, the compilation options nor the backend change.
+///
+/// Any change to any of the files read during compilation invalidates the whole artifact. The results
+/// of the frontend (the def maps and the HIR in the node interner) aren't serializable, so they can't
+/// be cached per crate: reusing the artifact is only possible when no crate of the package changed.
+///
+/// The files are recorded in the order in which they were added to the [`FileManager`], so that adding
+/// them again in that order gives them the same [`FileId`][fm::FileId]s as in the cached warnings.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackageFingerprint {
+    pub noir_version: String,
+    pub compile_options: CompileOptions,
+
+    /// The language of the backend which the circuit was optimized for.
+    pub np_language: FingerprintLanguage,
+
+    /// The paths of the files read during compilation, including those of the standard library.
+    pub files: Vec<PathBuf>,
+
+    /// The hashes of the sources of the package and its dependencies, by path.
+    ///
+    /// The standard library is left out as it can only change along with `noir_version`.
+    pub sources: BTreeMap<PathBuf, u64>,
+
+    /// The warnings which were reported when compiling the package.
+    pub warnings: Warnings,
+}
+
+/// A serializable copy of [`Language`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FingerprintLanguage {
+    R1CS,
+    PLONKCSat { width: usize },
+}
+
+impl From<Language> for FingerprintLanguage {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::R1CS => FingerprintLanguage::R1CS,
+            Language::PLONKCSat { width } => FingerprintLanguage::PLONKCSat { width },
+        }
+    }
+}
+
+impl PackageFingerprint {
+    /// Fingerprints the package whose frontend has been run in `context`.
+    pub fn new(
+        context: &Context,
+        noir_version: String,
+        compile_options: CompileOptions,
+        np_language: Language,
+        warnings: Warnings,
+    ) -> Self {
+        let file_manager = &context.file_manager;
+        let files = file_manager.files().into_iter().map(|(_, path)| path.to_path_buf()).collect();
+
+        let mut sources = BTreeMap::new();
+        for crate_id in context.crates() {
+            let Some(def_map) = context.def_map(&crate_id) else {
+                continue;
+            };
+            if crate_id == *context.stdlib_crate_id() {
+                continue;
+            }
+
+            for (_, module) in def_map.modules().iter() {
+                let file_id = module.location.file;
+                let hash = fxhash::hash64(file_manager.fetch_file(file_id).source());
+                sources.insert(file_manager.path(file_id).to_path_buf(), hash);
+            }
+        }
+
+        PackageFingerprint {
+            noir_version,
+            compile_options,
+            np_language: np_language.into(),
+            files,
+            sources,
+            warnings,
+        }
+    }
+
+    /// Returns whether the artifact which this fingerprint belongs to can be reused when compiling
+    /// with `compile_options` for a backend using `np_language`.
+    ///
+    /// `file_manager` must be the one the package has just been prepared with, i.e. holding only the
+    /// root files of its crates. The remaining files of the package are added to it so that the
+    /// cached warnings can be reported.
+    pub fn is_up_to_date(
+        &self,
+        noir_version: &str,
+        compile_options: &CompileOptions,
+        np_language: Language,
+        file_manager: &mut FileManager,
+    ) -> bool {
+        self.noir_version == noir_version
+            && self.compile_options == *compile_options
+            && self.np_language == np_language.into()
+            && !self.sources_changed(file_manager)
+    }
+
+    /// Returns whether any of the sources of the package differ from when it was fingerprinted.
+    ///
+    /// If the crates themselves changed, e.g. because a dependency was added, the sources are
+    /// considered to have changed.
+    fn sources_changed(&self, file_manager: &mut FileManager) -> bool {
+        let crate_roots = file_manager.files();
+        let roots_unchanged = crate_roots.len() <= self.files.len()
+            && crate_roots.iter().zip(&self.files).all(|((_, path), recorded)| *path == recorded);
+        if !roots_unchanged {
+            return true;
+        }
+
+        let mut file_ids = HashMap::new();
+        for path in &self.files {
+            match file_manager.add_file(path) {
+                Some(file_id) => file_ids.insert(path, file_id),
+                // The file was removed, so the package has changed.
+                None => return true,
+            };
+        }
+
+        self.sources.iter().any(|(path, hash)| {
+            file_ids.get(path).map_or(true, |file_id| {
+                fxhash::hash64(file_manager.fetch_file(*file_id).source()) != *hash
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use acvm::Language;
+    use fm::FileManager;
+    use noirc_driver::{check_crate, prepare_crate, CompileOptions};
+    use noirc_frontend::{
+        graph::{CrateGraph, CrateId},
+        hir::Context,
+    };
+    use tempfile::{tempdir, TempDir};
+
+    use super::PackageFingerprint;
+
+    const NOIR_VERSION: &str = "0.1.0";
+    const LANGUAGE: Language = Language::PLONKCSat { width: 3 };
+
+    /// Creates a package whose `main.nr` calls into a `foo` module.
+    fn create_package() -> TempDir {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/main.nr"), "mod foo;\nfn main() { foo::bar(); }")
+            .unwrap();
+        std::fs::write(dir.path().join("src/foo.nr"), "pub fn bar() {}").unwrap();
+        dir
+    }
+
+    /// Prepares the package at `root` as is done before compiling it.
+    fn prepare(root: &Path) -> (Context, CrateId) {
+        let file_manager = FileManager::new(root, Box::new(|path| std::fs::read_to_string(path)));
+        let mut context = Context::new(file_manager, CrateGraph::default());
+        let crate_id = prepare_crate(&mut context, Path::new("src/main.nr"));
+        (context, crate_id)
+    }
+
+    fn fingerprint(root: &Path) -> PackageFingerprint {
+        let (mut context, crate_id) = prepare(root);
+        check_crate(&mut context, crate_id, false).unwrap();
+        PackageFingerprint::new(
+            &context,
+            NOIR_VERSION.to_string(),
+            CompileOptions::default(),
+            LANGUAGE,
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn unchanged_package_is_up_to_date() {
+        let dir = create_package();
+        let fingerprint = fingerprint(dir.path());
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(!fingerprint.sources_changed(&mut context.file_manager));
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(fingerprint.is_up_to_date(
+            NOIR_VERSION,
+            &CompileOptions::default(),
+            LANGUAGE,
+            &mut context.file_manager,
+        ));
+    }
+
+    #[test]
+    fn changed_file_is_detected() {
+        let dir = create_package();
+        let fingerprint = fingerprint(dir.path());
+        std::fs::write(dir.path().join("src/foo.nr"), "pub fn bar() { assert(true); }").unwrap();
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(fingerprint.sources_changed(&mut context.file_manager));
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(!fingerprint.is_up_to_date(
+            NOIR_VERSION,
+            &CompileOptions::default(),
+            LANGUAGE,
+            &mut context.file_manager,
+        ));
+    }
+
+    #[test]
+    fn removed_file_is_detected() {
+        let dir = create_package();
+        let fingerprint = fingerprint(dir.path());
+        std::fs::remove_file(dir.path().join("src/foo.nr")).unwrap();
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(fingerprint.sources_changed(&mut context.file_manager));
+    }
+
+    #[test]
+    fn changed_compile_options_are_not_up_to_date() {
+        let dir = create_package();
+        let fingerprint = fingerprint(dir.path());
+        let compile_options = CompileOptions { deny_warnings: true, ..CompileOptions::default() };
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(!fingerprint.is_up_to_date(
+            NOIR_VERSION,
+            &compile_options,
+            LANGUAGE,
+            &mut context.file_manager,
+        ));
+    }
+
+    #[test]
+    fn changed_noir_version_is_not_up_to_date() {
+        let dir = create_package();
+        let fingerprint = fingerprint(dir.path());
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(!fingerprint.is_up_to_date(
+            "0.2.0",
+            &CompileOptions::default(),
+            LANGUAGE,
+            &mut context.file_manager,
+        ));
+    }
+
+    #[test]
+    fn changed_backend_language_is_not_up_to_date() {
+        let dir = create_package();
+        let fingerprint = fingerprint(dir.path());
+
+        let (mut context, _) = prepare(dir.path());
+        assert!(!fingerprint.is_up_to_date(
+            NOIR_VERSION,
+            &CompileOptions::default(),
+            Language::R1CS,
+            &mut context.file_manager,
+        ));
+    }
+}
@@ -5,6 +5,8 @@ pub const CONTRACT_DIR: &str = "contract";
 pub const PROOFS_DIR: &str = "proofs";
 /// The directory to store Noir source files
 pub const SRC_DIR: &str = "src";
+/// The directory to store the expected output of snapshot tests
+pub const SNAPSHOTS_DIR: &str = "snapshots";
 /// The directory to store circuits' serialized ACIR representations.
 pub const TARGET_DIR: &str = "target";
 
@@ -21,3 +23,7 @@ pub const PKG_FILE: &str = "Nargo.toml";
 pub const PROOF_EXT: &str = "proof";
 /// The extension for files containing proof witnesses.
 pub const WITNESS_EXT: &str = "tr";
+/// The extension for files containing the expected output of a snapshot test.
+pub const SNAPSHOT_EXT: &str = "snap";
+/// The extension for files containing the lcov coverage report of a package's tests.
+pub const COVERAGE_EXT: &str = "lcov";
@@ -12,6 +12,8 @@ use noirc_frontend::graph::CrateName;
 use noirc_printable_type::ForeignCallError;
 use thiserror::Error;
 
+use crate::package::PackageType;
+
 /// Errors covering situations where a package cannot be compiled.
 #[derive(Debug, Error)]
 pub enum CompileError {
@@ -21,6 +23,12 @@ pub enum CompileError {
     #[error("Package `{0}` is expected to have a `main` function but it does not")]
     MissingMainFunction(CrateName),
 
+    #[error("Package `{0}` has type `{1}` but only `contract` types have functions to select")]
+    NotAContract(CrateName, PackageType),
+
+    #[error("Contract package `{0}` has no function named `{1}`")]
+    MissingContractFunction(CrateName, String),
+
     /// Errors encountered while compiling the Noir program.
     /// These errors are already written to stderr.
     #[error("Aborting due to {} previous error{}", .0.error_count, if .0.error_count == 1 { "" } else { "s" })]
@@ -71,7 +79,10 @@ impl NargoError {
                 OpcodeResolutionError::IndexOutOfBounds { .. }
                 | OpcodeResolutionError::UnsupportedBlackBoxFunc(_)
                 | OpcodeResolutionError::OpcodeNotSolvable(_)
-                | OpcodeResolutionError::UnsatisfiedConstrain { .. } => None,
+                | OpcodeResolutionError::UnsatisfiedConstrain { .. }
+                | OpcodeResolutionError::UnknownUnconstrainedFunction(_)
+                | OpcodeResolutionError::UnknownLookupTable(_)
+                | OpcodeResolutionError::LookupValuesMismatch { .. } => None,
                 OpcodeResolutionError::BrilligFunctionFailed { message, .. } => Some(message),
                 OpcodeResolutionError::BlackBoxFunctionFailed(_, reason) => Some(reason),
             },
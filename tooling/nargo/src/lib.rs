@@ -14,7 +14,10 @@ pub mod ops;
 pub mod package;
 pub mod workspace;
 
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
 
 use fm::{FileManager, FileReader};
 use noirc_driver::{add_dep, prepare_crate, prepare_dependency};
@@ -43,8 +46,21 @@ pub fn prepare_dependencies(
 }
 
 pub fn prepare_package(package: &Package, file_reader: Box<FileReader>) -> (Context, CrateId) {
+    prepare_package_with_overlays(package, file_reader, &HashMap::new())
+}
+
+/// Prepares a package whose files are read from `overlays` when they are found there,
+/// and through `file_reader` otherwise.
+pub fn prepare_package_with_overlays(
+    package: &Package,
+    file_reader: Box<FileReader>,
+    overlays: &HashMap<PathBuf, String>,
+) -> (Context, CrateId) {
     // TODO: FileManager continues to leak into various crates
-    let fm = FileManager::new(&package.root_dir, file_reader);
+    let mut fm = FileManager::new(&package.root_dir, file_reader);
+    for (path, source) in overlays {
+        fm.add_overlay(path, source.clone());
+    }
     let graph = CrateGraph::default();
     let mut context = Context::new(fm, graph);
 
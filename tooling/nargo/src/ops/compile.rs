@@ -1,6 +1,7 @@
 use acvm::{acir::circuit::Opcode, Language};
 use fm::FileManager;
 use noirc_driver::{CompilationResult, CompileOptions, CompiledContract, CompiledProgram};
+use noirc_errors::MessageFormat;
 
 use crate::errors::CompileError;
 use crate::prepare_package;
@@ -45,6 +46,7 @@ pub fn compile_workspace(
                 &file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -56,6 +58,7 @@ pub fn compile_workspace(
                 &file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -133,6 +136,7 @@ pub(crate) fn report_errors<T>(
     file_manager: &FileManager,
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> Result<T, CompileError> {
     let (t, warnings) = result.map_err(|errors| {
         noirc_errors::reporter::report_all(
@@ -140,6 +144,7 @@ pub(crate) fn report_errors<T>(
             &errors,
             deny_warnings,
             silence_warnings,
+            message_format,
         )
     })?;
 
@@ -148,6 +153,7 @@ pub(crate) fn report_errors<T>(
         &warnings,
         deny_warnings,
         silence_warnings,
+        message_format,
     );
 
     Ok(t)
@@ -0,0 +1,294 @@
+//! Records which opcodes of a circuit are executed, and maps them back to lines of Noir source
+//! to report the coverage of `nargo test --coverage`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use acvm::acir::circuit::{Circuit, Opcode, OpcodeLocation};
+use acvm::acir::native_types::{Expression, WitnessMap};
+use acvm::pwg::{get_value, ACVMStatus, BrilligSolverStatus, StepResult, ACVM};
+use acvm::BlackBoxFunctionSolver;
+use codespan_reporting::files::Files;
+use fm::FileManager;
+use noirc_errors::{debug_info::DebugInfo, Location};
+
+use crate::NargoError;
+
+use super::execute::execution_error;
+use super::foreign_calls::ForeignCallExecutor;
+
+/// The number of times each opcode of a circuit was executed.
+#[derive(Debug, Default)]
+pub struct OpcodeHits(BTreeMap<OpcodeLocation, usize>);
+
+impl OpcodeHits {
+    fn record(&mut self, opcode_location: OpcodeLocation) {
+        *self.0.entry(opcode_location).or_default() += 1;
+    }
+
+    pub fn get(&self, opcode_location: &OpcodeLocation) -> usize {
+        self.0.get(opcode_location).copied().unwrap_or_default()
+    }
+
+    /// Returns the total number of Brillig opcodes which were executed.
+    pub fn brillig_opcodes(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|(location, _)| matches!(location, OpcodeLocation::Brillig { .. }))
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+/// Executes `circuit` in the same way as [`execute_circuit`][super::execute_circuit], while
+/// recording in `hits` each ACIR opcode which is solved and each Brillig opcode which is executed.
+///
+/// An ACIR opcode whose predicate, or the side effects predicate it was generated under according
+/// to `debug`, evaluates to zero has no effect on the execution, so it is not recorded.
+pub fn execute_circuit_with_coverage<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
+    circuit: &Circuit,
+    initial_witness: WitnessMap,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut F,
+    debug: &DebugInfo,
+    hits: &mut OpcodeHits,
+) -> Result<WitnessMap, NargoError> {
+    let mut acvm = ACVM::new(
+        blackbox_solver,
+        &circuit.opcodes,
+        initial_witness,
+        &circuit.unconstrained_functions,
+        &circuit.lookup_tables,
+    );
+
+    while *acvm.get_status() == ACVMStatus::InProgress {
+        let acir_index = acvm.instruction_pointer();
+        let opcode_location = OpcodeLocation::Acir(acir_index);
+        let enabled = is_enabled(
+            &circuit.opcodes[acir_index],
+            debug.opcode_predicate(&opcode_location),
+            acvm.witness_map(),
+        );
+
+        let status = match acvm.step_into_brillig_opcode() {
+            StepResult::IntoBrillig(mut solver) => {
+                loop {
+                    let brillig_index = solver.program_counter();
+                    let brillig_location = OpcodeLocation::Brillig { acir_index, brillig_index };
+                    match solver.step() {
+                        Ok(BrilligSolverStatus::InProgress) => hits.record(brillig_location),
+                        Ok(BrilligSolverStatus::Finished) => {
+                            hits.record(brillig_location);
+                            break;
+                        }
+                        // The foreign call opcode is executed again once its result is available.
+                        Ok(BrilligSolverStatus::ForeignCallWait(foreign_call)) => {
+                            let foreign_call_result =
+                                foreign_call_executor.execute(&foreign_call)?;
+                            solver.resolve_pending_foreign_call(foreign_call_result);
+                        }
+                        Err(error) => {
+                            hits.record(brillig_location);
+                            hits.record(opcode_location);
+                            return Err(execution_error(circuit, error));
+                        }
+                    }
+                }
+                acvm.finish_brillig_with_solver(solver)
+            }
+            StepResult::Status(status) => status,
+        };
+
+        match status {
+            ACVMStatus::Solved | ACVMStatus::InProgress => {
+                if enabled {
+                    hits.record(opcode_location);
+                }
+            }
+            ACVMStatus::Failure(error) => {
+                hits.record(opcode_location);
+                return Err(execution_error(circuit, error));
+            }
+            // The opcode is solved again once the result of the foreign call is available.
+            ACVMStatus::RequiresForeignCall(foreign_call) => {
+                let foreign_call_result = foreign_call_executor.execute(&foreign_call)?;
+                acvm.resolve_pending_foreign_call(foreign_call_result);
+            }
+        }
+    }
+
+    let solved_witness = acvm.finalize();
+    Ok(solved_witness)
+}
+
+/// Returns whether `opcode` has an effect when solved with `witness_map`.
+///
+/// A predicate which cannot be evaluated yet is treated as enabled.
+fn is_enabled(
+    opcode: &Opcode,
+    side_effects_predicate: Option<&Expression>,
+    witness_map: &WitnessMap,
+) -> bool {
+    let opcode_predicate = match opcode {
+        Opcode::Brillig(brillig) => brillig.predicate.as_ref(),
+        Opcode::BrilligCall { predicate, .. }
+        | Opcode::MemoryOp { predicate, .. }
+        | Opcode::Lookup { predicate, .. } => predicate.as_ref(),
+        _ => None,
+    };
+
+    [opcode_predicate, side_effects_predicate]
+        .into_iter()
+        .flatten()
+        .all(|predicate| get_value(predicate, witness_map).map_or(true, |value| !value.is_zero()))
+}
+
+/// The number of times each line of the Noir sources of a package was executed,
+/// accumulated across every test which has been run.
+#[derive(Debug)]
+pub struct CoverageReport {
+    root_dir: PathBuf,
+    files: BTreeMap<PathBuf, BTreeMap<usize, usize>>,
+}
+
+impl CoverageReport {
+    /// Creates an empty report, covering the source files within `root_dir`.
+    pub fn new(root_dir: PathBuf) -> Self {
+        CoverageReport { root_dir, files: BTreeMap::new() }
+    }
+
+    /// Adds the executions in `hits` of the opcodes of a program with the given `debug` info.
+    ///
+    /// An opcode counts towards every line of its call stack, and each line which any opcode
+    /// was generated from is included in the report, even if it was never executed.
+    pub fn record(&mut self, file_manager: &FileManager, debug: &DebugInfo, hits: &OpcodeHits) {
+        for (opcode_location, locations) in &debug.locations {
+            let count = hits.get(opcode_location);
+            let lines: BTreeSet<_> = locations
+                .iter()
+                .filter_map(|location| self.source_line(file_manager, location))
+                .collect();
+
+            for (path, line) in lines {
+                *self.files.entry(path).or_default().entry(line).or_default() += count;
+            }
+        }
+    }
+
+    /// Returns the file and line number at which `location` starts, if that file is covered.
+    fn source_line(
+        &self,
+        file_manager: &FileManager,
+        location: &Location,
+    ) -> Option<(PathBuf, usize)> {
+        let path = file_manager.path(location.file);
+        if !path.starts_with(&self.root_dir) {
+            return None;
+        }
+
+        let line_index = file_manager
+            .as_file_map()
+            .line_index(location.file, location.span.start() as usize)
+            .ok()?;
+        Some((path.to_path_buf(), line_index + 1))
+    }
+
+    /// Renders the report as an lcov tracefile, naming the test `test_name`.
+    pub fn to_lcov(&self, test_name: &str) -> String {
+        let mut lcov = String::new();
+        for (path, lines) in &self.files {
+            writeln!(lcov, "TN:{test_name}").unwrap();
+            writeln!(lcov, "SF:{}", path.display()).unwrap();
+            for (line, count) in lines {
+                writeln!(lcov, "DA:{line},{count}").unwrap();
+            }
+            let lines_hit = lines.values().filter(|count| **count > 0).count();
+            writeln!(lcov, "LF:{}", lines.len()).unwrap();
+            writeln!(lcov, "LH:{lines_hit}").unwrap();
+            writeln!(lcov, "end_of_record").unwrap();
+        }
+        lcov
+    }
+
+    /// Writes the report as an lcov tracefile to `path`.
+    pub fn write_lcov(&self, test_name: &str, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_lcov(test_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use acvm::acir::circuit::opcodes::{BlockId, MemOp};
+    use acvm::acir::circuit::Opcode;
+    use acvm::acir::native_types::{Expression, Witness, WitnessMap};
+    use acvm::FieldElement;
+
+    use super::{is_enabled, CoverageReport};
+
+    fn witness_map(values: &[(u32, u128)]) -> WitnessMap {
+        let mut witness_map = WitnessMap::new();
+        for (witness, value) in values {
+            witness_map.insert(Witness(*witness), FieldElement::from(*value));
+        }
+        witness_map
+    }
+
+    #[test]
+    fn opcodes_are_enabled_by_their_side_effects_predicate() {
+        let opcode = Opcode::Arithmetic(Expression::from(Witness(0)));
+        let predicate = Expression::from(Witness(1));
+
+        assert!(is_enabled(&opcode, None, &witness_map(&[])));
+        assert!(is_enabled(&opcode, Some(&predicate), &witness_map(&[(1, 1)])));
+        // An `if` branch which isn't taken is still solved, without any effect.
+        assert!(!is_enabled(&opcode, Some(&predicate), &witness_map(&[(1, 0)])));
+        // The predicate may depend on witnesses which the opcode solves itself.
+        assert!(is_enabled(&opcode, Some(&predicate), &witness_map(&[])));
+    }
+
+    #[test]
+    fn opcodes_are_disabled_by_their_own_predicate() {
+        let opcode = Opcode::MemoryOp {
+            block_id: BlockId(0),
+            op: MemOp::read_at_mem_index(Expression::zero(), Witness(0)),
+            predicate: Some(Expression::from(Witness(1))),
+        };
+        let side_effects_predicate = Expression::one();
+
+        assert!(is_enabled(&opcode, Some(&side_effects_predicate), &witness_map(&[(1, 1)])));
+        assert!(!is_enabled(&opcode, Some(&side_effects_predicate), &witness_map(&[(1, 0)])));
+        assert!(!is_enabled(&opcode, Some(&Expression::zero()), &witness_map(&[(1, 1)])));
+    }
+
+    #[test]
+    fn writes_lcov_records_per_file() {
+        let mut files = BTreeMap::new();
+        files.insert(PathBuf::from("/project/src/main.nr"), BTreeMap::from([(3, 2), (5, 0)]));
+        files.insert(PathBuf::from("/project/src/lib.nr"), BTreeMap::from([(1, 1)]));
+        let report = CoverageReport { root_dir: PathBuf::from("/project"), files };
+
+        let expected = "\
+TN:package
+SF:/project/src/lib.nr
+DA:1,1
+LF:1
+LH:1
+end_of_record
+TN:package
+SF:/project/src/main.nr
+DA:3,2
+DA:5,0
+LF:2
+LH:1
+end_of_record
+";
+        assert_eq!(report.to_lcov("package"), expected);
+    }
+}
@@ -13,7 +13,13 @@ pub fn execute_circuit<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
     blackbox_solver: &B,
     foreign_call_executor: &mut F,
 ) -> Result<WitnessMap, NargoError> {
-    let mut acvm = ACVM::new(blackbox_solver, &circuit.opcodes, initial_witness);
+    let mut acvm = ACVM::new(
+        blackbox_solver,
+        &circuit.opcodes,
+        initial_witness,
+        &circuit.unconstrained_functions,
+        &circuit.lookup_tables,
+    );
 
     loop {
         let solver_status = acvm.solve();
@@ -23,30 +29,7 @@ pub fn execute_circuit<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
             ACVMStatus::InProgress => {
                 unreachable!("Execution should not stop while in `InProgress` state.")
             }
-            ACVMStatus::Failure(error) => {
-                let call_stack = match &error {
-                    OpcodeResolutionError::UnsatisfiedConstrain {
-                        opcode_location: ErrorLocation::Resolved(opcode_location),
-                    } => Some(vec![*opcode_location]),
-                    OpcodeResolutionError::BrilligFunctionFailed { call_stack, .. } => {
-                        Some(call_stack.clone())
-                    }
-                    _ => None,
-                };
-
-                return Err(NargoError::ExecutionError(match call_stack {
-                    Some(call_stack) => {
-                        if let Some(assert_message) = circuit.get_assert_message(
-                            *call_stack.last().expect("Call stacks should not be empty"),
-                        ) {
-                            ExecutionError::AssertionFailed(assert_message.to_owned(), call_stack)
-                        } else {
-                            ExecutionError::SolvingError(error)
-                        }
-                    }
-                    None => ExecutionError::SolvingError(error),
-                }));
-            }
+            ACVMStatus::Failure(error) => return Err(execution_error(circuit, error)),
             ACVMStatus::RequiresForeignCall(foreign_call) => {
                 let foreign_call_result = foreign_call_executor.execute(&foreign_call)?;
                 acvm.resolve_pending_foreign_call(foreign_call_result);
@@ -57,3 +40,28 @@ pub fn execute_circuit<B: BlackBoxFunctionSolver, F: ForeignCallExecutor>(
     let solved_witness = acvm.finalize();
     Ok(solved_witness)
 }
+
+/// Converts an error encountered while solving `circuit` into a [`NargoError`],
+/// attaching the assertion message of the failing opcode if it has one.
+pub(super) fn execution_error(circuit: &Circuit, error: OpcodeResolutionError) -> NargoError {
+    let call_stack = match &error {
+        OpcodeResolutionError::UnsatisfiedConstrain {
+            opcode_location: ErrorLocation::Resolved(opcode_location),
+        } => Some(vec![*opcode_location]),
+        OpcodeResolutionError::BrilligFunctionFailed { call_stack, .. } => Some(call_stack.clone()),
+        _ => None,
+    };
+
+    NargoError::ExecutionError(match call_stack {
+        Some(call_stack) => {
+            if let Some(assert_message) = circuit
+                .get_assert_message(*call_stack.last().expect("Call stacks should not be empty"))
+            {
+                ExecutionError::AssertionFailed(assert_message.to_owned(), call_stack)
+            } else {
+                ExecutionError::SolvingError(error)
+            }
+        }
+        None => ExecutionError::SolvingError(error),
+    })
+}
@@ -94,12 +94,35 @@ pub struct DefaultForeignCallExecutor {
     mocked_responses: Vec<MockedCall>,
     /// Whether to print [`ForeignCall::Println`] output.
     show_output: bool,
+    /// Whether printed output goes to stderr rather than stdout.
+    output_to_stderr: bool,
+    /// The [`ForeignCall::Println`] output so far, if it is being captured.
+    captured_output: Option<String>,
 }
 
 impl DefaultForeignCallExecutor {
     pub fn new(show_output: bool) -> Self {
         DefaultForeignCallExecutor { show_output, ..DefaultForeignCallExecutor::default() }
     }
+
+    /// Records [`ForeignCall::Println`] output, whether or not it is printed, so that it can
+    /// be retrieved with [`DefaultForeignCallExecutor::captured_output`].
+    pub fn with_captured_output(mut self) -> Self {
+        self.captured_output = Some(String::new());
+        self
+    }
+
+    /// Prints [`ForeignCall::Println`] output to stderr rather than stdout, e.g. to keep stdout
+    /// free for machine-readable output.
+    pub fn with_output_to_stderr(mut self) -> Self {
+        self.output_to_stderr = true;
+        self
+    }
+
+    /// Returns the [`ForeignCall::Println`] output so far, if it is being captured.
+    pub fn captured_output(&self) -> Option<&str> {
+        self.captured_output.as_deref()
+    }
 }
 
 impl DefaultForeignCallExecutor {
@@ -120,9 +143,21 @@ impl DefaultForeignCallExecutor {
         decode_string_value(&fields)
     }
 
-    fn execute_println(foreign_call_inputs: &[ForeignCallParam]) -> Result<(), ForeignCallError> {
+    fn execute_println(
+        &mut self,
+        foreign_call_inputs: &[ForeignCallParam],
+    ) -> Result<(), ForeignCallError> {
         let display_values: PrintableValueDisplay = foreign_call_inputs.try_into()?;
-        println!("{display_values}");
+        if self.show_output {
+            if self.output_to_stderr {
+                eprintln!("{display_values}");
+            } else {
+                println!("{display_values}");
+            }
+        }
+        if let Some(output) = &mut self.captured_output {
+            output.push_str(&format!("{display_values}\n"));
+        }
         Ok(())
     }
 }
@@ -135,8 +170,8 @@ impl ForeignCallExecutor for DefaultForeignCallExecutor {
         let foreign_call_name = foreign_call.function.as_str();
         match ForeignCall::lookup(foreign_call_name) {
             Some(ForeignCall::Println) => {
-                if self.show_output {
-                    Self::execute_println(&foreign_call.inputs)?;
+                if self.show_output || self.captured_output.is_some() {
+                    self.execute_println(&foreign_call.inputs)?;
                 }
                 Ok(ForeignCallResult { values: vec![] })
             }
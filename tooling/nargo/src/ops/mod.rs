@@ -1,10 +1,12 @@
 pub use self::compile::{compile_program, compile_workspace};
+pub use self::coverage::{execute_circuit_with_coverage, CoverageReport, OpcodeHits};
 pub use self::execute::execute_circuit;
 pub use self::foreign_calls::{DefaultForeignCallExecutor, ForeignCallExecutor};
 pub use self::optimize::{optimize_contract, optimize_program};
-pub use self::test::{run_test, TestStatus};
+pub use self::test::{run_test, TestOptions, TestSnapshots, TestStatus};
 
 mod compile;
+mod coverage;
 mod execute;
 mod foreign_calls;
 mod optimize;
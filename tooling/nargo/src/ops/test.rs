@@ -1,12 +1,21 @@
+use std::path::PathBuf;
+
 use acvm::{acir::native_types::WitnessMap, BlackBoxFunctionSolver};
-use noirc_driver::{compile_no_check, CompileOptions};
+use noirc_driver::{compile_no_check, CompileOptions, CompiledProgram};
 use noirc_errors::{debug_info::DebugInfo, FileDiagnostic};
 use noirc_evaluator::errors::RuntimeError;
-use noirc_frontend::hir::{def_map::TestFunction, Context};
+use noirc_frontend::{
+    hir::{def_map::TestFunction, Context},
+    Type,
+};
+use noirc_printable_type::{decode_value, PrintableType, PrintableValueDisplay};
 
-use crate::{errors::try_to_diagnose_runtime_error, NargoError};
+use crate::{constants::SNAPSHOT_EXT, errors::try_to_diagnose_runtime_error, NargoError};
 
-use super::{execute_circuit, DefaultForeignCallExecutor};
+use super::{
+    execute_circuit, execute_circuit_with_coverage, CoverageReport, DefaultForeignCallExecutor,
+    OpcodeHits,
+};
 
 pub enum TestStatus {
     Pass,
@@ -14,30 +23,133 @@ pub enum TestStatus {
     CompileError(FileDiagnostic),
 }
 
+/// Where the snapshots of `#[test(snapshot)]` functions are stored.
+pub struct TestSnapshots {
+    /// The directory containing a snapshot file for each test
+    pub dir: PathBuf,
+    /// Whether snapshots which are missing or don't match should be rewritten
+    /// rather than failing the test
+    pub update: bool,
+}
+
+impl TestSnapshots {
+    fn path(&self, test_name: &str) -> PathBuf {
+        self.dir.join(format!("{}.{SNAPSHOT_EXT}", test_name.replace("::", "__")))
+    }
+
+    /// Checks `output` against the snapshot of the test `test_name`, or writes it
+    /// to the snapshot if snapshots are being updated.
+    fn check(&self, test_name: &str, output: &str) -> Result<(), String> {
+        let path = self.path(test_name);
+        let snapshot = std::fs::read_to_string(&path).ok();
+        if snapshot.as_deref() == Some(output) {
+            return Ok(());
+        }
+
+        if self.update {
+            return std::fs::create_dir_all(&self.dir)
+                .and_then(|_| std::fs::write(&path, output))
+                .map_err(|err| format!("\nerror: Could not write {}: {err}", path.display()));
+        }
+
+        let message = match snapshot {
+            Some(snapshot) => format!(
+                "\nerror: Test output does not match the snapshot in {}. \nExpected:\n{snapshot}\nGot:\n{output}",
+                path.display()
+            ),
+            None => format!("\nerror: Snapshot {} does not exist", path.display()),
+        };
+        Err(format!("{message}\nRun `nargo test --update-snapshots` to accept the new output"))
+    }
+}
+
+/// How the tests of a package are run, and what is recorded while running them.
+pub struct TestOptions {
+    /// Whether the output of `println` statements should be displayed
+    pub show_output: bool,
+    /// Where the snapshots of `#[test(snapshot)]` functions are checked against
+    pub snapshots: TestSnapshots,
+    /// The coverage report which the executed opcodes of each test are added to, if any
+    pub coverage: Option<CoverageReport>,
+}
+
 pub fn run_test<B: BlackBoxFunctionSolver>(
     blackbox_solver: &B,
     context: &Context,
+    test_name: &str,
     test_function: TestFunction,
-    show_output: bool,
+    options: &mut TestOptions,
     config: &CompileOptions,
 ) -> TestStatus {
     let program = compile_no_check(context, config, test_function.get_id(), None, false);
     match program {
         Ok(program) => {
+            let mut foreign_call_executor = DefaultForeignCallExecutor::new(options.show_output);
+            if test_function.is_snapshot() {
+                foreign_call_executor = foreign_call_executor.with_captured_output();
+            }
+
             // Run the backend to ensure the PWG evaluates functions like std::hash::pedersen,
             // otherwise constraints involving these expressions will not error.
-            let circuit_execution = execute_circuit(
-                &program.circuit,
-                WitnessMap::new(),
-                blackbox_solver,
-                &mut DefaultForeignCallExecutor::new(show_output),
-            );
+            let circuit_execution = match &mut options.coverage {
+                Some(coverage) => {
+                    let mut hits = OpcodeHits::default();
+                    let circuit_execution = execute_circuit_with_coverage(
+                        &program.circuit,
+                        WitnessMap::new(),
+                        blackbox_solver,
+                        &mut foreign_call_executor,
+                        &program.debug,
+                        &mut hits,
+                    );
+                    coverage.record(&context.file_manager, &program.debug, &hits);
+                    circuit_execution
+                }
+                None => execute_circuit(
+                    &program.circuit,
+                    WitnessMap::new(),
+                    blackbox_solver,
+                    &mut foreign_call_executor,
+                ),
+            };
+
+            if let (Some(output), Ok(solved_witness)) =
+                (foreign_call_executor.captured_output(), &circuit_execution)
+            {
+                let snapshot =
+                    snapshot_output(context, &test_function, &program, solved_witness, output);
+                if let Err(message) = options.snapshots.check(test_name, &snapshot) {
+                    return TestStatus::Fail { message, error_diagnostic: None };
+                }
+            }
+
             test_status_program_compile_pass(test_function, program.debug, circuit_execution)
         }
         Err(err) => test_status_program_compile_fail(err, test_function),
     }
 }
 
+/// Returns the `println` output of a snapshot test followed by its return value, if it has one.
+fn snapshot_output(
+    context: &Context,
+    test_function: &TestFunction,
+    program: &CompiledProgram,
+    solved_witness: &WitnessMap,
+    output: &str,
+) -> String {
+    let return_type = context.def_interner.function_meta(&test_function.get_id()).return_type();
+    if *return_type == Type::Unit {
+        return output.to_string();
+    }
+
+    let printable_type = PrintableType::from(return_type);
+    let mut return_witness_values =
+        program.abi.return_witnesses.iter().map(|witness| solved_witness[witness]);
+    let return_value = decode_value(&mut return_witness_values, &printable_type);
+    let return_value = PrintableValueDisplay::Plain(return_value, printable_type);
+    format!("{output}return: {return_value}\n")
+}
+
 /// Test function failed to compile
 ///
 /// Note: This could be because the compiler was able to deduce
@@ -2,7 +2,7 @@ use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
 
 use noirc_frontend::graph::CrateName;
 
-use crate::constants::{PROVER_INPUT_FILE, VERIFIER_INPUT_FILE};
+use crate::constants::{PROVER_INPUT_FILE, SNAPSHOTS_DIR, VERIFIER_INPUT_FILE};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum PackageType {
@@ -64,6 +64,9 @@ impl Package {
         // For now it is hard-coded to be toml.
         self.root_dir.join(format!("{VERIFIER_INPUT_FILE}.toml"))
     }
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.root_dir.join(SNAPSHOTS_DIR)
+    }
 
     pub fn is_binary(&self) -> bool {
         self.package_type == PackageType::Binary
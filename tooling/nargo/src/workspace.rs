@@ -10,7 +10,7 @@ use std::{
 };
 
 use crate::{
-    constants::{CONTRACT_DIR, PROOFS_DIR, TARGET_DIR},
+    constants::{CONTRACT_DIR, COVERAGE_EXT, PROOFS_DIR, TARGET_DIR},
     package::Package,
 };
 
@@ -28,6 +28,11 @@ impl Workspace {
         self.target_directory_path().join(name).with_extension("json")
     }
 
+    pub fn package_coverage_path(&self, package: &Package) -> PathBuf {
+        let name: String = package.name.clone().into();
+        self.target_directory_path().join(name).with_extension(COVERAGE_EXT)
+    }
+
     pub fn contracts_directory_path(&self, package: &Package) -> PathBuf {
         let name: String = package.name.clone().into();
         self.root_dir.join(CONTRACT_DIR).join(name)
@@ -0,0 +1,125 @@
+//! Counts the Brillig opcodes executed by the VM for each `execution_success` program, compiled with
+//! `main` as an unconstrained function, both with and without the Brillig peephole optimizer.
+//!
+//! Programs which can't be compiled to Brillig, or which can't be executed with the inputs in their
+//! `Prover.toml`, are skipped. A program which only fails with or without the optimizer is reported
+//! as a failure of the benchmark, as the optimizer must not change whether a program runs.
+use std::path::{Path, PathBuf};
+
+use nargo::constants::PROVER_INPUT_FILE;
+use nargo::ops::{execute_circuit_with_coverage, DefaultForeignCallExecutor, OpcodeHits};
+use nargo::prepare_package;
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_abi::input_parser::Format;
+use noirc_driver::{compile_main, CompileOptions};
+
+/// Returns the number of Brillig opcodes executed when running the program in `program_dir`.
+fn executed_brillig_opcodes(
+    program_dir: &Path,
+    skip_brillig_optimizer: bool,
+) -> Result<usize, String> {
+    let toml_path = get_package_manifest(program_dir).map_err(|err| err.to_string())?;
+    let workspace = resolve_workspace_from_toml(&toml_path, PackageSelection::DefaultOrAll, None)
+        .map_err(|err| err.to_string())?;
+    let package = workspace.into_iter().next().ok_or("The workspace has no packages")?;
+
+    let (mut context, crate_id) =
+        prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
+    let options = CompileOptions {
+        force_brillig: true,
+        skip_brillig_optimizer,
+        silence_warnings: true,
+        ..CompileOptions::default()
+    };
+    let (program, _) =
+        compile_main(&mut context, crate_id, &options, None, true).map_err(|errors| {
+            let messages: Vec<_> =
+                errors.iter().map(|error| error.diagnostic.message.as_str()).collect();
+            format!("Failed to compile: {}", messages.join(", "))
+        })?;
+
+    let inputs_path = package.root_dir.join(PROVER_INPUT_FILE).with_extension(Format::Toml.ext());
+    let inputs = std::fs::read_to_string(inputs_path).unwrap_or_default();
+    let inputs_map = Format::Toml.parse(&inputs, &program.abi).map_err(|err| err.to_string())?;
+    let initial_witness = program.abi.encode(&inputs_map, None).map_err(|err| err.to_string())?;
+
+    #[allow(deprecated)]
+    let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+    let mut hits = OpcodeHits::default();
+    execute_circuit_with_coverage(
+        &program.circuit,
+        initial_witness,
+        &blackbox_solver,
+        &mut DefaultForeignCallExecutor::new(false),
+        &program.debug,
+        &mut hits,
+    )
+    .map_err(|err| format!("Failed to execute: {err}"))?;
+    Ok(hits.brillig_opcodes())
+}
+
+fn main() {
+    let test_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_programs/execution_success")
+        .canonicalize()
+        .expect("test programs should exist");
+    let mut program_dirs: Vec<_> = std::fs::read_dir(test_dir)
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    program_dirs.sort();
+
+    let (mut total_unoptimized, mut total_optimized) = (0, 0);
+    let mut failures = Vec::new();
+    println!("{:<40} {:>12} {:>12} {:>8}", "program", "unoptimized", "optimized", "change");
+    for program_dir in program_dirs {
+        let name = program_dir.file_name().unwrap().to_string_lossy().into_owned();
+        let (unoptimized, optimized) = match (
+            executed_brillig_opcodes(&program_dir, true),
+            executed_brillig_opcodes(&program_dir, false),
+        ) {
+            (Ok(unoptimized), Ok(optimized)) => (unoptimized, optimized),
+            (Err(error), Err(_)) => {
+                eprintln!("Skipping {name}: {error}");
+                continue;
+            }
+            (Ok(_), Err(error)) => {
+                eprintln!("{name} only fails with the optimizer: {error}");
+                failures.push(name);
+                continue;
+            }
+            (Err(error), Ok(_)) => {
+                eprintln!("{name} only fails without the optimizer: {error}");
+                failures.push(name);
+                continue;
+            }
+        };
+        println!(
+            "{name:<40} {unoptimized:>12} {optimized:>12} {:>7.1}%",
+            change(unoptimized, optimized)
+        );
+        total_unoptimized += unoptimized;
+        total_optimized += optimized;
+    }
+    println!(
+        "{:<40} {total_unoptimized:>12} {total_optimized:>12} {:>7.1}%",
+        "total",
+        change(total_unoptimized, total_optimized)
+    );
+
+    assert!(
+        failures.is_empty(),
+        "The optimizer changed whether these programs run: {}",
+        failures.join(", ")
+    );
+}
+
+/// Returns the relative change from `before` to `after`, in percent.
+fn change(before: usize, after: usize) -> f64 {
+    if before == 0 {
+        return 0.0;
+    }
+    (after as f64 - before as f64) / before as f64 * 100.0
+}
@@ -47,6 +47,13 @@ fn main() {
     generate_compile_failure_tests(&mut test_file, &test_dir);
 }
 
+/// Programs which can't be compiled to Brillig.
+const IGNORED_BRILLIG_TESTS: [&str; 1] = [
+    // Recursive proof verification is left to the backend when proving, so the Brillig VM has no
+    // opcode to execute `std::verify_proof` with.
+    "double_verify_proof",
+];
+
 fn generate_execution_success_tests(test_file: &mut File, test_data_dir: &Path) {
     let test_sub_dir = "execution_success";
     let test_data_dir = test_data_dir.join(test_sub_dir);
@@ -76,6 +83,48 @@ fn execution_success_{test_name}() {{
     cmd.arg("--program-dir").arg(test_program_dir);
     cmd.arg("execute");
 
+    cmd.assert().success();
+}}
+            "#,
+            test_dir = test_dir.display(),
+        )
+        .expect("Could not write templated test file.");
+
+        write!(
+            test_file,
+            r#"
+#[test]
+fn execution_success_validate_ssa_{test_name}() {{
+    let test_program_dir = PathBuf::from("{test_dir}");
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(test_program_dir);
+    cmd.arg("execute").arg("--validate-ssa");
+
+    cmd.assert().success();
+}}
+            "#,
+            test_dir = test_dir.display(),
+        )
+        .expect("Could not write templated test file.");
+
+        if IGNORED_BRILLIG_TESTS.contains(&test_name.as_str()) {
+            continue;
+        }
+
+        write!(
+            test_file,
+            r#"
+#[test]
+fn execution_success_compare_brillig_{test_name}() {{
+    let test_program_dir = PathBuf::from("{test_dir}");
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(test_program_dir);
+    cmd.arg("execute").arg("--compare-brillig");
+
     cmd.assert().success();
 }}
             "#,
@@ -250,6 +299,8 @@ fn compile_success_contract_{test_name}() {{
     }
 }
 
+/// Generates tests which expect `compile_failure` programs to fail, reporting the diagnostics
+/// annotated in their sources and manifests (see `tests/expected_diagnostics`).
 fn generate_compile_failure_tests(test_file: &mut File, test_data_dir: &Path) {
     let test_sub_dir = "compile_failure";
     let test_data_dir = test_data_dir.join(test_sub_dir);
@@ -276,10 +327,12 @@ fn compile_failure_{test_name}() {{
 
     let mut cmd = Command::cargo_bin("nargo").unwrap();
     cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
-    cmd.arg("--program-dir").arg(test_program_dir);
-    cmd.arg("execute");
+    cmd.arg("--program-dir").arg(&test_program_dir);
+    cmd.arg("execute").arg("--message-format").arg("json");
 
-    cmd.assert().failure().stderr(predicate::str::contains("The application panicked (crashed).").not());
+    let assert = cmd.assert().failure().stderr(predicate::str::contains("The application panicked (crashed).").not());
+    let output = assert.get_output();
+    check_expected_diagnostics(&test_program_dir, &output.stdout, &output.stderr);
 }}
             "#,
             test_dir = test_dir.display(),
@@ -7,18 +7,19 @@ use nargo::{errors::CompileError, package::Package, prepare_package};
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::{AbiParameter, AbiType, MAIN_RETURN_NAME};
 use noirc_driver::{
-    check_crate, compute_function_abi, CompileOptions, NOIR_ARTIFACT_VERSION_STRING,
+    check_crate, compute_function_abi, lint_crate, CompileOptions, NOIR_ARTIFACT_VERSION_STRING,
 };
+use noirc_errors::MessageFormat;
 use noirc_frontend::{
     graph::{CrateId, CrateName},
     hir::Context,
 };
 
 use super::fs::write_to_file;
-use super::NargoConfig;
+use super::{print_status, NargoConfig};
 
 /// Checks the constraint system for errors
-#[derive(Debug, Clone, Args)]
+#[derive(Debug, Clone, Default, Args)]
 pub(crate) struct CheckCommand {
     /// The name of the package to check
     #[clap(long, conflicts_with = "workspace")]
@@ -28,8 +29,12 @@ pub(crate) struct CheckCommand {
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
 
+    /// Warn about results of unconstrained functions which are never constrained in the circuit
+    #[clap(long)]
+    lint: bool,
+
     #[clap(flatten)]
-    compile_options: CompileOptions,
+    pub(crate) compile_options: CompileOptions,
 }
 
 pub(crate) fn run(
@@ -48,13 +53,20 @@ pub(crate) fn run(
     )?;
 
     for package in &workspace {
-        check_package(package, &args.compile_options)?;
-        println!("[{}] Constraint system successfully built!", package.name);
+        check_package(package, &args.compile_options, args.lint)?;
+        print_status(
+            args.compile_options.message_format,
+            format_args!("[{}] Constraint system successfully built!", package.name),
+        );
     }
     Ok(())
 }
 
-fn check_package(package: &Package, compile_options: &CompileOptions) -> Result<(), CompileError> {
+fn check_package(
+    package: &Package,
+    compile_options: &CompileOptions,
+    lint: bool,
+) -> Result<(), CompileError> {
     let (mut context, crate_id) =
         prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
     check_crate_and_report_errors(
@@ -62,8 +74,19 @@ fn check_package(package: &Package, compile_options: &CompileOptions) -> Result<
         crate_id,
         compile_options.deny_warnings,
         compile_options.silence_warnings,
+        compile_options.message_format,
     )?;
 
+    if lint {
+        super::compile_cmd::report_errors(
+            lint_crate(&context, crate_id),
+            &context.file_manager,
+            compile_options.deny_warnings,
+            compile_options.silence_warnings,
+            compile_options.message_format,
+        )?;
+    }
+
     if package.is_library() || package.is_contract() {
         // Libraries do not have ABIs while contracts have many, so we cannot generate a `Prover.toml` file.
         Ok(())
@@ -183,6 +206,7 @@ pub(crate) fn check_crate_and_report_errors(
     crate_id: CrateId,
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> Result<(), CompileError> {
     let result = check_crate(context, crate_id, deny_warnings);
     super::compile_cmd::report_errors(
@@ -190,5 +214,6 @@ pub(crate) fn check_crate_and_report_errors(
         &context.file_manager,
         deny_warnings,
         silence_warnings,
+        message_format,
     )
 }
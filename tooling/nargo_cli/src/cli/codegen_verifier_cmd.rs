@@ -1,8 +1,8 @@
-use super::NargoConfig;
 use super::{
     compile_cmd::compile_bin_package,
     fs::{create_named_dir, write_to_file},
 };
+use super::{print_status, NargoConfig};
 use crate::backends::Backend;
 use crate::errors::CliError;
 
@@ -62,7 +62,10 @@ pub(crate) fn run(
         let contract_path = contract_dir.join("plonk_vk").with_extension("sol");
 
         let path = write_to_file(smart_contract_string.as_bytes(), &contract_path);
-        println!("[{}] Contract successfully created and located at {path}", package.name);
+        print_status(
+            args.compile_options.message_format,
+            format_args!("[{}] Contract successfully created and located at {path}", package.name),
+        );
     }
 
     Ok(())
@@ -5,18 +5,20 @@ use acvm::acir::circuit::Opcode;
 use acvm::Language;
 use backend_interface::BackendOpcodeSupport;
 use fm::FileManager;
-use iter_extended::vecmap;
+use iter_extended::try_vecmap;
 use nargo::artifacts::contract::PreprocessedContract;
 use nargo::artifacts::contract::PreprocessedContractFunction;
 use nargo::artifacts::debug::DebugArtifact;
+use nargo::artifacts::fingerprint::PackageFingerprint;
 use nargo::artifacts::program::PreprocessedProgram;
 use nargo::errors::CompileError;
 use nargo::package::Package;
 use nargo::prepare_package;
 use nargo::workspace::Workspace;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
-use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
 use noirc_driver::{CompilationResult, CompileOptions, CompiledContract, CompiledProgram};
+use noirc_driver::{ContractFunctionType, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_errors::MessageFormat;
 use noirc_frontend::graph::CrateName;
 
 use clap::Args;
@@ -25,8 +27,9 @@ use crate::backends::Backend;
 use crate::errors::CliError;
 
 use super::fs::program::{
-    read_debug_artifact_from_file, read_program_from_file, save_contract_to_file,
-    save_debug_artifact_to_file, save_program_to_file,
+    read_debug_artifact_from_file, read_fingerprint_from_file, read_program_from_file,
+    save_contract_to_file, save_debug_artifact_to_file, save_fingerprint_to_file,
+    save_program_to_file,
 };
 use super::NargoConfig;
 use rayon::prelude::*;
@@ -37,7 +40,7 @@ const BACKEND_IDENTIFIER: &str = "acvm-backend-barretenberg";
 /// Compile the program and its secret execution trace into ACIR format
 #[derive(Debug, Clone, Args)]
 pub(crate) struct CompileCommand {
-    /// Include Proving and Verification keys in the build artifacts.
+    /// Include the verification keys of the secret and open functions of contracts in the build artifacts.
     #[arg(long)]
     include_keys: bool,
 
@@ -88,7 +91,8 @@ pub(crate) fn run(
 
     // Save build artifacts to disk.
     for (package, contract) in contract_packages.into_iter().zip(compiled_contracts) {
-        save_contract(contract, &package, &circuit_dir);
+        let backend = args.include_keys.then_some(backend);
+        save_contract(contract, &package, &circuit_dir, backend)?;
     }
 
     Ok(())
@@ -128,6 +132,7 @@ pub(super) fn compile_workspace(
                 &file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -139,6 +144,7 @@ pub(super) fn compile_workspace(
                 &file_manager,
                 compile_options.deny_warnings,
                 compile_options.silence_warnings,
+                compile_options.message_format,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -167,11 +173,89 @@ pub(crate) fn compile_bin_package(
         &file_manager,
         compile_options.deny_warnings,
         compile_options.silence_warnings,
+        compile_options.message_format,
     )?;
 
     Ok(program)
 }
 
+/// Compiles the `main` function of `package` as an unconstrained function, so that the whole
+/// program runs in the Brillig VM.
+///
+/// The program isn't saved to the target directory, where it would replace the package's circuit.
+pub(crate) fn compile_brillig_package(
+    package: &Package,
+    compile_options: &CompileOptions,
+    np_language: Language,
+    opcode_support: &BackendOpcodeSupport,
+) -> Result<CompiledProgram, CliError> {
+    if package.is_library() {
+        return Err(CompileError::LibraryCrate(package.name.clone()).into());
+    }
+
+    let (mut context, crate_id) =
+        prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
+    let compile_options = CompileOptions { force_brillig: true, ..compile_options.clone() };
+    let compilation_result =
+        noirc_driver::compile_main(&mut context, crate_id, &compile_options, None, true);
+
+    // Any warnings are the same as those reported when compiling the package's circuit.
+    let program = report_errors(
+        compilation_result,
+        &context.file_manager,
+        compile_options.deny_warnings,
+        true,
+        compile_options.message_format,
+    )?;
+
+    Ok(nargo::ops::optimize_program(program, np_language, &|opcode| {
+        opcode_support.is_opcode_supported(opcode)
+    })?)
+}
+
+/// Compiles the program which is executed and proven for `package`: its `main` function or, if a
+/// `contract_function` is given, that function of the contract package.
+pub(crate) fn compile_executable_package(
+    workspace: &Workspace,
+    package: &Package,
+    contract_function: Option<&str>,
+    compile_options: &CompileOptions,
+    np_language: Language,
+    opcode_support: &BackendOpcodeSupport,
+) -> Result<CompiledProgram, CliError> {
+    let Some(function_name) = contract_function else {
+        return compile_bin_package(
+            workspace,
+            package,
+            compile_options,
+            np_language,
+            opcode_support,
+        );
+    };
+
+    if !package.is_contract() {
+        return Err(CompileError::NotAContract(package.name.clone(), package.package_type).into());
+    }
+
+    let (file_manager, compilation_result) =
+        compile_contract(package, compile_options, np_language, &|opcode| {
+            opcode_support.is_opcode_supported(opcode)
+        });
+
+    let contract = report_errors(
+        compilation_result,
+        &file_manager,
+        compile_options.deny_warnings,
+        compile_options.silence_warnings,
+        compile_options.message_format,
+    )?;
+
+    contract.into_function_program(function_name).ok_or_else(|| {
+        CompileError::MissingContractFunction(package.name.clone(), function_name.to_string())
+            .into()
+    })
+}
+
 fn compile_program(
     workspace: &Workspace,
     package: &Package,
@@ -182,9 +266,17 @@ fn compile_program(
     let (mut context, crate_id) =
         prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
 
+    // The SSA passes are validated with the inputs which the program is executed with.
+    let compile_options = &CompileOptions {
+        validate_ssa_inputs: compile_options.validate_ssa.then(|| package.prover_input_path()),
+        ..compile_options.clone()
+    };
+
     let program_artifact_path = workspace.package_build_path(package);
     let mut debug_artifact_path = program_artifact_path.clone();
     debug_artifact_path.set_file_name(format!("debug_{}.json", package.name));
+    let mut fingerprint_path = program_artifact_path.clone();
+    fingerprint_path.set_file_name(format!("fingerprint_{}.json", package.name));
     let cached_program = if let (Ok(preprocessed_program), Ok(mut debug_artifact)) = (
         read_program_from_file(program_artifact_path),
         read_debug_artifact_from_file(debug_artifact_path),
@@ -204,6 +296,28 @@ fn compile_program(
 
     let force_recompile =
         cached_program.as_ref().map_or(false, |p| p.noir_version != NOIR_ARTIFACT_VERSION_STRING);
+
+    // Printing or validating the intermediate representations requires compiling the program again.
+    let can_skip_compilation = !(compile_options.show_ssa
+        || compile_options.show_brillig
+        || compile_options.print_acir
+        || compile_options.validate_ssa);
+    if !force_recompile && can_skip_compilation {
+        if let (Some(program), Ok(fingerprint)) =
+            (&cached_program, read_fingerprint_from_file(&fingerprint_path))
+        {
+            // Nothing the program was compiled from changed, so the whole artifact can be reused.
+            if fingerprint.is_up_to_date(
+                NOIR_ARTIFACT_VERSION_STRING,
+                compile_options,
+                np_language,
+                &mut context.file_manager,
+            ) {
+                return (context.file_manager, Ok((program.clone(), fingerprint.warnings)));
+            }
+        }
+    }
+
     let (program, warnings) = match noirc_driver::compile_main(
         &mut context,
         crate_id,
@@ -233,6 +347,16 @@ fn compile_program(
 
     save_program(optimized_program.clone(), package, &workspace.target_directory_path());
 
+    let fingerprint = PackageFingerprint::new(
+        &context,
+        NOIR_ARTIFACT_VERSION_STRING.to_string(),
+        compile_options.clone(),
+        np_language,
+        warnings.clone(),
+    );
+    let circuit_name: String = (&package.name).into();
+    save_fingerprint_to_file(&fingerprint, &circuit_name, workspace.target_directory_path());
+
     (context.file_manager, Ok((optimized_program, warnings)))
 }
 
@@ -279,7 +403,14 @@ fn save_program(program: CompiledProgram, package: &Package, circuit_dir: &Path)
     save_debug_artifact_to_file(&debug_artifact, &circuit_name, circuit_dir);
 }
 
-fn save_contract(contract: CompiledContract, package: &Package, circuit_dir: &Path) {
+/// Saves the artifacts of `contract`, generating the verification keys of its functions
+/// if a `backend` is given.
+fn save_contract(
+    contract: CompiledContract,
+    package: &Package,
+    circuit_dir: &Path,
+    backend: Option<&Backend>,
+) -> Result<(), CliError> {
     // TODO(#1389): I wonder if it is incorrect for nargo-core to know anything about contracts.
     // As can be seen here, It seems like a leaky abstraction where ContractFunctions (essentially CompiledPrograms)
     // are compiled via nargo-core and then the PreprocessedContract is constructed here.
@@ -290,13 +421,25 @@ fn save_contract(contract: CompiledContract, package: &Package, circuit_dir: &Pa
         warnings: contract.warnings,
     };
 
-    let preprocessed_functions = vecmap(contract.functions, |func| PreprocessedContractFunction {
-        name: func.name,
-        function_type: func.function_type,
-        is_internal: func.is_internal,
-        abi: func.abi,
-        bytecode: func.bytecode,
-    });
+    let preprocessed_functions = try_vecmap(contract.functions, |func| {
+        // Unconstrained functions are executed rather than proven, so they have no verification key.
+        let verification_key = match backend {
+            Some(backend) if func.function_type != ContractFunctionType::Unconstrained => {
+                Some(backend.write_vk(&func.bytecode)?)
+            }
+            _ => None,
+        };
+
+        Ok::<_, CliError>(PreprocessedContractFunction {
+            name: func.name,
+            selector: func.selector,
+            function_type: func.function_type,
+            is_internal: func.is_internal,
+            abi: func.abi,
+            bytecode: func.bytecode,
+            verification_key,
+        })
+    })?;
 
     let preprocessed_contract = PreprocessedContract {
         noir_version: contract.noir_version,
@@ -317,6 +460,8 @@ fn save_contract(contract: CompiledContract, package: &Package, circuit_dir: &Pa
         &format!("{}-{}", package.name, preprocessed_contract.name),
         circuit_dir,
     );
+
+    Ok(())
 }
 
 /// Helper function for reporting any errors in a `CompilationResult<T>`
@@ -326,6 +471,7 @@ pub(crate) fn report_errors<T>(
     file_manager: &FileManager,
     deny_warnings: bool,
     silence_warnings: bool,
+    message_format: MessageFormat,
 ) -> Result<T, CompileError> {
     let (t, warnings) = result.map_err(|errors| {
         noirc_errors::reporter::report_all(
@@ -333,6 +479,7 @@ pub(crate) fn report_errors<T>(
             &errors,
             deny_warnings,
             silence_warnings,
+            message_format,
         )
     })?;
 
@@ -341,6 +488,7 @@ pub(crate) fn report_errors<T>(
         &warnings,
         deny_warnings,
         silence_warnings,
+        message_format,
     );
 
     Ok(t)
@@ -1,20 +1,28 @@
+use std::path::PathBuf;
+
 use acvm::acir::native_types::WitnessMap;
+use acvm::pwg::OpcodeResolutionError;
 use clap::Args;
 
 use nargo::artifacts::debug::DebugArtifact;
 use nargo::constants::PROVER_INPUT_FILE;
-use nargo::errors::try_to_diagnose_runtime_error;
+use nargo::errors::{try_to_diagnose_runtime_error, ExecutionError};
 use nargo::ops::DefaultForeignCallExecutor;
 use nargo::package::Package;
+use nargo::NargoError;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::{Format, InputValue};
 use noirc_abi::InputMap;
 use noirc_driver::{CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_errors::MessageFormat;
 use noirc_frontend::graph::CrateName;
 
-use super::compile_cmd::compile_bin_package;
-use super::fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir};
-use super::NargoConfig;
+use super::compile_cmd::{compile_brillig_package, compile_executable_package};
+use super::fs::{
+    inputs::{contract_function_inputs_name, read_inputs_with_proof_fields},
+    witness::save_witness_to_dir,
+};
+use super::{print_status, NargoConfig};
 use crate::backends::Backend;
 use crate::errors::CliError;
 
@@ -28,16 +36,30 @@ pub(crate) struct ExecuteCommand {
     #[clap(long, short, default_value = PROVER_INPUT_FILE)]
     prover_name: String,
 
+    /// A file of proof fields written by `nargo prove --emit-fields`, whose values are added to
+    /// the inputs for the prover
+    #[clap(long)]
+    proof_fields: Vec<PathBuf>,
+
     /// The name of the package to execute
     #[clap(long, conflicts_with = "workspace")]
     package: Option<CrateName>,
 
+    /// The name of the contract function to execute, whose inputs are read from `Prover.<FUNCTION>.toml`
+    #[clap(long, conflicts_with = "workspace")]
+    contract_fn: Option<String>,
+
     /// Execute all packages in the workspace
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
 
+    /// Also execute `main` compiled as an unconstrained function, and fail if its result differs
+    /// from that of the circuit
+    #[clap(long, conflicts_with = "contract_fn")]
+    compare_brillig: bool,
+
     #[clap(flatten)]
-    compile_options: CompileOptions,
+    pub(crate) compile_options: CompileOptions,
 }
 
 pub(crate) fn run(
@@ -58,25 +80,68 @@ pub(crate) fn run(
 
     let (np_language, opcode_support) = backend.get_backend_info_or_default();
     for package in &workspace {
-        let compiled_program = compile_bin_package(
+        let compiled_program = compile_executable_package(
             &workspace,
             package,
+            args.contract_fn.as_deref(),
             &args.compile_options,
             np_language,
             &opcode_support,
         )?;
 
+        let prover_name = contract_function_inputs_name(
+            &args.prover_name,
+            args.contract_fn.as_deref(),
+            &Format::Toml,
+        );
+        // Parse the initial witness values from Prover.toml
+        let (inputs_map, _) = read_inputs_with_proof_fields(
+            &package.root_dir,
+            &prover_name,
+            &args.proof_fields,
+            &compiled_program.abi,
+        )?;
+
+        if args.compare_brillig {
+            let brillig_program = compile_brillig_package(
+                package,
+                &args.compile_options,
+                np_language,
+                &opcode_support,
+            )?;
+            compare_with_brillig(
+                package,
+                &compiled_program,
+                &brillig_program,
+                &inputs_map,
+                args.compile_options.message_format,
+            )?;
+            print_status(
+                args.compile_options.message_format,
+                format_args!("[{}] Brillig execution matches the circuit", package.name),
+            );
+        }
+
         let (return_value, solved_witness) =
-            execute_program_and_decode(compiled_program, package, &args.prover_name)?;
+            execute_program_and_decode(compiled_program, &inputs_map, &args.compile_options)?;
 
-        println!("[{}] Circuit witness successfully solved", package.name);
+        print_status(
+            args.compile_options.message_format,
+            format_args!("[{}] Circuit witness successfully solved", package.name),
+        );
         if let Some(return_value) = return_value {
-            println!("[{}] Circuit output: {return_value:?}", package.name);
+            print_status(
+                args.compile_options.message_format,
+                format_args!("[{}] Circuit output: {return_value:?}", package.name),
+            );
         }
         if let Some(witness_name) = &args.witness_name {
             let witness_path = save_witness_to_dir(solved_witness, witness_name, target_dir)?;
 
-            println!("[{}] Witness saved to {}", package.name, witness_path.display());
+            print_status(
+                args.compile_options.message_format,
+                format_args!("[{}] Witness saved to {}", package.name, witness_path.display()),
+            );
         }
     }
     Ok(())
@@ -84,48 +149,175 @@ pub(crate) fn run(
 
 fn execute_program_and_decode(
     program: CompiledProgram,
-    package: &Package,
-    prover_name: &str,
+    inputs_map: &InputMap,
+    compile_options: &CompileOptions,
 ) -> Result<(Option<InputValue>, WitnessMap), CliError> {
-    // Parse the initial witness values from Prover.toml
-    let (inputs_map, _) =
-        read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &program.abi)?;
-    let solved_witness = execute_program(&program, &inputs_map)?;
+    let solved_witness = execute_program(&program, inputs_map, compile_options.message_format)?;
     let public_abi = program.abi.public_abi();
     let (_, return_value) = public_abi.decode(&solved_witness)?;
 
     Ok((return_value, solved_witness))
 }
 
+/// Executes `program`, the circuit of `package`, and `brillig_program`, its `main` function
+/// compiled as an unconstrained function, on the same inputs and checks that they agree.
+///
+/// Both programs must either return the same value or fail in the same way, as described by
+/// [`ExecutionFailure`]. When only one of them fails, the location of the failure is reported.
+fn compare_with_brillig(
+    package: &Package,
+    program: &CompiledProgram,
+    brillig_program: &CompiledProgram,
+    inputs_map: &InputMap,
+    message_format: MessageFormat,
+) -> Result<(), CliError> {
+    // Any output of `println` is shown when the circuit is executed afterwards.
+    let circuit_result = solve_program(
+        program,
+        program.abi.encode(inputs_map, None)?,
+        &mut DefaultForeignCallExecutor::new(false),
+    );
+    let brillig_result = solve_program(
+        brillig_program,
+        brillig_program.abi.encode(inputs_map, None)?,
+        &mut DefaultForeignCallExecutor::new(false),
+    );
+
+    let mismatch = match (circuit_result, brillig_result) {
+        (Ok(circuit_witness), Ok(brillig_witness)) => {
+            let (_, circuit_return) = program.abi.public_abi().decode(&circuit_witness)?;
+            let (_, brillig_return) = brillig_program.abi.public_abi().decode(&brillig_witness)?;
+            if circuit_return == brillig_return {
+                return Ok(());
+            }
+            format!(
+                "the circuit returned {circuit_return:?} while Brillig returned {brillig_return:?}"
+            )
+        }
+        (Err(circuit_err), Err(brillig_err)) => {
+            let circuit_failure = ExecutionFailure::new(&circuit_err);
+            let brillig_failure = ExecutionFailure::new(&brillig_err);
+            if circuit_failure == brillig_failure {
+                return Ok(());
+            }
+            format!(
+                "the circuit failed with {circuit_failure} while Brillig failed with {brillig_failure}"
+            )
+        }
+        (Err(err), Ok(_)) => {
+            report_execution_error(program, &err, message_format);
+            format!("the circuit failed to execute while Brillig succeeded: {err}")
+        }
+        (Ok(_), Err(err)) => {
+            report_execution_error(brillig_program, &err, message_format);
+            format!("Brillig failed to execute while the circuit succeeded: {err}")
+        }
+    };
+    Err(CliError::BrilligMismatch(package.name.clone(), mismatch))
+}
+
+/// The failure message of the Brillig VM when it hits a `Trap` opcode.
+const BRILLIG_TRAP_MESSAGE: &str = "explicit trap hit in brillig";
+
+/// How a program failed to execute, normalized so that the failures of a circuit and of its
+/// Brillig counterpart can be compared.
+#[derive(Debug, PartialEq, Eq)]
+enum ExecutionFailure {
+    /// A constraint failed, with its assertion message if it has one. An assertion without a
+    /// message fails an ACIR constraint in a circuit but hits a trap in Brillig.
+    Assertion(Option<String>),
+    /// Any other error, compared by its message.
+    Other(String),
+}
+
+impl ExecutionFailure {
+    fn new(error: &NargoError) -> Self {
+        match error {
+            NargoError::ExecutionError(ExecutionError::AssertionFailed(message, _)) => {
+                ExecutionFailure::Assertion(Some(message.clone()))
+            }
+            NargoError::ExecutionError(ExecutionError::SolvingError(
+                OpcodeResolutionError::UnsatisfiedConstrain { .. },
+            )) => ExecutionFailure::Assertion(None),
+            NargoError::ExecutionError(ExecutionError::SolvingError(
+                OpcodeResolutionError::BrilligFunctionFailed { message, .. },
+            )) if message == BRILLIG_TRAP_MESSAGE => ExecutionFailure::Assertion(None),
+            NargoError::ExecutionError(ExecutionError::SolvingError(
+                OpcodeResolutionError::BrilligFunctionFailed { message, .. },
+            )) => ExecutionFailure::Other(message.clone()),
+            other => ExecutionFailure::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ExecutionFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutionFailure::Assertion(Some(message)) => {
+                write!(f, "assertion failure '{message}'")
+            }
+            ExecutionFailure::Assertion(None) => {
+                write!(f, "an assertion failure without a message")
+            }
+            ExecutionFailure::Other(message) => write!(f, "error '{message}'"),
+        }
+    }
+}
+
 pub(crate) fn execute_program(
     compiled_program: &CompiledProgram,
     inputs_map: &InputMap,
+    message_format: MessageFormat,
 ) -> Result<WitnessMap, CliError> {
+    let initial_witness = compiled_program.abi.encode(inputs_map, None)?;
+    let mut foreign_call_executor = DefaultForeignCallExecutor::new(true);
+    if message_format == MessageFormat::Json {
+        foreign_call_executor = foreign_call_executor.with_output_to_stderr();
+    }
+    match solve_program(compiled_program, initial_witness, &mut foreign_call_executor) {
+        Ok(solved_witness) => Ok(solved_witness),
+        Err(err) => {
+            report_execution_error(compiled_program, &err, message_format);
+            Err(crate::errors::CliError::NargoError(err))
+        }
+    }
+}
+
+fn solve_program(
+    compiled_program: &CompiledProgram,
+    initial_witness: WitnessMap,
+    foreign_call_executor: &mut DefaultForeignCallExecutor,
+) -> Result<WitnessMap, NargoError> {
     #[allow(deprecated)]
     let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
 
-    let initial_witness = compiled_program.abi.encode(inputs_map, None)?;
-
-    let solved_witness_err = nargo::ops::execute_circuit(
+    nargo::ops::execute_circuit(
         &compiled_program.circuit,
         initial_witness,
         &blackbox_solver,
-        &mut DefaultForeignCallExecutor::new(true),
-    );
-    match solved_witness_err {
-        Ok(solved_witness) => Ok(solved_witness),
-        Err(err) => {
-            let debug_artifact = DebugArtifact {
-                debug_symbols: vec![compiled_program.debug.clone()],
-                file_map: compiled_program.file_map.clone(),
-                warnings: compiled_program.warnings.clone(),
-            };
-
-            if let Some(diagnostic) = try_to_diagnose_runtime_error(&err, &compiled_program.debug) {
-                diagnostic.report(&debug_artifact, false);
-            }
+        foreign_call_executor,
+    )
+}
 
-            Err(crate::errors::CliError::NargoError(err))
-        }
+/// Reports the location in the source code at which `compiled_program` failed, if known.
+fn report_execution_error(
+    compiled_program: &CompiledProgram,
+    err: &NargoError,
+    message_format: MessageFormat,
+) {
+    let debug_artifact = DebugArtifact {
+        debug_symbols: vec![compiled_program.debug.clone()],
+        file_map: compiled_program.file_map.clone(),
+        warnings: compiled_program.warnings.clone(),
+    };
+
+    if let Some(diagnostic) = try_to_diagnose_runtime_error(err, &compiled_program.debug) {
+        noirc_errors::reporter::report_all(
+            &debug_artifact,
+            &[diagnostic],
+            false,
+            false,
+            message_format,
+        );
     }
 }
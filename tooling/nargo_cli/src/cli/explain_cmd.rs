@@ -0,0 +1,22 @@
+use clap::Args;
+use noirc_errors::ErrorCode;
+
+use crate::errors::CliError;
+
+/// Explains an error code reported by the compiler
+///
+/// Prints a detailed explanation of the error, with examples of code triggering it
+/// and of how it can be fixed.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ExplainCommand {
+    /// The error code to explain, e.g. E0301
+    code: String,
+}
+
+pub(crate) fn run(args: ExplainCommand) -> Result<(), CliError> {
+    let code = ErrorCode::find(&args.code)
+        .ok_or_else(|| CliError::Generic(format!("{} is not a valid error code", args.code)))?;
+
+    println!("{}", code.explanation());
+    Ok(())
+}
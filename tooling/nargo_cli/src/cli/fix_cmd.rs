@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use clap::Args;
+use fm::FileId;
+use nargo::{package::Package, prepare_package};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_driver::{check_crate, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_errors::{FileDiagnostic, Replacement, Suggestion};
+use noirc_frontend::graph::CrateName;
+
+use crate::errors::CliError;
+
+use super::NargoConfig;
+
+/// Applies the fixes suggested by the compiler's diagnostics
+///
+/// Only fixes which are known to be correct are applied, other suggestions are left for the
+/// user to review.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct FixCommand {
+    /// The name of the package to fix
+    #[clap(long, conflicts_with = "workspace")]
+    package: Option<CrateName>,
+
+    /// Fix all packages in the workspace
+    #[clap(long, conflicts_with = "package")]
+    workspace: bool,
+
+    /// Print the changes which would be made as a diff instead of writing them
+    #[arg(long)]
+    dry_run: bool,
+}
+
+pub(crate) fn run(args: FixCommand, config: NargoConfig) -> Result<(), CliError> {
+    let toml_path = get_package_manifest(&config.program_dir)?;
+    let default_selection =
+        if args.workspace { PackageSelection::All } else { PackageSelection::DefaultOrAll };
+    let selection = args.package.map_or(default_selection, PackageSelection::Selected);
+    let workspace = resolve_workspace_from_toml(
+        &toml_path,
+        selection,
+        Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+    )?;
+
+    for package in &workspace {
+        let fixed_files = fix_package(package, args.dry_run)?;
+        if fixed_files == 0 {
+            println!("[{}] No fixes to apply", package.name);
+        } else if !args.dry_run {
+            println!("[{}] Fixed {fixed_files} file(s)", package.name);
+        }
+    }
+    Ok(())
+}
+
+/// Applies the machine applicable suggestions of the diagnostics reported for `package`,
+/// returning the number of files which were changed.
+fn fix_package(package: &Package, dry_run: bool) -> Result<usize, CliError> {
+    let (mut context, crate_id) =
+        prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
+
+    let diagnostics = match check_crate(&mut context, crate_id, false) {
+        Ok(((), warnings)) => warnings,
+        Err(errors_and_warnings) => errors_and_warnings,
+    };
+
+    let mut suggestions_by_file: BTreeMap<FileId, Vec<Suggestion>> = BTreeMap::new();
+    for FileDiagnostic { file_id, diagnostic, call_stack: _ } in diagnostics {
+        let suggestions =
+            diagnostic.suggestions.into_iter().filter(Suggestion::is_machine_applicable);
+        suggestions_by_file.entry(file_id).or_default().extend(suggestions);
+    }
+
+    let file_manager = &context.file_manager;
+    let mut fixed_files = 0;
+    for (file_id, suggestions) in suggestions_by_file {
+        // Dependencies and the standard library are not ours to change.
+        let path = file_manager.path(file_id);
+        if suggestions.is_empty() || !path.starts_with(&package.root_dir) {
+            continue;
+        }
+
+        let original = file_manager.fetch_file(file_id).source();
+        let fixed = apply_suggestions(original, suggestions);
+        if fixed == original {
+            continue;
+        }
+        fixed_files += 1;
+
+        if dry_run {
+            let label = path.display().to_string();
+            let diff = similar_asserts::SimpleDiff::from_str(original, &fixed, &label, "fixed");
+            println!("{diff}");
+        } else {
+            std::fs::write(path, fixed).map_err(|error| CliError::Generic(error.to_string()))?;
+        }
+    }
+
+    Ok(fixed_files)
+}
+
+/// Applies the replacements of `suggestions` to `source`.
+///
+/// Suggestions are applied either entirely or not at all, so one with a replacement overlapping
+/// those of a previous suggestion is skipped, as is one which doesn't fall on character boundaries.
+fn apply_suggestions(source: &str, suggestions: Vec<Suggestion>) -> String {
+    let overlaps = |a: &Replacement, b: &Replacement| {
+        (a.span.start() < b.span.end() && b.span.start() < a.span.end())
+            || (a.span == b.span && a.text != b.text)
+    };
+    let on_char_boundaries = |replacement: &Replacement| {
+        source.is_char_boundary(replacement.span.start() as usize)
+            && source.is_char_boundary(replacement.span.end() as usize)
+    };
+
+    let mut replacements: Vec<Replacement> = Vec::new();
+    for suggestion in suggestions {
+        let applicable = suggestion.replacements.iter().all(|replacement| {
+            on_char_boundaries(replacement)
+                && !replacements.iter().any(|applied| overlaps(applied, replacement))
+        });
+        if applicable {
+            replacements.extend(suggestion.replacements);
+        }
+    }
+    replacements.sort_by_key(|replacement| (replacement.span.start(), replacement.span.end()));
+    // The same fix may be suggested by several diagnostics.
+    replacements.dedup();
+
+    let mut fixed = String::with_capacity(source.len());
+    let mut position = 0;
+    for Replacement { span, text } in replacements {
+        fixed.push_str(&source[position..span.start() as usize]);
+        fixed.push_str(&text);
+        position = span.end() as usize;
+    }
+    fixed.push_str(&source[position..]);
+    fixed
+}
+
+#[cfg(test)]
+mod tests {
+    use noirc_errors::{Applicability, Replacement, Span, Suggestion};
+
+    use super::apply_suggestions;
+
+    fn replacement(range: std::ops::Range<u32>, text: &str) -> Replacement {
+        Replacement { span: Span::from(range), text: text.to_string() }
+    }
+
+    fn suggestion(replacements: Vec<Replacement>) -> Suggestion {
+        Suggestion::multipart(String::new(), replacements, Applicability::MachineApplicable)
+    }
+
+    #[test]
+    fn applies_suggestions_in_source_order() {
+        let source = "let x = 1; constrain x == 1;";
+        let suggestions = vec![
+            suggestion(vec![replacement(11..21, "assert("), replacement(27..27, ")")]),
+            suggestion(vec![replacement(4..5, "_x")]),
+        ];
+        assert_eq!(apply_suggestions(source, suggestions), "let _x = 1; assert(x == 1);");
+    }
+
+    #[test]
+    fn skips_suggestions_overlapping_previous_ones() {
+        let source = "let x = 1;";
+        let suggestions = vec![
+            suggestion(vec![replacement(4..5, "_x")]),
+            suggestion(vec![replacement(0..0, "// "), replacement(4..9, "y = 2")]),
+            suggestion(vec![replacement(4..5, "_x")]),
+        ];
+        assert_eq!(apply_suggestions(source, suggestions), "let _x = 1;");
+    }
+
+    #[test]
+    fn applies_several_suggestions_across_a_file() {
+        let source =
+            "fn main(x: Field) -> Field {\n    let y = 1;\n    constrain x == 2;\n    x\n}\n";
+        let offset = |text: &str| source.find(text).unwrap() as u32;
+        let return_type = offset("Field {");
+        let y = offset("y =");
+        let constrain = offset("constrain");
+        let expression_end = offset("x == 2") + 6;
+        let suggestions = vec![
+            suggestion(vec![replacement(y..y + 1, "_y")]),
+            suggestion(vec![
+                replacement(constrain..constrain + 10, "assert("),
+                replacement(expression_end..expression_end, ")"),
+            ]),
+            suggestion(vec![replacement(return_type..return_type, "pub ")]),
+            suggestion(vec![replacement(y..y + 1, "_y")]),
+        ];
+        assert_eq!(
+            apply_suggestions(source, suggestions),
+            "fn main(x: Field) -> pub Field {\n    let _y = 1;\n    assert(x == 2);\n    x\n}\n"
+        );
+    }
+}
@@ -4,7 +4,7 @@ use clap::Args;
 use fm::FileManager;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
-use noirc_errors::CustomDiagnostic;
+use noirc_errors::{CustomDiagnostic, MessageFormat};
 use noirc_frontend::{hir::def_map::parse_file, parser::ParserError};
 
 use crate::errors::CliError;
@@ -57,6 +57,7 @@ pub(crate) fn run(args: FormatCommand, config: NargoConfig) -> Result<(), CliErr
                     &file_manager,
                     false,
                     false,
+                    MessageFormat::Human,
                 );
                 return Ok(());
             }
@@ -98,7 +99,7 @@ pub(crate) fn run(args: FormatCommand, config: NargoConfig) -> Result<(), CliErr
     Ok(())
 }
 
-fn visit_noir_files(
+pub(super) fn visit_noir_files(
     dir: &Path,
     cb: &mut dyn FnMut(&DirEntry) -> std::io::Result<()>,
 ) -> std::io::Result<()> {
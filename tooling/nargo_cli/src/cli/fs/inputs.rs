@@ -1,8 +1,12 @@
 use noirc_abi::{
+    errors::InputParserError,
     input_parser::{Format, InputValue},
     Abi, InputMap, MAIN_RETURN_NAME,
 };
-use std::{collections::BTreeMap, path::Path};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use crate::errors::FilesystemError;
 
@@ -25,7 +29,7 @@ pub(crate) fn read_inputs_from_file<P: AsRef<Path>>(
         return Ok((BTreeMap::new(), None));
     }
 
-    let file_path = path.as_ref().join(file_name).with_extension(format.ext());
+    let file_path = input_file_path(path.as_ref(), file_name, &format);
     if !file_path.exists() {
         return Err(FilesystemError::MissingTomlFile(file_name.to_owned(), file_path));
     }
@@ -37,6 +41,47 @@ pub(crate) fn read_inputs_from_file<P: AsRef<Path>>(
     Ok((input_map, return_value))
 }
 
+/// Like [`read_inputs_from_file`] for TOML files, with the values of the `proof_fields` files
+/// emitted by `nargo prove --emit-fields` added to those of `file_name`.
+///
+/// Each of these files holds a single input named after its proof, so that the fields of several
+/// proofs don't clash.
+///
+/// The file `file_name` may be missing if the proof fields provide all of the circuit's parameters.
+pub(crate) fn read_inputs_with_proof_fields<P: AsRef<Path>>(
+    path: P,
+    file_name: &str,
+    proof_fields: &[PathBuf],
+    abi: &Abi,
+) -> Result<(InputMap, Option<InputValue>), FilesystemError> {
+    if proof_fields.is_empty() {
+        return read_inputs_from_file(path, file_name, Format::Toml, abi);
+    }
+
+    let read_toml = |file_path: &Path| -> Result<toml::Table, FilesystemError> {
+        let input_string = std::fs::read_to_string(file_path)
+            .map_err(|_| FilesystemError::PathNotValid(file_path.to_path_buf()))?;
+        toml::from_str(&input_string).map_err(|error| InputParserError::from(error).into())
+    };
+
+    let file_path = input_file_path(path.as_ref(), file_name, &Format::Toml);
+    let mut inputs = if file_path.exists() { read_toml(&file_path)? } else { toml::Table::new() };
+    for fields_path in proof_fields {
+        for (name, value) in read_toml(fields_path)? {
+            if inputs.contains_key(&name) {
+                return Err(FilesystemError::DuplicateInput(name, fields_path.clone()));
+            }
+            inputs.insert(name, value);
+        }
+    }
+
+    let input_string = toml::to_string(&inputs).map_err(InputParserError::from)?;
+    let mut input_map = Format::Toml.parse(&input_string, abi)?;
+    let return_value = input_map.remove(MAIN_RETURN_NAME);
+
+    Ok((input_map, return_value))
+}
+
 pub(crate) fn write_inputs_to_file<P: AsRef<Path>>(
     input_map: &InputMap,
     return_value: &Option<InputValue>,
@@ -45,7 +90,7 @@ pub(crate) fn write_inputs_to_file<P: AsRef<Path>>(
     file_name: &str,
     format: Format,
 ) -> Result<(), FilesystemError> {
-    let file_path = path.as_ref().join(file_name).with_extension(format.ext());
+    let file_path = input_file_path(path.as_ref(), file_name, &format);
 
     // We must insert the return value into the `InputMap` in order for it to be written to file.
     let serialized_output = match return_value {
@@ -65,9 +110,32 @@ pub(crate) fn write_inputs_to_file<P: AsRef<Path>>(
     Ok(())
 }
 
+/// Returns the name of the inputs file `file_name` for `contract_function`, e.g. `Prover.transfer.toml`
+/// for the `transfer` function, or `file_name` itself when not targeting a contract function.
+///
+/// The extension of `format` is included in the name so that the function name isn't taken for
+/// the extension of the file, and replaced with it.
+pub(crate) fn contract_function_inputs_name(
+    file_name: &str,
+    contract_function: Option<&str>,
+    format: &Format,
+) -> String {
+    match contract_function {
+        Some(function_name) => {
+            let file_stem = Path::new(file_name).with_extension("");
+            format!("{}.{function_name}.{}", file_stem.display(), format.ext())
+        }
+        None => file_name.to_owned(),
+    }
+}
+
+fn input_file_path(path: &Path, file_name: &str, format: &Format) -> PathBuf {
+    path.join(file_name).with_extension(format.ext())
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{collections::BTreeMap, vec};
+    use std::{collections::BTreeMap, path::Path, vec};
 
     use acvm::FieldElement;
     use nargo::constants::VERIFIER_INPUT_FILE;
@@ -77,7 +145,9 @@ mod tests {
     };
     use tempfile::TempDir;
 
-    use super::{read_inputs_from_file, write_inputs_to_file};
+    use super::{
+        contract_function_inputs_name, input_file_path, read_inputs_from_file, write_inputs_to_file,
+    };
 
     #[test]
     fn write_and_read_recovers_inputs_and_return_value() {
@@ -127,4 +197,27 @@ mod tests {
         assert_eq!(loaded_inputs, input_map);
         assert_eq!(loaded_return_value, return_value);
     }
+
+    #[test]
+    fn input_file_names_have_their_extension_replaced() {
+        let dir = Path::new("project");
+        assert_eq!(input_file_path(dir, "Prover", &Format::Toml), dir.join("Prover.toml"));
+        assert_eq!(input_file_path(dir, "Prover.toml", &Format::Toml), dir.join("Prover.toml"));
+        assert_eq!(input_file_path(dir, "foo.bar", &Format::Toml), dir.join("foo.toml"));
+        assert_eq!(input_file_path(dir, "Prover", &Format::Json), dir.join("Prover.json"));
+    }
+
+    #[test]
+    fn contract_function_inputs_keep_the_function_name() {
+        let dir = Path::new("project");
+        for file_name in ["Prover", "Prover.toml"] {
+            let file_name =
+                contract_function_inputs_name(file_name, Some("transfer"), &Format::Toml);
+            assert_eq!(
+                input_file_path(dir, &file_name, &Format::Toml),
+                dir.join("Prover.transfer.toml")
+            );
+        }
+        assert_eq!(contract_function_inputs_name("Prover", None, &Format::Toml), "Prover");
+    }
 }
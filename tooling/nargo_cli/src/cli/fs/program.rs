@@ -1,7 +1,8 @@
 use std::path::{Path, PathBuf};
 
 use nargo::artifacts::{
-    contract::PreprocessedContract, debug::DebugArtifact, program::PreprocessedProgram,
+    contract::PreprocessedContract, debug::DebugArtifact, fingerprint::PackageFingerprint,
+    program::PreprocessedProgram,
 };
 use noirc_frontend::graph::CrateName;
 
@@ -35,6 +36,15 @@ pub(crate) fn save_debug_artifact_to_file<P: AsRef<Path>>(
     save_build_artifact_to_file(debug_artifact, &artifact_name, circuit_dir)
 }
 
+pub(crate) fn save_fingerprint_to_file<P: AsRef<Path>>(
+    fingerprint: &PackageFingerprint,
+    circuit_name: &str,
+    circuit_dir: P,
+) -> PathBuf {
+    let artifact_name = format!("fingerprint_{circuit_name}");
+    save_build_artifact_to_file(fingerprint, &artifact_name, circuit_dir)
+}
+
 fn save_build_artifact_to_file<P: AsRef<Path>, T: ?Sized + serde::Serialize>(
     build_artifact: &T,
     artifact_name: &str,
@@ -71,3 +81,14 @@ pub(crate) fn read_debug_artifact_from_file<P: AsRef<Path>>(
 
     Ok(program)
 }
+
+pub(crate) fn read_fingerprint_from_file<P: AsRef<Path>>(
+    fingerprint_path: P,
+) -> Result<PackageFingerprint, FilesystemError> {
+    let input_string = std::fs::read(&fingerprint_path)
+        .map_err(|_| FilesystemError::PathNotValid(fingerprint_path.as_ref().into()))?;
+    let fingerprint = serde_json::from_slice(&input_string)
+        .map_err(|err| FilesystemError::ProgramSerializationError(err.to_string()))?;
+
+    Ok(fingerprint)
+}
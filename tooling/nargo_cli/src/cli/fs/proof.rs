@@ -1,10 +1,20 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-use nargo::constants::PROOF_EXT;
+use acvm::FieldElement;
+use iter_extended::vecmap;
+use nargo::{constants::PROOF_EXT, package::Package};
+use noirc_abi::{
+    input_parser::{Format, InputValue},
+    Abi, AbiParameter, AbiType, AbiVisibility,
+};
 
 use crate::errors::FilesystemError;
 
-use super::{create_named_dir, write_to_file};
+use super::{create_named_dir, inputs::write_inputs_to_file, write_to_file};
+
+/// The extension of the file holding the [`RecursionInputs`] of a proof, which is saved next to it.
+const RECURSION_INPUTS_EXT: &str = "fields";
 
 pub(crate) fn save_proof_to_dir<P: AsRef<Path>>(
     proof: &[u8],
@@ -18,3 +28,77 @@ pub(crate) fn save_proof_to_dir<P: AsRef<Path>>(
 
     Ok(proof_path)
 }
+
+/// Returns the name under which the proof of `package`, or of its `contract_function`, is saved.
+pub(crate) fn proof_name(package: &Package, contract_function: Option<&str>) -> String {
+    match contract_function {
+        Some(function_name) => format!("{}-{function_name}", package.name),
+        None => package.name.to_string(),
+    }
+}
+
+/// The inputs of `std::verify_proof` with which an outer circuit verifies a proof recursively.
+///
+/// They are saved as a TOML inputs file so that the outer package can add them to its own inputs.
+pub(crate) struct RecursionInputs {
+    pub(crate) verification_key: Vec<FieldElement>,
+    pub(crate) proof: Vec<FieldElement>,
+    pub(crate) public_inputs: Vec<FieldElement>,
+    pub(crate) key_hash: FieldElement,
+}
+
+/// Returns the name of the input holding the [`RecursionInputs`] of the proof named `proof_name`.
+///
+/// The inputs of each proof are grouped under their own name so that an outer package can take
+/// those of several proofs, e.g. as a `main(inner: ProofFields, other: ProofFields)` function.
+fn recursion_inputs_name(proof_name: &str) -> String {
+    // The proof of a contract function is named `<PACKAGE>-<FUNCTION>`, which isn't an identifier.
+    proof_name.replace('-', "_")
+}
+
+pub(crate) fn save_recursion_inputs_to_dir<P: AsRef<Path>>(
+    recursion_inputs: RecursionInputs,
+    proof_name: &str,
+    proof_dir: P,
+) -> Result<PathBuf, FilesystemError> {
+    let RecursionInputs { verification_key, proof, public_inputs, key_hash } = recursion_inputs;
+
+    let fields = |name: &str, fields: Vec<FieldElement>| {
+        let typ = AbiType::Array { length: fields.len() as u64, typ: Box::new(AbiType::Field) };
+        let value = InputValue::Vec(vecmap(fields, InputValue::Field));
+        (name.to_owned(), typ, value)
+    };
+    let proof_fields = [
+        fields("verification_key", verification_key),
+        fields("proof", proof),
+        fields("public_inputs", public_inputs),
+        ("key_hash".to_owned(), AbiType::Field, InputValue::Field(key_hash)),
+    ];
+
+    let name = recursion_inputs_name(proof_name);
+    let typ = AbiType::Struct {
+        path: "ProofFields".to_owned(),
+        fields: vecmap(&proof_fields, |(name, typ, _)| (name.clone(), typ.clone())),
+    };
+    let value = InputValue::Struct(
+        proof_fields.into_iter().map(|(name, _, value)| (name, value)).collect(),
+    );
+
+    let abi = Abi {
+        parameters: vec![AbiParameter {
+            name: name.clone(),
+            typ,
+            visibility: AbiVisibility::Private,
+        }],
+        param_witnesses: BTreeMap::new(),
+        return_type: None,
+        return_witnesses: Vec::new(),
+    };
+    let input_map = BTreeMap::from([(name, value)]);
+
+    create_named_dir(proof_dir.as_ref(), "proof");
+    let file_name = format!("{proof_name}.{RECURSION_INPUTS_EXT}.{}", Format::Toml.ext());
+    write_inputs_to_file(&input_map, &None, &abi, proof_dir.as_ref(), &file_name, Format::Toml)?;
+
+    Ok(proof_dir.as_ref().join(file_name))
+}
@@ -43,7 +43,7 @@ pub(crate) struct InfoCommand {
     profile_info: bool,
 
     #[clap(flatten)]
-    compile_options: CompileOptions,
+    pub(crate) compile_options: CompileOptions,
 }
 
 pub(crate) fn run(
@@ -2,6 +2,7 @@ use clap::{Args, Parser, Subcommand};
 use const_format::formatcp;
 use nargo_toml::find_package_root;
 use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
+use noirc_errors::MessageFormat;
 use std::path::PathBuf;
 
 use color_eyre::eyre;
@@ -16,6 +17,8 @@ mod codegen_verifier_cmd;
 mod compile_cmd;
 mod debug_cmd;
 mod execute_cmd;
+mod explain_cmd;
+mod fix_cmd;
 mod fmt_cmd;
 mod info_cmd;
 mod init_cmd;
@@ -24,6 +27,7 @@ mod new_cmd;
 mod prove_cmd;
 mod test_cmd;
 mod verify_cmd;
+mod watch_cmd;
 
 const GIT_HASH: &str = env!("GIT_COMMIT");
 const IS_DIRTY: &str = env!("GIT_DIRTY");
@@ -67,6 +71,8 @@ enum NargoCommand {
     New(new_cmd::NewCommand),
     Init(init_cmd::InitCommand),
     Execute(execute_cmd::ExecuteCommand),
+    Explain(explain_cmd::ExplainCommand),
+    Fix(fix_cmd::FixCommand),
     #[command(hide = true)] // Hidden while the feature is being built out
     Debug(debug_cmd::DebugCommand),
     Prove(prove_cmd::ProveCommand),
@@ -74,6 +80,7 @@ enum NargoCommand {
     Test(test_cmd::TestCommand),
     Info(info_cmd::InfoCommand),
     Lsp(lsp_cmd::LspCommand),
+    Watch(watch_cmd::WatchCommand),
 }
 
 pub(crate) fn start_cli() -> eyre::Result<()> {
@@ -91,6 +98,7 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
             | NargoCommand::Init(_)
             | NargoCommand::Lsp(_)
             | NargoCommand::Backend(_)
+            | NargoCommand::Explain(_)
     ) {
         config.program_dir = find_package_root(&config.program_dir)?;
     }
@@ -113,7 +121,19 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         NargoCommand::Backend(args) => backend_cmd::run(args),
         NargoCommand::Lsp(args) => lsp_cmd::run(&backend, args, config),
         NargoCommand::Fmt(args) => fmt_cmd::run(args, config),
+        NargoCommand::Watch(args) => watch_cmd::run(&backend, args, config),
+        NargoCommand::Explain(args) => explain_cmd::run(args),
+        NargoCommand::Fix(args) => fix_cmd::run(args, config),
     }?;
 
     Ok(())
 }
+
+/// Prints a progress message to stdout, or to stderr when diagnostics are written to stdout as
+/// JSON so that every line of stdout can be parsed as a diagnostic.
+pub(crate) fn print_status(message_format: MessageFormat, message: std::fmt::Arguments) {
+    match message_format {
+        MessageFormat::Human => println!("{message}"),
+        MessageFormat::Json => eprintln!("{message}"),
+    }
+}
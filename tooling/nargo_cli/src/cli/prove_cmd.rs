@@ -5,14 +5,15 @@ use nargo::workspace::Workspace;
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_abi::input_parser::Format;
 use noirc_driver::{CompileOptions, CompiledProgram, NOIR_ARTIFACT_VERSION_STRING};
+use noirc_errors::MessageFormat;
 use noirc_frontend::graph::CrateName;
 
-use super::compile_cmd::compile_bin_package;
+use super::compile_cmd::compile_executable_package;
 use super::fs::{
-    inputs::{read_inputs_from_file, write_inputs_to_file},
-    proof::save_proof_to_dir,
+    inputs::{contract_function_inputs_name, read_inputs_from_file, write_inputs_to_file},
+    proof::{proof_name, save_proof_to_dir, save_recursion_inputs_to_dir, RecursionInputs},
 };
-use super::NargoConfig;
+use super::{print_status, NargoConfig};
 use crate::{backends::Backend, cli::execute_cmd::execute_program, errors::CliError};
 
 /// Create proof for this program. The proof is returned as a hex encoded string.
@@ -30,10 +31,24 @@ pub(crate) struct ProveCommand {
     #[arg(long)]
     verify: bool,
 
+    /// Create a proof which can be verified recursively within another circuit
+    #[arg(long)]
+    recursive: bool,
+
+    /// Write the fields with which another circuit verifies the proof recursively to
+    /// `proofs/<PACKAGE>.fields.toml`, ready to be used as its input named `<PACKAGE>`
+    #[arg(long, requires = "recursive")]
+    emit_fields: bool,
+
     /// The name of the package to prove
     #[clap(long, conflicts_with = "workspace")]
     package: Option<CrateName>,
 
+    /// The name of the contract function to prove, whose inputs are read from `Prover.<FUNCTION>.toml`
+    /// and whose public inputs are written to `Verifier.<FUNCTION>.toml`
+    #[clap(long, conflicts_with = "workspace")]
+    contract_fn: Option<String>,
+
     /// Prove all packages in the workspace
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
@@ -57,44 +72,73 @@ pub(crate) fn run(
         Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
     )?;
 
+    let contract_function = args.contract_fn.as_deref();
+    let options = ProveOptions {
+        prover_name: contract_function_inputs_name(
+            &args.prover_name,
+            contract_function,
+            &Format::Toml,
+        ),
+        verifier_name: contract_function_inputs_name(
+            &args.verifier_name,
+            contract_function,
+            &Format::Toml,
+        ),
+        check_proof: args.verify,
+        is_recursive: args.recursive,
+        emit_fields: args.emit_fields,
+        message_format: args.compile_options.message_format,
+    };
+
     let (np_language, opcode_support) = backend.get_backend_info()?;
     for package in &workspace {
-        let program = compile_bin_package(
+        let program = compile_executable_package(
             &workspace,
             package,
+            contract_function,
             &args.compile_options,
             np_language,
             &opcode_support,
         )?;
 
-        prove_package(
-            backend,
-            &workspace,
-            package,
-            program,
-            &args.prover_name,
-            &args.verifier_name,
-            args.verify,
-        )?;
+        prove_package(backend, &workspace, package, contract_function, program, &options)?;
     }
 
     Ok(())
 }
 
+/// Where the inputs of a proof are read from, and what is written besides the proof.
+pub(crate) struct ProveOptions {
+    /// The name of the file holding the inputs for the prover
+    pub(crate) prover_name: String,
+    /// The name of the file which the public inputs for the verifier are written to
+    pub(crate) verifier_name: String,
+    /// Whether the proof is verified after proving
+    pub(crate) check_proof: bool,
+    /// Whether the proof can be verified recursively within another circuit
+    pub(crate) is_recursive: bool,
+    /// Whether the fields with which another circuit verifies the proof are written next to it
+    pub(crate) emit_fields: bool,
+    pub(crate) message_format: MessageFormat,
+}
+
 pub(crate) fn prove_package(
     backend: &Backend,
     workspace: &Workspace,
     package: &Package,
+    contract_function: Option<&str>,
     compiled_program: CompiledProgram,
-    prover_name: &str,
-    verifier_name: &str,
-    check_proof: bool,
+    options: &ProveOptions,
 ) -> Result<(), CliError> {
     // Parse the initial witness values from Prover.toml
-    let (inputs_map, _) =
-        read_inputs_from_file(&package.root_dir, prover_name, Format::Toml, &compiled_program.abi)?;
+    let (inputs_map, _) = read_inputs_from_file(
+        &package.root_dir,
+        &options.prover_name,
+        Format::Toml,
+        &compiled_program.abi,
+    )?;
 
-    let solved_witness = execute_program(&compiled_program, &inputs_map)?;
+    let solved_witness = execute_program(&compiled_program, &inputs_map, options.message_format)?;
 
     // Write public inputs into Verifier.toml
     let public_abi = compiled_program.abi.public_abi();
@@ -105,23 +149,54 @@ pub(crate) fn prove_package(
         &return_value,
         &public_abi,
         &package.root_dir,
-        verifier_name,
+        &options.verifier_name,
         Format::Toml,
     )?;
 
-    let proof = backend.prove(&compiled_program.circuit, solved_witness, false)?;
+    let proof = backend.prove(&compiled_program.circuit, solved_witness, options.is_recursive)?;
+    let public_inputs = public_abi.encode(&public_inputs, return_value)?;
 
-    if check_proof {
-        let public_inputs = public_abi.encode(&public_inputs, return_value)?;
-        let valid_proof =
-            backend.verify(&proof, public_inputs, &compiled_program.circuit, false)?;
+    if options.check_proof {
+        let valid_proof = backend.verify(
+            &proof,
+            public_inputs.clone(),
+            &compiled_program.circuit,
+            options.is_recursive,
+        )?;
 
         if !valid_proof {
             return Err(CliError::InvalidProof("".into()));
         }
     }
 
-    save_proof_to_dir(&proof, &String::from(&package.name), workspace.proofs_directory_path())?;
+    let proof_name = proof_name(package, contract_function);
+    save_proof_to_dir(&proof, &proof_name, workspace.proofs_directory_path())?;
+
+    if options.emit_fields {
+        let public_input_values =
+            public_inputs.clone().into_iter().map(|(_, value)| value).collect();
+        let (proof, key_hash, verification_key) = backend.get_intermediate_proof_artifacts(
+            &compiled_program.circuit,
+            &proof,
+            public_inputs,
+        )?;
+        let recursion_inputs = RecursionInputs {
+            verification_key,
+            proof,
+            public_inputs: public_input_values,
+            key_hash,
+        };
+
+        let fields_path = save_recursion_inputs_to_dir(
+            recursion_inputs,
+            &proof_name,
+            workspace.proofs_directory_path(),
+        )?;
+        print_status(
+            options.message_format,
+            format_args!("[{}] Recursion inputs saved to {}", package.name, fields_path.display()),
+        );
+    }
 
     Ok(())
 }
@@ -3,9 +3,10 @@ use std::io::Write;
 use acvm::BlackBoxFunctionSolver;
 use clap::Args;
 use nargo::{
-    ops::{run_test, TestStatus},
+    ops::{run_test, CoverageReport, TestOptions, TestSnapshots, TestStatus},
     package::Package,
     prepare_package,
+    workspace::Workspace,
 };
 use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
 use noirc_driver::{CompileOptions, NOIR_ARTIFACT_VERSION_STRING};
@@ -14,7 +15,7 @@ use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
 use crate::{backends::Backend, cli::check_cmd::check_crate_and_report_errors, errors::CliError};
 
-use super::NargoConfig;
+use super::{print_status, NargoConfig};
 
 /// Run the tests for this program
 #[derive(Debug, Clone, Args)]
@@ -30,6 +31,14 @@ pub(crate) struct TestCommand {
     #[clap(long)]
     exact: bool,
 
+    /// Rewrite the snapshots of `#[test(snapshot)]` functions with their current output
+    #[arg(long)]
+    update_snapshots: bool,
+
+    /// Write an lcov report of the Noir source lines executed by the tests to `target/<package>.lcov`
+    #[arg(long)]
+    coverage: bool,
+
     /// The name of the package to test
     #[clap(long, conflicts_with = "workspace")]
     package: Option<CrateName>,
@@ -39,7 +48,7 @@ pub(crate) struct TestCommand {
     workspace: bool,
 
     #[clap(flatten)]
-    compile_options: CompileOptions,
+    pub(crate) compile_options: CompileOptions,
 }
 
 pub(crate) fn run(
@@ -71,9 +80,24 @@ pub(crate) fn run(
     #[allow(deprecated)]
     let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
     for package in &workspace {
+        let mut options = TestOptions {
+            show_output: args.show_output,
+            snapshots: TestSnapshots {
+                dir: package.snapshots_dir(),
+                update: args.update_snapshots,
+            },
+            coverage: args.coverage.then(|| CoverageReport::new(package.root_dir.clone())),
+        };
         // By unwrapping here with `?`, we stop the test runner upon a package failing
         // TODO: We should run the whole suite even if there are failures in a package
-        run_tests(&blackbox_solver, package, pattern, args.show_output, &args.compile_options)?;
+        run_tests(
+            &blackbox_solver,
+            &workspace,
+            package,
+            pattern,
+            &mut options,
+            &args.compile_options,
+        )?;
     }
 
     Ok(())
@@ -81,9 +105,10 @@ pub(crate) fn run(
 
 fn run_tests<S: BlackBoxFunctionSolver>(
     blackbox_solver: &S,
+    workspace: &Workspace,
     package: &Package,
     test_name: FunctionNameMatch,
-    show_output: bool,
+    options: &mut TestOptions,
     compile_options: &CompileOptions,
 ) -> Result<(), CliError> {
     let (mut context, crate_id) =
@@ -93,11 +118,15 @@ fn run_tests<S: BlackBoxFunctionSolver>(
         crate_id,
         compile_options.deny_warnings,
         compile_options.silence_warnings,
+        compile_options.message_format,
     )?;
 
     let test_functions = context.get_all_test_functions_in_crate_matching(&crate_id, test_name);
 
-    println!("[{}] Running {} test functions", package.name, test_functions.len());
+    print_status(
+        compile_options.message_format,
+        format_args!("[{}] Running {} test functions", package.name, test_functions.len()),
+    );
     let mut failing = 0;
 
     let writer = StandardStream::stderr(ColorChoice::Always);
@@ -108,7 +137,14 @@ fn run_tests<S: BlackBoxFunctionSolver>(
             .expect("Failed to write to stdout");
         writer.flush().expect("Failed to flush writer");
 
-        match run_test(blackbox_solver, &context, test_function, show_output, compile_options) {
+        match run_test(
+            blackbox_solver,
+            &context,
+            &test_name,
+            test_function,
+            options,
+            compile_options,
+        ) {
             TestStatus::Pass { .. } => {
                 writer
                     .set_color(ColorSpec::new().set_fg(Some(Color::Green)))
@@ -129,6 +165,7 @@ fn run_tests<S: BlackBoxFunctionSolver>(
                         &[diag],
                         compile_options.deny_warnings,
                         compile_options.silence_warnings,
+                        compile_options.message_format,
                     );
                 }
                 failing += 1;
@@ -139,6 +176,7 @@ fn run_tests<S: BlackBoxFunctionSolver>(
                     &[err],
                     compile_options.deny_warnings,
                     compile_options.silence_warnings,
+                    compile_options.message_format,
                 );
                 failing += 1;
             }
@@ -146,6 +184,18 @@ fn run_tests<S: BlackBoxFunctionSolver>(
         writer.reset().expect("Failed to reset writer");
     }
 
+    if let Some(coverage_report) = &options.coverage {
+        let coverage_path = workspace.package_coverage_path(package);
+        coverage_report.write_lcov(&package.name.to_string(), &coverage_path).map_err(|err| {
+            CliError::Generic(format!(
+                "Could not write coverage report to {}: {err}",
+                coverage_path.display()
+            ))
+        })?;
+        writeln!(writer, "[{}] Wrote coverage report to {}", package.name, coverage_path.display())
+            .expect("Failed to write to stdout");
+    }
+
     if failing == 0 {
         write!(writer, "[{}] ", package.name).expect("Failed to write to stdout");
         writer.set_color(ColorSpec::new().set_fg(Some(Color::Green))).expect("Failed to set color");
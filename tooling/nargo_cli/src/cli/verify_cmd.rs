@@ -1,7 +1,11 @@
 use super::NargoConfig;
 use super::{
-    compile_cmd::compile_bin_package,
-    fs::{inputs::read_inputs_from_file, load_hex_data},
+    compile_cmd::compile_executable_package,
+    fs::{
+        inputs::{contract_function_inputs_name, read_inputs_from_file},
+        load_hex_data,
+        proof::proof_name,
+    },
 };
 use crate::{backends::Backend, errors::CliError};
 
@@ -25,6 +29,15 @@ pub(crate) struct VerifyCommand {
     #[clap(long, conflicts_with = "workspace")]
     package: Option<CrateName>,
 
+    /// The name of the contract function whose proof to verify, with public inputs read from
+    /// `Verifier.<FUNCTION>.toml`
+    #[clap(long, conflicts_with = "workspace")]
+    contract_fn: Option<String>,
+
+    /// Verify a proof created with `nargo prove --recursive`
+    #[arg(long)]
+    recursive: bool,
+
     /// Verify all packages in the workspace
     #[clap(long, conflicts_with = "package")]
     workspace: bool,
@@ -50,15 +63,27 @@ pub(crate) fn run(
 
     let (np_language, opcode_support) = backend.get_backend_info()?;
     for package in &workspace {
-        let program = compile_bin_package(
+        let contract_function = args.contract_fn.as_deref();
+        let program = compile_executable_package(
             &workspace,
             package,
+            contract_function,
             &args.compile_options,
             np_language,
             &opcode_support,
         )?;
 
-        verify_package(backend, &workspace, package, program, &args.verifier_name)?;
+        let verifier_name =
+            contract_function_inputs_name(&args.verifier_name, contract_function, &Format::Toml);
+        verify_package(
+            backend,
+            &workspace,
+            package,
+            contract_function,
+            program,
+            &verifier_name,
+            args.recursive,
+        )?;
     }
 
     Ok(())
@@ -68,8 +93,10 @@ fn verify_package(
     backend: &Backend,
     workspace: &Workspace,
     package: &Package,
+    contract_function: Option<&str>,
     compiled_program: CompiledProgram,
     verifier_name: &str,
+    is_recursive: bool,
 ) -> Result<(), CliError> {
     // Load public inputs (if any) from `verifier_name`.
     let public_abi = compiled_program.abi.public_abi();
@@ -78,12 +105,15 @@ fn verify_package(
 
     let public_inputs = public_abi.encode(&public_inputs_map, return_value)?;
 
-    let proof_path =
-        workspace.proofs_directory_path().join(package.name.to_string()).with_extension(PROOF_EXT);
+    let proof_path = workspace
+        .proofs_directory_path()
+        .join(proof_name(package, contract_function))
+        .with_extension(PROOF_EXT);
 
     let proof = load_hex_data(&proof_path)?;
 
-    let valid_proof = backend.verify(&proof, public_inputs, &compiled_program.circuit, false)?;
+    let valid_proof =
+        backend.verify(&proof, public_inputs, &compiled_program.circuit, is_recursive)?;
 
     if valid_proof {
         Ok(())
@@ -0,0 +1,187 @@
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use clap::{Args, Subcommand};
+use nargo::{
+    constants::{PKG_FILE, SRC_DIR},
+    package::{Dependency, Package},
+    workspace::Workspace,
+};
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_driver::NOIR_ARTIFACT_VERSION_STRING;
+use noirc_errors::MessageFormat;
+
+use crate::backends::Backend;
+use crate::errors::CliError;
+
+use super::{
+    check_cmd, execute_cmd, fmt_cmd::visit_noir_files, info_cmd, print_status, test_cmd,
+    NargoConfig,
+};
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches the package for changes and runs a command every time it changes
+///
+/// The source files and manifests of the package and of its local dependencies are watched,
+/// as well as its `Prover.toml` file.
+#[derive(Debug, Clone, Args)]
+pub(crate) struct WatchCommand {
+    /// The command to run on changes [default: check]
+    #[command(subcommand)]
+    action: Option<WatchAction>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum WatchAction {
+    Check(check_cmd::CheckCommand),
+    Test(test_cmd::TestCommand),
+    Execute(execute_cmd::ExecuteCommand),
+    Info(info_cmd::InfoCommand),
+}
+
+impl WatchAction {
+    fn message_format(&self) -> MessageFormat {
+        let compile_options = match self {
+            WatchAction::Check(args) => &args.compile_options,
+            WatchAction::Test(args) => &args.compile_options,
+            WatchAction::Execute(args) => &args.compile_options,
+            WatchAction::Info(args) => &args.compile_options,
+        };
+        compile_options.message_format
+    }
+}
+
+pub(crate) fn run(
+    backend: &Backend,
+    args: WatchCommand,
+    config: NargoConfig,
+) -> Result<(), CliError> {
+    let action = args.action.unwrap_or_else(|| WatchAction::Check(Default::default()));
+    let toml_path = get_package_manifest(&config.program_dir)?;
+
+    let mut last_snapshot = None;
+    loop {
+        // The manifest may change the packages and dependencies making up the workspace,
+        // so the files to watch are looked up again every time.
+        let watched_paths = match resolve_workspace_from_toml(
+            &toml_path,
+            PackageSelection::All,
+            Some(NOIR_ARTIFACT_VERSION_STRING.to_string()),
+        ) {
+            Ok(workspace) => watched_paths(&workspace),
+            Err(_) => vec![toml_path.clone()],
+        };
+
+        let snapshot = snapshot(&watched_paths);
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            last_snapshot = Some(snapshot);
+
+            // Clear the screen so that only the diagnostics of the latest run are shown.
+            // JSON diagnostics are meant to be read by tools, which would choke on escape codes.
+            let message_format = action.message_format();
+            if message_format == MessageFormat::Human {
+                print!("\x1B[2J\x1B[1;1H");
+                let _ = std::io::stdout().flush();
+            }
+            if let Err(error) = run_action(backend, &action, &config) {
+                eprintln!("{error}");
+            }
+            print_status(message_format, format_args!("[nargo] Watching for changes..."));
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn run_action(
+    backend: &Backend,
+    action: &WatchAction,
+    config: &NargoConfig,
+) -> Result<(), CliError> {
+    let config = config.clone();
+    match action.clone() {
+        WatchAction::Check(args) => check_cmd::run(backend, args, config),
+        WatchAction::Test(args) => test_cmd::run(backend, args, config),
+        WatchAction::Execute(args) => execute_cmd::run(backend, args, config),
+        WatchAction::Info(args) => info_cmd::run(backend, args, config),
+    }
+}
+
+/// Returns the files and directories which may affect the packages of `workspace`.
+fn watched_paths(workspace: &Workspace) -> Vec<PathBuf> {
+    fn add_package_paths(package: &Package, paths: &mut Vec<PathBuf>) {
+        paths.push(package.root_dir.join(PKG_FILE));
+        paths.push(package.prover_input_path());
+        paths.push(package.root_dir.join(SRC_DIR));
+
+        // Remote dependencies are fetched at a fixed version, so only local ones can change.
+        for dependency in package.dependencies.values() {
+            if let Dependency::Local { package } = dependency {
+                add_package_paths(package, paths);
+            }
+        }
+    }
+
+    let mut paths = vec![workspace.root_dir.join(PKG_FILE)];
+    for package in &workspace.members {
+        add_package_paths(package, &mut paths);
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Returns the modification time of each of the `paths` which exist, looking for Noir files
+/// within those which are directories.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut snapshot = BTreeMap::new();
+    let mut add_file = |path: &Path| {
+        if let Ok(modified) = path.metadata().and_then(|metadata| metadata.modified()) {
+            snapshot.insert(path.to_path_buf(), modified);
+        }
+    };
+
+    for path in paths {
+        if path.is_dir() {
+            // A file which is removed while walking the directory will be caught on the next poll.
+            let _ = visit_noir_files(path, &mut |entry| {
+                add_file(&entry.path());
+                Ok(())
+            });
+        } else {
+            add_file(path);
+        }
+    }
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::snapshot;
+
+    #[test]
+    fn snapshot_changes_with_noir_files() {
+        let dir = tempdir().unwrap();
+        let src_dir = dir.path().join("src");
+        std::fs::create_dir(&src_dir).unwrap();
+        std::fs::write(src_dir.join("main.nr"), "fn main() {}").unwrap();
+        std::fs::write(src_dir.join("notes.txt"), "").unwrap();
+        let paths = vec![src_dir.clone(), dir.path().join("Nargo.toml")];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.keys().collect::<Vec<_>>(), vec![&src_dir.join("main.nr")]);
+
+        std::fs::write(src_dir.join("foo.nr"), "fn foo() {}").unwrap();
+        let after = snapshot(&paths);
+        assert_ne!(before, after);
+        assert!(after.contains_key(&src_dir.join("foo.nr")));
+    }
+}
@@ -3,6 +3,7 @@ use hex::FromHexError;
 use nargo::{errors::CompileError, NargoError};
 use nargo_toml::ManifestError;
 use noirc_abi::errors::{AbiError, InputParserError};
+use noirc_frontend::graph::CrateName;
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -16,6 +17,8 @@ pub(crate) enum FilesystemError {
         " Error: cannot find {0}.toml file.\n Expected location: {1:?} \n Please generate this file at the expected location."
     )]
     MissingTomlFile(String, PathBuf),
+    #[error("Error: input `{0}` from {} is already defined by another inputs file", .1.display())]
+    DuplicateInput(String, PathBuf),
 
     /// Input parsing error
     #[error(transparent)]
@@ -42,6 +45,9 @@ pub(crate) enum CliError {
     #[error("Invalid package name {0}. Did you mean to use `--name`?")]
     InvalidPackageName(String),
 
+    #[error("The circuit and Brillig executions of package {0} disagree: {1}")]
+    BrilligMismatch(CrateName, String),
+
     /// ABI encoding/decoding error
     #[error(transparent)]
     AbiError(#[from] AbiError),
@@ -0,0 +1,78 @@
+//! Tests that `--compare-brillig` reports programs whose circuit and Brillig executions disagree.
+
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use assert_fs::TempDir;
+use predicates::prelude::*;
+use std::process::Command;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+const NARGO_TOML: &str = r#"
+[package]
+name = "foo"
+type = "bin"
+authors = [""]
+[dependencies]
+"#;
+
+/// Creates a binary package with the given `main.nr` and `Prover.toml`.
+fn package(main: &str, prover_toml: &str) -> TempDir {
+    let project_dir = TempDir::new().unwrap();
+    project_dir.child("Nargo.toml").write_str(NARGO_TOML).unwrap();
+    project_dir.child("src/main.nr").write_str(main).unwrap();
+    project_dir.child("Prover.toml").write_str(prover_toml).unwrap();
+    project_dir
+}
+
+fn nargo_compare_brillig(project_dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(project_dir.path());
+    cmd.arg("execute").arg("--compare-brillig");
+    cmd
+}
+
+#[test]
+fn reports_a_circuit_failure_which_brillig_does_not_have() {
+    // The inverse of zero is zero in the Brillig VM, so only the circuit fails.
+    let main = "fn main(x: Field, y: Field) -> pub Field { x / y }";
+    let project_dir = package(main, "x = \"1\"\ny = \"0\"");
+
+    nargo_compare_brillig(&project_dir)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("the circuit failed to execute while Brillig succeeded"));
+}
+
+#[test]
+fn reports_failures_of_different_kinds() {
+    // The circuit fails to find the inverse of `y`, while the Brillig VM refuses to divide by zero.
+    let main = "fn main(x: u32, y: u32) -> pub u32 { x / y }";
+    let project_dir = package(main, "x = \"1\"\ny = \"0\"");
+
+    nargo_compare_brillig(&project_dir).assert().failure().stderr(
+        predicate::str::contains("the circuit failed with an assertion failure without a message")
+            .and(predicate::str::contains("while Brillig failed with error 'Division by zero'")),
+    );
+}
+
+#[test]
+fn accepts_the_same_assertion_failure() {
+    let main = r#"
+    fn main(x: Field, y: Field) {
+        assert(x == y, "x and y differ");
+        assert(x != 0);
+    }
+    "#;
+
+    for prover_toml in ["x = \"1\"\ny = \"2\"", "x = \"0\"\ny = \"0\""] {
+        let project_dir = package(main, prover_toml);
+
+        // Both executions fail, so the circuit's failure is then reported as usual.
+        nargo_compare_brillig(&project_dir)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("disagree").not());
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests that `--contract-fn` reads the inputs of a contract function from the file named after it,
+//! and names what it writes after the function.
+
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathAssert, PathChild};
+use assert_fs::TempDir;
+use predicates::prelude::*;
+use std::process::Command;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+const NARGO_TOML: &str = r#"
+[package]
+name = "foo"
+type = "contract"
+authors = [""]
+[dependencies]
+"#;
+
+const MAIN_NR: &str = r#"
+contract Foo {
+    fn double(x: Field, y: pub Field) {
+        assert(x * 2 == y);
+    }
+}
+"#;
+
+/// Creates a contract package whose `double` function checks that `y` is twice `x`.
+fn contract_package() -> TempDir {
+    let project_dir = TempDir::new().unwrap();
+    project_dir.child("Nargo.toml").write_str(NARGO_TOML).unwrap();
+    project_dir.child("src/main.nr").write_str(MAIN_NR).unwrap();
+    project_dir
+}
+
+fn nargo(project_dir: &TempDir, command: &str) -> Command {
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(project_dir.path());
+    cmd.arg(command).arg("--contract-fn").arg("double");
+    cmd
+}
+
+#[test]
+fn execute_reads_the_inputs_of_the_contract_function() {
+    let project_dir = contract_package();
+    project_dir.child("Prover.toml").write_str("x = \"2\"\ny = \"5\"").unwrap();
+    project_dir.child("Prover.double.toml").write_str("x = \"2\"\ny = \"4\"").unwrap();
+
+    nargo(&project_dir, "execute")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Circuit witness successfully solved"));
+}
+
+#[test]
+fn execute_reads_the_contract_function_inputs_of_the_given_prover_name() {
+    let project_dir = contract_package();
+    project_dir.child("Prover.double.toml").write_str("x = \"2\"\ny = \"4\"").unwrap();
+    project_dir.child("Inputs.double.toml").write_str("x = \"2\"\ny = \"5\"").unwrap();
+
+    // The extension of the prover name is replaced, as when not executing a contract function.
+    nargo(&project_dir, "execute").arg("--prover-name").arg("Inputs.toml").assert().failure();
+}
+
+#[test]
+fn execute_fails_without_the_inputs_of_the_contract_function() {
+    let project_dir = contract_package();
+    project_dir.child("Prover.toml").write_str("x = \"2\"\ny = \"4\"").unwrap();
+
+    nargo(&project_dir, "execute")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Prover.double.toml"));
+}
+
+#[test]
+fn prove_writes_a_proof_named_after_the_contract_function() {
+    let project_dir = contract_package();
+    project_dir.child("Prover.toml").write_str("x = \"2\"\ny = \"5\"").unwrap();
+    project_dir.child("Prover.double.toml").write_str("x = \"2\"\ny = \"4\"").unwrap();
+
+    nargo(&project_dir, "prove").assert().success();
+
+    project_dir.child("proofs/foo-double.proof").assert(predicate::path::is_file());
+    project_dir.child("proofs/foo.proof").assert(predicate::path::missing());
+    project_dir.child("Verifier.double.toml").assert(predicate::str::contains("y = "));
+    project_dir.child("Verifier.toml").assert(predicate::path::missing());
+}
@@ -1,3 +1,5 @@
+mod expected_diagnostics;
+
 #[allow(unused_imports)]
 #[cfg(test)]
 mod tests {
@@ -10,6 +12,7 @@ mod tests {
     use std::path::PathBuf;
     use std::process::Command;
 
+    use super::expected_diagnostics::check_expected_diagnostics;
     use super::*;
 
     test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
@@ -0,0 +1,361 @@
+//! Checks the diagnostics reported for `compile_failure` programs against annotations in their
+//! Noir sources.
+//!
+//! An annotation is a comment of the form `// ~ERROR <text>` or `// ~WARNING <text>`, which
+//! expects a diagnostic of that severity to start on the same line. Each `^` placed between the
+//! `~` and the severity moves the expected line up by one, e.g. `// ~^ERROR <text>` expects the
+//! diagnostic on the previous line. The diagnostic matches if `<text>` is its error code, or is
+//! contained in its message or one of its labels.
+//!
+//! Some failures aren't reported within the program's sources, e.g. a malformed manifest, an error
+//! in a dependency or a diagnostic without a location. These are annotated in the program's
+//! `Nargo.toml` with `# ~ERROR <text>`, which matches such a diagnostic, or any error which
+//! `nargo` wrote to stderr.
+//!
+//! Every program must have at least one annotation. Each of them must be matched by a reported
+//! diagnostic, and each error reported within the program must be matched by an annotation.
+//! Warnings which are not annotated are ignored.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+const MANIFEST_FILE: &str = "Nargo.toml";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Error,
+    Warning,
+}
+
+/// The fields we check of a diagnostic reported by `nargo --message-format json`.
+#[derive(Debug, Deserialize)]
+struct ReportedDiagnostic {
+    severity: Severity,
+    message: String,
+    code: Option<String>,
+    spans: Vec<ReportedSpan>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReportedSpan {
+    file: PathBuf,
+    line_start: usize,
+    is_primary: bool,
+    label: String,
+}
+
+impl ReportedDiagnostic {
+    /// Returns the file and line at which this diagnostic is reported, if it has a location.
+    fn location(&self) -> Option<(PathBuf, usize)> {
+        let span = self.spans.iter().find(|span| span.is_primary)?;
+        // Files of the standard library are not on disk, and are never annotated.
+        let file = fs::canonicalize(&span.file).ok()?;
+        Some((file, span.line_start))
+    }
+
+    /// Returns whether this diagnostic is reported within the sources of the program in
+    /// `program_dir`.
+    fn is_within(&self, program_dir: &Path) -> bool {
+        self.location().map_or(false, |(file, _)| file.starts_with(program_dir))
+    }
+
+    fn contains(&self, text: &str) -> bool {
+        self.code.as_deref() == Some(text)
+            || self.message.contains(text)
+            || self.spans.iter().any(|span| span.label.contains(text))
+    }
+}
+
+#[derive(Debug)]
+struct Annotation {
+    file: PathBuf,
+    line: usize,
+    severity: Severity,
+    text: String,
+}
+
+impl Annotation {
+    /// Returns whether this annotation is placed in a manifest rather than in a Noir source.
+    fn is_in_manifest(&self) -> bool {
+        self.file.file_name().map_or(false, |name| name == MANIFEST_FILE)
+    }
+
+    fn matches(&self, diagnostic: &ReportedDiagnostic, program_dir: &Path) -> bool {
+        if diagnostic.severity != self.severity {
+            return false;
+        }
+
+        let is_expected_location = if self.is_in_manifest() {
+            !diagnostic.is_within(program_dir)
+        } else {
+            diagnostic.location() == Some((self.file.clone(), self.line))
+        };
+        is_expected_location && diagnostic.contains(&self.text)
+    }
+
+    fn matches_stderr(&self, stderr: &str) -> bool {
+        self.is_in_manifest() && self.severity == Severity::Error && stderr.contains(&self.text)
+    }
+}
+
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {:?} {:?}", self.file.display(), self.line, self.severity, self.text)
+    }
+}
+
+impl fmt::Display for ReportedDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location() {
+            Some((file, line)) => write!(f, "{}:{line}: ", file.display())?,
+            None => write!(f, "<unknown location>: ")?,
+        }
+        write!(f, "{:?} {:?}", self.severity, self.message)
+    }
+}
+
+/// Parses the annotations in `source`, the contents of `file`.
+fn parse_annotations(file: &Path, source: &str) -> Vec<Annotation> {
+    let is_manifest = file.file_name().map_or(false, |name| name == MANIFEST_FILE);
+    let marker = if is_manifest { "# ~" } else { "// ~" };
+
+    let mut annotations = Vec::new();
+    for (index, line) in source.lines().enumerate() {
+        let Some((_, annotation)) = line.split_once(marker) else {
+            continue;
+        };
+        let line_number = index + 1;
+
+        let carets = annotation.chars().take_while(|char| *char == '^').count();
+        let annotation = &annotation[carets..];
+        let (severity, text) = if let Some(text) = annotation.strip_prefix("ERROR") {
+            (Severity::Error, text)
+        } else if let Some(text) = annotation.strip_prefix("WARNING") {
+            (Severity::Warning, text)
+        } else {
+            panic!("{}:{line_number}: expected `~ERROR` or `~WARNING`", file.display());
+        };
+        let line = line_number.checked_sub(carets).filter(|line| *line > 0).unwrap_or_else(|| {
+            panic!("{}:{line_number}: annotation points before the file", file.display())
+        });
+
+        annotations.push(Annotation {
+            file: file.to_path_buf(),
+            line,
+            severity,
+            text: text.trim().to_string(),
+        });
+    }
+    annotations
+}
+
+/// Collects the annotations of every Noir source file and manifest within `dir`.
+fn collect_annotations(dir: &Path, annotations: &mut Vec<Annotation>) {
+    let mut entries: Vec<_> =
+        fs::read_dir(dir).unwrap().flatten().map(|entry| entry.path()).collect();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_annotations(&path, annotations);
+        } else if path.extension().map_or(false, |extension| extension == "nr")
+            || path.file_name().map_or(false, |name| name == MANIFEST_FILE)
+        {
+            let source = fs::read_to_string(&path).unwrap();
+            annotations.append(&mut parse_annotations(&path, &source));
+        }
+    }
+}
+
+/// Checks the `diagnostics` reported for the program in `program_dir` and the errors written to
+/// `stderr` against the program's `annotations`, describing any mismatch.
+fn check_annotations(
+    program_dir: &Path,
+    annotations: &[Annotation],
+    diagnostics: &[ReportedDiagnostic],
+    stderr: &str,
+) -> Result<(), String> {
+    if annotations.is_empty() {
+        return Err(format!(
+            "{} has no annotations, add a `~ERROR` comment for each expected error",
+            program_dir.display()
+        ));
+    }
+
+    let unmatched_annotations: Vec<_> = annotations
+        .iter()
+        .filter(|annotation| {
+            !annotation.matches_stderr(stderr)
+                && !diagnostics.iter().any(|diagnostic| annotation.matches(diagnostic, program_dir))
+        })
+        .map(ToString::to_string)
+        .collect();
+
+    let unexpected_errors: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            diagnostic.severity == Severity::Error
+                && diagnostic.is_within(program_dir)
+                && !annotations.iter().any(|annotation| annotation.matches(diagnostic, program_dir))
+        })
+        .map(ToString::to_string)
+        .collect();
+
+    if unmatched_annotations.is_empty() && unexpected_errors.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "diagnostics do not match the annotations of {}\n\nexpected but not reported:\n{}\n\nreported but not expected:\n{}\n\nstderr:\n{stderr}",
+        program_dir.display(),
+        unmatched_annotations.join("\n"),
+        unexpected_errors.join("\n"),
+    ))
+}
+
+/// Checks the diagnostics which `nargo --message-format json` wrote to `stdout`, and the errors it
+/// wrote to `stderr`, against the annotations of the program in `program_dir`.
+pub(crate) fn check_expected_diagnostics(program_dir: &Path, stdout: &[u8], stderr: &[u8]) {
+    let program_dir = fs::canonicalize(program_dir).unwrap();
+
+    // Any other output, such as the progress of the command, must be written to stderr.
+    let stdout = String::from_utf8_lossy(stdout);
+    let diagnostics: Vec<ReportedDiagnostic> = stdout
+        .lines()
+        .map(|line| {
+            serde_json::from_str(line)
+                .unwrap_or_else(|err| panic!("stdout should only hold JSON, got {line:?}: {err}"))
+        })
+        .collect();
+
+    let mut annotations = Vec::new();
+    collect_annotations(&program_dir, &mut annotations);
+
+    if let Err(mismatch) = check_annotations(
+        &program_dir,
+        &annotations,
+        &diagnostics,
+        &String::from_utf8_lossy(stderr),
+    ) {
+        panic!("{mismatch}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use serde_json::json;
+
+    use super::{check_annotations, parse_annotations, ReportedDiagnostic, Severity};
+
+    const SOURCE: &str = "fn main() {
+    let x: u8 = 300; // ~ERROR cannot fit into `u8`
+    let y = x;
+}
+";
+
+    /// Writes `SOURCE` as the entry point of a program in a new directory.
+    fn create_program() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let program_dir = fs::canonicalize(dir.path()).unwrap();
+        fs::create_dir(program_dir.join("src")).unwrap();
+        fs::write(program_dir.join("src/main.nr"), SOURCE).unwrap();
+        (dir, program_dir)
+    }
+
+    fn diagnostic(severity: &str, message: &str, file: &Path, line: usize) -> ReportedDiagnostic {
+        serde_json::from_value(json!({
+            "severity": severity,
+            "message": message,
+            "code": "E0401",
+            "spans": [{ "file": file, "line_start": line, "is_primary": true, "label": "" }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn carets_move_the_expected_line_up() {
+        let source = "let x = 1;\nlet y = 2;\n// ~^^ERROR first\n// ~WARNING  second  \n";
+        let annotations = parse_annotations(Path::new("src/main.nr"), source);
+
+        let parsed: Vec<_> = annotations
+            .iter()
+            .map(|annotation| (annotation.line, annotation.severity, annotation.text.as_str()))
+            .collect();
+        assert_eq!(parsed, vec![(1, Severity::Error, "first"), (4, Severity::Warning, "second")]);
+    }
+
+    #[test]
+    fn manifest_annotations_use_toml_comments() {
+        let source =
+            "[package]\nname = \"\" # ~ERROR Empty package name\n// ~ERROR not a comment\n";
+        let annotations = parse_annotations(Path::new("Nargo.toml"), source);
+
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].text, "Empty package name");
+    }
+
+    #[test]
+    #[should_panic(expected = "src/main.nr:1: expected `~ERROR` or `~WARNING`")]
+    fn rejects_invalid_severity() {
+        parse_annotations(Path::new("src/main.nr"), "fn main() {} // ~NOTE unused");
+    }
+
+    #[test]
+    #[should_panic(expected = "annotation points before the file")]
+    fn rejects_carets_before_the_file() {
+        parse_annotations(Path::new("src/main.nr"), "\n// ~^^ERROR text");
+    }
+
+    #[test]
+    fn matches_diagnostics_on_the_annotated_line() {
+        let (_dir, program_dir) = create_program();
+        let file = program_dir.join("src/main.nr");
+        let annotations = parse_annotations(&file, SOURCE);
+
+        let diagnostics = [
+            diagnostic("error", "The value `300` cannot fit into `u8`", &file, 2),
+            diagnostic("warning", "unused variable y", &file, 3),
+        ];
+        assert_eq!(check_annotations(&program_dir, &annotations, &diagnostics, ""), Ok(()));
+    }
+
+    #[test]
+    fn reports_unmatched_annotations() {
+        let (_dir, program_dir) = create_program();
+        let file = program_dir.join("src/main.nr");
+        let annotations = parse_annotations(&file, SOURCE);
+
+        // The error is reported on another line than the annotated one.
+        let diagnostics = [diagnostic("error", "cannot fit into `u8`", &file, 3)];
+        let mismatch = check_annotations(&program_dir, &annotations, &diagnostics, "").unwrap_err();
+
+        let (expected, unexpected) = mismatch.split_once("reported but not expected").unwrap();
+        assert!(expected.contains(&format!("{}:2: Error", file.display())));
+        assert!(unexpected.contains(&format!("{}:3: Error", file.display())));
+    }
+
+    #[test]
+    fn manifest_annotations_match_stderr() {
+        let (_dir, program_dir) = create_program();
+        let manifest = program_dir.join("Nargo.toml");
+        let annotations =
+            parse_annotations(&manifest, "[package]\n# ~ERROR cannot depend on binary packages\n");
+
+        let stderr = "Package `bin_dep` has type `bin` but you cannot depend on binary packages";
+        assert_eq!(check_annotations(&program_dir, &annotations, &[], stderr), Ok(()));
+        assert!(check_annotations(&program_dir, &annotations, &[], "").is_err());
+    }
+
+    #[test]
+    fn requires_annotations() {
+        let (_dir, program_dir) = create_program();
+        let mismatch = check_annotations(&program_dir, &[], &[], "").unwrap_err();
+        assert!(mismatch.contains("has no annotations"));
+    }
+}
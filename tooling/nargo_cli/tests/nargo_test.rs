@@ -0,0 +1,98 @@
+//! Tests of the reports and files written by `nargo test`, run against copies of the programs in
+//! `test_programs/noir_test_success` so that the repository is left untouched.
+
+use assert_cmd::prelude::*;
+use assert_fs::fixture::PathCopy;
+use assert_fs::TempDir;
+use predicates::prelude::*;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+/// Copies the `noir_test_success` program `name` into a temporary directory.
+fn copy_test_program(name: &str) -> TempDir {
+    let test_program_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_programs/noir_test_success")
+        .join(name);
+    let program_dir = TempDir::new().unwrap();
+    program_dir.copy_from(test_program_dir, &["**/*"]).unwrap();
+    program_dir
+}
+
+fn nargo_test(program_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(program_dir);
+    cmd.arg("test");
+    cmd
+}
+
+/// Returns the number of the line of `source` which contains `text`.
+fn line_of(source: &str, text: &str) -> usize {
+    source.lines().position(|line| line.contains(text)).expect("text not found in source") + 1
+}
+
+#[test]
+fn coverage_counts_untaken_branches_as_not_executed() {
+    let program_dir = copy_test_program("coverage_branches");
+    nargo_test(&program_dir).arg("--coverage").assert().success();
+
+    let lcov = std::fs::read_to_string(program_dir.join("target/coverage_branches.lcov")).unwrap();
+    let line_counts: BTreeMap<usize, usize> = lcov
+        .lines()
+        .filter_map(|line| line.strip_prefix("DA:"))
+        .map(|counts| {
+            let (line, count) = counts.split_once(',').unwrap();
+            (line.parse().unwrap(), count.parse().unwrap())
+        })
+        .collect();
+
+    let source = std::fs::read_to_string(program_dir.join("src/main.nr")).unwrap();
+    let then_branch = line_of(&source, "assert(x * x == 1)");
+    let else_branch = line_of(&source, "assert(x * x == 4)");
+    assert_eq!(line_counts.get(&then_branch), Some(&0), "then branch in:\n{lcov}");
+    assert!(
+        line_counts.get(&else_branch).is_some_and(|count| *count > 0),
+        "else branch in:\n{lcov}"
+    );
+}
+
+const SNAPSHOT: &str = "snapshots/test_println_output.snap";
+
+#[test]
+fn snapshot_tests_fail_when_output_does_not_match() {
+    let program_dir = copy_test_program("snapshot_output");
+    std::fs::write(program_dir.join(SNAPSHOT), "moving point\n").unwrap();
+
+    nargo_test(&program_dir)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Test output does not match the snapshot"));
+}
+
+#[test]
+fn snapshot_tests_fail_when_snapshot_is_missing() {
+    let program_dir = copy_test_program("snapshot_output");
+    std::fs::remove_file(program_dir.join(SNAPSHOT)).unwrap();
+
+    nargo_test(&program_dir).assert().failure().stderr(predicate::str::contains("does not exist"));
+    assert!(!program_dir.join(SNAPSHOT).exists());
+}
+
+#[test]
+fn update_snapshots_writes_missing_and_mismatched_snapshots() {
+    let program_dir = copy_test_program("snapshot_output");
+    let expected = std::fs::read_to_string(program_dir.join(SNAPSHOT)).unwrap();
+    let return_snapshot = program_dir.join("snapshots/test_return_value.snap");
+    let expected_return = std::fs::read_to_string(&return_snapshot).unwrap();
+    std::fs::write(program_dir.join(SNAPSHOT), "moving point\n").unwrap();
+    std::fs::remove_file(&return_snapshot).unwrap();
+
+    nargo_test(&program_dir).arg("--update-snapshots").assert().success();
+
+    assert_eq!(std::fs::read_to_string(program_dir.join(SNAPSHOT)).unwrap(), expected);
+    assert_eq!(std::fs::read_to_string(return_snapshot).unwrap(), expected_return);
+    nargo_test(&program_dir).assert().success();
+}
@@ -0,0 +1,124 @@
+//! This integration test checks that the fields written by `nargo prove --recursive --emit-fields`
+//! can be used as the inputs of an outer program which verifies the proof recursively.
+
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+use assert_fs::fixture::ChildPath;
+use assert_fs::prelude::{FileWriteStr, PathAssert, PathChild};
+use assert_fs::TempDir;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+fn nargo(program_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(program_dir);
+    cmd
+}
+
+/// Creates a package named `name` in `test_dir` with the given `main.nr`.
+fn new_package(test_dir: &TempDir, name: &str, main: &str) -> ChildPath {
+    let package_dir = test_dir.child(name);
+    Command::cargo_bin("nargo").unwrap().arg("new").arg(package_dir.path()).assert().success();
+    package_dir.child("src").child("main.nr").write_str(main).unwrap();
+    package_dir
+}
+
+/// Proves the package in `package_dir` with `--recursive --emit-fields` and returns the file
+/// holding its proof fields.
+fn prove_with_fields(package_dir: &ChildPath, name: &str) -> ChildPath {
+    nargo(package_dir.path())
+        .arg("prove")
+        .arg("--recursive")
+        .arg("--emit-fields")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Recursion inputs saved to"));
+
+    let fields_file = package_dir.child("proofs").child(format!("{name}.fields.toml"));
+    fields_file.assert(predicate::path::is_file());
+    // The fields are namespaced by the proof, so that several proofs can be verified together.
+    fields_file.assert(predicate::str::contains(format!("[{name}]")));
+    fields_file.assert(predicate::str::contains("key_hash"));
+    fields_file
+}
+
+#[test]
+fn outer_program_executes_with_emitted_proof_fields() {
+    let test_dir = TempDir::new().unwrap();
+
+    // The mock backend's proofs can't hold any public inputs.
+    let inner_dir =
+        new_package(&test_dir, "inner", "fn main(x: Field, y: Field) { assert(x != y); }");
+    inner_dir.child("Prover.toml").write_str("x = 1\ny = 2\n").unwrap();
+    let inner_fields = prove_with_fields(&inner_dir, "inner");
+
+    let other_dir = new_package(&test_dir, "other", "fn main(x: Field) { assert(x != 0); }");
+    other_dir.child("Prover.toml").write_str("x = 3\n").unwrap();
+    let other_fields = prove_with_fields(&other_dir, "other");
+
+    // The mock backend splits the verification key into 3 fields and the proof into 4.
+    let outer_dir = new_package(
+        &test_dir,
+        "outer",
+        r#"use dep::std;
+
+struct ProofFields {
+    verification_key: [Field; 3],
+    proof: [Field; 4],
+    public_inputs: [Field; 0],
+    key_hash: Field,
+}
+
+fn main(
+    inner: ProofFields,
+    other: ProofFields,
+    input_aggregation_object: [Field; 16]
+) -> pub [Field; 16] {
+    let aggregation_object = verify(inner, input_aggregation_object);
+    verify(other, aggregation_object)
+}
+
+fn verify(fields: ProofFields, input_aggregation_object: [Field; 16]) -> [Field; 16] {
+    std::verify_proof(
+        fields.verification_key.as_slice(),
+        fields.proof.as_slice(),
+        fields.public_inputs.as_slice(),
+        fields.key_hash,
+        input_aggregation_object
+    )
+}
+"#,
+    );
+    let input_aggregation_object =
+        format!("input_aggregation_object = [{}]\n", ["0"; 16].join(", "));
+    outer_dir.child("Prover.toml").write_str(&input_aggregation_object).unwrap();
+
+    nargo(outer_dir.path())
+        .arg("execute")
+        .arg("--proof-fields")
+        .arg(inner_fields.path())
+        .arg("--proof-fields")
+        .arg(other_fields.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Circuit witness successfully solved"));
+
+    // The proof fields can't redefine the outer program's own inputs.
+    outer_dir
+        .child("Prover.toml")
+        .write_str(&format!("{input_aggregation_object}[inner]\nkey_hash = 1\n"))
+        .unwrap();
+    nargo(outer_dir.path())
+        .arg("execute")
+        .arg("--proof-fields")
+        .arg(inner_fields.path())
+        .arg("--proof-fields")
+        .arg(other_fields.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("input `inner`"));
+}
@@ -0,0 +1,101 @@
+//! Tests that the loop invariant code motion and common subexpression elimination passes reduce
+//! the ACIR opcodes and executed Brillig opcodes of the test programs written for them.
+use std::path::{Path, PathBuf};
+
+use nargo::constants::PROVER_INPUT_FILE;
+use nargo::ops::{execute_circuit_with_coverage, DefaultForeignCallExecutor, OpcodeHits};
+use nargo::prepare_package;
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_abi::input_parser::Format;
+use noirc_driver::{compile_main, CompileOptions, CompiledProgram};
+
+fn program_dir(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_programs/execution_success")
+        .join(name)
+}
+
+/// Compiles the program in `program_dir`, with or without the passes under test.
+fn compile(program_dir: &Path, skip_redundancy_elimination: bool) -> CompiledProgram {
+    let toml_path = get_package_manifest(program_dir).unwrap();
+    let workspace =
+        resolve_workspace_from_toml(&toml_path, PackageSelection::DefaultOrAll, None).unwrap();
+    let package = workspace.into_iter().next().unwrap();
+
+    let (mut context, crate_id) =
+        prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
+    let options = CompileOptions {
+        skip_redundancy_elimination,
+        silence_warnings: true,
+        ..CompileOptions::default()
+    };
+    let (program, _) = compile_main(&mut context, crate_id, &options, None, true)
+        .unwrap_or_else(|_| panic!("{} should compile", program_dir.display()));
+    program
+}
+
+/// Returns the number of Brillig opcodes executed when running `program` with the inputs
+/// in the `Prover.toml` of `program_dir`.
+fn executed_brillig_opcodes(program_dir: &Path, program: &CompiledProgram) -> usize {
+    let inputs_path = program_dir.join(PROVER_INPUT_FILE).with_extension(Format::Toml.ext());
+    let inputs = std::fs::read_to_string(inputs_path).unwrap();
+    let inputs_map = Format::Toml.parse(&inputs, &program.abi).unwrap();
+    let initial_witness = program.abi.encode(&inputs_map, None).unwrap();
+
+    #[allow(deprecated)]
+    let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+    let mut hits = OpcodeHits::default();
+    execute_circuit_with_coverage(
+        &program.circuit,
+        initial_witness,
+        &blackbox_solver,
+        &mut DefaultForeignCallExecutor::new(false),
+        &program.debug,
+        &mut hits,
+    )
+    .expect("the program should execute");
+    hits.brillig_opcodes()
+}
+
+fn brillig_bytecode_len(program: &CompiledProgram) -> usize {
+    program.circuit.unconstrained_functions.iter().map(|function| function.bytecode.len()).sum()
+}
+
+#[test]
+fn reduces_acir_opcodes() {
+    let program_dir = program_dir("loop_invariant_code_motion");
+    let unoptimized = compile(&program_dir, true);
+    let optimized = compile(&program_dir, false);
+
+    // Once unrolled and flattened, constant folding already removes the repeated multiplications,
+    // so the opcodes saved are those of the division which the `if` repeats.
+    let (unoptimized_opcodes, optimized_opcodes) =
+        (unoptimized.circuit.opcodes.len(), optimized.circuit.opcodes.len());
+    assert!(
+        optimized_opcodes < unoptimized_opcodes,
+        "expected fewer than {unoptimized_opcodes} ACIR opcodes, got {optimized_opcodes}"
+    );
+}
+
+#[test]
+fn reduces_brillig_opcodes() {
+    let program_dir = program_dir("brillig_loop_invariant_code_motion");
+    let unoptimized = compile(&program_dir, true);
+    let optimized = compile(&program_dir, false);
+
+    // The multiplication hoisted out of the loop is executed once rather than on every iteration.
+    let unoptimized_executed = executed_brillig_opcodes(&program_dir, &unoptimized);
+    let optimized_executed = executed_brillig_opcodes(&program_dir, &optimized);
+    assert!(
+        optimized_executed < unoptimized_executed,
+        "expected fewer than {unoptimized_executed} executed Brillig opcodes, got {optimized_executed}"
+    );
+
+    // The multiplication in the `if` reuses the product computed before it.
+    let (unoptimized_len, optimized_len) =
+        (brillig_bytecode_len(&unoptimized), brillig_bytecode_len(&optimized));
+    assert!(
+        optimized_len < unoptimized_len,
+        "expected fewer than {unoptimized_len} Brillig opcodes, got {optimized_len}"
+    );
+}
@@ -0,0 +1,107 @@
+//! Tests that unconstrained programs run the same when their variables are spilled to memory
+//! because of a small `--brillig-register-file-size`.
+use std::path::{Path, PathBuf};
+
+use nargo::constants::PROVER_INPUT_FILE;
+use nargo::ops::{execute_circuit, DefaultForeignCallExecutor};
+use nargo::prepare_package;
+use nargo_toml::{get_package_manifest, resolve_workspace_from_toml, PackageSelection};
+use noirc_abi::input_parser::{Format, InputValue};
+use noirc_driver::{compile_main, CompileOptions, CompiledProgram};
+
+/// Programs keeping values live across loops, calls, and array and vector operations.
+const PROGRAMS: [&str; 6] = [
+    "brillig_register_spilling",
+    "brillig_loop",
+    "brillig_calls",
+    "brillig_recursion",
+    "brillig_arrays",
+    "brillig_slices",
+];
+
+const REGISTER_FILE_SIZES: [Option<usize>; 3] = [Some(4), Some(1), Some(0)];
+
+fn program_dir(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../test_programs/execution_success")
+        .join(name)
+}
+
+/// Compiles the program in `program_dir` as a whole to Brillig, using at most
+/// `register_file_size` registers for the variables of each function.
+fn compile(program_dir: &Path, register_file_size: Option<usize>) -> CompiledProgram {
+    let toml_path = get_package_manifest(program_dir).unwrap();
+    let workspace =
+        resolve_workspace_from_toml(&toml_path, PackageSelection::DefaultOrAll, None).unwrap();
+    let package = workspace.into_iter().next().unwrap();
+
+    let (mut context, crate_id) =
+        prepare_package(package, Box::new(|path| std::fs::read_to_string(path)));
+    let options = CompileOptions {
+        force_brillig: true,
+        brillig_register_file_size: register_file_size,
+        silence_warnings: true,
+        ..CompileOptions::default()
+    };
+    let (program, _) = compile_main(&mut context, crate_id, &options, None, true)
+        .unwrap_or_else(|_| panic!("{} should compile", program_dir.display()));
+    program
+}
+
+/// Executes `program` with the inputs in the `Prover.toml` of `program_dir` and returns its
+/// return value, if any.
+fn execute(program_dir: &Path, program: &CompiledProgram) -> Option<InputValue> {
+    let inputs_path = program_dir.join(PROVER_INPUT_FILE).with_extension(Format::Toml.ext());
+    let inputs = std::fs::read_to_string(inputs_path).unwrap();
+    let inputs_map = Format::Toml.parse(&inputs, &program.abi).unwrap();
+    let initial_witness = program.abi.encode(&inputs_map, None).unwrap();
+
+    #[allow(deprecated)]
+    let blackbox_solver = barretenberg_blackbox_solver::BarretenbergSolver::new();
+    let solved_witness = execute_circuit(
+        &program.circuit,
+        initial_witness,
+        &blackbox_solver,
+        &mut DefaultForeignCallExecutor::new(false),
+    )
+    .unwrap_or_else(|err| panic!("{} should execute: {err}", program_dir.display()));
+    let (_, return_value) = program.abi.decode(&solved_witness).unwrap();
+    return_value
+}
+
+fn brillig_bytecode_len(program: &CompiledProgram) -> usize {
+    program.circuit.unconstrained_functions.iter().map(|function| function.bytecode.len()).sum()
+}
+
+#[test]
+fn spilled_programs_run_as_without_spilling() {
+    for name in PROGRAMS {
+        let program_dir = program_dir(name);
+        let expected = execute(&program_dir, &compile(&program_dir, None));
+
+        for register_file_size in REGISTER_FILE_SIZES {
+            let program = compile(&program_dir, register_file_size);
+            assert_eq!(
+                execute(&program_dir, &program),
+                expected,
+                "{name} with register file size {register_file_size:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn small_register_files_spill_variables() {
+    let program_dir = program_dir("brillig_register_spilling");
+    let unrestricted = brillig_bytecode_len(&compile(&program_dir, None));
+
+    // Spilled variables are loaded from and stored to memory around the instructions using them.
+    for register_file_size in [Some(1), Some(0)] {
+        let spilled = brillig_bytecode_len(&compile(&program_dir, register_file_size));
+        assert!(
+            spilled > unrestricted,
+            "expected more than {unrestricted} Brillig opcodes with register file size \
+             {register_file_size:?}, got {spilled}"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Tests that `--strict-overflow` makes left shifts which discard set bits fail.
+
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use assert_fs::TempDir;
+use predicates::prelude::*;
+use std::process::Command;
+
+test_binary::build_test_binary_once!(mock_backend, "../backend_interface/test-binaries");
+
+const NARGO_TOML: &str = r#"
+[package]
+name = "foo"
+type = "bin"
+authors = [""]
+[dependencies]
+"#;
+
+const MAIN_NR: &str = r#"
+use dep::std;
+
+fn main(x: u8, shift: u8) -> pub u8 {
+    assert(std::wrapping_add(x, 255) == x - 1);
+    assert(std::wrapping_mul(x, 16) == 0xf0);
+    shifted(x, shift)
+}
+
+fn shifted(x: u8, shift: u8) -> u8 {
+    x << shift
+}
+
+#[test]
+fn test_unconstrained_shift() {
+    assert(unconstrained_shift(0x1f, 4) == 0xf0);
+}
+
+unconstrained fn unconstrained_shift(x: u8, shift: u8) -> u8 {
+    x << shift
+}
+"#;
+
+/// Creates a package which shifts `0x1f` left by four bits, discarding its top bit.
+fn package() -> TempDir {
+    let project_dir = TempDir::new().unwrap();
+    project_dir.child("Nargo.toml").write_str(NARGO_TOML).unwrap();
+    project_dir.child("src/main.nr").write_str(MAIN_NR).unwrap();
+    project_dir.child("Prover.toml").write_str("x = \"31\"\nshift = \"4\"").unwrap();
+    project_dir
+}
+
+fn nargo(project_dir: &TempDir, command: &str) -> Command {
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.env("NARGO_BACKEND_PATH", path_to_mock_backend());
+    cmd.arg("--program-dir").arg(project_dir.path());
+    cmd.arg(command);
+    cmd
+}
+
+#[test]
+fn left_shifts_discard_set_bits_by_default() {
+    let project_dir = package();
+
+    nargo(&project_dir, "execute")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Circuit output: Field(240)"));
+    nargo(&project_dir, "test").assert().success();
+}
+
+#[test]
+fn strict_overflow_fails_left_shifts_which_discard_set_bits() {
+    let project_dir = package();
+
+    // The wrapping methods are unaffected, so only the shift fails.
+    nargo(&project_dir, "execute")
+        .arg("--strict-overflow")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("attempt to left shift with overflow"));
+    nargo(&project_dir, "execute")
+        .arg("--strict-overflow")
+        .arg("--force-brillig")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("attempt to left shift with overflow"));
+    nargo(&project_dir, "test").arg("--strict-overflow").assert().failure();
+}
+
+#[test]
+fn strict_overflow_accepts_left_shifts_which_keep_every_bit() {
+    let project_dir = package();
+    project_dir.child("Prover.toml").write_str("x = \"15\"\nshift = \"4\"").unwrap();
+
+    nargo(&project_dir, "execute")
+        .arg("--strict-overflow")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Circuit output: Field(240)"));
+}
@@ -50,6 +50,12 @@ config! {
     array_width: usize, 100, "Maximum width of an array literal before falling back to vertical formatting";
     fn_call_width: usize, 60, "Maximum width of the args of a function call before falling back to vertical formatting";
     single_line_if_else_max_width: usize, 50, "Maximum line length for single line if-else expressions";
+    reorder_imports: bool, false, "Sort and reformat consecutive imports and the items of their use lists";
+    merge_imports: bool, false, "Merge consecutive imports sharing a root into a single use tree";
+    format_items: bool, false, "Format structs, traits, impls, globals, type aliases and module declarations instead of keeping them as written";
+    struct_trailing_comma: bool, false, "Put a comma after the last field of a struct declaration";
+    struct_field_align_threshold: usize, 0, "Maximum difference between struct field name lengths for their types to be aligned";
+    where_single_line: bool, true, "Keep where clauses on the same line as the signature when they fit";
 }
 
 impl Config {
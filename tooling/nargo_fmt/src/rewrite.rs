@@ -1,11 +1,13 @@
 mod array;
 mod expr;
+mod imports;
 mod infix;
 mod parenthesized;
 mod typ;
 
 pub(crate) use array::rewrite as array;
 pub(crate) use expr::{rewrite as expr, rewrite_sub_expr as sub_expr};
+pub(crate) use imports::rewrite as imports;
 pub(crate) use infix::rewrite as infix;
 pub(crate) use parenthesized::rewrite as parenthesized;
 pub(crate) use typ::rewrite as typ;
@@ -0,0 +1,286 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use noirc_frontend::{PathKind, UseTree, UseTreeKind};
+
+use crate::{
+    config::Config,
+    visitor::{Indent, Shape},
+};
+
+/// Rewrites a group of consecutive `use` statements, one statement per line.
+pub(crate) fn rewrite(config: &Config, shape: Shape, use_trees: Vec<UseTree>) -> String {
+    let mut trees: Vec<Tree> = use_trees.into_iter().map(Tree::from).collect();
+
+    if config.merge_imports {
+        let mut root = Trie::default();
+        for tree in trees {
+            for (path, alias) in tree.into_paths() {
+                root.insert(&path, alias);
+            }
+        }
+        trees = root.into_trees();
+    }
+
+    for tree in &mut trees {
+        tree.normalize(config.reorder_imports);
+    }
+
+    if config.reorder_imports {
+        trees.sort();
+    }
+    trees.dedup();
+
+    let separator = shape.indent.to_string_with_newline();
+    trees
+        .iter()
+        .map(|tree| format!("use {};", tree.rewrite(config, shape.indent, "use ".len(), 1)))
+        .collect::<Vec<_>>()
+        .join(&separator)
+}
+
+/// A use tree with its prefix split into segments, e.g. `dep::std::{hash, ec}` is made
+/// of the segments `dep`, `std` and the list `{hash, ec}`. Only the last segment may be
+/// a list or have an alias.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Tree {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Name { name: String, alias: Option<String> },
+    List(Vec<Tree>),
+}
+
+impl Segment {
+    fn name(name: String) -> Segment {
+        Segment::Name { name, alias: None }
+    }
+}
+
+impl From<UseTree> for Tree {
+    fn from(use_tree: UseTree) -> Tree {
+        let mut segments = Vec::new();
+
+        match use_tree.prefix.kind {
+            PathKind::Crate => segments.push(Segment::name("crate".to_string())),
+            PathKind::Dep => segments.push(Segment::name("dep".to_string())),
+            PathKind::Plain => {}
+        }
+        segments.extend(
+            use_tree.prefix.segments.into_iter().map(|ident| Segment::name(ident.0.contents)),
+        );
+        segments.push(match use_tree.kind {
+            UseTreeKind::Path(name, alias) => {
+                Segment::Name { name: name.0.contents, alias: alias.map(|alias| alias.0.contents) }
+            }
+            UseTreeKind::List(trees) => Segment::List(trees.into_iter().map(Tree::from).collect()),
+        });
+
+        Tree { segments }
+    }
+}
+
+impl Tree {
+    /// Sorts and deduplicates the items of lists, and replaces lists holding a single item by that item.
+    fn normalize(&mut self, reorder: bool) {
+        let Some(Segment::List(children)) = self.segments.last_mut() else {
+            return;
+        };
+
+        for child in children.iter_mut() {
+            child.normalize(reorder);
+        }
+        if reorder {
+            children.sort();
+        }
+        children.dedup();
+
+        if children.len() == 1 {
+            let child = children.pop().unwrap();
+            self.segments.pop();
+            self.segments.extend(child.segments);
+        }
+    }
+
+    /// Returns the full path and alias of every item imported by this tree.
+    fn into_paths(self) -> Vec<(Vec<String>, Option<String>)> {
+        let mut paths = Vec::new();
+        self.collect_paths(Vec::new(), &mut paths);
+        paths
+    }
+
+    fn collect_paths(
+        self,
+        mut prefix: Vec<String>,
+        paths: &mut Vec<(Vec<String>, Option<String>)>,
+    ) {
+        for segment in self.segments {
+            match segment {
+                Segment::Name { name, alias: None } => prefix.push(name),
+                Segment::Name { name, alias } => {
+                    prefix.push(name);
+                    paths.push((prefix, alias));
+                    return;
+                }
+                Segment::List(children) => {
+                    for child in children {
+                        child.collect_paths(prefix.clone(), paths);
+                    }
+                    return;
+                }
+            }
+        }
+        paths.push((prefix, None));
+    }
+
+    /// Lays the tree out on a single line if it fits, and otherwise puts each item of its
+    /// list on a line of its own.
+    ///
+    /// `offset` is the width already used on the first line and `suffix_width` the width
+    /// of what follows the tree on its last line.
+    fn rewrite(
+        &self,
+        config: &Config,
+        indent: Indent,
+        offset: usize,
+        suffix_width: usize,
+    ) -> String {
+        let single_line = self.to_string();
+        let width = indent.width() + offset + single_line.chars().count() + suffix_width;
+
+        let Some((Segment::List(children), prefix)) = self.segments.split_last() else {
+            return single_line;
+        };
+        if width <= config.max_width {
+            return single_line;
+        }
+
+        let mut nested_indent = indent;
+        nested_indent.block_indent(config);
+
+        let mut result = String::new();
+        for segment in prefix {
+            result.push_str(&format!("{segment}::"));
+        }
+        result.push('{');
+        for child in children {
+            result.push_str(&nested_indent.to_string_with_newline());
+            result.push_str(&child.rewrite(config, nested_indent, 0, 1));
+            result.push(',');
+        }
+        result.push_str(&indent.to_string_with_newline());
+        result.push('}');
+        result
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let segments: Vec<_> = self.segments.iter().map(ToString::to_string).collect();
+        write!(f, "{}", segments.join("::"))
+    }
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Segment::Name { name, alias: Some(alias) } => write!(f, "{name} as {alias}"),
+            Segment::Name { name, alias: None } => write!(f, "{name}"),
+            Segment::List(children) => {
+                let children: Vec<_> = children.iter().map(ToString::to_string).collect();
+                write!(f, "{{{}}}", children.join(", "))
+            }
+        }
+    }
+}
+
+impl Ord for Segment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (
+                Segment::Name { name, alias },
+                Segment::Name { name: other_name, alias: other_alias },
+            ) => compare_names(name, other_name).then_with(|| alias.cmp(other_alias)),
+            (Segment::Name { .. }, Segment::List(_)) => Ordering::Less,
+            (Segment::List(_), Segment::Name { .. }) => Ordering::Greater,
+            (Segment::List(children), Segment::List(other_children)) => {
+                children.cmp(other_children)
+            }
+        }
+    }
+}
+
+impl PartialOrd for Segment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders names as rustfmt does: `crate` and `dep` come first, followed by modules and functions
+/// in `snake_case`, then types in `CamelCase` and finally constants in `SCREAMING_SNAKE_CASE`.
+fn compare_names(name: &str, other_name: &str) -> Ordering {
+    fn rank(name: &str) -> u8 {
+        if name == "crate" || name == "dep" {
+            0
+        } else if name.starts_with(|c: char| c.is_lowercase() || c == '_') {
+            1
+        } else if name.chars().any(char::is_lowercase) {
+            2
+        } else {
+            3
+        }
+    }
+
+    rank(name).cmp(&rank(other_name)).then_with(|| name.cmp(other_name))
+}
+
+/// The paths imported by a group of use trees, used to merge the trees sharing a prefix.
+#[derive(Default)]
+struct Trie {
+    /// The aliases under which the path leading to this node is imported, if it is.
+    imports: Vec<Option<String>>,
+    children: Vec<(String, Trie)>,
+}
+
+impl Trie {
+    fn insert(&mut self, path: &[String], alias: Option<String>) {
+        let Some((name, rest)) = path.split_first() else {
+            if !self.imports.contains(&alias) {
+                self.imports.push(alias);
+            }
+            return;
+        };
+
+        let index = match self.children.iter().position(|(child, _)| child == name) {
+            Some(index) => index,
+            None => {
+                self.children.push((name.clone(), Trie::default()));
+                self.children.len() - 1
+            }
+        };
+        self.children[index].1.insert(rest, alias);
+    }
+
+    fn into_trees(self) -> Vec<Tree> {
+        let mut trees = Vec::new();
+
+        for (name, node) in self.children {
+            for alias in &node.imports {
+                let segment = Segment::Name { name: name.clone(), alias: alias.clone() };
+                trees.push(Tree { segments: vec![segment] });
+            }
+
+            let mut children = node.into_trees();
+            let mut segments = vec![Segment::name(name)];
+            match children.len() {
+                0 => continue,
+                1 => segments.extend(children.pop().unwrap().segments),
+                _ => segments.push(Segment::List(children)),
+            }
+            trees.push(Tree { segments });
+        }
+
+        trees
+    }
+}
@@ -3,7 +3,10 @@ use crate::visitor::{FmtVisitor, Shape};
 use noirc_frontend::hir::resolution::errors::Span;
 use noirc_frontend::lexer::Lexer;
 use noirc_frontend::token::Token;
-use noirc_frontend::{Expression, Ident, Param, Visibility};
+use noirc_frontend::{
+    Expression, Ident, ItemVisibility, Param, Pattern, UnresolvedType, UnresolvedTypeData,
+    Visibility,
+};
 
 pub(crate) fn changed_comment_content(original: &str, new: &str) -> bool {
     comments(original).ne(comments(new))
@@ -251,6 +254,13 @@ impl Item for Param {
     }
 
     fn format(self, visitor: &FmtVisitor, shape: Shape) -> String {
+        let is_mutable = matches!(self.pattern, Pattern::Mutable(..));
+        if let Some(self_param) =
+            format_self_param(pattern_ident(&self.pattern), &self.typ, is_mutable)
+        {
+            return self_param;
+        }
+
         let visibility = match self.visibility {
             Visibility::Public => "pub ",
             Visibility::Private => "",
@@ -262,6 +272,91 @@ impl Item for Param {
     }
 }
 
+fn pattern_ident(pattern: &Pattern) -> Option<&Ident> {
+    match pattern {
+        Pattern::Identifier(ident) => Some(ident),
+        Pattern::Mutable(pattern, _) => pattern_ident(pattern),
+        Pattern::Tuple(..) | Pattern::Struct(..) => None,
+    }
+}
+
+/// A parameter of a trait method. Unlike a [`Param`], it has no span covering `&mut` or `mut`
+/// in front of `self`, so its start is found in the source beforehand.
+pub(crate) struct TraitMethodParam {
+    pub(crate) start: u32,
+    pub(crate) name: Ident,
+    pub(crate) typ: UnresolvedType,
+}
+
+impl Item for TraitMethodParam {
+    fn span(&self) -> Span {
+        let end = self.typ.span.map_or(self.name.span().end(), |span| span.end());
+        (self.start..end).into()
+    }
+
+    fn format(self, visitor: &FmtVisitor, shape: Shape) -> String {
+        let before_name = visitor.slice(self.start..self.name.span().start());
+        let is_mutable = before_name.trim() == "mut";
+        if let Some(self_param) = format_self_param(Some(&self.name), &self.typ, is_mutable) {
+            return self_param;
+        }
+
+        let name = visitor.slice(self.name.span());
+        let ty = rewrite::typ(visitor, shape, self.typ);
+
+        format!("{name}: {ty}")
+    }
+}
+
+/// Returns `self`, `mut self` or `&mut self` if the parameter was written in one of these
+/// shorthand forms, whose type is implied rather than written in the source.
+fn format_self_param(
+    name: Option<&Ident>,
+    typ: &UnresolvedType,
+    is_mutable: bool,
+) -> Option<String> {
+    let name = name.filter(|name| name.0.contents == "self")?;
+    if typ.span != Some(name.span()) {
+        return None;
+    }
+
+    let self_param = if matches!(typ.typ, UnresolvedTypeData::MutableReference(_)) {
+        "&mut self"
+    } else if is_mutable {
+        "mut self"
+    } else {
+        "self"
+    };
+    Some(self_param.to_string())
+}
+
+/// A field of a struct definition, with its type aligned to those of the other fields
+/// if `aligned_width` is set. `start` is where the field's visibility, or its name if it
+/// has none, starts.
+pub(crate) struct StructField {
+    pub(crate) start: u32,
+    pub(crate) visibility: ItemVisibility,
+    pub(crate) name: Ident,
+    pub(crate) typ: UnresolvedType,
+    pub(crate) aligned_width: Option<usize>,
+}
+
+impl Item for StructField {
+    fn span(&self) -> Span {
+        (self.start..self.typ.span.unwrap().end()).into()
+    }
+
+    fn format(self, visitor: &FmtVisitor, shape: Shape) -> String {
+        let name = format!("{}{}", self.visibility, visitor.slice(self.name.span()));
+        let padding = self
+            .aligned_width
+            .map_or(String::new(), |width| " ".repeat(width - name.chars().count()));
+        let ty = rewrite::typ(visitor, shape, self.typ);
+
+        format!("{name}:{padding} {ty}")
+    }
+}
+
 impl Item for Ident {
     fn span(&self) -> Span {
         self.span()
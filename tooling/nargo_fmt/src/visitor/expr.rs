@@ -249,7 +249,7 @@ pub(crate) fn format_parens(
     format_seq(shape, "(", ")", visitor, trailing_comma, exprs, span, tactic, mode, reduce)
 }
 
-fn format_exprs(
+pub(crate) fn format_exprs(
     config: &Config,
     tactic: Tactic,
     trailing_comma: bool,
@@ -388,6 +388,7 @@ pub(crate) fn wrap_exprs(
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub(crate) enum Tactic {
     Horizontal,
+    Vertical,
     HorizontalVertical,
     LimitedHorizontalVertical(usize),
     Mixed,
@@ -409,6 +410,7 @@ impl Tactic {
                 _ if has_single_line_comment => return DefinitiveTactic::Vertical,
 
                 Tactic::Horizontal => return DefinitiveTactic::Horizontal,
+                Tactic::Vertical => return DefinitiveTactic::Vertical,
                 Tactic::LimitedHorizontalVertical(limit) => limit,
                 Tactic::HorizontalVertical | Tactic::Mixed => 100,
             };
@@ -1,18 +1,25 @@
 use noirc_frontend::{
+    ast,
     hir::resolution::errors::Span,
+    lexer::Lexer,
     parser::{Item, ItemKind},
     token::{Keyword, Token},
-    Distinctness, NoirFunction, ParsedModule, Visibility,
+    Distinctness, FunctionReturnType, Ident, ItemVisibility, LetStatement, NoirFunction,
+    NoirStruct, NoirTrait, NoirTraitImpl, NoirTypeAlias, ParsedModule, TraitBound, TraitImplItem,
+    TraitItem, TypeImpl, UnresolvedTraitConstraint, UnresolvedTypeData, Visibility,
 };
 
 use crate::{
     rewrite,
-    utils::{last_line_contains_single_line_comment, last_line_used_width, FindToken},
-    visitor::expr::{format_seq, NewlineMode},
+    utils::{
+        self, count_newlines, last_line_contains_single_line_comment, last_line_used_width,
+        FindToken, Item as _, StructField, TraitMethodParam,
+    },
+    visitor::expr::{format_exprs, format_seq, NewlineMode},
 };
 
 use super::{
-    expr::Tactic::{HorizontalVertical, LimitedHorizontalVertical},
+    expr::Tactic::{self, HorizontalVertical, LimitedHorizontalVertical},
     Shape,
 };
 
@@ -40,25 +47,7 @@ impl super::FmtVisitor<'_> {
         let parameters = func.def.parameters;
 
         if !func.def.generics.is_empty() {
-            let full_span = name_span.end()..params_open;
-            let start = name_span.end();
-            let end = self.span_after(full_span, Token::Greater).start();
-
-            let generics = func.def.generics;
-            let span = (start..end).into();
-            let generics = format_seq(
-                self.shape(),
-                "<",
-                ">",
-                self.fork(),
-                false,
-                generics,
-                span,
-                HorizontalVertical,
-                NewlineMode::IfContainsNewLine,
-                false,
-            );
-
+            let generics = self.format_generics(func.def.generics, name_span.end()..params_open);
             result.push_str(&generics);
         }
 
@@ -100,6 +89,14 @@ impl super::FmtVisitor<'_> {
 
         let maybe_comment = self.slice(params_end..func_span.start());
 
+        if !func.def.where_clause.is_empty() && !self.has_comments(params_end..func_span.start()) {
+            let used_width = last_line_used_width(&result, self.indent.width());
+            let (where_clause, vertical) =
+                self.format_where_clause(func.def.where_clause, used_width, true);
+            result.push_str(&where_clause);
+            return (result, vertical);
+        }
+
         (result.trim_end().to_string(), last_line_contains_single_line_comment(maybe_comment))
     }
 
@@ -111,6 +108,8 @@ impl super::FmtVisitor<'_> {
         params_end: u32,
     ) -> String {
         let mut result = String::new();
+        let where_clause_is_formatted =
+            !func.def.where_clause.is_empty() && !self.has_comments(params_end..func_span.start());
 
         if let Some(span) = return_type_span {
             result.push_str(" -> ");
@@ -127,41 +126,157 @@ impl super::FmtVisitor<'_> {
             result.push_str(&typ);
 
             let slice = self.slice(span.end()..func_span.start());
-            if !slice.trim().is_empty() {
+            if !slice.trim().is_empty() && !where_clause_is_formatted {
                 result.push_str(slice);
             }
-        } else {
+        } else if !where_clause_is_formatted {
             result.push_str(self.slice(params_end..func_span.start()));
         }
 
         result
     }
 
+    /// Formats the generic parameters of an item, which are found within `span`.
+    fn format_generics(&self, generics: Vec<Ident>, span: impl Into<Span>) -> String {
+        if generics.is_empty() {
+            return String::new();
+        }
+
+        let span = span.into();
+        let start = self.span_before(span, Token::Less).start();
+        let end = self.span_after(span, Token::Greater).start();
+
+        format_seq(
+            self.shape(),
+            "<",
+            ">",
+            self.fork(),
+            false,
+            generics,
+            (start..end).into(),
+            HorizontalVertical,
+            NewlineMode::IfContainsNewLine,
+            false,
+        )
+    }
+
+    /// Formats a where clause which follows a signature taking `used_width` on its last line.
+    ///
+    /// The clause is kept on the same line if it fits and `where_single_line` is set, and otherwise
+    /// starts on a new line with one constraint per line. The returned flag is set in the latter case,
+    /// as the block which follows must then open on a line of its own.
+    fn format_where_clause(
+        &self,
+        where_clause: Vec<UnresolvedTraitConstraint>,
+        used_width: usize,
+        has_block: bool,
+    ) -> (String, bool) {
+        if where_clause.is_empty() {
+            return (String::new(), false);
+        }
+
+        // The parser splits `T: A + B` into one constraint per bound, which we join back together.
+        let mut constraints: Vec<(String, Vec<String>)> = Vec::new();
+        for constraint in where_clause {
+            let typ = rewrite::typ(self, self.shape(), constraint.typ);
+            let bound = self.format_trait_bound(constraint.trait_bound);
+            match constraints.last_mut() {
+                Some((last_typ, bounds)) if *last_typ == typ => bounds.push(bound),
+                _ => constraints.push((typ, vec![bound])),
+            }
+        }
+        let constraints: Vec<_> = constraints
+            .into_iter()
+            .map(|(typ, bounds)| format!("{typ}: {}", bounds.join(" + ")))
+            .collect();
+
+        let single_line = format!(" where {}", constraints.join(", "));
+        let suffix_width = if has_block { 2 } else { 1 }; // 2 = ` {`, 1 = `;`
+        if self.config.where_single_line
+            && used_width + single_line.chars().count() + suffix_width <= self.config.max_width
+        {
+            return (single_line, false);
+        }
+
+        let mut nested_indent = self.indent;
+        nested_indent.block_indent(self.config);
+        let separator = format!(",{}", nested_indent.to_string_with_newline());
+
+        let mut result = self.indent.to_string_with_newline();
+        result.push_str("where");
+        result.push_str(&nested_indent.to_string_with_newline());
+        result.push_str(&constraints.join(&separator));
+        if has_block {
+            result.push(',');
+        }
+        (result, true)
+    }
+
+    fn format_trait_bound(&self, trait_bound: TraitBound) -> String {
+        let path = self.slice(trait_bound.trait_path.span);
+        if trait_bound.trait_generics.is_empty() {
+            return path.to_string();
+        }
+
+        let generics: Vec<_> = trait_bound
+            .trait_generics
+            .into_iter()
+            .map(|typ| rewrite::typ(self, self.shape(), typ))
+            .collect();
+        format!("{path}<{}>", generics.join(", "))
+    }
+
+    fn has_comments(&self, span: impl Into<Span>) -> bool {
+        utils::comments(self.slice(span)).next().is_some()
+    }
+
+    /// Returns the position of the first token within `span` which is not a comment.
+    fn first_token_start(&self, span: impl Into<Span>) -> u32 {
+        let span = span.into();
+        let slice = self.slice(span);
+        let offset = slice
+            .find_token_with(|token| {
+                !matches!(token, Token::LineComment(_, _) | Token::BlockComment(_, _))
+            })
+            .map_or(slice.len() as u32, |token| token.start());
+        span.start() + offset
+    }
+
+    /// Returns the position following the delimiter which closes the one at `open`.
+    fn closing_delimiter_end(&self, open: u32, end: u32) -> u32 {
+        let mut depth = 0;
+        for spanned in Lexer::new(self.slice(open..end)).flatten() {
+            match spanned.token() {
+                Token::LeftParen | Token::LeftBracket | Token::LeftBrace => depth += 1,
+                Token::RightParen | Token::RightBracket | Token::RightBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return open + spanned.to_span().end();
+                    }
+                }
+                _ => {}
+            }
+        }
+        end
+    }
+
     pub(crate) fn visit_file(&mut self, module: ParsedModule) {
         self.visit_module(module);
         self.format_missing_indent(self.source.len() as u32, false);
     }
 
     fn visit_module(&mut self, module: ParsedModule) {
-        for Item { kind, span } in module.items {
-            match kind {
-                ItemKind::Function(func) => {
-                    self.format_missing_indent(span.start(), true);
-
-                    if std::mem::take(&mut self.ignore_next_node) {
-                        self.push_str(self.slice(span));
-                        self.last_position = span.end();
-                        continue;
-                    }
+        let mut items = module.items.into_iter().peekable();
 
-                    let (fn_before_block, force_brace_newline) =
-                        self.format_fn_before_block(func.clone(), span.start());
-
-                    self.push_str(&fn_before_block);
-                    self.push_str(if force_brace_newline { "\n" } else { " " });
+        while let Some(Item { kind, span }) = items.next() {
+            if !self.formats(&kind) {
+                self.push_rewrite(self.slice(span).to_string(), span);
+                self.last_position = span.end();
+                continue;
+            }
 
-                    self.visit_block(func.def.body, func.def.span);
-                }
+            match kind {
+                ItemKind::Function(func) => self.visit_function(span, func),
                 ItemKind::Submodules(module) => {
                     self.format_missing_indent(span.start(), true);
 
@@ -177,7 +292,7 @@ impl super::FmtVisitor<'_> {
 
                     let keyword = if module.is_contract { "contract" } else { "mod" };
 
-                    self.push_str(&format!("{keyword} {name} "));
+                    self.push_str(&format!("{}{keyword} {name} ", module.visibility));
 
                     if module.contents.items.is_empty() {
                         self.visit_empty_block((after_brace - 1..span.end()).into());
@@ -191,18 +306,542 @@ impl super::FmtVisitor<'_> {
                     self.close_block((self.last_position..span.end() - 1).into());
                     self.last_position = span.end();
                 }
-                ItemKind::Import(_)
-                | ItemKind::Struct(_)
-                | ItemKind::Trait(_)
-                | ItemKind::TraitImpl(_)
-                | ItemKind::Impl(_)
-                | ItemKind::TypeAlias(_)
-                | ItemKind::Global(_)
-                | ItemKind::ModuleDecl(_) => {
-                    self.push_rewrite(self.slice(span).to_string(), span);
+                ItemKind::Import(use_tree, attributes, visibility) => {
+                    self.format_missing_indent(span.start(), true);
+
+                    if std::mem::take(&mut self.ignore_next_node)
+                        || self.has_comments(span)
+                        || !attributes.is_empty()
+                        || visibility != ItemVisibility::Private
+                    {
+                        self.push_str(self.slice(span));
+                        self.last_position = span.end();
+                        continue;
+                    }
+
+                    let mut use_trees = vec![use_tree];
+                    let mut end = span.end();
+                    while let Some(next) =
+                        items.next_if(|next| self.continues_import_group(end, next))
+                    {
+                        if let ItemKind::Import(use_tree, ..) = next.kind {
+                            use_trees.push(use_tree);
+                        }
+                        end = next.span.end();
+                    }
+
+                    let imports = rewrite::imports(self.config, self.shape(), use_trees);
+                    self.push_str(&imports);
+                    self.last_position = end;
+                }
+                ItemKind::Struct(noir_struct) => {
+                    let rewrite = self.rewrite_struct(noir_struct, span);
+                    self.push_rewrite(rewrite, span);
+                    self.last_position = span.end();
+                }
+                ItemKind::Global(global, visibility) => {
+                    let rewrite = self.rewrite_global(global, visibility, span);
+                    self.push_rewrite(rewrite, span);
+                    self.last_position = span.end();
+                }
+                ItemKind::TypeAlias(type_alias) => {
+                    let rewrite = self.rewrite_type_alias(type_alias, span);
+                    self.push_rewrite(rewrite, span);
                     self.last_position = span.end();
                 }
+                ItemKind::ModuleDecl(declaration) => {
+                    let rewrite = if self.has_comments(span) {
+                        self.slice(span).to_string()
+                    } else {
+                        format!("{declaration};")
+                    };
+                    self.push_rewrite(rewrite, span);
+                    self.last_position = span.end();
+                }
+                ItemKind::Impl(type_impl) => self.visit_impl(type_impl, span),
+                ItemKind::TraitImpl(trait_impl) => self.visit_trait_impl(trait_impl, span),
+                ItemKind::Trait(noir_trait) => self.visit_trait(noir_trait, span),
             }
         }
     }
+
+    /// Returns false for items which the config asks to keep as they were written.
+    fn formats(&self, kind: &ItemKind) -> bool {
+        match kind {
+            ItemKind::Function(_) | ItemKind::Submodules(_) => true,
+            ItemKind::Import(..) => self.config.reorder_imports || self.config.merge_imports,
+            _ => self.config.format_items,
+        }
+    }
+
+    fn visit_function(&mut self, span: Span, func: NoirFunction) {
+        self.format_missing_indent(span.start(), true);
+
+        if std::mem::take(&mut self.ignore_next_node) {
+            self.push_str(self.slice(span));
+            self.last_position = span.end();
+            return;
+        }
+
+        let (fn_before_block, force_brace_newline) =
+            self.format_fn_before_block(func.clone(), span.start());
+
+        self.push_str(&fn_before_block);
+        self.push_block_separator(force_brace_newline);
+
+        self.visit_block(func.def.body, func.def.span);
+    }
+
+    /// Pushes what separates a signature from the brace opening its block.
+    fn push_block_separator(&mut self, brace_on_new_line: bool) {
+        if brace_on_new_line {
+            self.push_str(&self.indent.to_string_with_newline());
+        } else {
+            self.push_str(" ");
+        }
+    }
+
+    /// Returns true if `next` is a plain `use` which can be sorted and merged together with the
+    /// group of imports ending at `end`: nothing but a single line break may separate them.
+    fn continues_import_group(&self, end: u32, next: &Item) -> bool {
+        let gap = self.slice(end..next.span.start());
+        matches!(
+            &next.kind,
+            ItemKind::Import(_, attributes, ItemVisibility::Private) if attributes.is_empty()
+        ) && gap.trim().is_empty()
+            && count_newlines(gap) <= 1
+            && !self.has_comments(next.span)
+    }
+
+    /// Visits the items of an `impl`, trait or trait `impl` whose body spans from the brace
+    /// at `open_brace` up to `end`.
+    fn visit_item_block(
+        &mut self,
+        open_brace: u32,
+        end: u32,
+        is_empty: bool,
+        visit_items: impl FnOnce(&mut Self),
+    ) {
+        self.last_position = open_brace + 1;
+
+        if is_empty {
+            self.visit_empty_block((open_brace..end).into());
+            return;
+        }
+
+        self.push_str("{");
+        self.indent.block_indent(self.config);
+        visit_items(self);
+
+        self.close_block((self.last_position..end - 1).into());
+        self.last_position = end;
+    }
+
+    fn rewrite_struct(&self, noir_struct: NoirStruct, span: Span) -> String {
+        let name_end = noir_struct.name.span().end();
+        let body_start = self.first_token_start(
+            name_end
+                + self.slice(name_end..span.end()).find_token_with(is_struct_body).unwrap().start()
+                ..span.end(),
+        );
+        let keyword_start = span.start()
+            + self.slice(span).find_token(Token::Keyword(Keyword::Struct)).unwrap().start();
+
+        if self.has_comments(keyword_start..body_start) {
+            return self.slice(span).to_string();
+        }
+
+        let attributes = self.slice(span.start()..keyword_start);
+        let generics = self.format_generics(noir_struct.generics, name_end..body_start);
+        let mut result = format!("{attributes}struct {}{generics}", noir_struct.name);
+
+        if noir_struct.fields.is_empty() {
+            let body = self.slice(body_start..span.end());
+            if self.has_comments(body_start..span.end()) {
+                result.push(' ');
+                result.push_str(body);
+            } else if body == ";" {
+                result.push(';');
+            } else {
+                result.push_str(" {}");
+            }
+            return result;
+        }
+
+        let name_widths = noir_struct.fields.iter().map(|field| {
+            let visibility = field.visibility.to_string();
+            visibility.chars().count() + field.name.0.contents.chars().count()
+        });
+        let max_name_width = name_widths.clone().max().unwrap();
+        let min_name_width = name_widths.min().unwrap();
+        let threshold = self.config.struct_field_align_threshold;
+        let aligned_width = (threshold > 0 && max_name_width - min_name_width <= threshold)
+            .then_some(max_name_width);
+
+        let mut field_start = body_start;
+        let fields: Vec<_> = noir_struct
+            .fields
+            .into_iter()
+            .map(|field| {
+                // A field's span starts at its name, so find where its visibility starts, if any.
+                let name_start = field.name.span().start();
+                let start = if field.visibility == ItemVisibility::Private {
+                    name_start
+                } else {
+                    let visibility_start = self
+                        .slice(field_start..name_start)
+                        .find_token(Token::Keyword(Keyword::Pub))
+                        .unwrap()
+                        .start();
+                    field_start + visibility_start
+                };
+                field_start = field.typ.span.unwrap().end();
+
+                let ast::StructField { visibility, name, typ } = field;
+                StructField { start, visibility, name, typ, aligned_width }
+            })
+            .collect();
+
+        let visitor = self.fork();
+        let mut nested_shape = visitor.shape();
+        nested_shape.indent.block_indent(self.config);
+
+        let fields_span = (body_start..span.end()).into();
+        let exprs: Vec<_> =
+            utils::Exprs::new(&visitor, nested_shape, fields_span, fields).collect();
+        let fields = format_exprs(
+            self.config,
+            Tactic::Vertical,
+            self.config.struct_trailing_comma,
+            exprs,
+            nested_shape,
+            false,
+        );
+
+        result.push_str(&format!(
+            " {{{}{fields}{}}}",
+            nested_shape.indent.to_string_with_newline(),
+            self.indent.to_string_with_newline()
+        ));
+        result
+    }
+
+    fn rewrite_global(
+        &self,
+        global: LetStatement,
+        visibility: ItemVisibility,
+        span: Span,
+    ) -> String {
+        if self.has_comments(span) || !global.attributes.is_empty() {
+            return self.slice(span).to_string();
+        }
+
+        let name = self.slice(global.pattern.span());
+        let typ = match global.r#type.typ {
+            UnresolvedTypeData::Unspecified => String::new(),
+            _ => format!(": {}", rewrite::typ(self, self.shape(), global.r#type)),
+        };
+        let expr = rewrite::sub_expr(self, self.shape(), global.expression);
+
+        format!("{visibility}global {name}{typ} = {expr};")
+    }
+
+    fn rewrite_type_alias(&self, type_alias: NoirTypeAlias, span: Span) -> String {
+        if self.has_comments(span) {
+            return self.slice(span).to_string();
+        }
+
+        let name_end = type_alias.name.span().end();
+        let generics = self
+            .format_generics(type_alias.generics, name_end..type_alias.typ.span.unwrap().start());
+        let typ = rewrite::typ(self, self.shape(), type_alias.typ);
+
+        format!("{}type {}{generics} = {typ};", type_alias.visibility, type_alias.name)
+    }
+
+    fn visit_impl(&mut self, type_impl: TypeImpl, span: Span) {
+        self.format_missing_indent(span.start(), true);
+
+        if std::mem::take(&mut self.ignore_next_node) {
+            self.push_str(self.slice(span));
+            self.last_position = span.end();
+            return;
+        }
+
+        let type_span = type_impl.type_span;
+        let open_brace = self.span_before(type_span.end()..span.end(), Token::LeftBrace).start();
+
+        let header = if self.has_comments(span.start()..open_brace) {
+            self.slice(span.start()..open_brace).trim_end().to_string()
+        } else {
+            let generics =
+                self.format_generics(type_impl.generics, span.start()..type_span.start());
+            let typ = rewrite::typ(self, self.shape(), type_impl.object_type);
+            format!("impl{generics} {typ}")
+        };
+        self.push_str(&header);
+        self.push_str(" ");
+
+        let methods = type_impl.methods;
+        self.visit_item_block(open_brace, span.end(), methods.is_empty(), |this| {
+            for method in methods {
+                this.visit_method(method);
+            }
+        });
+    }
+
+    fn visit_trait_impl(&mut self, trait_impl: NoirTraitImpl, span: Span) {
+        self.format_missing_indent(span.start(), true);
+
+        if std::mem::take(&mut self.ignore_next_node) {
+            self.push_str(self.slice(span));
+            self.last_position = span.end();
+            return;
+        }
+
+        let type_end = trait_impl.object_type.span.unwrap().end();
+        let open_brace = self.span_before(type_end..span.end(), Token::LeftBrace).start();
+
+        let (header, brace_on_new_line) = if self.has_comments(span.start()..open_brace) {
+            (self.slice(span.start()..open_brace).trim_end().to_string(), false)
+        } else {
+            let impl_start = span.start()
+                + self.slice(span).find_token(Token::Keyword(Keyword::Impl)).unwrap().start();
+            let attributes = self.slice(span.start()..impl_start);
+            let trait_start = trait_impl.trait_name.span.start();
+            let generics = self.format_generics(trait_impl.impl_generics, impl_start..trait_start);
+            let trait_bound = self.format_trait_bound(TraitBound {
+                trait_path: trait_impl.trait_name,
+                trait_id: None,
+                trait_generics: trait_impl.trait_generics,
+            });
+            let typ = rewrite::typ(self, self.shape(), trait_impl.object_type);
+
+            let mut header = format!("{attributes}impl{generics} {trait_bound} for {typ}");
+            let used_width = last_line_used_width(&header, self.indent.width());
+            let (where_clause, vertical) =
+                self.format_where_clause(trait_impl.where_clause, used_width, true);
+            header.push_str(&where_clause);
+            (header, vertical)
+        };
+        self.push_str(&header);
+        self.push_block_separator(brace_on_new_line);
+
+        let items = trait_impl.items;
+        self.visit_item_block(open_brace, span.end(), items.is_empty(), |this| {
+            for item in items {
+                this.visit_trait_impl_item(item, span.end());
+            }
+        });
+    }
+
+    fn visit_trait_impl_item(&mut self, item: TraitImplItem, block_end: u32) {
+        match item {
+            TraitImplItem::Function(method) => self.visit_method(method),
+            TraitImplItem::Type { name, alias } => {
+                let start = self.first_token_start(self.last_position..name.span().start());
+                let alias_end = alias.span.unwrap().end();
+                let end = self.span_after(alias_end..block_end, Token::Semicolon).start();
+
+                let typ = rewrite::typ(self, self.shape(), alias);
+                self.push_sub_item_rewrite(format!("type {name} = {typ};"), (start..end).into());
+            }
+            TraitImplItem::Constant(name, typ, value) => {
+                let start = self.first_token_start(self.last_position..name.span().start());
+                let end = self.span_after(value.span.end()..block_end, Token::Semicolon).start();
+
+                let typ = rewrite::typ(self, self.shape(), typ);
+                let value = rewrite::sub_expr(self, self.shape(), value);
+                self.push_sub_item_rewrite(
+                    format!("let {name}: {typ} = {value};"),
+                    (start..end).into(),
+                );
+            }
+        }
+    }
+
+    /// Visits a method of an `impl`, whose span starts with its attributes or modifiers.
+    fn visit_method(&mut self, method: NoirFunction) {
+        let start = self.first_token_start(self.last_position..method.name_ident().span().start());
+        let end = method.def.span.end();
+        self.visit_function((start..end).into(), method);
+    }
+
+    /// Pushes the rewrite of an item within a block, keeping it as written if it contains comments.
+    fn push_sub_item_rewrite(&mut self, rewrite: String, span: Span) {
+        let rewrite = if self.has_comments(span) { self.slice(span).to_string() } else { rewrite };
+        self.push_rewrite(rewrite, span);
+        self.last_position = span.end();
+    }
+
+    fn visit_trait(&mut self, noir_trait: NoirTrait, span: Span) {
+        self.format_missing_indent(span.start(), true);
+
+        if std::mem::take(&mut self.ignore_next_node) {
+            self.push_str(self.slice(span));
+            self.last_position = span.end();
+            return;
+        }
+
+        let name_end = noir_trait.name.span().end();
+        let open_brace = self.span_before(name_end..span.end(), Token::LeftBrace).start();
+
+        let (header, brace_on_new_line) = if self.has_comments(span.start()..open_brace) {
+            (self.slice(span.start()..open_brace).trim_end().to_string(), false)
+        } else {
+            let generics = self.format_generics(noir_trait.generics, name_end..open_brace);
+            let mut header =
+                format!("{}trait {}{generics}", noir_trait.visibility, noir_trait.name);
+            let used_width = last_line_used_width(&header, self.indent.width());
+            let (where_clause, vertical) =
+                self.format_where_clause(noir_trait.where_clause, used_width, true);
+            header.push_str(&where_clause);
+            (header, vertical)
+        };
+        self.push_str(&header);
+        self.push_block_separator(brace_on_new_line);
+
+        let items = noir_trait.items;
+        self.visit_item_block(open_brace, span.end(), items.is_empty(), |this| {
+            for item in items {
+                this.visit_trait_item(item, span.end());
+            }
+        });
+    }
+
+    fn visit_trait_item(&mut self, item: TraitItem, block_end: u32) {
+        match item {
+            TraitItem::Function { name, generics, parameters, return_type, where_clause, body } => {
+                let start = self.first_token_start(self.last_position..name.span().start());
+                let name_end = name.span().end();
+                let params_open = self.span_before(name_end..block_end, Token::LeftParen).start();
+                let params_end = self.closing_delimiter_end(params_open, block_end);
+
+                // The declaration ends at the first `;` or `{` which is not nested, e.g. in an array type.
+                let mut depth = 0;
+                let terminator = Lexer::new(self.slice(params_end..block_end))
+                    .flatten()
+                    .find(|spanned| match spanned.token() {
+                        Token::LeftParen | Token::LeftBracket => {
+                            depth += 1;
+                            false
+                        }
+                        Token::RightParen | Token::RightBracket => {
+                            depth -= 1;
+                            false
+                        }
+                        Token::Semicolon | Token::LeftBrace => depth == 0,
+                        _ => false,
+                    })
+                    .unwrap();
+                let terminator_start = params_end + terminator.to_span().start();
+                let end = match body {
+                    Some(_) => self.closing_delimiter_end(terminator_start, block_end),
+                    None => terminator_start + 1,
+                };
+
+                self.format_missing_indent(start, true);
+
+                if std::mem::take(&mut self.ignore_next_node)
+                    || self.has_comments(start..params_open)
+                    || self.has_comments(params_end..terminator_start)
+                {
+                    self.push_str(self.slice(start..end));
+                    self.last_position = end;
+                    return;
+                }
+
+                let mut result = format!("fn {name}");
+                result.push_str(&self.format_generics(generics, name_end..params_open));
+
+                let return_type = match return_type {
+                    FunctionReturnType::Default(_) => String::new(),
+                    FunctionReturnType::Ty(typ) => {
+                        format!(" -> {}", rewrite::typ(self, self.shape(), typ))
+                    }
+                };
+
+                let mut boundary = params_open + 1;
+                let mut params = Vec::with_capacity(parameters.len());
+                for (index, (name, typ)) in parameters.into_iter().enumerate() {
+                    if index > 0 {
+                        boundary =
+                            self.span_after(boundary..name.span().start(), Token::Comma).start();
+                    }
+                    let start = self.first_token_start(boundary..name.span().start());
+                    let param = TraitMethodParam { start, name, typ };
+                    boundary = param.span().end();
+                    params.push(param);
+                }
+
+                let parameters = if params.is_empty() {
+                    "()".to_string()
+                } else {
+                    let used_width = self.indent.width() + result.chars().count();
+                    let overhead = if return_type.is_empty() { 2 } else { 3 }; // 2 = `()`, 3 = `() `
+                    let one_line_budget = self.budget(used_width + return_type.len() + overhead);
+                    let shape = Shape { width: one_line_budget, indent: self.indent };
+
+                    format_seq(
+                        shape,
+                        "(",
+                        ")",
+                        self.fork(),
+                        false,
+                        params,
+                        (params_open..params_end).into(),
+                        LimitedHorizontalVertical(one_line_budget),
+                        NewlineMode::IfContainsNewLine,
+                        false,
+                    )
+                };
+                result.push_str(&parameters);
+                result.push_str(&return_type);
+
+                let used_width = last_line_used_width(&result, self.indent.width());
+                let (where_clause, brace_on_new_line) =
+                    self.format_where_clause(where_clause, used_width, body.is_some());
+                result.push_str(&where_clause);
+                self.push_str(&result);
+
+                match body {
+                    Some(body) => {
+                        self.push_block_separator(brace_on_new_line);
+                        self.visit_block(body, (terminator_start..end).into());
+                    }
+                    None => {
+                        self.push_str(";");
+                        self.last_position = end;
+                    }
+                }
+            }
+            TraitItem::Constant { name, typ, default_value } => {
+                let start = self.first_token_start(self.last_position..name.span().start());
+                let last_end = match &default_value {
+                    Some(value) => value.span.end(),
+                    None => typ.span.unwrap().end(),
+                };
+                let end = self.span_after(last_end..block_end, Token::Semicolon).start();
+
+                let typ = rewrite::typ(self, self.shape(), typ);
+                let default_value = default_value.map_or(String::new(), |value| {
+                    format!(" = {}", rewrite::sub_expr(self, self.shape(), value))
+                });
+                self.push_sub_item_rewrite(
+                    format!("let {name}: {typ}{default_value};"),
+                    (start..end).into(),
+                );
+            }
+            TraitItem::Type { name } => {
+                let start = self.first_token_start(self.last_position..name.span().start());
+                let end = self.span_after(name.span().end()..block_end, Token::Semicolon).start();
+
+                self.push_sub_item_rewrite(format!("type {name};"), (start..end).into());
+            }
+        }
+    }
+}
+
+fn is_struct_body(token: &Token) -> bool {
+    matches!(token, Token::LeftBrace | Token::Semicolon)
 }
@@ -359,6 +359,23 @@ impl Abi {
         Ok(witness_map.into())
     }
 
+    /// Encode a set of inputs as the field elements of each parameter in turn, in the order in
+    /// which the parameters are declared, independently of the witnesses they are assigned to.
+    pub fn encode_parameters(&self, input_map: &InputMap) -> Result<Vec<FieldElement>, AbiError> {
+        let mut encoded_parameters = Vec::new();
+        for param in &self.parameters {
+            let value = input_map
+                .get(&param.name)
+                .ok_or_else(|| AbiError::MissingParam(param.name.clone()))?
+                .clone();
+            if !value.matches_abi(&param.typ) {
+                return Err(AbiError::TypeMismatch { param: param.clone(), value });
+            }
+            encoded_parameters.extend(Self::encode_value(value, &param.typ)?);
+        }
+        Ok(encoded_parameters)
+    }
+
     fn encode_value(value: InputValue, abi_type: &AbiType) -> Result<Vec<FieldElement>, AbiError> {
         let mut encoded_value = Vec::new();
         match (value, abi_type) {
@@ -594,4 +611,32 @@ mod test {
         // We also decode the return value (we can do this immediately as we know it shares a witness with an input).
         assert_eq!(return_value.unwrap(), reconstructed_inputs["thing2"]);
     }
+
+    #[test]
+    fn parameters_are_encoded_in_declaration_order() {
+        let parameter = |name: &str, typ| AbiParameter {
+            name: name.to_string(),
+            typ,
+            visibility: AbiVisibility::Private,
+        };
+        let abi = Abi {
+            parameters: vec![
+                parameter("b", AbiType::Array { length: 2, typ: Box::new(AbiType::Field) }),
+                parameter("a", AbiType::Field),
+            ],
+            param_witnesses: BTreeMap::new(),
+            return_type: None,
+            return_witnesses: Vec::new(),
+        };
+        let field = |value: u128| FieldElement::from(value);
+        let inputs: InputMap = BTreeMap::from([
+            (
+                "b".to_string(),
+                InputValue::Vec(vec![InputValue::Field(field(1)), InputValue::Field(field(2))]),
+            ),
+            ("a".to_string(), InputValue::Field(field(3))),
+        ]);
+
+        assert_eq!(abi.encode_parameters(&inputs).unwrap(), vec![field(1), field(2), field(3)]);
+    }
 }